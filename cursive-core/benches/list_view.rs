@@ -0,0 +1,71 @@
+//! Measures the frame time of large layouts in a `ScrollView`, with and
+//! without virtualization.
+//!
+//! Run with `cargo bench -p cursive_core --bench list_view`.
+use cursive_core::event::{Event, Key};
+use cursive_core::traits::Scrollable;
+use cursive_core::views::{LinearLayout, ListView, TextView};
+use cursive_core::{backend, theme, Printer, Vec2, View};
+use std::time::{Duration, Instant};
+
+const ROWS: usize = 5_000;
+const FRAMES: u32 = 200;
+
+fn list_view(virtualized: bool) -> impl View {
+    let mut list = ListView::new().virtualized(virtualized);
+    for i in 0..ROWS {
+        list.add_child(
+            &format!("Row {}", i),
+            TextView::new(format!("Content of row number {}", i)),
+        );
+    }
+    list.scrollable()
+}
+
+fn linear_layout(virtualized: bool) -> impl View {
+    let mut layout = LinearLayout::vertical().virtualized(virtualized);
+    for i in 0..ROWS {
+        layout
+            .add_child(TextView::new(format!("Content of row number {}", i)));
+    }
+    layout.scrollable()
+}
+
+// Returns the average time to lay out and draw one frame.
+fn frame_time<V: View>(mut view: V) -> Duration {
+    let backend = backend::Dummy::init();
+    let theme = theme::load_default();
+    let size = Vec2::new(80, 40);
+
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        view.layout(size);
+        view.draw(&Printer::new(size, &theme, &*backend));
+        view.on_event(Event::Key(Key::Down));
+    }
+    start.elapsed() / FRAMES
+}
+
+fn compare(name: &str, full: Duration, virtualized: Duration) {
+    println!("{}:", name);
+    println!("  full:        {:?}", full);
+    println!("  virtualized: {:?}", virtualized);
+    println!(
+        "  speedup:     {:.1}x",
+        full.as_secs_f64() / virtualized.as_secs_f64()
+    );
+}
+
+fn main() {
+    println!("{} rows, average over {} frames.", ROWS, FRAMES);
+    compare(
+        "ListView",
+        frame_time(list_view(false)),
+        frame_time(list_view(true)),
+    );
+    compare(
+        "LinearLayout",
+        frame_time(linear_layout(false)),
+        frame_time(linear_layout(true)),
+    );
+}
@@ -72,6 +72,62 @@ pub enum HAlign {
     Right,
 }
 
+/// Direction in which text is written.
+///
+/// Right-to-left lines are reordered for display, keeping left-to-right
+/// words in their reading order. With the `bidi` feature, right-to-left
+/// words inside left-to-right text are reordered as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TextDirection {
+    /// Left-to-right, like English.
+    #[default]
+    Ltr,
+    /// Right-to-left, like Arabic or Hebrew.
+    ///
+    /// Horizontal alignment is mirrored.
+    Rtl,
+    /// Uses the direction of the first strong character in the text.
+    ///
+    /// Text without any strong character is left-to-right.
+    Auto,
+}
+
+impl TextDirection {
+    /// Returns the direction to use for `text`.
+    ///
+    /// This is never `TextDirection::Auto`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::align::TextDirection;
+    ///
+    /// let auto = TextDirection::Auto;
+    /// assert_eq!(auto.resolve("42 שלום"), TextDirection::Rtl);
+    /// assert_eq!(auto.resolve("Hi שלום"), TextDirection::Ltr);
+    /// assert_eq!(TextDirection::Rtl.resolve("Hi"), TextDirection::Rtl);
+    /// ```
+    pub fn resolve(self, text: &str) -> TextDirection {
+        match self {
+            TextDirection::Auto => {
+                crate::utils::bidi::paragraph_direction(text)
+            }
+            direction => direction,
+        }
+    }
+
+    /// Returns the alignment to use for text in this direction.
+    ///
+    /// Right-to-left text mirrors the alignment. `TextDirection::Auto`
+    /// should be resolved first.
+    pub fn align(self, align: HAlign) -> HAlign {
+        match self {
+            TextDirection::Rtl => align.mirrored(),
+            _ => align,
+        }
+    }
+}
+
 /// Vertical alignment
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum VAlign {
@@ -84,6 +140,15 @@ pub enum VAlign {
 }
 
 impl HAlign {
+    /// Returns the mirrored alignment: left becomes right and vice versa.
+    pub fn mirrored(self) -> Self {
+        match self {
+            HAlign::Left => HAlign::Right,
+            HAlign::Center => HAlign::Center,
+            HAlign::Right => HAlign::Left,
+        }
+    }
+
     /// Returns the offset required to position a view.
     ///
     /// When drawing a view with size `content` when the available size is
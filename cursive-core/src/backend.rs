@@ -10,8 +10,8 @@
 
 use crate::event::Event;
 use crate::theme;
+use crate::utils::width::TextWidth;
 use crate::Vec2;
-use unicode_width::UnicodeWidthStr;
 
 /// Trait defining the required methods to be a backend.
 ///
@@ -43,6 +43,26 @@ pub trait Backend {
     /// This usually sets the terminal window title.
     fn set_title(&mut self, title: String);
 
+    /// Temporarily gives the terminal back to the user.
+    ///
+    /// This should restore the terminal to its normal state (leave raw mode
+    /// and the alternate screen, show the cursor, ...), so another program
+    /// can use it until [`resume`] is called.
+    ///
+    /// The default implementation does nothing.
+    ///
+    /// [`resume`]: Backend::resume
+    fn suspend(&mut self) {}
+
+    /// Takes back control of the terminal after a call to [`suspend`].
+    ///
+    /// The screen will be cleared and redrawn afterwards.
+    ///
+    /// The default implementation does nothing.
+    ///
+    /// [`suspend`]: Backend::suspend
+    fn resume(&mut self) {}
+
     /// Refresh the screen.
     ///
     /// This will be called each frame after drawing has been done.
@@ -0,0 +1,671 @@
+//! Build views from a configuration value.
+//!
+//! This requires the `builder` feature to be enabled.
+//!
+//! A view tree can be described in a data file (JSON, YAML, ...), loaded
+//! into a [`Config`], and built with [`Context::build`]. Each view is an
+//! object with a single key, the type of the view, mapping to its
+//! parameters:
+//!
+//! ```yaml
+//! Dialog:
+//!   title: Settings
+//!   content:
+//!     LinearLayout:
+//!       children:
+//!         - TextView: Username
+//!         - EditView:
+//!             name: username
+//!             content: $user
+//!         - Checkbox:
+//!             name: notifications
+//!             checked: true
+//!   buttons:
+//!     - label: Save
+//!       callback: save
+//! ```
+//!
+//! * Any view can have a `name` parameter, in which case it will be wrapped
+//!   in a [`NamedView`].
+//! * String values starting with `$` refer to variables set with
+//!   [`Context::set_variable`].
+//! * Callbacks are referred to by the name they were registered with, using
+//!   [`Context::add_callback`].
+//!
+//! The following views are supported out of the box:
+//!
+//! * `Button`: `label`, `callback`
+//! * `Checkbox`: `checked`
+//! * `Dialog`: `title`, `content`, `buttons` (list of `label` and
+//!   `callback`, or a plain label for a dismiss button)
+//! * `EditView`: `content`, `secret`, `max_content_width`, `on_submit`
+//! * `LinearLayout`: `orientation` (`vertical` or `horizontal`), `children`
+//! * `SelectView`: `items` (list of labels, or of `label` and `value`),
+//!   `on_submit`
+//! * `TextView`: `content` (or directly the text)
+//!
+//! More can be added with [`Context::register`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursive_core::builder::{Config, Context};
+//!
+//! let config: Config = serde_json::from_str(
+//!     r#"{
+//!         "Dialog": {
+//!             "title": "Hello",
+//!             "content": { "TextView": "$greeting" },
+//!             "buttons": [ { "label": "Quit", "callback": "quit" } ]
+//!         }
+//!     }"#,
+//! )
+//! .unwrap();
+//!
+//! let mut context = Context::new();
+//! context.set_variable("greeting", "Hello world!");
+//! context.add_callback("quit", |s| s.quit());
+//!
+//! let view = context.build(&config).unwrap();
+//! ```
+//!
+//! [`NamedView`]: crate::views::NamedView
+use crate::direction::Orientation;
+use crate::event::Callback;
+use crate::view::View;
+use crate::views::{
+    Button, Checkbox, Dialog, EditView, LinearLayout, NamedView, SelectView,
+    TextView,
+};
+use crate::Cursive;
+use std::collections::HashMap;
+use std::fmt;
+
+#[cfg(feature = "watch")]
+use crate::{event::EventResult, views::FocusTracker};
+#[cfg(feature = "watch")]
+use std::{cell::RefCell, rc::Rc};
+
+/// Description of a view, as a JSON-like value.
+///
+/// Other formats (YAML, TOML, ...) can be converted to this through `serde`.
+pub type Config = serde_json::Value;
+
+/// Error encountered while building a view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildError {
+    // Path to the failing node, from the innermost segment.
+    path: Vec<String>,
+    message: String,
+}
+
+impl BuildError {
+    /// Creates a new error with the given message.
+    pub fn new<S: Into<String>>(message: S) -> Self {
+        BuildError {
+            path: Vec::new(),
+            message: message.into(),
+        }
+    }
+
+    /// Prepends a segment to the path of this error.
+    ///
+    /// Segments starting with `[` are array indices.
+    #[must_use]
+    pub fn at<S: Into<String>>(mut self, segment: S) -> Self {
+        self.path.push(segment.into());
+        self
+    }
+
+    /// Returns the path to the node that failed, like
+    /// `Dialog.content.LinearLayout.children[1]`.
+    pub fn path(&self) -> String {
+        let mut path = String::new();
+        for segment in self.path.iter().rev() {
+            if !path.is_empty() && !segment.starts_with('[') {
+                path.push('.');
+            }
+            path.push_str(segment);
+        }
+        path
+    }
+
+    /// Returns the error message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path(), self.message)
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// A view that can be built from a [`Config`].
+pub trait FromConfig: Sized {
+    /// Builds the view from its parameters.
+    ///
+    /// `config` is the value associated with the type name. `context` can
+    /// be used to read parameters and build child views.
+    fn from_config(
+        config: &Config,
+        context: &Context,
+    ) -> Result<Self, BuildError>;
+}
+
+type Builder = fn(&Config, &Context) -> Result<Box<dyn View>, BuildError>;
+
+fn build_boxed<V>(
+    config: &Config,
+    context: &Context,
+) -> Result<Box<dyn View>, BuildError>
+where
+    V: FromConfig + View,
+{
+    let view = V::from_config(config, context)?;
+
+    // Keep the concrete type, so the view can be found with `call_on_name`.
+    Ok(match context.get_string(config, "name")? {
+        Some(name) => context.named(name, view),
+        None => Box::new(view),
+    })
+}
+
+/// Holds everything needed to build views from a [`Config`].
+///
+/// This includes the known view types, the variables, and the callbacks.
+pub struct Context {
+    builders: HashMap<String, Builder>,
+    variables: HashMap<String, Config>,
+    callbacks: HashMap<String, Callback>,
+
+    // Set while building a watched layout: records the last focused name.
+    #[cfg(feature = "watch")]
+    pub(crate) focused: Option<Rc<RefCell<Option<String>>>>,
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Context {
+    /// Creates a new context, knowing about the core views.
+    pub fn new() -> Self {
+        let mut context = Context {
+            builders: HashMap::new(),
+            variables: HashMap::new(),
+            callbacks: HashMap::new(),
+            #[cfg(feature = "watch")]
+            focused: None,
+        };
+
+        context.register::<Button>("Button");
+        context.register::<Checkbox>("Checkbox");
+        context.register::<Dialog>("Dialog");
+        context.register::<EditView>("EditView");
+        context.register::<LinearLayout>("LinearLayout");
+        context.register::<SelectView<String>>("SelectView");
+        context.register::<TextView>("TextView");
+
+        context
+    }
+
+    /// Registers a new view type.
+    ///
+    /// Configs with a `type_name` key will be built with `V::from_config`.
+    pub fn register<V>(&mut self, type_name: &str)
+    where
+        V: FromConfig + View,
+    {
+        self.builders
+            .insert(type_name.to_string(), build_boxed::<V>);
+    }
+
+    /// Sets a variable, which can be referred to as `$name` in configs.
+    pub fn set_variable<S, C>(&mut self, name: S, value: C)
+    where
+        S: Into<String>,
+        C: Into<Config>,
+    {
+        self.variables.insert(name.into(), value.into());
+    }
+
+    /// Registers a callback, which can be referred to by name in configs.
+    pub fn add_callback<S, F>(&mut self, name: S, callback: F)
+    where
+        S: Into<String>,
+        F: 'static + Fn(&mut Cursive),
+    {
+        self.callbacks
+            .insert(name.into(), Callback::from_fn(callback));
+    }
+
+    /// Builds a view from the given config.
+    pub fn build(&self, config: &Config) -> Result<Box<dyn View>, BuildError> {
+        let object = config.as_object().ok_or_else(|| {
+            BuildError::new("expected an object with a view type as key")
+        })?;
+
+        let mut entries = object.iter();
+        let (type_name, params) = match (entries.next(), entries.next()) {
+            (Some(entry), None) => entry,
+            _ => {
+                return Err(BuildError::new(format!(
+                    "expected a single view type, found {} keys",
+                    object.len()
+                )))
+            }
+        };
+
+        let builder = self.builders.get(type_name).ok_or_else(|| {
+            BuildError::new(format!("unknown view type `{}`", type_name))
+                .at(type_name.as_str())
+        })?;
+
+        builder(params, self).map_err(|e| e.at(type_name.as_str()))
+    }
+
+    // Wraps `view` in a `NamedView`.
+    fn named<V: View>(&self, name: String, view: V) -> Box<dyn View> {
+        #[cfg(feature = "watch")]
+        {
+            if let Some(ref focused) = self.focused {
+                let focused = Rc::clone(focused);
+                let tracked = name.clone();
+                return Box::new(
+                    FocusTracker::new(NamedView::new(name, view)).on_focus(
+                        move |_| {
+                            *focused.borrow_mut() = Some(tracked.clone());
+                            EventResult::Ignored
+                        },
+                    ),
+                );
+            }
+        }
+
+        Box::new(NamedView::new(name, view))
+    }
+
+    /// Resolves variables: returns the variable value if `config` is a
+    /// `$name` string.
+    pub fn resolve<'a>(&'a self, config: &'a Config) -> &'a Config {
+        config
+            .as_str()
+            .and_then(|s| s.strip_prefix('$'))
+            .and_then(|name| self.variables.get(name))
+            .unwrap_or(config)
+    }
+
+    // Returns the (resolved) parameter `key`, if present.
+    fn get<'a>(&'a self, config: &'a Config, key: &str) -> Option<&'a Config> {
+        config
+            .get(key)
+            .map(|value| self.resolve(value))
+            .filter(|value| !value.is_null())
+    }
+
+    /// Reads a string parameter.
+    ///
+    /// Returns `Ok(None)` if the parameter is missing.
+    pub fn get_string(
+        &self,
+        config: &Config,
+        key: &str,
+    ) -> Result<Option<String>, BuildError> {
+        self.get(config, key)
+            .map(|value| {
+                value.as_str().map(String::from).ok_or_else(|| {
+                    BuildError::new("expected a string").at(key)
+                })
+            })
+            .transpose()
+    }
+
+    /// Reads a boolean parameter.
+    ///
+    /// Returns `Ok(None)` if the parameter is missing.
+    pub fn get_bool(
+        &self,
+        config: &Config,
+        key: &str,
+    ) -> Result<Option<bool>, BuildError> {
+        self.get(config, key)
+            .map(|value| {
+                value.as_bool().ok_or_else(|| {
+                    BuildError::new("expected a boolean").at(key)
+                })
+            })
+            .transpose()
+    }
+
+    /// Reads an integer parameter.
+    ///
+    /// Returns `Ok(None)` if the parameter is missing.
+    pub fn get_usize(
+        &self,
+        config: &Config,
+        key: &str,
+    ) -> Result<Option<usize>, BuildError> {
+        self.get(config, key)
+            .map(|value| {
+                value.as_u64().map(|n| n as usize).ok_or_else(|| {
+                    BuildError::new("expected a positive integer").at(key)
+                })
+            })
+            .transpose()
+    }
+
+    /// Reads a callback parameter, given by name.
+    ///
+    /// Returns `Ok(None)` if the parameter is missing.
+    pub fn get_callback(
+        &self,
+        config: &Config,
+        key: &str,
+    ) -> Result<Option<Callback>, BuildError> {
+        match self.get_string(config, key)? {
+            None => Ok(None),
+            Some(name) => match self.callbacks.get(&name) {
+                Some(callback) => Ok(Some(callback.clone())),
+                None => Err(BuildError::new(format!(
+                    "unknown callback `{}`",
+                    name
+                ))
+                .at(key)),
+            },
+        }
+    }
+
+    /// Reads a list parameter.
+    ///
+    /// Returns an empty list if the parameter is missing.
+    pub fn get_list<'a>(
+        &'a self,
+        config: &'a Config,
+        key: &str,
+    ) -> Result<&'a [Config], BuildError> {
+        match self.get(config, key) {
+            None => Ok(&[]),
+            Some(value) => value
+                .as_array()
+                .map(Vec::as_slice)
+                .ok_or_else(|| BuildError::new("expected a list").at(key)),
+        }
+    }
+
+    /// Builds the view given as parameter `key`.
+    ///
+    /// Returns `Ok(None)` if the parameter is missing.
+    pub fn get_view(
+        &self,
+        config: &Config,
+        key: &str,
+    ) -> Result<Option<Box<dyn View>>, BuildError> {
+        self.get(config, key)
+            .map(|value| self.build(value).map_err(|e| e.at(key)))
+            .transpose()
+    }
+
+    /// Builds each view from the list parameter `key`.
+    pub fn get_views(
+        &self,
+        config: &Config,
+        key: &str,
+    ) -> Result<Vec<Box<dyn View>>, BuildError> {
+        self.get_list(config, key)?
+            .iter()
+            .enumerate()
+            .map(|(i, value)| {
+                self.build(value)
+                    .map_err(|e| e.at(format!("[{}]", i)).at(key))
+            })
+            .collect()
+    }
+}
+
+impl FromConfig for TextView {
+    fn from_config(
+        config: &Config,
+        context: &Context,
+    ) -> Result<Self, BuildError> {
+        // The content can be given directly.
+        if let Some(content) = context.resolve(config).as_str() {
+            return Ok(TextView::new(content));
+        }
+
+        let content = context.get_string(config, "content")?;
+        Ok(TextView::new(content.unwrap_or_default()))
+    }
+}
+
+impl FromConfig for Button {
+    fn from_config(
+        config: &Config,
+        context: &Context,
+    ) -> Result<Self, BuildError> {
+        let label = context.get_string(config, "label")?.unwrap_or_default();
+        let button = match context.get_callback(config, "callback")? {
+            Some(callback) => Button::new(label, move |s| callback(s)),
+            None => Button::new(label, |_| ()),
+        };
+        Ok(button)
+    }
+}
+
+impl FromConfig for Checkbox {
+    fn from_config(
+        config: &Config,
+        context: &Context,
+    ) -> Result<Self, BuildError> {
+        let checked = context.get_bool(config, "checked")?.unwrap_or(false);
+        Ok(Checkbox::new().with_checked(checked))
+    }
+}
+
+impl FromConfig for EditView {
+    fn from_config(
+        config: &Config,
+        context: &Context,
+    ) -> Result<Self, BuildError> {
+        let mut view = EditView::new();
+
+        if let Some(content) = context.get_string(config, "content")? {
+            view.set_content(content);
+        }
+        if let Some(secret) = context.get_bool(config, "secret")? {
+            view.set_secret(secret);
+        }
+        if let Some(width) = context.get_usize(config, "max_content_width")? {
+            view.set_max_content_width(Some(width));
+        }
+        if let Some(callback) = context.get_callback(config, "on_submit")? {
+            view.set_on_submit(move |s, _| callback(s));
+        }
+
+        Ok(view)
+    }
+}
+
+impl FromConfig for SelectView<String> {
+    fn from_config(
+        config: &Config,
+        context: &Context,
+    ) -> Result<Self, BuildError> {
+        let mut view = SelectView::new();
+
+        for (i, item) in context.get_list(config, "items")?.iter().enumerate()
+        {
+            let item = context.resolve(item);
+            if let Some(label) = item.as_str() {
+                view.add_item_str(label);
+                continue;
+            }
+
+            let at = |e: BuildError| e.at(format!("[{}]", i)).at("items");
+            let label = context
+                .get_string(item, "label")
+                .map_err(at)?
+                .ok_or_else(|| at(BuildError::new("missing `label`")))?;
+            let value = context
+                .get_string(item, "value")
+                .map_err(at)?
+                .unwrap_or_else(|| label.clone());
+            view.add_item(label, value);
+        }
+
+        if let Some(callback) = context.get_callback(config, "on_submit")? {
+            view.set_on_submit(move |s, _: &String| callback(s));
+        }
+
+        Ok(view)
+    }
+}
+
+impl FromConfig for LinearLayout {
+    fn from_config(
+        config: &Config,
+        context: &Context,
+    ) -> Result<Self, BuildError> {
+        let orientation = match context.get_string(config, "orientation")? {
+            None => Orientation::Vertical,
+            Some(orientation) => match orientation.as_str() {
+                "vertical" => Orientation::Vertical,
+                "horizontal" => Orientation::Horizontal,
+                other => {
+                    return Err(BuildError::new(format!(
+                        "unknown orientation `{}`",
+                        other
+                    ))
+                    .at("orientation"))
+                }
+            },
+        };
+
+        let mut layout = LinearLayout::new(orientation);
+        for child in context.get_views(config, "children")? {
+            layout.add_child(child);
+        }
+        Ok(layout)
+    }
+}
+
+impl FromConfig for Dialog {
+    fn from_config(
+        config: &Config,
+        context: &Context,
+    ) -> Result<Self, BuildError> {
+        let mut dialog = match context.get_view(config, "content")? {
+            Some(content) => Dialog::around(content),
+            None => Dialog::new(),
+        };
+
+        if let Some(title) = context.get_string(config, "title")? {
+            dialog.set_title(title);
+        }
+
+        for (i, button) in
+            context.get_list(config, "buttons")?.iter().enumerate()
+        {
+            let button = context.resolve(button);
+
+            // A plain label is a dismiss button.
+            if let Some(label) = button.as_str() {
+                dialog.add_button(label, |s| {
+                    s.pop_layer();
+                });
+                continue;
+            }
+
+            let at = |e: BuildError| e.at(format!("[{}]", i)).at("buttons");
+            let label = context
+                .get_string(button, "label")
+                .map_err(at)?
+                .ok_or_else(|| at(BuildError::new("missing `label`")))?;
+            match context.get_callback(button, "callback").map_err(at)? {
+                Some(callback) => {
+                    dialog.add_button(label, move |s| callback(s))
+                }
+                None => dialog.add_button(label, |_| ()),
+            }
+        }
+
+        Ok(dialog)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::ViewWrapper;
+
+    fn parse(json: &str) -> Config {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn build_tree() {
+        let mut context = Context::new();
+        context.set_variable("user", "alice");
+        context.add_callback("save", |_| ());
+
+        let config = parse(
+            r#"{ "Dialog": {
+                "title": "Settings",
+                "content": { "LinearLayout": { "children": [
+                    { "TextView": "Username" },
+                    { "EditView": { "name": "user", "content": "$user" } },
+                    { "SelectView": { "items": ["a", { "label": "b" }] } }
+                ] } },
+                "buttons": [ { "label": "Save", "callback": "save" } ]
+            } }"#,
+        );
+
+        let view = context.build(&config).unwrap();
+        let dialog = view.downcast_ref::<Dialog>().unwrap();
+        assert_eq!(dialog.get_title(), "Settings");
+        assert_eq!(dialog.buttons().count(), 1);
+
+        let layout =
+            dialog.get_content().downcast_ref::<LinearLayout>().unwrap();
+        assert_eq!(layout.len(), 3);
+
+        let named = layout
+            .get_child(1)
+            .unwrap()
+            .downcast_ref::<NamedView<EditView>>()
+            .unwrap();
+        assert_eq!(named.name(), "user");
+        let content = named.with_view(|v| v.get_content()).unwrap();
+        assert_eq!(&*content, "alice");
+    }
+
+    #[test]
+    fn error_path() {
+        let context = Context::new();
+
+        let config = parse(
+            r#"{ "Dialog": { "content": { "LinearLayout": { "children": [
+                { "TextView": "ok" },
+                { "Button": { "label": "Go", "callback": "missing" } }
+            ] } } } }"#,
+        );
+        let error = context.build(&config).err().unwrap();
+        assert_eq!(
+            error.path(),
+            "Dialog.content.LinearLayout.children[1].Button.callback"
+        );
+        assert_eq!(error.message(), "unknown callback `missing`");
+
+        let error = context.build(&parse(r#"{ "Foo": {} }"#)).err().unwrap();
+        assert_eq!(error.to_string(), "Foo: unknown view type `Foo`");
+    }
+}
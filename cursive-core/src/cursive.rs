@@ -1,23 +1,40 @@
 use std::any::Any;
+use std::collections::HashMap;
+use std::future::Future;
+use std::io;
 use std::num::NonZeroU32;
-#[cfg(feature = "toml")]
 use std::path::Path;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+use web_time::Instant;
 
-use crossbeam_channel::{self, Receiver, Sender};
+use crossbeam_channel::{self, Receiver, SendError, Sender};
 
 use crate::{
+    align::Align,
     backend,
-    cursive_run::CursiveRunner,
+    cursive_run::{CursiveRunner, ExitReason},
     direction,
-    event::{Event, EventResult},
+    event::{Callback, Event, EventResult, MouseButton, MouseEvent},
+    help,
+    notification::{self, Notification, NotificationId},
     printer::Printer,
-    theme,
+    recording, theme,
+    utils::markup::StyledString,
     view::{self, Finder, IntoBoxedView, Position, View, ViewNotFound},
     views::{self, LayerPosition},
-    Dump, Vec2,
+    Dump, Rect, Vec2,
 };
 
+#[cfg(feature = "watch")]
+use crate::{builder, layout_watch};
+
 static DEBUG_VIEW_NAME: &str = "_cursive_debug_view";
+static HELP_VIEW_NAME: &str = "_cursive_help_view";
+static CONFIRM_VIEW_NAME: &str = "_cursive_confirm_view";
 
 type RootView = views::OnEventView<views::ScreensView<views::StackView>>;
 
@@ -36,8 +53,18 @@ pub struct Cursive {
 
     menubar: views::Menubar,
 
+    statusbar: views::Statusbar,
+
+    // Notifications currently shown, from the oldest.
+    notifications: Vec<notification::Toast>,
+    next_notification_id: notification::NotificationId,
+    notification_align: Align,
+
     pub(crate) needs_clear: bool,
 
+    // Set when something changed since the last frame.
+    pub(crate) needs_redraw: bool,
+
     running: bool,
 
     // Handle asynchronous callbacks
@@ -52,14 +79,117 @@ pub struct Cursive {
     // Handle auto-refresh when no event is received.
     fps: Option<NonZeroU32>,
 
+    // When this instance was created.
+    start_time: Instant,
+
+    // When the last frame was drawn, if any.
+    last_frame: Option<Instant>,
+
+    // Time between the last two frames.
+    frame_delta: Duration,
+
     // List of callbacks to run on the backend.
-    // The current assumption is that we only add calls here during event processing.
+    // The current assumption is that we only add calls here during event or
+    // callback processing.
     pub(crate) backend_calls: Vec<Box<dyn FnOnce(&mut dyn backend::Backend)>>,
+
+    // If set, input events are written to a file.
+    recorder: Option<recording::Recorder>,
+
+    // `true` while the terminal is handed back to the user.
+    paused: bool,
+
+    // Named views included in `search()`.
+    searchables: Vec<SearchEntry>,
+
+    // Commands shown in the command palette, in registration order.
+    commands: Vec<views::Command>,
+
+    // How many times each command was run from the palette, by name.
+    command_usage: HashMap<String, usize>,
+
+    // Called before quitting, see `set_on_pre_quit()`.
+    on_pre_quit: Option<Rc<QuitGuard>>,
+
+    // Called before popping layers, by name, in registration order.
+    // See `guard_layer()`.
+    layer_guards: Vec<(String, Rc<QuitGuard>)>,
+
+    // Action waiting for the confirmation dialog of a guard.
+    pending_confirm: Option<GuardTarget>,
+
+    // `true` while a guard is running.
+    in_guard: bool,
+
+    // Called when the active screen changes, see `set_on_screen_change()`.
+    on_screen_change: Option<Rc<ScreenChangeCb>>,
+
+    // Called when the highlighted menu item changes, see
+    // `set_on_menu_help()`.
+    on_menu_help: Option<Rc<MenuHelpCb>>,
+
+    // Layout files rebuilt when they change, by mount point name.
+    #[cfg(feature = "watch")]
+    layout_watches: HashMap<String, layout_watch::LayoutWatch>,
+
+    // Timings of the last refresh, set by the runner.
+    #[cfg(feature = "frame-stats")]
+    pub(crate) last_frame_stats: Option<crate::FrameStats>,
+}
+
+// A named view registered with `register_searchable()`.
+//
+// The functions know the concrete type of the view.
+struct SearchEntry {
+    name: String,
+    search: fn(&mut Cursive, &str, &str) -> Vec<view::SearchMatch>,
+    reveal: fn(&mut Cursive, &str, &view::SearchMatch),
 }
 
 /// Identifies a screen in the cursive root.
 pub type ScreenId = usize;
 
+/// What to do when quitting, or popping a guarded layer.
+///
+/// Returned by the callbacks given to [`Cursive::set_on_pre_quit`] and
+/// [`Cursive::guard_layer`].
+pub enum QuitAction {
+    /// Go ahead.
+    Quit,
+
+    /// Do nothing.
+    Cancel,
+
+    /// Show this dialog first.
+    ///
+    /// The dialog buttons decide what happens. Calling again the guarded
+    /// method (`quit()` or `pop_layer()`) from one of them bypasses the
+    /// guard.
+    ///
+    /// See [`QuitAction::confirm`].
+    Confirm(Box<views::Dialog>),
+}
+
+impl QuitAction {
+    /// Shows the given dialog first.
+    pub fn confirm(dialog: views::Dialog) -> Self {
+        QuitAction::Confirm(Box::new(dialog))
+    }
+}
+
+type QuitGuard = dyn Fn(&mut Cursive) -> QuitAction;
+
+type ScreenChangeCb = dyn Fn(&mut Cursive, ScreenId, ScreenId);
+
+type MenuHelpCb = dyn Fn(&mut Cursive, Option<&str>);
+
+// Action protected by a guard.
+#[derive(Clone, PartialEq, Eq)]
+enum GuardTarget {
+    Quit,
+    Layer(String),
+}
+
 /// Convenient alias to the result of `Cursive::cb_sink`.
 ///
 /// # Notes
@@ -71,6 +201,117 @@ pub type ScreenId = usize;
 /// [`send_wrapper`]: https://crates.io/crates/send_wrapper
 pub type CbSink = Sender<Box<dyn FnOnce(&mut Cursive) + Send>>;
 
+// Callback sent through a `CbSink`.
+type SinkCallback = Box<dyn FnOnce(&mut Cursive) + Send>;
+
+/// Sender for callbacks, usable from async tasks.
+///
+/// Returned by [`Cursive::cb_sink_async`].
+///
+/// [`send_async`](CbSinkAsync::send_async) completes once the event loop has
+/// run the callback, and gives back its result. The task waits without
+/// blocking its thread, and the event loop never waits for the task.
+#[derive(Clone)]
+pub struct CbSinkAsync {
+    sink: CbSink,
+}
+
+impl CbSinkAsync {
+    /// Sends a callback to the event loop, and waits until it ran.
+    ///
+    /// Returns the value returned by the callback, or `None` if the
+    /// `Cursive` root was dropped before running it.
+    pub async fn send_async<F, R>(&self, cb: F) -> Option<R>
+    where
+        F: FnOnce(&mut Cursive) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let reply = Arc::new(Mutex::new(Reply {
+            value: None,
+            done: false,
+            waker: None,
+        }));
+        let sender = ReplySender(Arc::clone(&reply));
+
+        // If the callback never runs, dropping it completes the reply.
+        if self
+            .sink
+            .send(Box::new(move |s| sender.send(cb(s))))
+            .is_err()
+        {
+            return None;
+        }
+
+        ReplyFuture(reply).await
+    }
+
+    /// Sends a callback to be run by the event loop, from a non-async
+    /// context.
+    ///
+    /// This does not wait for the callback to run.
+    pub fn send(
+        &self,
+        cb: SinkCallback,
+    ) -> Result<(), SendError<SinkCallback>> {
+        self.sink.send(cb)
+    }
+
+    /// Returns the synchronous sender used by this sink.
+    pub fn sync_sink(&self) -> &CbSink {
+        &self.sink
+    }
+}
+
+// Result of a callback sent with `CbSinkAsync::send_async`.
+struct Reply<R> {
+    value: Option<R>,
+
+    // Set once the callback ran, or was dropped without running.
+    done: bool,
+
+    // Task waiting for the reply.
+    waker: Option<Waker>,
+}
+
+// Sending half of a `Reply`, moved into the callback.
+//
+// The reply is completed when this is dropped, so the task never waits for a
+// callback that will not run.
+struct ReplySender<R>(Arc<Mutex<Reply<R>>>);
+
+impl<R> ReplySender<R> {
+    fn send(self, value: R) {
+        self.0.lock().unwrap().value = Some(value);
+    }
+}
+
+impl<R> Drop for ReplySender<R> {
+    fn drop(&mut self) {
+        let mut reply = self.0.lock().unwrap();
+        reply.done = true;
+        if let Some(waker) = reply.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+// Waits for a `Reply` to complete.
+struct ReplyFuture<R>(Arc<Mutex<Reply<R>>>);
+
+impl<R> Future for ReplyFuture<R> {
+    type Output = Option<R>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<R>> {
+        let mut reply = self.0.lock().unwrap();
+        if reply.done {
+            Poll::Ready(reply.value.take())
+        } else {
+            reply.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
 new_default!(Cursive);
 
 impl Cursive {
@@ -90,16 +331,40 @@ impl Cursive {
             theme,
             root: views::OnEventView::new(views::ScreensView::single_screen(
                 views::StackView::new(),
-            )),
+            ))
+            .help_scope(help::GLOBAL_SCOPE),
             menubar: views::Menubar::new(),
+            statusbar: views::Statusbar::new(),
+            notifications: Vec::new(),
+            next_notification_id: 0,
+            notification_align: Align::bot_right(),
             last_size: Vec2::zero(),
             needs_clear: true,
+            needs_redraw: true,
             running: true,
             cb_source,
             cb_sink,
             fps: None,
+            start_time: Instant::now(),
+            last_frame: None,
+            frame_delta: Duration::from_secs(0),
             user_data: Box::new(()),
             backend_calls: Vec::new(),
+            recorder: None,
+            paused: false,
+            searchables: Vec::new(),
+            commands: Vec::new(),
+            command_usage: HashMap::new(),
+            #[cfg(feature = "watch")]
+            layout_watches: HashMap::new(),
+            on_pre_quit: None,
+            layer_guards: Vec::new(),
+            pending_confirm: None,
+            in_guard: false,
+            on_screen_change: None,
+            on_menu_help: None,
+            #[cfg(feature = "frame-stats")]
+            last_frame_stats: None,
         };
         cursive.reset_default_callbacks();
 
@@ -113,14 +378,50 @@ impl Cursive {
         self.last_size
     }
 
+    /// Computes the size `view` requires on the current screen.
+    ///
+    /// The view is measured in the space available to layers, without the
+    /// menubar and statusbar. See [`utils::measure`](crate::utils::measure).
+    ///
+    /// Note: this uses the screen size from the last layout phase, so it
+    /// measures within `(0, 0)` before the first one.
+    pub fn measure_view<V: View + ?Sized>(&self, view: &mut V) -> Vec2 {
+        crate::utils::measure(view, self.layers_size(self.last_size))
+    }
+
+    // Space left for the layers, on a screen of the given size.
+    fn layers_size(&self, size: Vec2) -> Vec2 {
+        let offset = if self.menubar.autohide { 0 } else { 1 };
+        size.saturating_sub((0, offset + self.statusbar_height()))
+    }
+
     pub(crate) fn layout(&mut self, size: Vec2) {
         self.last_size = size;
-        let offset = if self.menubar.autohide { 0 } else { 1 };
-        let size = size.saturating_sub((0, offset));
+        let size = self.layers_size(size);
         self.root.layout(size);
+        self.statusbar.layout(Vec2::new(size.x, 1));
+    }
+
+    // Number of rows reserved for the statusbar.
+    fn statusbar_height(&self) -> usize {
+        if self.statusbar.visible() {
+            1
+        } else {
+            0
+        }
+    }
+
+    // Records that a frame is drawn at `now`.
+    fn record_frame(&mut self, now: Instant) {
+        if let Some(last_frame) = self.last_frame {
+            self.frame_delta = now.saturating_duration_since(last_frame);
+        }
+        self.last_frame = Some(now);
     }
 
     pub(crate) fn draw(&mut self, size: Vec2, backend: &dyn backend::Backend) {
+        self.record_frame(Instant::now());
+
         let printer = Printer::new(size, &self.theme, backend);
 
         let selected = self.menubar.receive_events();
@@ -129,7 +430,12 @@ impl Cursive {
         let offset = if self.menubar.autohide { 0 } else { 1 };
 
         // The printer for the stackview
-        let sv_printer = printer.offset((0, offset)).focused(!selected);
+        let sv_size =
+            size.saturating_sub((0, offset + self.statusbar_height()));
+        let sv_printer = printer
+            .offset((0, offset))
+            .cropped(sv_size)
+            .focused(!selected);
         self.root.draw(&sv_printer);
 
         self.root.get_inner().draw_bg(&sv_printer);
@@ -147,6 +453,23 @@ impl Cursive {
         // finally draw stackview layers
         // using variables from above
         self.root.get_inner().draw_fg(&sv_printer);
+
+        if self.statusbar.visible() {
+            let printer = printer
+                .offset((0, size.y.saturating_sub(1)))
+                .cropped((size.x, 1))
+                .focused(false);
+            self.statusbar.draw(&printer);
+        }
+
+        // Notifications go on top of everything.
+        let area = Rect::from_size((0, offset), sv_size);
+        notification::layout(
+            &mut self.notifications,
+            area,
+            self.notification_align,
+        );
+        notification::draw(&self.notifications, &printer);
     }
 
     /// Sets some data to be stored in Cursive.
@@ -233,22 +556,217 @@ impl Cursive {
             .push(Box::new(move |backend| backend.set_title(title)));
     }
 
+    /// Temporarily gives the terminal back, until [`resume`] is called.
+    ///
+    /// The terminal is restored to its normal state: this is useful to run
+    /// an external program that needs it (an editor, a pager, ...).
+    ///
+    /// While paused, the event loop does not read input or draw anything,
+    /// but callbacks sent through the [`cb_sink`] are still processed, so
+    /// another thread can call [`resume`] once it is done.
+    ///
+    /// To run a blocking function in between, [`run_external`] is simpler.
+    ///
+    /// Note that not all backends support this.
+    ///
+    /// [`resume`]: Cursive::resume
+    /// [`cb_sink`]: Cursive::cb_sink
+    /// [`run_external`]: Cursive::run_external
+    pub fn pause(&mut self) {
+        if self.paused {
+            return;
+        }
+        self.paused = true;
+        self.backend_calls
+            .push(Box::new(|backend| backend.suspend()));
+    }
+
+    /// Takes back control of the terminal after a call to [`pause`].
+    ///
+    /// The screen will be redrawn.
+    ///
+    /// [`pause`]: Cursive::pause
+    pub fn resume(&mut self) {
+        if !self.paused {
+            return;
+        }
+        self.paused = false;
+        self.backend_calls
+            .push(Box::new(|backend| backend.resume()));
+        self.clear();
+    }
+
+    /// Returns `true` if the terminal was handed back with [`pause`].
+    ///
+    /// [`pause`]: Cursive::pause
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Runs `f` with the terminal restored to its normal state.
+    ///
+    /// The terminal is given back before `f` runs, and taken back once it
+    /// returns. The event loop is blocked in the meantime.
+    ///
+    /// Note that not all backends support this.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use cursive_core::Cursive;
+    /// # let mut siv = Cursive::new();
+    /// siv.add_global_callback('e', |s| {
+    ///     s.run_external(|| {
+    ///         std::process::Command::new("vi").status().ok();
+    ///     });
+    /// });
+    /// ```
+    pub fn run_external<F>(&mut self, f: F)
+    where
+        F: FnOnce() + 'static,
+    {
+        self.backend_calls.push(Box::new(move |backend| {
+            backend.suspend();
+            f();
+            backend.resume();
+        }));
+        self.clear();
+    }
+
     /// Show the debug console.
     ///
     /// Currently, this will show logs if [`logger::init()`](crate::logger::init()) was called.
+    ///
+    /// The console follows new logs, unless scrolled up. See
+    /// [`DebugView`](views::DebugView) for the available filters.
     pub fn show_debug_console(&mut self) {
+        // `true` while the console follows new logs.
+        let following = Rc::new(std::cell::Cell::new(true));
+
+        let logs = views::ScrollView::new(views::NamedView::new(
+            DEBUG_VIEW_NAME,
+            views::DebugView::new(),
+        ))
+        .scroll_x(true)
+        .scroll_strategy(view::ScrollStrategy::StickToBottom)
+        .on_scroll_inner(move |scroll, _| {
+            // Scrolling manually stops following, and scrolling back to the
+            // bottom resumes it.
+            if !scroll.is_at_bottom() {
+                following.set(false);
+            } else if !following.replace(true) {
+                return scroll
+                    .set_scroll_strategy(view::ScrollStrategy::StickToBottom);
+            }
+            EventResult::Ignored
+        });
+
+        let filter = |label: &str, set: fn(&mut views::DebugView, &str)| {
+            views::LinearLayout::horizontal()
+                .child(views::TextView::new(label))
+                .child(views::ResizedView::with_fixed_width(
+                    16,
+                    views::EditView::new().on_edit(move |s, text, _| {
+                        s.call_on_name(DEBUG_VIEW_NAME, |v| set(v, text));
+                    }),
+                ))
+        };
+
         self.add_layer(
             views::Dialog::around(
-                views::ScrollView::new(views::NamedView::new(
-                    DEBUG_VIEW_NAME,
-                    views::DebugView::new(),
-                ))
-                .scroll_x(true),
+                views::LinearLayout::vertical().child(logs).child(
+                    views::LinearLayout::horizontal()
+                        .child(filter("Target: ", |v, text| {
+                            v.set_target_filter(text)
+                        }))
+                        .child(filter(" Search: ", |v, text| {
+                            v.set_search(text)
+                        })),
+                ),
+            )
+            .title("Debug console")
+            .button("View tree", Cursive::show_debug_tree),
+        );
+    }
+
+    /// Returns a description of the current view tree.
+    ///
+    /// Only the active screen is included. See `View::debug_tree()` for the
+    /// format.
+    pub fn debug_tree(&self) -> String {
+        let root: &dyn View = &self.root;
+        root.debug_tree()
+    }
+
+    /// Shows the current view tree in a new layer.
+    ///
+    /// This is also available from the debug console.
+    pub fn show_debug_tree(&mut self) {
+        let tree = self.debug_tree();
+        self.add_layer(
+            views::Dialog::around(
+                views::ScrollView::new(views::TextView::new(tree))
+                    .scroll_x(true),
             )
-            .title("Debug console"),
+            .title("View tree")
+            .dismiss_button("Close"),
+        );
+    }
+
+    /// Returns the documented key bindings.
+    ///
+    /// This includes the global callbacks registered with
+    /// [`add_global_callback_with_help`](Cursive::add_global_callback_with_help),
+    /// and the bindings of views in the active screen (see
+    /// [`OnEventView::on_event_help`](views::OnEventView::on_event_help)).
+    pub fn help_bindings(&self) -> Vec<help::Binding> {
+        let mut bindings = Vec::new();
+        self.root
+            .key_help(&mut |binding| bindings.push(binding.clone()));
+        bindings
+    }
+
+    /// Shows the documented key bindings in a new layer, grouped by scope.
+    ///
+    /// The list is updated when global callbacks are added or removed while
+    /// it is visible. Use [`refresh_help`](Cursive::refresh_help) after
+    /// changing bindings of views.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// # use cursive_core::event::Event;
+    /// let mut siv = Cursive::new();
+    /// siv.add_global_callback_with_help(Event::CtrlChar('q'), "Quit", |s| {
+    ///     s.quit()
+    /// });
+    /// siv.add_global_callback_with_help('?', "Show this help", |s| {
+    ///     s.show_help()
+    /// });
+    /// ```
+    pub fn show_help(&mut self) {
+        let text = help::render(&self.help_bindings());
+        self.add_layer(
+            views::Dialog::around(views::ScrollView::new(
+                views::NamedView::new(
+                    HELP_VIEW_NAME,
+                    views::TextView::new(text),
+                ),
+            ))
+            .title("Help")
+            .dismiss_button("Close"),
         );
     }
 
+    /// Updates the help screen, if visible, with the current bindings.
+    pub fn refresh_help(&mut self) {
+        let text = help::render(&self.help_bindings());
+        self.call_on_name(HELP_VIEW_NAME, |v: &mut views::TextView| {
+            v.set_content(text)
+        });
+    }
+
     /// Show the debug console, or hide it if it's already visible.
     ///
     /// # Examples
@@ -297,8 +815,37 @@ impl Cursive {
         &self.cb_sink
     }
 
+    /// Returns a sink for callbacks, for use in async tasks.
+    ///
+    /// This is the same channel as [`cb_sink`](Cursive::cb_sink), with an
+    /// awaitable `send_async` method which waits for the callback to run.
+    /// It works with any async runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::*;
+    /// let siv = Cursive::new();
+    /// let sink = siv.cb_sink_async();
+    ///
+    /// // Usually given to something like `tokio::spawn`.
+    /// let task = async move {
+    ///     let layers = sink.send_async(|s| s.screen().len()).await;
+    ///     if layers == Some(0) {
+    ///         sink.send_async(|s| s.quit()).await;
+    ///     }
+    /// };
+    /// # drop(task);
+    /// ```
+    pub fn cb_sink_async(&self) -> CbSinkAsync {
+        CbSinkAsync {
+            sink: self.cb_sink.clone(),
+        }
+    }
+
     /// Selects the menubar.
     pub fn select_menubar(&mut self) {
+        self.needs_redraw = true;
         if let Ok(res) = self.menubar.take_focus(direction::Direction::none())
         {
             res.process(self);
@@ -310,6 +857,7 @@ impl Cursive {
     /// * When enabled (default), the menu is only visible when selected.
     /// * When disabled, the menu is always visible and reserves the top row.
     pub fn set_autohide_menu(&mut self, autohide: bool) {
+        self.needs_redraw = true;
         self.menubar.autohide = autohide;
     }
 
@@ -370,9 +918,143 @@ impl Cursive {
     /// siv.add_global_callback(event::Key::Esc, |s| s.select_menubar());
     /// ```
     pub fn menubar(&mut self) -> &mut views::Menubar {
+        self.needs_redraw = true;
         &mut self.menubar
     }
 
+    /// Access the statusbar, shown at the bottom of the screen.
+    ///
+    /// The statusbar is not part of the layer stack: it stays in place when
+    /// layers are added or removed. When visible, it reserves the last row
+    /// of the screen.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::align::HAlign;
+    /// use cursive_core::views::Dialog;
+    ///
+    /// let mut siv = cursive_core::Cursive::new();
+    ///
+    /// let statusbar = siv.statusbar();
+    /// statusbar.set_segment(HAlign::Left, "Ready");
+    /// statusbar.set_segment(HAlign::Right, "Help");
+    /// statusbar.set_on_click(HAlign::Right, |s| {
+    ///     s.add_layer(Dialog::info("Help message!"))
+    /// });
+    /// ```
+    pub fn statusbar(&mut self) -> &mut views::Statusbar {
+        self.needs_redraw = true;
+        &mut self.statusbar
+    }
+
+    /// Sets a callback to run when the highlighted menu item changes.
+    ///
+    /// The callback receives the help text of the highlighted item, set with
+    /// [`Item::with_help`](crate::menu::Item::with_help), or `None` when the
+    /// item has no help or the menu is closed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// use cursive_core::align::HAlign;
+    ///
+    /// let mut siv = Cursive::new();
+    /// siv.set_on_menu_help(|s, help| {
+    ///     s.statusbar().set_segment(HAlign::Left, help.unwrap_or(""));
+    /// });
+    /// ```
+    pub fn set_on_menu_help<F>(&mut self, cb: F)
+    where
+        F: 'static + Fn(&mut Cursive, Option<&str>),
+    {
+        self.on_menu_help = Some(Rc::new(cb));
+    }
+
+    /// Removes the callback set with
+    /// [`set_on_menu_help`](Cursive::set_on_menu_help).
+    pub fn clear_on_menu_help(&mut self) {
+        self.on_menu_help = None;
+    }
+
+    // Called by menus when the highlighted item changes.
+    pub(crate) fn menu_help_changed(&mut self, help: Option<&str>) {
+        if let Some(cb) = self.on_menu_help.clone() {
+            cb(self, help);
+        }
+    }
+
+    /// Shows a notification with the given message.
+    ///
+    /// It disappears after 3 seconds, or when clicked.
+    ///
+    /// See [`Cursive::add_notification`] for more options.
+    pub fn notify<S>(&mut self, text: S) -> NotificationId
+    where
+        S: Into<StyledString>,
+    {
+        self.add_notification(Notification::new(text))
+    }
+
+    /// Shows a notification.
+    ///
+    /// Notifications are stacked in a corner of the screen, on top of all
+    /// layers. They never take the focus, and don't block input to the views
+    /// below. They disappear after their duration, or when clicked.
+    ///
+    /// Returns an id that can be given to [`Cursive::dismiss_notification`].
+    pub fn add_notification(
+        &mut self,
+        notification: Notification,
+    ) -> NotificationId {
+        let id = self.next_notification_id;
+        self.next_notification_id += 1;
+        self.needs_redraw = true;
+
+        self.notifications.push(notification::Toast::new(
+            id,
+            notification,
+            Instant::now(),
+        ));
+        id
+    }
+
+    // Removes the notifications whose duration ran out at `now`.
+    //
+    // Returns `true` if any notification was removed.
+    pub(crate) fn expire_notifications(&mut self, now: Instant) -> bool {
+        let count = self.notifications.len();
+        self.notifications.retain(
+            |toast| !matches!(toast.deadline, Some(deadline) if deadline <= now),
+        );
+
+        let expired = self.notifications.len() != count;
+        if expired {
+            self.needs_redraw = true;
+        }
+        expired
+    }
+
+    /// Removes a notification before its time.
+    ///
+    /// Does nothing if the notification is already gone.
+    pub fn dismiss_notification(&mut self, id: NotificationId) {
+        self.needs_redraw = true;
+        self.notifications.retain(|toast| toast.id != id);
+    }
+
+    /// Sets the corner where notifications are shown.
+    ///
+    /// With a vertically centered alignment, notifications are stacked from
+    /// the top.
+    ///
+    /// Defaults to the bottom-right corner.
+    pub fn set_notification_align(&mut self, align: Align) {
+        self.needs_redraw = true;
+        self.notification_align = align;
+    }
+
     /// Returns the currently used theme.
     pub fn current_theme(&self) -> &theme::Theme {
         &self.theme
@@ -428,6 +1110,13 @@ impl Cursive {
     ///
     /// Note that the actual frequency is not guaranteed.
     ///
+    /// At each frame, an `Event::Refresh` is sent to the views, and the
+    /// screen is laid out and drawn again.
+    ///
+    /// Without a refresh rate, the screen is only drawn again after an event
+    /// or when a redraw is requested (see
+    /// [`request_redraw`](crate::request_redraw)).
+    ///
     /// Between 0 and 30. Call with `fps = 0` to disable (default value).
     pub fn set_fps(&mut self, fps: u32) {
         self.fps = NonZeroU32::new(fps);
@@ -449,23 +1138,88 @@ impl Cursive {
         self.fps
     }
 
-    /// Returns a reference to the currently active screen.
-    pub fn screen(&self) -> &views::StackView {
-        self.root.get_inner().screen().unwrap()
-    }
-
-    /// Returns a mutable reference to the currently active screen.
-    pub fn screen_mut(&mut self) -> &mut views::StackView {
-        self.root.get_inner_mut().screen_mut().unwrap()
+    /// Returns the time between the last two frames.
+    ///
+    /// Returns a zero duration until two frames were drawn.
+    ///
+    /// This can be used to scale animations to the actual refresh rate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// let mut siv = Cursive::new();
+    /// siv.set_fps(30);
+    /// siv.add_global_callback(cursive_core::event::Event::Refresh, |s| {
+    ///     let speed = 10.0; // cells per second
+    ///     let _step = speed * s.frame_delta().as_secs_f32();
+    /// });
+    /// ```
+    pub fn frame_delta(&self) -> Duration {
+        self.frame_delta
     }
 
-    /// Returns the id of the currently active screen.
-    pub fn active_screen(&self) -> ScreenId {
-        self.root.get_inner().active_screen()
-    }
+    /// Returns the time spent on the last refresh of the screen.
+    ///
+    /// Returns `None` before the first refresh. See [`FrameStats`] for what
+    /// is measured.
+    ///
+    /// This requires the `frame-stats` feature to be enabled. Without it,
+    /// nothing is measured.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::{backend, views::TextView, Cursive};
+    ///
+    /// let mut siv = Cursive::new();
+    /// siv.add_layer(TextView::new("Hello"));
+    ///
+    /// let mut runner = siv.runner(backend::Dummy::init());
+    /// runner.refresh();
+    ///
+    /// let stats = runner.last_frame_stats().unwrap();
+    /// assert_eq!(stats.total(), stats.layout + stats.draw + stats.present);
+    /// ```
+    ///
+    /// [`FrameStats`]: crate::FrameStats
+    #[cfg(feature = "frame-stats")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "frame-stats")))]
+    pub fn last_frame_stats(&self) -> Option<crate::FrameStats> {
+        self.last_frame_stats
+    }
+
+    /// Returns the time since the last frame was drawn.
+    ///
+    /// Returns `None` before the first frame.
+    pub fn since_last_frame(&self) -> Option<Duration> {
+        self.last_frame.map(|last_frame| last_frame.elapsed())
+    }
+
+    /// Returns the time since this `Cursive` instance was created.
+    pub fn uptime(&self) -> Duration {
+        self.start_time.elapsed()
+    }
+
+    /// Returns a reference to the currently active screen.
+    pub fn screen(&self) -> &views::StackView {
+        self.root.get_inner().screen().unwrap()
+    }
+
+    /// Returns a mutable reference to the currently active screen.
+    pub fn screen_mut(&mut self) -> &mut views::StackView {
+        self.needs_redraw = true;
+        self.root.get_inner_mut().screen_mut().unwrap()
+    }
+
+    /// Returns the id of the currently active screen.
+    pub fn active_screen(&self) -> ScreenId {
+        self.root.get_inner().active_screen()
+    }
 
     /// Adds a new screen, and returns its ID.
     pub fn add_screen(&mut self) -> ScreenId {
+        self.needs_redraw = true;
         self.root
             .get_inner_mut()
             .add_screen(views::StackView::new())
@@ -479,8 +1233,148 @@ impl Cursive {
     }
 
     /// Sets the active screen. Panics if no such screen exist.
+    ///
+    /// Runs the callback given to
+    /// [`set_on_screen_change`](Cursive::set_on_screen_change) if the active
+    /// screen changes.
     pub fn set_screen(&mut self, screen_id: ScreenId) {
+        let old = self.active_screen();
+        self.needs_redraw = true;
         self.root.get_inner_mut().set_active_screen(screen_id);
+        self.notify_screen_change(old);
+    }
+
+    // Runs the screen change callback if the active screen is no longer
+    // `old`.
+    fn notify_screen_change(&mut self, old: ScreenId) {
+        let new = self.active_screen();
+        if old != new {
+            self.needs_redraw = true;
+            if let Some(cb) = self.on_screen_change.clone() {
+                cb(self, old, new);
+            }
+        }
+    }
+
+    /// Sets a callback to run when the active screen changes.
+    ///
+    /// The callback receives the IDs of the previous and of the new active
+    /// screen. It runs after the switch, so it can populate the new screen,
+    /// for example on its first visit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// # use cursive_core::views::TextView;
+    /// let mut siv = Cursive::new();
+    /// let settings = siv.add_named_screen("settings");
+    ///
+    /// siv.set_on_screen_change(move |s, _old, new| {
+    ///     if new == settings && s.screen().is_empty() {
+    ///         s.add_layer(TextView::new("Settings"));
+    ///     }
+    /// });
+    ///
+    /// siv.set_named_screen("settings");
+    /// assert_eq!(siv.screen().len(), 1);
+    /// ```
+    pub fn set_on_screen_change<F>(&mut self, cb: F)
+    where
+        F: 'static + Fn(&mut Cursive, ScreenId, ScreenId),
+    {
+        self.on_screen_change = Some(Rc::new(cb));
+    }
+
+    /// Removes the callback set with
+    /// [`set_on_screen_change`](Cursive::set_on_screen_change).
+    pub fn clear_on_screen_change(&mut self) {
+        self.on_screen_change = None;
+    }
+
+    /// Removes a screen, with its layers, and returns it.
+    ///
+    /// The IDs and names of other screens are unchanged, and the ID of the
+    /// removed screen is never reused.
+    ///
+    /// Returns `None` if no such screen exist, or if it is the active
+    /// screen.
+    pub fn remove_screen(
+        &mut self,
+        screen_id: ScreenId,
+    ) -> Option<views::StackView> {
+        self.root.get_inner_mut().remove_screen(screen_id)
+    }
+
+    /// Removes the screen with the given name, and returns it.
+    ///
+    /// Returns `None` if no screen has this name, or if it is the active
+    /// screen.
+    pub fn remove_named_screen(
+        &mut self,
+        name: &str,
+    ) -> Option<views::StackView> {
+        let screen_id = self.find_screen(name)?;
+        self.remove_screen(screen_id)
+    }
+
+    /// Adds a new screen with the given name, and returns its ID.
+    ///
+    /// The screen can later be activated with `set_named_screen`. If another
+    /// screen already had this name, it loses it.
+    ///
+    /// Each screen keeps its own layers and focus when switching.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// # use cursive_core::views::TextView;
+    /// let mut siv = Cursive::new();
+    ///
+    /// siv.add_named_screen("help");
+    /// siv.set_named_screen("help").unwrap();
+    /// siv.add_layer(TextView::new("Press q to quit."));
+    ///
+    /// assert_eq!(siv.active_screen_name(), Some("help"));
+    /// ```
+    pub fn add_named_screen<S: Into<String>>(&mut self, name: S) -> ScreenId {
+        self.root
+            .get_inner_mut()
+            .add_named_screen(name, views::StackView::new())
+    }
+
+    /// Gives a name to an existing screen.
+    ///
+    /// This can be used to name the initial screen (with ID `0`).
+    ///
+    /// Panics if no such screen exist.
+    pub fn set_screen_name<S: Into<String>>(
+        &mut self,
+        screen_id: ScreenId,
+        name: S,
+    ) {
+        self.root.get_inner_mut().set_screen_name(screen_id, name);
+    }
+
+    /// Sets the active screen using its name.
+    ///
+    /// Returns the ID of the activated screen, or `None` if no screen has
+    /// this name. In that case, the active screen is unchanged.
+    pub fn set_named_screen(&mut self, name: &str) -> Option<ScreenId> {
+        let screen_id = self.find_screen(name)?;
+        self.set_screen(screen_id);
+        Some(screen_id)
+    }
+
+    /// Returns the ID of the screen with the given name, if any.
+    pub fn find_screen(&self, name: &str) -> Option<ScreenId> {
+        self.root.get_inner().find_screen(name)
+    }
+
+    /// Returns the name of the active screen, if it has one.
+    pub fn active_screen_name(&self) -> Option<&str> {
+        self.root.get_inner().active_screen_name()
     }
 
     /// Tries to find the view pointed to by the given selector.
@@ -518,6 +1412,7 @@ impl Cursive {
         V: View,
         F: FnOnce(&mut V) -> R,
     {
+        self.needs_redraw = true;
         self.root.call_on(sel, callback)
     }
 
@@ -613,9 +1508,264 @@ impl Cursive {
         self.call_on_name(id, views::NamedView::<V>::get_mut)
     }
 
+    /// Finds a named view, looking only inside another named view.
+    ///
+    /// This looks for a `NamedView<V>` called `name`, inside the subtree of
+    /// the view called `root` (of any type). This is useful when the same
+    /// component is used multiple times, with the same inner names.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// # use cursive_core::views::{LinearLayout, TextView};
+    /// use cursive_core::traits::Nameable;
+    ///
+    /// fn panel(title: &str) -> LinearLayout {
+    ///     LinearLayout::vertical().child(TextView::new(title).with_name("title"))
+    /// }
+    ///
+    /// let mut siv = Cursive::new();
+    /// siv.add_layer(
+    ///     LinearLayout::horizontal()
+    ///         .child(panel("Left").with_name("left"))
+    ///         .child(panel("Right").with_name("right")),
+    /// );
+    ///
+    /// let title = siv.find_name_in::<TextView>("right", "title").unwrap();
+    /// assert_eq!(title.get_content().source(), "Right");
+    /// ```
+    pub fn find_name_in<V>(
+        &mut self,
+        root: &str,
+        name: &str,
+    ) -> Option<views::ViewRef<V>>
+    where
+        V: View,
+    {
+        let mut result = None;
+        self.root.call_on_any(
+            &view::Selector::Name(root),
+            &mut |root: &mut dyn View| {
+                root.call_on_any(
+                    &view::Selector::Name(name),
+                    &mut |v: &mut dyn View| {
+                        if result.is_some() {
+                            return;
+                        }
+                        if let Some(v) =
+                            v.downcast_mut::<views::NamedView<V>>()
+                        {
+                            result = Some(v.get_mut());
+                        }
+                    },
+                );
+            },
+        );
+        result
+    }
+
+    /// Finds a view named with a [`TypedName`].
+    ///
+    /// This is the same as `find_name`, with the view type coming from the
+    /// name itself.
+    ///
+    /// [`TypedName`]: view::TypedName
+    pub fn find_typed_name<V>(
+        &mut self,
+        name: view::TypedName<V>,
+    ) -> Option<views::ViewRef<V>>
+    where
+        V: View,
+    {
+        self.find_name(name.name())
+    }
+
+    /// Calls the given closure on a view named with a [`TypedName`].
+    ///
+    /// [`TypedName`]: view::TypedName
+    pub fn call_on_typed_name<V, F, R>(
+        &mut self,
+        name: view::TypedName<V>,
+        callback: F,
+    ) -> Option<R>
+    where
+        V: View,
+        F: FnOnce(&mut V) -> R,
+    {
+        self.call_on_name(name.name(), callback)
+    }
+
+    /// Includes the view named `name` in the results of [`search`].
+    ///
+    /// `V` is the type of the named view. If no such view exists when
+    /// searching, it is simply skipped.
+    ///
+    /// [`search`]: Cursive::search
+    pub fn register_searchable<V>(&mut self, name: &str)
+    where
+        V: View + view::Searchable,
+    {
+        self.unregister_searchable(name);
+        self.searchables.push(SearchEntry {
+            name: name.to_string(),
+            search: search_named::<V>,
+            reveal: reveal_named::<V>,
+        });
+    }
+
+    /// Removes the view named `name` from the results of [`search`].
+    ///
+    /// [`search`]: Cursive::search
+    pub fn unregister_searchable(&mut self, name: &str) {
+        self.searchables.retain(|entry| entry.name != name);
+    }
+
+    /// Searches all registered views for `query`.
+    ///
+    /// Views are searched in the order they were registered. Each hit comes
+    /// with a callback that reveals the match in its view, and focuses the
+    /// view if it could do so.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// # use cursive_core::views::TextView;
+    /// use cursive_core::view::Nameable;
+    ///
+    /// let mut siv = Cursive::new();
+    /// siv.add_layer(TextView::new("Hello\nWorld").with_name("text"));
+    /// siv.register_searchable::<TextView>("text");
+    ///
+    /// let hits = siv.search("world");
+    /// assert_eq!(hits.len(), 1);
+    /// assert_eq!(hits[0].found.position, 1);
+    /// ```
+    pub fn search(&mut self, query: &str) -> Vec<view::SearchHit> {
+        let entries: Vec<_> = self
+            .searchables
+            .iter()
+            .map(|entry| (entry.name.clone(), entry.search, entry.reveal))
+            .collect();
+
+        let mut hits = Vec::new();
+        for (name, search, reveal) in entries {
+            for found in search(self, &name, query) {
+                let callback = {
+                    let name = name.clone();
+                    let found = found.clone();
+                    Callback::from_fn(move |s| reveal(s, &name, &found))
+                };
+                hits.push(view::SearchHit {
+                    name: name.clone(),
+                    found,
+                    callback,
+                });
+            }
+        }
+        hits
+    }
+
+    /// Adds a command to the command palette.
+    ///
+    /// A command with the same name is replaced.
+    ///
+    /// See [`show_command_palette`](Cursive::show_command_palette).
+    pub fn register_command<N, D, F>(&mut self, name: N, description: D, cb: F)
+    where
+        N: Into<String>,
+        D: Into<String>,
+        F: 'static + Fn(&mut Cursive),
+    {
+        let command = views::Command::new(name, description, cb);
+        self.unregister_command(&command.name);
+        self.commands.push(command);
+    }
+
+    /// Removes a command from the command palette.
+    pub fn unregister_command(&mut self, name: &str) {
+        self.commands.retain(|command| command.name != name);
+    }
+
+    /// Shows the command palette in a new layer.
+    ///
+    /// It lists the registered commands, followed by every entry of the
+    /// menubar, named after its path (like `File > Save As`). The most used
+    /// commands come first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// use cursive_core::event::Event;
+    ///
+    /// let mut siv = Cursive::new();
+    /// siv.register_command("Quit", "Exit the application", |s| s.quit());
+    /// siv.add_global_callback(Event::CtrlChar('p'), |s| {
+    ///     s.show_command_palette()
+    /// });
+    /// ```
+    pub fn show_command_palette(&mut self) {
+        let mut commands = self.commands.clone();
+        commands.extend(self.menubar.commands());
+
+        let palette = views::CommandPalette::with_usage(
+            commands,
+            self.command_usage.clone(),
+        );
+        self.add_layer(views::Dialog::around(palette).title("Commands"));
+    }
+
+    /// Returns how many times each command was run from the palette.
+    ///
+    /// This can be saved, and given back to
+    /// [`import_usage`](Cursive::import_usage) in a later session.
+    pub fn export_usage(&self) -> HashMap<String, usize> {
+        self.command_usage.clone()
+    }
+
+    /// Sets how many times each command was run from the palette.
+    ///
+    /// Replaces the current usage counts.
+    pub fn import_usage(&mut self, usage: HashMap<String, usize>) {
+        self.command_usage = usage;
+    }
+
+    // Called by the command palette when a command is run.
+    pub(crate) fn record_command_usage(&mut self, name: &str) {
+        *self.command_usage.entry(name.to_string()).or_insert(0) += 1;
+    }
+
     /// Moves the focus to the view identified by `name`.
     ///
     /// Convenient method to call `focus` with a [`view::Selector::Name`].
+    ///
+    /// The view tree is searched for the named view, and every container on
+    /// the path to it updates its focus accordingly.
+    ///
+    /// The returned `EventResult` may include callbacks from views losing or
+    /// gaining focus; process it to run them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// # use cursive_core::views::{EditView, LinearLayout};
+    /// use cursive_core::traits::Nameable;
+    ///
+    /// let mut siv = Cursive::new();
+    /// siv.add_layer(
+    ///     LinearLayout::vertical()
+    ///         .child(EditView::new().with_name("name"))
+    ///         .child(EditView::new().with_name("email")),
+    /// );
+    ///
+    /// // For example after a failed validation, move to the faulty field.
+    /// siv.focus_name("email").unwrap().process(&mut siv);
+    ///
+    /// assert!(siv.focus_name("missing").is_err());
+    /// ```
     pub fn focus_name(
         &mut self,
         name: &str,
@@ -624,11 +1774,18 @@ impl Cursive {
     }
 
     /// Moves the focus to the view identified by `sel`.
+    ///
+    /// If the view is on another screen, this screen becomes active, and the
+    /// callback given to
+    /// [`set_on_screen_change`](Cursive::set_on_screen_change) runs.
     pub fn focus(
         &mut self,
         sel: &view::Selector<'_>,
     ) -> Result<EventResult, ViewNotFound> {
-        self.root.focus_view(sel)
+        let old = self.active_screen();
+        let result = self.root.focus_view(sel);
+        self.notify_screen_change(old);
+        result
     }
 
     /// Adds a global callback.
@@ -650,6 +1807,24 @@ impl Cursive {
         self.set_on_post_event(event.into(), cb);
     }
 
+    /// Adds a global callback, with a description for the help screen.
+    ///
+    /// The binding is listed by [`show_help`](Cursive::show_help), in the
+    /// [`help::GLOBAL_SCOPE`] group.
+    pub fn add_global_callback_with_help<F, E, S>(
+        &mut self,
+        event: E,
+        description: S,
+        cb: F,
+    ) where
+        F: Fn(&mut Cursive) + 'static,
+        E: Into<Event>,
+        S: Into<String>,
+    {
+        self.root.set_on_event_help(event, description, cb);
+        self.refresh_help();
+    }
+
     /// Registers a callback for ignored events.
     ///
     /// This is the same as `add_global_callback`, but can register any `EventTrigger`.
@@ -746,6 +1921,7 @@ impl Cursive {
     {
         let event = event.into();
         self.root.clear_event(event);
+        self.refresh_help();
     }
 
     /// This resets the default callbacks.
@@ -775,9 +1951,48 @@ impl Cursive {
         self.screen_mut().add_layer(view);
     }
 
+    /// Add a layer to the current screen, at the given position.
+    ///
+    /// The view gets a background and a shadow like with
+    /// [`add_layer`](Cursive::add_layer). The position is computed again
+    /// when the terminal is resized.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::align::Align;
+    /// # use cursive_core::view::Position;
+    /// # use cursive_core::views::TextView;
+    /// # use cursive_core::Cursive;
+    /// let mut siv = Cursive::new();
+    /// siv.add_layer_at(
+    ///     Position::aligned(Align::bot_right()),
+    ///     TextView::new("Saved."),
+    /// );
+    /// ```
+    pub fn add_layer_at<T>(&mut self, position: Position, view: T)
+    where
+        T: IntoBoxedView,
+    {
+        self.screen_mut().add_layer_at(position, view);
+    }
+
     /// Adds a new full-screen layer to the current screen.
     ///
+    /// This is the recommended way to add a layer filling the terminal: the
+    /// view is given the entire screen, even if it asks for less. There is no
+    /// need to wrap it in a [`ResizedView`](views::ResizedView).
+    ///
     /// Fullscreen layers have no shadow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// # use cursive_core::views::TextView;
+    /// let mut siv = Cursive::new();
+    /// siv.add_fullscreen_layer(TextView::new("Fills the terminal"));
+    /// ```
     pub fn add_fullscreen_layer<T>(&mut self, view: T)
     where
         T: IntoBoxedView,
@@ -786,10 +2001,135 @@ impl Cursive {
     }
 
     /// Convenient method to remove a layer from the current screen.
+    ///
+    /// If the top layer is guarded (see [`guard_layer`](Cursive::guard_layer)),
+    /// the guard may cancel this, in which case nothing is removed.
     pub fn pop_layer(&mut self) -> Option<Box<dyn View>> {
+        if let Some((name, guard)) = self.guarded_top_layer() {
+            if !self.check_guard(&*guard, GuardTarget::Layer(name)) {
+                return None;
+            }
+        }
         self.screen_mut().pop_layer()
     }
 
+    /// Sets a callback to run before popping a layer containing the view
+    /// named `name`.
+    ///
+    /// This applies to [`pop_layer`](Cursive::pop_layer), when this layer is
+    /// on top. See [`set_on_pre_quit`](Cursive::set_on_pre_quit) for the
+    /// possible outcomes.
+    ///
+    /// If the layer contains several guarded views, only the guard set first
+    /// runs. Setting a guard again for the same name replaces it, and keeps
+    /// its rank.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::{Cursive, QuitAction};
+    /// # use cursive_core::views::{Dialog, TextView};
+    /// use cursive_core::view::Nameable;
+    ///
+    /// let mut siv = Cursive::new();
+    /// siv.add_layer(TextView::new("Draft").with_name("editor"));
+    /// siv.guard_layer("editor", |_| {
+    ///     QuitAction::confirm(
+    ///         Dialog::text("Discard the draft?")
+    ///             .button("Discard", |s| {
+    ///                 // Close this dialog, then the editor.
+    ///                 s.pop_layer();
+    ///                 s.pop_layer();
+    ///             })
+    ///             .dismiss_button("Cancel"),
+    ///     )
+    /// });
+    ///
+    /// // This shows the dialog instead.
+    /// assert!(siv.pop_layer().is_none());
+    /// assert_eq!(siv.screen().len(), 2);
+    ///
+    /// // What the "Discard" button does: the guard is bypassed.
+    /// siv.pop_layer();
+    /// assert!(siv.pop_layer().is_some());
+    /// assert_eq!(siv.screen().len(), 0);
+    /// ```
+    pub fn guard_layer<F>(&mut self, name: &str, cb: F)
+    where
+        F: 'static + Fn(&mut Cursive) -> QuitAction,
+    {
+        let cb: Rc<QuitGuard> = Rc::new(cb);
+        match self.layer_guards.iter_mut().find(|(n, _)| n == name) {
+            Some((_, guard)) => *guard = cb,
+            None => self.layer_guards.push((name.to_string(), cb)),
+        }
+    }
+
+    /// Removes the guard set with [`guard_layer`](Cursive::guard_layer).
+    pub fn unguard_layer(&mut self, name: &str) {
+        self.layer_guards.retain(|(n, _)| n != name);
+    }
+
+    // Returns the first guard matching the top layer, if any.
+    fn guarded_top_layer(&mut self) -> Option<(String, Rc<QuitGuard>)> {
+        let guards = self.layer_guards.clone();
+        let top = self.screen_mut().get_mut(LayerPosition::FromFront(0))?;
+
+        guards.into_iter().find(|(name, _)| {
+            let mut found = false;
+            top.call_on_any(&view::Selector::Name(name), &mut |_| {
+                found = true
+            });
+            found
+        })
+    }
+
+    // Runs the guard, and returns `true` if we should proceed.
+    fn check_guard(&mut self, guard: &QuitGuard, target: GuardTarget) -> bool {
+        // Called again from the confirmation dialog: go ahead.
+        if self.pending_confirm.as_ref() == Some(&target) {
+            self.pending_confirm = None;
+            return true;
+        }
+
+        // Called from the guard itself.
+        if self.in_guard {
+            return true;
+        }
+
+        self.in_guard = true;
+        let action = guard(self);
+        self.in_guard = false;
+
+        match action {
+            QuitAction::Quit => true,
+            QuitAction::Cancel => false,
+            QuitAction::Confirm(dialog) => {
+                self.add_layer(views::NamedView::new(
+                    CONFIRM_VIEW_NAME,
+                    *dialog,
+                ));
+                self.pending_confirm = Some(target);
+                false
+            }
+        }
+    }
+
+    // Forgets the pending action once its confirmation dialog is closed.
+    //
+    // This runs before each event, so the dialog buttons can still close the
+    // dialog before calling the guarded method.
+    fn expire_pending_confirm(&mut self) {
+        if self.pending_confirm.is_some()
+            && self
+                .screen_mut()
+                .find_layer_from_name(CONFIRM_VIEW_NAME)
+                .is_none()
+        {
+            self.pending_confirm = None;
+        }
+    }
+
     /// Convenient stub forwarding layer repositioning.
     pub fn reposition_layer(
         &mut self,
@@ -799,26 +2139,259 @@ impl Cursive {
         self.screen_mut().reposition_layer(layer, position);
     }
 
+    /// Starts recording input events to the file at `path`.
+    ///
+    /// Every event received from the backend is written to the file, along
+    /// with the time elapsed since the start of the recording. The file can
+    /// then be given to [`Cursive::replay`].
+    ///
+    /// If the file already exists, it is overwritten. Any previous recording
+    /// is stopped.
+    pub fn start_recording<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> io::Result<()> {
+        self.recorder = Some(recording::Recorder::create(path)?);
+        Ok(())
+    }
+
+    /// Stops recording input events.
+    pub fn stop_recording(&mut self) {
+        self.recorder = None;
+    }
+
+    /// Returns `true` if input events are currently being recorded.
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+
+    pub(crate) fn record_event(&mut self, event: &Event) {
+        if let Some(ref mut recorder) = self.recorder {
+            if let Err(err) = recorder.record(event) {
+                log::warn!("Stopping event recording: {}", err);
+                self.recorder = None;
+            }
+        }
+    }
+
+    /// Replays events recorded with [`Cursive::start_recording`].
+    ///
+    /// The events are sent from a background thread, through the callback
+    /// sink, with the same delays as when they were recorded, divided by
+    /// `speed`: `2.0` replays twice as fast.
+    ///
+    /// Returns an error if `speed` is not a positive number, or if the file
+    /// cannot be read or is not a valid recording. The events are only
+    /// processed while the event loop is running.
+    pub fn replay<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        speed: f32,
+    ) -> io::Result<()> {
+        let mut events = recording::load(path)?;
+        recording::scale_delays(&mut events, speed)?;
+        let cb_sink = self.cb_sink.clone();
+
+        std::thread::spawn(move || {
+            let start = Instant::now();
+            for (time, event) in events {
+                if let Some(delay) = time.checked_sub(start.elapsed()) {
+                    std::thread::sleep(delay);
+                }
+                let sent = cb_sink.send(Box::new(move |s| s.on_event(event)));
+                if sent.is_err() {
+                    // The Cursive root is gone.
+                    break;
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Builds the view described in a layout file, and rebuilds it when the
+    /// file changes.
+    ///
+    /// This requires the `watch` feature to be enabled.
+    ///
+    /// The file is a JSON description understood by [`Context::build`],
+    /// using `context` for variables and callbacks. The view is mounted in
+    /// the [`BoxedView`] named `name`, which should already be in the tree.
+    ///
+    /// When the file changes, the view is rebuilt and swapped in:
+    ///
+    /// * Named inputs (`EditView`, `Checkbox` and `SelectView`) that still
+    ///   exist in the new layout keep their value.
+    /// * The last focused named view is focused again, if it still exists.
+    /// * Errors are shown in a dialog, and the previous view is kept.
+    ///
+    /// The file is checked twice per second. Any previous watch for the same
+    /// mount point is replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use cursive_core::builder::Context;
+    /// use cursive_core::traits::Nameable;
+    /// use cursive_core::views::{BoxedView, DummyView};
+    ///
+    /// let mut siv = cursive_core::Cursive::new();
+    /// siv.add_layer(BoxedView::boxed(DummyView).with_name("main"));
+    ///
+    /// let mut context = Context::new();
+    /// context.add_callback("quit", |s| s.quit());
+    /// siv.watch_layout("layout.json", "main", context);
+    /// ```
+    ///
+    /// [`Context::build`]: crate::builder::Context::build
+    /// [`BoxedView`]: crate::views::BoxedView
+    #[cfg(feature = "watch")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "watch")))]
+    pub fn watch_layout<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        name: &str,
+        context: builder::Context,
+    ) {
+        let path = path.as_ref().to_path_buf();
+        let watch = layout_watch::LayoutWatch::new(path.clone(), context);
+        let stop = std::sync::Arc::clone(&watch.stop);
+        self.layout_watches.insert(name.to_string(), watch);
+        self.reload_layout(name);
+
+        let cb_sink = self.cb_sink.clone();
+        let name = name.to_string();
+        std::thread::spawn(move || {
+            let mut last = layout_watch::modified(&path);
+            while !stop.load(std::sync::atomic::Ordering::Relaxed) {
+                std::thread::sleep(layout_watch::POLL_INTERVAL);
+                let current = layout_watch::modified(&path);
+                if current == last {
+                    continue;
+                }
+                last = current;
+
+                let name = name.clone();
+                let sent =
+                    cb_sink.send(Box::new(move |s| s.reload_layout(&name)));
+                if sent.is_err() {
+                    // The Cursive root is gone.
+                    break;
+                }
+            }
+        });
+    }
+
+    /// Stops watching the layout file mounted at `name`.
+    ///
+    /// The current view is left in place.
+    #[cfg(feature = "watch")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "watch")))]
+    pub fn unwatch_layout(&mut self, name: &str) {
+        self.layout_watches.remove(name);
+    }
+
+    // Rebuilds the watched layout mounted at `name`.
+    #[cfg(feature = "watch")]
+    fn reload_layout(&mut self, name: &str) {
+        let (view, focused) = match self.layout_watches.get(name) {
+            Some(watch) => (watch.build(), watch.focused.borrow().clone()),
+            None => return,
+        };
+
+        let mut view = match view {
+            Ok(view) => views::BoxedView::new(view),
+            Err(message) => {
+                self.add_layer(
+                    views::Dialog::info(message).title("Layout error"),
+                );
+                return;
+            }
+        };
+
+        let mounted =
+            self.call_on_name(name, |mount: &mut views::BoxedView| {
+                layout_watch::restore_inputs(mount, &mut view);
+                std::mem::swap(mount, &mut view);
+            });
+        if mounted.is_none() {
+            self.add_layer(
+                views::Dialog::info(format!(
+                    "No BoxedView named `{}` to mount the layout.",
+                    name
+                ))
+                .title("Layout error"),
+            );
+            return;
+        }
+
+        if let Some(focused) = focused {
+            // The view may be gone, or may not be focusable anymore.
+            let _ = self.focus_name(&focused);
+        }
+    }
+
     /// Processes an event.
     ///
     /// * If the menubar is active, it will be handled the event.
+    /// * Mouse events on a notification will be handled by the notification.
+    /// * Mouse events on the statusbar will be handled by the statusbar.
     /// * The view tree will be handled the event.
     /// * If ignored, global_callbacks will be checked for this event.
     pub fn on_event(&mut self, event: Event) {
+        self.expire_pending_confirm();
+
+        if event == Event::WindowResize {
+            self.needs_redraw = true;
+        }
+
         if let Event::Mouse {
-            event, position, ..
+            event: mouse_event,
+            position,
+            ..
         } = event
         {
-            if event.grabs_focus()
+            let toast = self
+                .notifications
+                .iter()
+                .position(|toast| toast.area.contains(position));
+            if let Some(i) = toast {
+                self.needs_redraw = true;
+                if mouse_event == MouseEvent::Release(MouseButton::Left) {
+                    let toast = self.notifications.remove(i);
+                    if let Some(cb) = toast.on_click {
+                        cb(self);
+                    }
+                }
+                return;
+            }
+
+            if mouse_event.grabs_focus()
                 && !self.menubar.autohide
                 && !self.menubar.has_submenu()
                 && position.y == 0
             {
+                self.needs_redraw = true;
                 self.select_menubar();
             }
+
+            if self.statusbar.visible()
+                && !self.menubar.receive_events()
+                && position.y + 1 == self.last_size.y
+            {
+                let offset = self.last_size.y - 1;
+                let result =
+                    self.statusbar.on_event(event.relativized((0, offset)));
+                if result.is_consumed() {
+                    self.needs_redraw = true;
+                    result.process(self);
+                    return;
+                }
+            }
         }
 
         if self.menubar.receive_events() {
+            self.needs_redraw = true;
             self.menubar.on_event(event).process(self);
         } else {
             let offset = if self.menubar.autohide { 0 } else { 1 };
@@ -826,8 +2399,19 @@ impl Cursive {
             let result =
                 View::on_event(&mut self.root, event.relativized((0, offset)));
 
-            if let EventResult::Consumed(Some(cb)) = result {
-                cb(self);
+            match result {
+                EventResult::Consumed(Some(cb)) => {
+                    self.needs_redraw = true;
+                    cb(self);
+                }
+                EventResult::Consumed(None) => self.needs_redraw = true,
+                EventResult::Ignored => {
+                    // Menu shortcuts work even while the menu is closed.
+                    if let Some(cb) = self.menubar.find_shortcut(&event) {
+                        self.needs_redraw = true;
+                        cb(self);
+                    }
+                }
             }
         }
     }
@@ -839,6 +2423,8 @@ impl Cursive {
     pub(crate) fn process_callback(&mut self) -> bool {
         match self.cb_source.try_recv() {
             Ok(cb) => {
+                self.expire_pending_confirm();
+                self.needs_redraw = true;
                 cb(self);
                 true
             }
@@ -860,6 +2446,31 @@ impl Cursive {
         self.run_with(backend::Dummy::init)
     }
 
+    /// Runs the event loop on a predetermined sequence of events.
+    ///
+    /// Events are fed one per frame to a dummy backend, which is never polled
+    /// for input. When the events are exhausted, this calls
+    /// [`quit`](Cursive::quit) and stops, even if a guard would cancel it.
+    ///
+    /// Useful for tests and scripted runs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::{Cursive, ExitReason};
+    /// # use cursive_core::event::Event;
+    /// # use cursive_core::views::EditView;
+    /// let mut siv = Cursive::new();
+    /// siv.add_layer(EditView::new());
+    ///
+    /// let events = vec![Event::Char('o'), Event::Char('k')];
+    /// assert_eq!(siv.run_with_input(events), ExitReason::InputExhausted);
+    /// ```
+    pub fn run_with_input(&mut self, events: Vec<Event>) -> ExitReason {
+        self.running = true;
+        self.runner(backend::Dummy::init()).run_with_input(events)
+    }
+
     /// Returns a new runner on the given backend.
     ///
     /// Used to manually control the event loop. In most cases, running
@@ -915,10 +2526,113 @@ impl Cursive {
     }
 
     /// Stops the event loop.
+    ///
+    /// If a guard was set with [`set_on_pre_quit`](Cursive::set_on_pre_quit),
+    /// it may cancel this. This includes the default `Ctrl-C` binding.
     pub fn quit(&mut self) {
+        if let Some(guard) = self.on_pre_quit.clone() {
+            if !self.check_guard(&*guard, GuardTarget::Quit) {
+                return;
+            }
+        }
+        self.running = false;
+    }
+
+    /// Stops the event loop, bypassing any guard.
+    ///
+    /// Unlike [`quit`](Cursive::quit), this does not run the callback set with
+    /// [`set_on_pre_quit`](Cursive::set_on_pre_quit) or
+    /// [`set_on_quit`](Cursive::set_on_quit).
+    pub fn force_quit(&mut self) {
+        self.pending_confirm = None;
         self.running = false;
     }
 
+    /// Sets a callback to run before quitting.
+    ///
+    /// It decides whether [`quit`](Cursive::quit) actually stops the event
+    /// loop:
+    ///
+    /// * `QuitAction::Quit` stops it.
+    /// * `QuitAction::Cancel` keeps it running.
+    /// * `QuitAction::Confirm(dialog)` shows the dialog. Calling `quit()`
+    ///   from its buttons then stops the event loop, without running the
+    ///   callback again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::{Cursive, QuitAction};
+    /// # use cursive_core::views::Dialog;
+    /// let mut siv = Cursive::new();
+    /// siv.set_on_pre_quit(|_| {
+    ///     QuitAction::confirm(
+    ///         Dialog::text("There are unsaved changes.")
+    ///             .button("Quit anyway", |s| s.quit())
+    ///             .dismiss_button("Cancel"),
+    ///     )
+    /// });
+    ///
+    /// siv.quit();
+    /// assert!(siv.is_running());
+    /// ```
+    pub fn set_on_pre_quit<F>(&mut self, cb: F)
+    where
+        F: 'static + Fn(&mut Cursive) -> QuitAction,
+    {
+        self.on_pre_quit = Some(Rc::new(cb));
+    }
+
+    /// Sets a callback deciding whether [`quit`](Cursive::quit) should go
+    /// ahead.
+    ///
+    /// Returning `false` cancels the quit, for example after showing a
+    /// dialog, which can then call [`force_quit`](Cursive::force_quit).
+    ///
+    /// This replaces any callback set with
+    /// [`set_on_pre_quit`](Cursive::set_on_pre_quit).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// # use cursive_core::views::Dialog;
+    /// let mut siv = Cursive::new();
+    /// siv.set_on_quit(|s| {
+    ///     s.add_layer(
+    ///         Dialog::text("Save changes?")
+    ///             .button("Discard", |s| s.force_quit())
+    ///             .dismiss_button("Cancel"),
+    ///     );
+    ///     false
+    /// });
+    ///
+    /// siv.quit();
+    /// assert!(siv.is_running());
+    /// assert_eq!(siv.screen().len(), 1);
+    ///
+    /// siv.force_quit();
+    /// assert!(!siv.is_running());
+    /// ```
+    pub fn set_on_quit<F>(&mut self, cb: F)
+    where
+        F: 'static + Fn(&mut Cursive) -> bool,
+    {
+        self.set_on_pre_quit(move |s| {
+            if cb(s) {
+                QuitAction::Quit
+            } else {
+                QuitAction::Cancel
+            }
+        });
+    }
+
+    /// Removes the callback set with
+    /// [`set_on_pre_quit`](Cursive::set_on_pre_quit).
+    pub fn clear_on_pre_quit(&mut self) {
+        self.on_pre_quit = None;
+    }
+
     /// Does not do anything.
     pub fn noop(&mut self) {
         // foo
@@ -930,6 +2644,7 @@ impl Cursive {
     /// * The view tree
     /// * Callbacks
     /// * Menubar
+    /// * Statusbar
     /// * User data
     /// * Callback sink
     ///
@@ -944,6 +2659,7 @@ impl Cursive {
             cb_source: std::mem::replace(&mut self.cb_source, cb_source),
             fps: self.fps.take(),
             menubar: std::mem::take(&mut self.menubar),
+            statusbar: std::mem::take(&mut self.statusbar),
             root_view: std::mem::replace(&mut self.root, root),
             theme: std::mem::take(&mut self.theme),
             user_data: std::mem::replace(&mut self.user_data, Box::new(())),
@@ -957,6 +2673,7 @@ impl Cursive {
     /// * All current views will be dropped, replaced by the dump.
     /// * All callbacks will be replaced.
     /// * Menubar will be replaced.
+    /// * Statusbar will be replaced.
     /// * User Data will be replaced.
     /// * The callback channel will be replaced - any previous call to
     ///   `cb_sink` on this instance will be disconnected.
@@ -965,9 +2682,276 @@ impl Cursive {
         self.cb_source = dump.cb_source;
         self.fps = dump.fps;
         self.menubar = dump.menubar;
+        self.statusbar = dump.statusbar;
         self.root = dump.root_view;
         self.theme = dump.theme;
         self.user_data = dump.user_data;
         self.clear();
     }
 }
+
+// Used by `register_searchable()`.
+fn search_named<V>(
+    siv: &mut Cursive,
+    name: &str,
+    query: &str,
+) -> Vec<view::SearchMatch>
+where
+    V: View + view::Searchable,
+{
+    siv.call_on_name(name, |v: &mut V| v.search(query))
+        .unwrap_or_default()
+}
+
+fn reveal_named<V>(siv: &mut Cursive, name: &str, found: &view::SearchMatch)
+where
+    V: View + view::Searchable,
+{
+    let result = match siv.call_on_name(name, |v: &mut V| v.reveal(found)) {
+        Some(EventResult::Ignored) | None => return,
+        Some(result) => result,
+    };
+
+    // The view could show the match: bring it to the user.
+    if let Ok(res) = siv.focus_name(name) {
+        res.process(siv);
+    }
+    result.process(siv);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct NoopWaker;
+
+    impl std::task::Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    #[test]
+    fn send_async() {
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+
+        let mut siv = Cursive::new();
+        let sink = siv.cb_sink_async();
+
+        // The task waits until the event loop runs the callback.
+        let mut task = Box::pin(sink.send_async(|s| s.screen().len()));
+        assert!(task.as_mut().poll(&mut cx).is_pending());
+        assert!(task.as_mut().poll(&mut cx).is_pending());
+        assert!(siv.process_callback());
+        assert_eq!(task.as_mut().poll(&mut cx), Poll::Ready(Some(0)));
+
+        // Nobody will run this callback.
+        let mut task = Box::pin(sink.send_async(|s| s.quit()));
+        assert!(task.as_mut().poll(&mut cx).is_pending());
+        drop(siv);
+        assert_eq!(task.as_mut().poll(&mut cx), Poll::Ready(None));
+    }
+
+    #[test]
+    fn frame_timing() {
+        let mut siv = Cursive::new();
+        assert_eq!(siv.frame_delta(), Duration::from_secs(0));
+        assert_eq!(siv.since_last_frame(), None);
+
+        let start = Instant::now();
+        siv.record_frame(start);
+        assert_eq!(siv.frame_delta(), Duration::from_secs(0));
+        assert!(siv.since_last_frame().is_some());
+
+        siv.record_frame(start + Duration::from_millis(16));
+        assert_eq!(siv.frame_delta(), Duration::from_millis(16));
+
+        // A clock going backward doesn't underflow.
+        siv.record_frame(start);
+        assert_eq!(siv.frame_delta(), Duration::from_secs(0));
+
+        assert!(siv.uptime() >= start.duration_since(siv.start_time));
+    }
+
+    #[test]
+    fn notifications_expire() {
+        let mut siv = Cursive::new();
+        let start = Instant::now();
+        siv.add_notification(
+            Notification::new("Short").duration(Duration::from_secs(1)),
+        );
+        siv.add_notification(Notification::new("Long"));
+        siv.add_notification(Notification::new("Forever").persistent());
+
+        assert!(!siv.expire_notifications(start));
+        assert_eq!(siv.notifications.len(), 3);
+
+        assert!(siv.expire_notifications(start + Duration::from_secs(2)));
+        assert_eq!(siv.notifications.len(), 2);
+
+        assert!(siv.expire_notifications(start + Duration::from_secs(3600)));
+        assert_eq!(siv.notifications.len(), 1);
+        assert_eq!(siv.notifications[0].deadline, None);
+    }
+
+    #[test]
+    fn quit_guard() {
+        use crate::event::Key;
+        use std::cell::Cell;
+
+        let calls = Rc::new(Cell::new(0));
+        let mut siv = Cursive::new();
+        siv.add_layer(views::TextView::new("Content"));
+
+        let counter = Rc::clone(&calls);
+        siv.set_on_pre_quit(move |_| {
+            counter.set(counter.get() + 1);
+            QuitAction::Cancel
+        });
+        siv.quit();
+        siv.on_event(Event::CtrlChar('c'));
+        assert!(siv.is_running());
+        assert_eq!(calls.get(), 2);
+
+        let counter = Rc::clone(&calls);
+        siv.set_on_pre_quit(move |_| {
+            counter.set(counter.get() + 1);
+            QuitAction::confirm(
+                views::Dialog::text("Quit?")
+                    .button("Quit anyway", |s| s.quit())
+                    .dismiss_button("Cancel"),
+            )
+        });
+
+        // Cancelling the dialog forgets about the quit.
+        siv.quit();
+        assert_eq!(siv.screen().len(), 2);
+        siv.layout(Vec2::new(40, 20));
+        siv.on_event(Event::Key(Key::Right));
+        siv.on_event(Event::Key(Key::Enter));
+        assert_eq!(siv.screen().len(), 1);
+        siv.on_event(Event::CtrlChar('c'));
+        assert!(siv.is_running());
+        assert_eq!(calls.get(), 4);
+
+        // "Quit anyway" calls `quit()` again, which skips the guard.
+        siv.layout(Vec2::new(40, 20));
+        siv.on_event(Event::Key(Key::Enter));
+        assert!(!siv.is_running());
+        assert_eq!(calls.get(), 4);
+    }
+
+    #[test]
+    fn layer_guard() {
+        use crate::view::Nameable;
+        use std::cell::Cell;
+
+        let allow = Rc::new(Cell::new(false));
+        let mut siv = Cursive::new();
+        siv.add_layer(views::TextView::new("Draft").with_name("editor"));
+        siv.add_layer(views::TextView::new("Popup"));
+
+        let allowed = Rc::clone(&allow);
+        siv.guard_layer("editor", move |_| {
+            if allowed.get() {
+                QuitAction::Quit
+            } else {
+                QuitAction::Cancel
+            }
+        });
+
+        // Only the guarded layer is protected.
+        assert!(siv.pop_layer().is_some());
+        assert!(siv.pop_layer().is_none());
+        assert_eq!(siv.screen().len(), 1);
+
+        allow.set(true);
+        assert!(siv.pop_layer().is_some());
+        assert_eq!(siv.screen().len(), 0);
+
+        // Without the guard, the layer always goes.
+        allow.set(false);
+        siv.add_layer(views::TextView::new("Draft").with_name("editor"));
+        siv.unguard_layer("editor");
+        assert!(siv.pop_layer().is_some());
+    }
+
+    #[test]
+    fn layer_guard_order() {
+        use crate::view::Nameable;
+        use std::cell::RefCell;
+
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let mut siv = Cursive::new();
+        siv.add_layer(
+            views::LinearLayout::vertical()
+                .child(views::TextView::new("a").with_name("a"))
+                .child(views::TextView::new("b").with_name("b")),
+        );
+
+        for &name in &["b", "a"] {
+            let log = Rc::clone(&calls);
+            siv.guard_layer(name, move |_| {
+                log.borrow_mut().push(name);
+                QuitAction::Cancel
+            });
+        }
+
+        // The guard set first always wins.
+        for _ in 0..5 {
+            assert!(siv.pop_layer().is_none());
+        }
+        assert_eq!(*calls.borrow(), ["b"; 5]);
+
+        // Replacing a guard keeps its rank.
+        let log = Rc::clone(&calls);
+        siv.guard_layer("b", move |_| {
+            log.borrow_mut().push("b2");
+            QuitAction::Cancel
+        });
+        siv.pop_layer();
+        assert_eq!(calls.borrow().last(), Some(&"b2"));
+    }
+
+    #[test]
+    fn focus_changes_screen() {
+        use crate::view::Nameable;
+        use std::cell::RefCell;
+
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let mut siv = Cursive::new();
+        siv.add_layer(views::EditView::new().with_name("main"));
+        let settings = siv.add_active_screen();
+        siv.add_layer(views::EditView::new().with_name("settings"));
+
+        let log = Rc::clone(&changes);
+        siv.set_on_screen_change(move |_, old, new| {
+            log.borrow_mut().push((old, new))
+        });
+
+        // Focusing a view on the active screen is not a screen change.
+        assert!(siv.focus_name("settings").is_ok());
+        assert!(siv.focus_name("main").is_ok());
+        assert_eq!(siv.active_screen(), 0);
+        assert!(siv.focus_name("missing").is_err());
+        assert_eq!(*changes.borrow(), vec![(settings, 0)]);
+    }
+
+    #[test]
+    fn named_screens() {
+        let mut siv = Cursive::new();
+        let help = siv.add_named_screen("help");
+        assert_eq!(siv.active_screen(), 0);
+
+        assert_eq!(siv.set_named_screen("help"), Some(help));
+        assert_eq!(siv.active_screen(), help);
+        assert_eq!(siv.active_screen_name(), Some("help"));
+
+        // Unknown names leave the active screen alone.
+        assert_eq!(siv.set_named_screen("missing"), None);
+        assert_eq!(siv.active_screen(), help);
+
+        siv.set_screen_name(0, "main");
+        assert_eq!(siv.set_named_screen("main"), Some(0));
+        assert_eq!(siv.active_screen_name(), Some("main"));
+    }
+}
@@ -1,10 +1,21 @@
-use crate::{backend, event::Event, theme, Cursive, Vec2};
+use crate::{backend, event::Event, redraw, theme, Cursive, Vec2};
 use std::borrow::{Borrow, BorrowMut};
 use std::time::Duration;
+use web_time::Instant;
 
 // How long we wait between two empty input polls
 const INPUT_POLL_DELAY_MS: u64 = 30;
 
+/// Why an event loop stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitReason {
+    /// [`Cursive::quit`] was called while events were still pending.
+    Quit,
+
+    /// All the given events were processed.
+    InputExhausted,
+}
+
 /// Event loop runner for a cursive instance.
 ///
 /// You can get one from `Cursive::runner`, then either call `.run()`, or
@@ -18,6 +29,8 @@ pub struct CursiveRunner<C> {
     // Last layer sizes of the stack view.
     // If it changed, clear the screen.
     last_sizes: Vec<Vec2>,
+    // Value of the redraw generation when we last drew the screen.
+    last_generation: usize,
 }
 
 impl<C> std::ops::Deref for CursiveRunner<C>
@@ -48,6 +61,7 @@ impl<C> CursiveRunner<C> {
             backend,
             boring_frame_count: 0,
             last_sizes: Vec::new(),
+            last_generation: redraw::generation(),
         }
     }
 
@@ -79,6 +93,22 @@ where
         }
     }
 
+    // Polls the backend for input, unless the terminal was handed back.
+    fn poll_event(&mut self) -> Option<Event> {
+        if self.is_paused() {
+            None
+        } else {
+            self.backend.poll_event()
+        }
+    }
+
+    // Did anything change since the last frame?
+    fn needs_redraw(&self) -> bool {
+        self.needs_redraw
+            || self.needs_clear
+            || self.last_generation != redraw::generation()
+    }
+
     fn draw(&mut self) {
         let sizes = self.screen().layer_sizes();
         if self.last_sizes != sizes {
@@ -120,8 +150,9 @@ where
         let mut boring = true;
 
         // First, handle all available input
-        while let Some(event) = self.backend.poll_event() {
+        while let Some(event) = self.poll_event() {
             boring = false;
+            self.record_event(&event);
             self.on_event(event);
             self.process_pending_backend_calls();
 
@@ -133,6 +164,7 @@ where
         // Then, handle any available callback
         while self.process_callback() {
             boring = false;
+            self.process_pending_backend_calls();
 
             if !self.is_running() {
                 return true;
@@ -154,25 +186,43 @@ where
     /// [3]: CursiveRunner::process_events()
     pub fn post_events(&mut self, received_something: bool) {
         let boring = !received_something;
+
+        // Notifications past their duration are removed from the screen.
+        let expired = self.expire_notifications(Instant::now());
+
         // How many times should we try if it's still boring?
         // Total duration will be INPUT_POLL_DELAY_MS * repeats
         // So effectively fps = 1000 / INPUT_POLL_DELAY_MS / repeats
-        if !boring
-            || self
+        let refresh_due = boring
+            && self
                 .fps()
                 .map(|fps| 1000 / INPUT_POLL_DELAY_MS as u32 / fps.get())
                 .map(|repeats| self.boring_frame_count >= repeats)
-                .unwrap_or(false)
+                .unwrap_or(false);
+
+        // Views may ask for another frame outside of events, for instance
+        // to run an animation.
+        let requested = self.last_generation != redraw::generation();
+
+        // While paused, the terminal is not ours: never draw anything.
+        if !self.is_paused()
+            && (!boring || expired || refresh_due || requested)
         {
             // We deserve to draw something!
 
-            if boring {
+            if refresh_due {
                 // We're only here because of a timeout.
                 self.on_event(Event::Refresh);
                 self.process_pending_backend_calls();
             }
 
-            self.refresh();
+            // Nothing changed? Keep the current frame.
+            // A refresh tick always redraws: views may depend on time alone.
+            if refresh_due || self.needs_redraw() {
+                self.refresh();
+            } else {
+                self.boring_frame_count = 0;
+            }
         }
 
         if boring {
@@ -182,18 +232,46 @@ where
     }
 
     /// Refresh the screen with the current view tree state.
+    ///
+    /// This always lays out and draws the views, even if nothing changed.
+    ///
+    /// With the `frame-stats` feature, the time spent in each phase is
+    /// available from `Cursive::last_frame_stats()`.
     pub fn refresh(&mut self) {
         self.boring_frame_count = 0;
 
+        // Requests made while drawing this frame are for the next one.
+        self.needs_redraw = false;
+        self.last_generation = redraw::generation();
+
+        #[cfg(feature = "frame-stats")]
+        let start = Instant::now();
+
         // Do we need to redraw everytime?
         // Probably, actually.
         // TODO: Do we need to re-layout everytime?
         self.layout();
 
+        #[cfg(feature = "frame-stats")]
+        let layout_end = Instant::now();
+
         // TODO: Do we need to redraw every view every time?
         // (Is this getting repetitive? :p)
         self.draw();
+
+        #[cfg(feature = "frame-stats")]
+        let draw_end = Instant::now();
+
         self.backend.refresh();
+
+        #[cfg(feature = "frame-stats")]
+        {
+            self.last_frame_stats = Some(crate::FrameStats {
+                layout: layout_end - start,
+                draw: draw_end - layout_end,
+                present: draw_end.elapsed(),
+            });
+        }
     }
 
     /// Return the name of the backend used.
@@ -239,4 +317,195 @@ where
             self.step();
         }
     }
+
+    /// Runs the event loop on a predetermined sequence of events.
+    ///
+    /// Events are processed one per frame, along with any pending callback,
+    /// without waiting for the backend input. Once every event was
+    /// processed, this stops with [`Cursive::force_quit`]: guards set with
+    /// [`Cursive::set_on_pre_quit`] are not run, since no more input would
+    /// come to answer them.
+    ///
+    /// Returns why the loop stopped.
+    pub fn run_with_input(&mut self, events: Vec<Event>) -> ExitReason {
+        self.refresh();
+
+        let mut events = events.into_iter();
+        while self.is_running() {
+            let event = match events.next() {
+                Some(event) => event,
+                None => {
+                    self.force_quit();
+                    return ExitReason::InputExhausted;
+                }
+            };
+
+            self.record_event(&event);
+            self.on_event(event);
+            self.process_pending_backend_calls();
+
+            while self.is_running() && self.process_callback() {
+                self.process_pending_backend_calls();
+            }
+
+            if self.needs_redraw() {
+                self.refresh();
+            }
+        }
+
+        ExitReason::Quit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::align::Align;
+    use crate::event::Key;
+    use crate::view::Nameable;
+    use crate::views::{
+        Canvas, Dialog, EditView, HideableView, TextContent, TextView,
+    };
+    use crate::QuitAction;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn run_with_input() {
+        let mut siv = Cursive::new();
+        siv.add_layer(EditView::new().with_name("edit"));
+
+        let events = vec![Event::Char('h'), Event::Char('i')];
+        assert_eq!(siv.run_with_input(events), ExitReason::InputExhausted);
+        assert!(!siv.is_running());
+
+        let content = siv
+            .call_on_name("edit", |view: &mut EditView| view.get_content())
+            .unwrap();
+        assert_eq!(&*content, "hi");
+
+        // Events after a quit are never processed.
+        siv.add_global_callback(Key::Esc, Cursive::quit);
+        let events =
+            vec![Event::Char('!'), Event::Key(Key::Esc), Event::Char('?')];
+        assert_eq!(siv.run_with_input(events), ExitReason::Quit);
+
+        let content = siv
+            .call_on_name("edit", |view: &mut EditView| view.get_content())
+            .unwrap();
+        assert_eq!(&*content, "hi!");
+    }
+
+    #[test]
+    fn run_with_input_skips_guard() {
+        let mut siv = Cursive::new();
+        siv.add_layer(EditView::new());
+        siv.set_on_pre_quit(|_| {
+            QuitAction::confirm(Dialog::text("Quit?").dismiss_button("No"))
+        });
+
+        let events = vec![Event::Char('a')];
+        assert_eq!(siv.run_with_input(events), ExitReason::InputExhausted);
+        assert!(!siv.is_running());
+
+        // No confirmation dialog was shown.
+        assert_eq!(siv.screen().len(), 1);
+    }
+
+    #[test]
+    fn redraw_only_on_change() {
+        let content = TextContent::new("Hello");
+        let mut siv = Cursive::new();
+        siv.add_layer(TextView::new_with_content(content.clone()));
+        siv.add_layer(EditView::new());
+
+        let mut runner = siv.runner(backend::Dummy::init());
+        runner.refresh();
+        assert!(!runner.needs_redraw);
+
+        // Nobody cares about this event.
+        runner.on_event(Event::Key(Key::F12));
+        assert!(!runner.needs_redraw);
+
+        runner.on_event(Event::Char('a'));
+        assert!(runner.needs_redraw);
+        runner.refresh();
+        assert!(!runner.needs_redraw);
+
+        // Changes made through the root are noticed.
+        let mutations: [fn(&mut Cursive); 5] = [
+            |s| s.set_autohide_menu(false),
+            |s| s.statusbar().set_autohide(false),
+            |s| s.set_notification_align(Align::top_left()),
+            |s| {
+                s.notify("Hello");
+            },
+            |s| {
+                let screen = s.add_screen();
+                s.set_screen(screen);
+            },
+        ];
+        for mutate in &mutations {
+            mutate(&mut runner);
+            assert!(runner.needs_redraw);
+            runner.refresh();
+        }
+
+        // Shared content updates are noticed, even from another thread.
+        std::thread::spawn(move || content.set_content("World"))
+            .join()
+            .unwrap();
+        assert!(runner.needs_redraw());
+    }
+
+    #[test]
+    fn animation_keeps_drawing() {
+        let mut siv = Cursive::new();
+        siv.add_layer(
+            HideableView::new(TextView::new("Hello"))
+                .animated(true)
+                .animation_duration(Duration::from_secs(3600))
+                .with_name("hideable"),
+        );
+
+        let mut runner = siv.runner(backend::Dummy::init());
+        runner.refresh();
+        runner.call_on_name("hideable", |v: &mut HideableView<TextView>| {
+            v.hide()
+        });
+        runner.refresh();
+
+        // Without any event or refresh rate, the animation still gets new
+        // frames.
+        runner.post_events(false);
+        runner.post_events(false);
+        assert_eq!(runner.boring_frame_count, 1);
+    }
+
+    #[test]
+    fn fps_tick_redraws() {
+        let draws = Rc::new(Cell::new(0));
+        let counter = Rc::clone(&draws);
+
+        let mut siv = Cursive::new();
+        // This view ignores `Event::Refresh`.
+        siv.add_fullscreen_layer(
+            Canvas::new(())
+                .with_draw(move |_, _| counter.set(counter.get() + 1)),
+        );
+
+        let mut runner = siv.runner(backend::Dummy::init());
+        runner.refresh();
+        let drawn = draws.get();
+
+        // Nothing changed: the frame is kept.
+        runner.post_events(false);
+        assert_eq!(draws.get(), drawn);
+
+        // With a refresh rate, each tick draws again.
+        runner.set_fps(30);
+        runner.post_events(false);
+        runner.post_events(false);
+        assert!(draws.get() > drawn);
+    }
 }
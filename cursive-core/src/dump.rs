@@ -13,6 +13,7 @@ pub struct Dump {
     pub(crate) fps: Option<NonZeroU32>,
 
     pub(crate) menubar: views::Menubar,
+    pub(crate) statusbar: views::Statusbar,
     pub(crate) root_view:
         views::OnEventView<views::ScreensView<views::StackView>>,
 
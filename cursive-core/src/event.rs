@@ -15,9 +15,13 @@
 
 use crate::Cursive;
 use crate::Vec2;
+use enumset::{EnumSet, EnumSetType};
 use std::any::Any;
+use std::fmt;
 use std::ops::Deref;
 use std::rc::Rc;
+use std::str::FromStr;
+use std::sync::Arc;
 
 /// Callback is a function that can be triggered by an event.
 /// It has a mutable access to the cursive root.
@@ -236,6 +240,67 @@ impl From<Box<dyn Fn(&mut Cursive)>> for Callback {
     }
 }
 
+/// Thread-safe callback, with a mutable access to the cursive root.
+///
+/// Unlike [`Callback`], it can be created on a background thread and sent
+/// to the main thread, for example as part of a view description. Views
+/// store regular `Callback`s: it converts into one, and builders taking
+/// `impl Into<Callback>` (like [`Tree::leaf_callback`]) accept it directly.
+///
+/// [`Tree::leaf_callback`]: crate::menu::Tree::leaf_callback
+///
+/// # Limitations
+///
+/// This does not make views `Send`: views still hold `Rc`-based callbacks,
+/// so they cannot be built on a background thread. Send the `ArcCallback`s
+/// (and whatever data the views need) instead, and build the views on the
+/// main thread, for example from a [`CbSink`](crate::CbSink) callback.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::event::ArcCallback;
+/// use cursive_core::menu::Tree;
+///
+/// let quit = std::thread::spawn(|| ArcCallback::from_fn(|s| s.quit()))
+///     .join()
+///     .unwrap();
+///
+/// let menu = Tree::new().leaf_callback("Quit", quit);
+/// ```
+#[derive(Clone)]
+pub struct ArcCallback(Arc<dyn Fn(&mut Cursive) + Send + Sync>);
+
+impl ArcCallback {
+    /// Wraps the given function into an `ArcCallback` object.
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: 'static + Fn(&mut Cursive) + Send + Sync,
+    {
+        ArcCallback(Arc::new(f))
+    }
+}
+
+impl Deref for ArcCallback {
+    type Target = dyn Fn(&mut Cursive) + Send + Sync + 'static;
+
+    fn deref(&self) -> &Self::Target {
+        &*self.0
+    }
+}
+
+impl From<Arc<dyn Fn(&mut Cursive) + Send + Sync>> for ArcCallback {
+    fn from(f: Arc<dyn Fn(&mut Cursive) + Send + Sync>) -> Self {
+        ArcCallback(f)
+    }
+}
+
+impl From<ArcCallback> for Callback {
+    fn from(f: ArcCallback) -> Self {
+        Callback::from_fn(move |s| f(s))
+    }
+}
+
 /// Answer to an event notification.
 /// The event can be consumed or ignored.
 pub enum EventResult {
@@ -481,7 +546,42 @@ impl MouseEvent {
     }
 }
 
+/// A modifier key held while another key is pressed.
+///
+/// Use [`Event::modifiers`] to get the modifiers of a keyboard event.
+#[derive(EnumSetType, Debug)]
+pub enum Modifier {
+    /// The Shift key.
+    Shift,
+    /// The Ctrl key.
+    Ctrl,
+    /// The Alt key.
+    Alt,
+}
+
 /// Represents an event as seen by the application.
+///
+/// # Modifiers
+///
+/// Keyboard events with the most common modifiers have their own variants,
+/// like `CtrlChar` or `AltShift`. Other combinations, like `Ctrl+Shift+a`,
+/// use `ModifiedChar` or `ModifiedKey`. Build events with
+/// [`Event::from_char`] and [`Event::from_key`] to always get the same
+/// variant for a given combination, and use [`Event::modifiers`] to handle
+/// modifiers uniformly.
+///
+/// Not every terminal reports every combination:
+///
+/// * The crossterm backend reports any combination, as long as the terminal
+///   sends it.
+/// * The ncurses and pancurses backends report modifiers on non-character
+///   keys only if the terminfo entry describes them (most `xterm`-like
+///   terminals do). Modifiers on characters are limited to Ctrl and Alt.
+/// * The termion backend only reports `CtrlChar`, `AltChar`, `Shift+Tab`
+///   and arrow keys with a single modifier.
+/// * The BearLibTerminal backend reports Shift and Ctrl, but not Alt.
+/// * Most terminals can't tell `Ctrl+Shift+a` from `Ctrl+a`, and send the
+///   same sequence for `Ctrl+i` and `Tab`.
 #[derive(PartialEq, Eq, Clone, Hash, Debug)]
 pub enum Event {
     /// Event fired when the window is resized.
@@ -516,6 +616,17 @@ pub enum Event {
     /// A non-character key was pressed with the Ctrl and Alt keys pressed.
     CtrlAlt(Key),
 
+    /// A character was entered with a combination of modifiers that doesn't
+    /// have its own variant, like Ctrl+Alt or Ctrl+Shift.
+    ///
+    /// Use [`Event::from_char`] to build this event.
+    ModifiedChar(char, EnumSet<Modifier>),
+    /// A non-character key was pressed with a combination of modifiers that
+    /// doesn't have its own variant, like Ctrl+Alt+Shift.
+    ///
+    /// Use [`Event::from_key`] to build this event.
+    ModifiedKey(Key, EnumSet<Modifier>),
+
     /// A mouse event was sent.
     Mouse {
         /// Position of the top-left corner of the view receiving this event.
@@ -542,6 +653,126 @@ pub enum Event {
 }
 
 impl Event {
+    /// Builds a character event with the given modifiers.
+    ///
+    /// Shift alone is ignored, since the character already reflects it.
+    /// Combinations without a dedicated variant give `ModifiedChar`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::event::{Event, Modifier};
+    ///
+    /// assert_eq!(Event::from_char('a', Modifier::Ctrl), Event::CtrlChar('a'));
+    /// assert_eq!(
+    ///     Event::from_char('A', Modifier::Ctrl | Modifier::Shift)
+    ///         .modifiers(),
+    ///     Modifier::Ctrl | Modifier::Shift
+    /// );
+    /// ```
+    pub fn from_char<M>(c: char, modifiers: M) -> Self
+    where
+        M: Into<EnumSet<Modifier>>,
+    {
+        let mut modifiers = modifiers.into();
+        if modifiers == Modifier::Shift {
+            modifiers = EnumSet::empty();
+        }
+
+        if modifiers.is_empty() {
+            Event::Char(c)
+        } else if modifiers == Modifier::Ctrl {
+            Event::CtrlChar(c)
+        } else if modifiers == Modifier::Alt {
+            Event::AltChar(c)
+        } else {
+            Event::ModifiedChar(c, modifiers)
+        }
+    }
+
+    /// Builds a non-character key event with the given modifiers.
+    ///
+    /// Combinations without a dedicated variant give `ModifiedKey`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::event::{Event, Key, Modifier};
+    ///
+    /// assert_eq!(
+    ///     Event::from_key(Key::Up, Modifier::Ctrl | Modifier::Shift),
+    ///     Event::CtrlShift(Key::Up)
+    /// );
+    /// ```
+    pub fn from_key<M>(key: Key, modifiers: M) -> Self
+    where
+        M: Into<EnumSet<Modifier>>,
+    {
+        let modifiers = modifiers.into();
+        let ctrl = modifiers.contains(Modifier::Ctrl);
+        let alt = modifiers.contains(Modifier::Alt);
+        let shift = modifiers.contains(Modifier::Shift);
+
+        match (ctrl, alt, shift) {
+            (false, false, false) => Event::Key(key),
+            (false, false, true) => Event::Shift(key),
+            (false, true, false) => Event::Alt(key),
+            (false, true, true) => Event::AltShift(key),
+            (true, false, false) => Event::Ctrl(key),
+            (true, false, true) => Event::CtrlShift(key),
+            (true, true, false) => Event::CtrlAlt(key),
+            (true, true, true) => Event::ModifiedKey(key, modifiers),
+        }
+    }
+
+    /// Returns the modifiers held during this keyboard event.
+    ///
+    /// Returns an empty set for other events, and for characters typed with
+    /// Shift alone.
+    pub fn modifiers(&self) -> EnumSet<Modifier> {
+        match *self {
+            Event::CtrlChar(_) | Event::Ctrl(_) => Modifier::Ctrl.into(),
+            Event::AltChar(_) | Event::Alt(_) => Modifier::Alt.into(),
+            Event::Shift(_) => Modifier::Shift.into(),
+            Event::AltShift(_) => Modifier::Alt | Modifier::Shift,
+            Event::CtrlShift(_) => Modifier::Ctrl | Modifier::Shift,
+            Event::CtrlAlt(_) => Modifier::Ctrl | Modifier::Alt,
+            Event::ModifiedChar(_, modifiers)
+            | Event::ModifiedKey(_, modifiers) => modifiers,
+            _ => EnumSet::empty(),
+        }
+    }
+
+    /// Returns the non-character key of this event, whatever the modifiers.
+    ///
+    /// Returns `None` if `self` is not a non-character key event.
+    pub fn key(&self) -> Option<Key> {
+        match *self {
+            Event::Key(key)
+            | Event::Shift(key)
+            | Event::Alt(key)
+            | Event::AltShift(key)
+            | Event::Ctrl(key)
+            | Event::CtrlShift(key)
+            | Event::CtrlAlt(key)
+            | Event::ModifiedKey(key, _) => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Returns the character of this event, whatever the modifiers.
+    ///
+    /// Returns `None` if `self` is not a character event.
+    pub fn char(&self) -> Option<char> {
+        match *self {
+            Event::Char(c)
+            | Event::CtrlChar(c)
+            | Event::AltChar(c)
+            | Event::ModifiedChar(c, _) => Some(c),
+            _ => None,
+        }
+    }
+
     /// Returns the position of the mouse, if `self` is a mouse event.
     pub fn mouse_position(&self) -> Option<Vec2> {
         if let Event::Mouse { position, .. } = *self {
@@ -604,3 +835,455 @@ impl From<Key> for Event {
         Event::Key(k)
     }
 }
+
+/// Error parsing an event name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEventError {
+    input: String,
+}
+
+impl ParseEventError {
+    fn new(input: &str) -> Self {
+        ParseEventError {
+            input: input.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseEventError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Could not parse event: {:?}", self.input)
+    }
+}
+
+impl std::error::Error for ParseEventError {}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Key::Ins => write!(f, "Insert"),
+            Key::Del => write!(f, "Delete"),
+            key => write!(f, "{:?}", key),
+        }
+    }
+}
+
+impl FromStr for Key {
+    type Err = ParseEventError;
+
+    /// Parses a key name, ignoring case.
+    ///
+    /// Accepts the names given by `Display`, as well as a few aliases like
+    /// `Ins`, `Del` or `Escape`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let key = match s.to_lowercase().as_str() {
+            "enter" | "return" => Key::Enter,
+            "tab" => Key::Tab,
+            "backspace" => Key::Backspace,
+            "esc" | "escape" => Key::Esc,
+            "left" => Key::Left,
+            "right" => Key::Right,
+            "up" => Key::Up,
+            "down" => Key::Down,
+            "insert" | "ins" => Key::Ins,
+            "delete" | "del" => Key::Del,
+            "home" => Key::Home,
+            "end" => Key::End,
+            "pageup" => Key::PageUp,
+            "pagedown" => Key::PageDown,
+            "pausebreak" => Key::PauseBreak,
+            "numpadcenter" => Key::NumpadCenter,
+            name => match name.strip_prefix('f').map(str::parse::<u8>) {
+                Some(Ok(n)) if n <= 12 => Key::from_f(n),
+                _ => return Err(ParseEventError::new(s)),
+            },
+        };
+        Ok(key)
+    }
+}
+
+impl fmt::Display for MouseButton {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl FromStr for MouseButton {
+    type Err = ParseEventError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let button = match s.to_lowercase().as_str() {
+            "left" => MouseButton::Left,
+            "middle" => MouseButton::Middle,
+            "right" => MouseButton::Right,
+            "button4" => MouseButton::Button4,
+            "button5" => MouseButton::Button5,
+            "other" => MouseButton::Other,
+            _ => return Err(ParseEventError::new(s)),
+        };
+        Ok(button)
+    }
+}
+
+impl fmt::Display for MouseEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            MouseEvent::Press(btn) => write!(f, "Press{}", btn),
+            MouseEvent::Release(btn) => write!(f, "Release{}", btn),
+            MouseEvent::Hold(btn) => write!(f, "Hold{}", btn),
+            MouseEvent::WheelUp => write!(f, "WheelUp"),
+            MouseEvent::WheelDown => write!(f, "WheelDown"),
+        }
+    }
+}
+
+impl FromStr for MouseEvent {
+    type Err = ParseEventError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.to_lowercase();
+        let button = |prefix: &str| {
+            lower
+                .strip_prefix(prefix)
+                .and_then(|button| button.parse().ok())
+        };
+
+        let event = match lower.as_str() {
+            "wheelup" => MouseEvent::WheelUp,
+            "wheeldown" => MouseEvent::WheelDown,
+            _ => {
+                if let Some(btn) = button("press") {
+                    MouseEvent::Press(btn)
+                } else if let Some(btn) = button("release") {
+                    MouseEvent::Release(btn)
+                } else if let Some(btn) = button("hold") {
+                    MouseEvent::Hold(btn)
+                } else {
+                    return Err(ParseEventError::new(s));
+                }
+            }
+        };
+        Ok(event)
+    }
+}
+
+// Name of a character, when used in an event.
+//
+// Space and `+` have names, so they don't get lost among the separators.
+fn char_name(c: char) -> String {
+    match c {
+        ' ' => "Space".to_string(),
+        '+' => "Plus".to_string(),
+        c => c.to_string(),
+    }
+}
+
+// Writes modifiers in their canonical order, like `Ctrl+Alt+Shift+`.
+fn write_modifiers(
+    f: &mut fmt::Formatter<'_>,
+    modifiers: EnumSet<Modifier>,
+) -> fmt::Result {
+    for (modifier, name) in &[
+        (Modifier::Ctrl, "Ctrl"),
+        (Modifier::Alt, "Alt"),
+        (Modifier::Shift, "Shift"),
+    ] {
+        if modifiers.contains(*modifier) {
+            write!(f, "{}+", name)?;
+        }
+    }
+    Ok(())
+}
+
+impl fmt::Display for Event {
+    /// Writes the canonical name of this event, like `Ctrl+Shift+F5`.
+    ///
+    /// Mouse events are named without their position, like `MouseWheelUp`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Event::WindowResize => write!(f, "WindowResize"),
+            Event::FocusLost => write!(f, "FocusLost"),
+            Event::Refresh => write!(f, "Refresh"),
+            Event::Exit => write!(f, "Exit"),
+            Event::Char(c) => write!(f, "{}", char_name(c)),
+            Event::CtrlChar(c) => write!(f, "Ctrl+{}", char_name(c)),
+            Event::AltChar(c) => write!(f, "Alt+{}", char_name(c)),
+            Event::Key(key) => write!(f, "{}", key),
+            Event::Shift(key) => write!(f, "Shift+{}", key),
+            Event::Alt(key) => write!(f, "Alt+{}", key),
+            Event::AltShift(key) => write!(f, "Alt+Shift+{}", key),
+            Event::Ctrl(key) => write!(f, "Ctrl+{}", key),
+            Event::CtrlShift(key) => write!(f, "Ctrl+Shift+{}", key),
+            Event::CtrlAlt(key) => write!(f, "Ctrl+Alt+{}", key),
+            Event::ModifiedChar(c, modifiers) => {
+                write_modifiers(f, modifiers)?;
+                write!(f, "{}", char_name(c))
+            }
+            Event::ModifiedKey(key, modifiers) => {
+                write_modifiers(f, modifiers)?;
+                write!(f, "{}", key)
+            }
+            Event::Mouse { event, .. } => write!(f, "Mouse{}", event),
+            Event::Unknown(ref bytes) => write!(f, "Unknown{:?}", bytes),
+        }
+    }
+}
+
+impl FromStr for Event {
+    type Err = ParseEventError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Event::parse(s)
+    }
+}
+
+impl Event {
+    /// Returns the canonical name of this event, like `Ctrl+Shift+F5`.
+    ///
+    /// This is the same as the `Display` implementation, and can be parsed
+    /// back with [`Event::parse`].
+    pub fn name(&self) -> String {
+        self.to_string()
+    }
+
+    /// Parses an event name, like `Ctrl+Shift+F5` or `MouseWheelUp`.
+    ///
+    /// Modifiers and key names are case-insensitive, but single characters
+    /// are kept as-is: `Alt+x` and `Alt+X` are different events. Space and
+    /// `+` are written `Space` and `Plus`.
+    ///
+    /// Mouse events are parsed with a zero position and offset. `Unknown`
+    /// events cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::event::{Event, Key};
+    ///
+    /// assert_eq!(Event::parse("ctrl+shift+f5"), Ok(Event::CtrlShift(Key::F5)));
+    /// assert_eq!(Event::parse("Alt+x"), Ok(Event::AltChar('x')));
+    /// assert_eq!(Event::CtrlAlt(Key::Del).to_string(), "Ctrl+Alt+Delete");
+    /// assert!(Event::parse("Hyper+Q").is_err());
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, ParseEventError> {
+        let err = || ParseEventError::new(s);
+
+        // The last part is the key, everything before is a modifier.
+        let mut parts: Vec<&str> = s.split('+').collect();
+        let name = parts.pop().ok_or_else(err)?;
+
+        let mut modifiers = EnumSet::new();
+        for part in parts {
+            let modifier = match part.to_lowercase().as_str() {
+                "ctrl" | "control" => Modifier::Ctrl,
+                "alt" => Modifier::Alt,
+                "shift" => Modifier::Shift,
+                _ => return Err(err()),
+            };
+            if !modifiers.insert(modifier) {
+                // Repeated modifier
+                return Err(err());
+            }
+        }
+
+        let mut chars = name.chars();
+        let c = match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => match name.to_lowercase().as_str() {
+                "space" => Some(' '),
+                "plus" => Some('+'),
+                _ => None,
+            },
+        };
+
+        if let Some(c) = c {
+            // Shift alone is already part of the character.
+            if modifiers == Modifier::Shift {
+                return Err(err());
+            }
+            return Ok(Event::from_char(c, modifiers));
+        }
+
+        if let Ok(key) = name.parse() {
+            return Ok(Event::from_key(key, modifiers));
+        }
+
+        if !modifiers.is_empty() {
+            return Err(err());
+        }
+
+        let lower = name.to_lowercase();
+        let event = match lower.as_str() {
+            "windowresize" => Event::WindowResize,
+            "focuslost" => Event::FocusLost,
+            "refresh" => Event::Refresh,
+            "exit" => Event::Exit,
+            _ => {
+                let event = lower
+                    .strip_prefix("mouse")
+                    .and_then(|event| event.parse().ok())
+                    .ok_or_else(err)?;
+                Event::Mouse {
+                    offset: Vec2::zero(),
+                    position: Vec2::zero(),
+                    event,
+                }
+            }
+        };
+        Ok(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all_keys() -> Vec<Key> {
+        let mut keys = vec![
+            Key::Enter,
+            Key::Tab,
+            Key::Backspace,
+            Key::Esc,
+            Key::Left,
+            Key::Right,
+            Key::Up,
+            Key::Down,
+            Key::Ins,
+            Key::Del,
+            Key::Home,
+            Key::End,
+            Key::PageUp,
+            Key::PageDown,
+            Key::PauseBreak,
+            Key::NumpadCenter,
+        ];
+        keys.extend((0..=12).map(Key::from_f));
+        keys
+    }
+
+    fn all_events() -> Vec<Event> {
+        let mut events = vec![
+            Event::WindowResize,
+            Event::FocusLost,
+            Event::Refresh,
+            Event::Exit,
+        ];
+
+        let chars = "aZ09 +!@#-_=/\\|~`'\"é€日";
+        for c in chars.chars() {
+            events.push(Event::Char(c));
+            events.push(Event::CtrlChar(c));
+            events.push(Event::AltChar(c));
+            events.push(Event::from_char(c, Modifier::Ctrl | Modifier::Alt));
+            events.push(Event::from_char(c, EnumSet::all()));
+        }
+
+        let modifiers: [fn(Key) -> Event; 7] = [
+            Event::Key,
+            Event::Shift,
+            Event::Alt,
+            Event::AltShift,
+            Event::Ctrl,
+            Event::CtrlShift,
+            Event::CtrlAlt,
+        ];
+        for key in all_keys() {
+            events.extend(modifiers.iter().map(|modifier| modifier(key)));
+            events.push(Event::from_key(key, EnumSet::all()));
+        }
+
+        let buttons = [
+            MouseButton::Left,
+            MouseButton::Middle,
+            MouseButton::Right,
+            MouseButton::Button4,
+            MouseButton::Button5,
+            MouseButton::Other,
+        ];
+        let mut mouse_events =
+            vec![MouseEvent::WheelUp, MouseEvent::WheelDown];
+        for &btn in &buttons {
+            mouse_events.push(MouseEvent::Press(btn));
+            mouse_events.push(MouseEvent::Release(btn));
+            mouse_events.push(MouseEvent::Hold(btn));
+        }
+        events.extend(mouse_events.into_iter().map(|event| Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::zero(),
+            event,
+        }));
+
+        events
+    }
+
+    #[test]
+    fn round_trip() {
+        for event in all_events() {
+            let name = event.to_string();
+            assert_eq!(Event::parse(&name), Ok(event.clone()), "{}", name);
+
+            // Only single characters are case-sensitive.
+            let is_char = matches!(
+                event,
+                Event::Char(_)
+                    | Event::CtrlChar(_)
+                    | Event::AltChar(_)
+                    | Event::ModifiedChar(..)
+            );
+            if !is_char {
+                assert_eq!(Event::parse(&name.to_lowercase()), Ok(event));
+            }
+        }
+    }
+
+    #[test]
+    fn parse_names() {
+        assert_eq!(Event::parse("shift+TAB"), Ok(Event::Shift(Key::Tab)));
+        assert_eq!(Event::parse("Shift+Alt+Up"), Ok(Event::AltShift(Key::Up)));
+        assert_eq!(Event::parse("ctrl+plus"), Ok(Event::CtrlChar('+')));
+        assert_eq!(Event::parse("Space"), Ok(Event::Char(' ')));
+        assert_eq!(Event::parse("f10"), Ok(Event::Key(Key::F10)));
+        assert_eq!(
+            Event::parse("mousewheelup").map(|e| e.to_string()),
+            Ok("MouseWheelUp".to_string())
+        );
+
+        assert_eq!(
+            Event::parse("Shift+Ctrl+Alt+Up"),
+            Ok(Event::ModifiedKey(Key::Up, EnumSet::all()))
+        );
+        assert_eq!(
+            Event::ModifiedChar('A', Modifier::Ctrl | Modifier::Shift)
+                .to_string(),
+            "Ctrl+Shift+A"
+        );
+
+        for bad in &["", "Ctrl+", "Ctrl+Ctrl+a", "Shift+a", "F13", "Ctrl+Exit"]
+        {
+            assert!(Event::parse(bad).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn modifiers() {
+        for event in all_events() {
+            let modifiers = event.modifiers();
+            let rebuilt = match (event.char(), event.key()) {
+                (Some(c), None) => Event::from_char(c, modifiers),
+                (None, Some(key)) => Event::from_key(key, modifiers),
+                (None, None) => {
+                    assert!(modifiers.is_empty(), "{}", event);
+                    continue;
+                }
+                (Some(_), Some(_)) => unreachable!(),
+            };
+            assert_eq!(rebuilt, event);
+        }
+
+        assert_eq!(Event::from_char('A', Modifier::Shift), Event::Char('A'));
+        assert_eq!(
+            Event::CtrlShift(Key::Left).modifiers(),
+            Modifier::Ctrl | Modifier::Shift
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use std::time::Duration;
+
+/// Time spent refreshing the screen during a frame.
+///
+/// This requires the `frame-stats` feature to be enabled.
+///
+/// Each frame is measured in [`CursiveRunner::refresh`], in three phases:
+///
+/// * `layout`: computing the size and position of every view, through
+///   `View::required_size` and `View::layout`.
+/// * `draw`: drawing every view to the backend, through `View::draw`.
+/// * `present`: the backend sending the drawn frame to the terminal.
+///
+/// Time spent handling events and running callbacks is not included.
+///
+/// [`CursiveRunner::refresh`]: crate::CursiveRunner::refresh
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FrameStats {
+    /// Time spent laying out the views.
+    pub layout: Duration,
+    /// Time spent drawing the views.
+    pub draw: Duration,
+    /// Time spent by the backend to output the frame.
+    pub present: Duration,
+}
+
+impl FrameStats {
+    /// Returns the total time spent on this frame.
+    pub fn total(&self) -> Duration {
+        self.layout + self.draw + self.present
+    }
+}
@@ -0,0 +1,140 @@
+//! Help screen listing the key bindings.
+//!
+//! Bindings registered with a description, using
+//! [`Cursive::add_global_callback_with_help`] or
+//! [`OnEventView::on_event_help`], are listed by [`Cursive::show_help`].
+//!
+//! [`Cursive::add_global_callback_with_help`]: crate::Cursive::add_global_callback_with_help
+//! [`OnEventView::on_event_help`]: crate::views::OnEventView::on_event_help
+//! [`Cursive::show_help`]: crate::Cursive::show_help
+use crate::event::Event;
+use crate::theme::Effect;
+use crate::utils::markup::StyledString;
+use crate::utils::width::TextWidth;
+
+/// Scope of the bindings registered with
+/// [`Cursive::add_global_callback_with_help`](crate::Cursive::add_global_callback_with_help).
+pub const GLOBAL_SCOPE: &str = "Global";
+
+/// Default scope of the bindings registered on an `OnEventView`.
+pub const DEFAULT_SCOPE: &str = "Views";
+
+/// A documented key binding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Binding {
+    /// Event triggering the binding.
+    pub event: Event,
+
+    /// What the binding does.
+    pub description: String,
+
+    /// Group of bindings this one belongs to.
+    pub scope: String,
+}
+
+/// Returns a readable name for the given event, like `Ctrl+Q`.
+///
+/// Returns `None` for events without a key, like mouse events.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::event::{Event, Key};
+/// use cursive_core::help::key_name;
+///
+/// assert_eq!(key_name(&Event::CtrlChar('q')).as_deref(), Some("Ctrl+Q"));
+/// assert_eq!(key_name(&Event::Shift(Key::Tab)).as_deref(), Some("Shift+Tab"));
+/// assert_eq!(key_name(&Event::Char(' ')).as_deref(), Some("Space"));
+/// ```
+pub fn key_name(event: &Event) -> Option<String> {
+    match *event {
+        // Ctrl characters are usually written in uppercase.
+        Event::CtrlChar(c) => {
+            Some(Event::CtrlChar(c.to_ascii_uppercase()).to_string())
+        }
+        Event::Char(_)
+        | Event::AltChar(_)
+        | Event::Key(_)
+        | Event::Shift(_)
+        | Event::Alt(_)
+        | Event::AltShift(_)
+        | Event::Ctrl(_)
+        | Event::CtrlShift(_)
+        | Event::CtrlAlt(_) => Some(event.to_string()),
+        _ => None,
+    }
+}
+
+/// Renders the bindings as text, grouped by scope.
+///
+/// Scopes appear in the order they are first seen. Bindings without a key
+/// name are skipped.
+pub(crate) fn render(bindings: &[Binding]) -> StyledString {
+    let bindings: Vec<(String, &Binding)> = bindings
+        .iter()
+        .filter_map(|binding| Some((key_name(&binding.event)?, binding)))
+        .collect();
+
+    let mut scopes: Vec<&str> = Vec::new();
+    for (_, binding) in &bindings {
+        if !scopes.contains(&binding.scope.as_str()) {
+            scopes.push(&binding.scope);
+        }
+    }
+
+    let key_width = bindings
+        .iter()
+        .map(|(key, _)| key.width())
+        .max()
+        .unwrap_or(0);
+
+    let mut text = StyledString::new();
+    for (i, scope) in scopes.iter().enumerate() {
+        if i > 0 {
+            text.append_plain("\n");
+        }
+        text.append_styled(*scope, Effect::Bold);
+        text.append_plain("\n");
+
+        for (key, binding) in &bindings {
+            if binding.scope != *scope {
+                continue;
+            }
+            let padding = " ".repeat(key_width - key.width());
+            text.append_plain(format!(
+                "  {}{}  {}\n",
+                key, padding, binding.description
+            ));
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::Key;
+
+    fn binding(event: Event, description: &str, scope: &str) -> Binding {
+        Binding {
+            event,
+            description: description.to_string(),
+            scope: scope.to_string(),
+        }
+    }
+
+    #[test]
+    fn render_groups_by_scope() {
+        let bindings = [
+            binding(Event::CtrlChar('q'), "Quit", GLOBAL_SCOPE),
+            binding(Event::Key(Key::Del), "Remove", "List"),
+            binding(Event::Char('?'), "Help", GLOBAL_SCOPE),
+        ];
+
+        assert_eq!(
+            render(&bindings).source(),
+            "Global\n  Ctrl+Q  Quit\n  ?       Help\n\
+             \nList\n  Delete  Remove\n"
+        );
+    }
+}
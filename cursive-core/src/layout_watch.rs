@@ -0,0 +1,150 @@
+//! Rebuild views when their layout file changes.
+//!
+//! Layout files are JSON descriptions, as understood by
+//! [`Context::build`](crate::builder::Context::build).
+use crate::builder::{Config, Context};
+use crate::view::{Finder, View};
+use crate::views::{BoxedView, Checkbox, EditView, SelectView};
+use std::cell::RefCell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+/// Delay between two checks of the layout file.
+pub(crate) const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A layout file being watched.
+pub(crate) struct LayoutWatch {
+    pub path: PathBuf,
+    pub context: Context,
+
+    /// Name of the last focused named view.
+    pub focused: Rc<RefCell<Option<String>>>,
+
+    /// Set when the watch is dropped, to stop the polling thread.
+    pub stop: Arc<AtomicBool>,
+}
+
+impl LayoutWatch {
+    /// Starts watching the file at `path`, using `context` to build views.
+    pub fn new(path: PathBuf, mut context: Context) -> Self {
+        let focused = Rc::new(RefCell::new(None));
+        context.focused = Some(Rc::clone(&focused));
+
+        LayoutWatch {
+            path,
+            context,
+            focused,
+            stop: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Reads the file and builds the view it describes.
+    ///
+    /// Returns a displayable message on error.
+    pub fn build(&self) -> Result<Box<dyn View>, String> {
+        let content = fs::read_to_string(&self.path)
+            .map_err(|e| format!("Could not read {:?}: {}", self.path, e))?;
+        let config: Config = serde_json::from_str(&content)
+            .map_err(|e| format!("Could not parse {:?}: {}", self.path, e))?;
+        self.context
+            .build(&config)
+            .map_err(|e| format!("Invalid layout in {:?}: {}", self.path, e))
+    }
+}
+
+impl Drop for LayoutWatch {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Returns the last modification time of the file, if available.
+pub(crate) fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Copies the values of named inputs from `old` to `new`.
+///
+/// Only inputs present in both views, with the same type, are copied.
+pub(crate) fn restore_inputs(old: &mut BoxedView, new: &mut BoxedView) {
+    let mut names = Vec::new();
+    collect_names(&**new, &mut names);
+
+    for name in &names {
+        if let Some(content) =
+            old.call_on_name(name, |v: &mut EditView| v.get_content())
+        {
+            new.call_on_name(name, |v: &mut EditView| {
+                v.set_content(&*content);
+            });
+        }
+        if let Some(checked) =
+            old.call_on_name(name, |v: &mut Checkbox| v.is_checked())
+        {
+            new.call_on_name(name, |v: &mut Checkbox| {
+                v.set_checked(checked);
+            });
+        }
+        if let Some(Some(i)) =
+            old.call_on_name(name, |v: &mut SelectView| v.selected_id())
+        {
+            new.call_on_name(name, |v: &mut SelectView| {
+                if i < v.len() {
+                    v.set_selection(i);
+                }
+            });
+        }
+    }
+}
+
+// Lists the names of all named views in the tree.
+fn collect_names(view: &dyn View, names: &mut Vec<String>) {
+    if let Some(name) = view.debug_name() {
+        names.push(name.to_string());
+    }
+    view.debug_children(&mut |child, _| collect_names(child, names));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restore_named_inputs() {
+        let context = Context::new();
+        let build = |json: &str| {
+            let config: Config = serde_json::from_str(json).unwrap();
+            BoxedView::new(context.build(&config).unwrap())
+        };
+
+        let mut old = build(
+            r#"{ "LinearLayout": { "children": [
+                { "EditView": { "name": "user" } },
+                { "Checkbox": { "name": "check" } },
+                { "EditView": { "name": "removed" } }
+            ] } }"#,
+        );
+        old.call_on_name("user", |v: &mut EditView| v.set_content("alice"));
+        old.call_on_name("check", |v: &mut Checkbox| v.set_checked(true));
+
+        let mut new = build(
+            r#"{ "LinearLayout": { "orientation": "horizontal", "children": [
+                { "Checkbox": { "name": "check" } },
+                { "TextView": "User:" },
+                { "EditView": { "name": "user" } }
+            ] } }"#,
+        );
+        restore_inputs(&mut old, &mut new);
+
+        let content =
+            new.call_on_name("user", |v: &mut EditView| v.get_content());
+        assert_eq!(content.as_deref().map(String::as_str), Some("alice"));
+        let checked =
+            new.call_on_name("check", |v: &mut Checkbox| v.is_checked());
+        assert_eq!(checked, Some(true));
+    }
+}
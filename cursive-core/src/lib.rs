@@ -45,6 +45,9 @@ pub mod reexports {
 
     #[cfg(feature = "toml")]
     pub use toml;
+
+    #[cfg(feature = "builder")]
+    pub use serde_json;
 }
 
 #[macro_use]
@@ -56,10 +59,15 @@ pub mod views;
 
 pub mod align;
 pub mod backend;
+#[cfg(feature = "builder")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "builder")))]
+pub mod builder;
 pub mod direction;
 pub mod event;
+pub mod help;
 pub mod logger;
 pub mod menu;
+pub mod notification;
 pub mod theme;
 pub mod traits;
 pub mod vec;
@@ -67,18 +75,28 @@ pub mod vec;
 mod cursive;
 mod cursive_run;
 mod dump;
+#[cfg(feature = "frame-stats")]
+mod frame_stats;
+#[cfg(feature = "watch")]
+mod layout_watch;
 mod printer;
+mod recording;
 mod rect;
+mod redraw;
 mod with;
 mod xy;
 
 mod div;
 
-pub use self::cursive::{CbSink, Cursive, ScreenId};
-pub use self::cursive_run::CursiveRunner;
+pub use self::cursive::{CbSink, CbSinkAsync, Cursive, QuitAction, ScreenId};
+pub use self::cursive_run::{CursiveRunner, ExitReason};
 pub use self::dump::Dump;
+#[cfg(feature = "frame-stats")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "frame-stats")))]
+pub use self::frame_stats::FrameStats;
 pub use self::printer::Printer;
 pub use self::rect::Rect;
+pub use self::redraw::request_redraw;
 pub use self::vec::Vec2;
 pub use self::view::View;
 pub use self::with::With;
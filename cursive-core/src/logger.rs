@@ -1,8 +1,10 @@
 //! Logging utilities.
 
+use crossbeam_channel::{Receiver, Sender};
 use lazy_static::lazy_static;
 use std::collections::VecDeque;
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard};
 
 /// Saves all log records in a global deque.
 ///
@@ -11,37 +13,117 @@ pub struct CursiveLogger;
 
 static LOGGER: CursiveLogger = CursiveLogger;
 
+// Maximum number of records kept in `LOGS`.
+static CAPACITY: AtomicUsize = AtomicUsize::new(1_000);
+
 /// A log record.
+///
+/// New fields may be added, so records from outside this crate are built
+/// with [`Record::new`].
+#[non_exhaustive]
 pub struct Record {
     /// Log level used for this record
     pub level: log::Level,
+    /// Target of this record, usually the module that logged it
+    pub target: String,
     /// Time this message was logged
     pub time: time::OffsetDateTime,
     /// Message content
     pub message: String,
 }
 
+impl Record {
+    /// Creates a new record.
+    pub fn new(
+        level: log::Level,
+        target: String,
+        time: time::OffsetDateTime,
+        message: String,
+    ) -> Self {
+        Record {
+            level,
+            target,
+            time,
+            message,
+        }
+    }
+
+    fn now(level: log::Level, target: &str, message: String) -> Self {
+        Record {
+            level,
+            target: target.to_string(),
+            time: time::OffsetDateTime::now_local()
+                .unwrap_or_else(|_| time::OffsetDateTime::now_utc()),
+            message,
+        }
+    }
+}
+
 lazy_static! {
     /// Circular buffer for logs. Use it to implement [`DebugView`].
     ///
+    /// Records logged while it was locked are only added by [`logs()`],
+    /// which should be preferred to locking it directly.
+    ///
     /// [`DebugView`]: ../views/struct.DebugView.html
     pub static ref LOGS: Mutex<VecDeque<Record>> =
         Mutex::new(VecDeque::new());
+
+    // Records waiting for `LOGS` to be available.
+    //
+    // Logging never waits for the lock, so other threads are not blocked
+    // while the UI thread reads the logs.
+    static ref PENDING: (Sender<Record>, Receiver<Record>) =
+        crossbeam_channel::unbounded();
 }
 
 /// Log a record in cursive's log queue.
 pub fn log(record: &log::Record<'_>) {
-    let mut logs = LOGS.lock().unwrap();
-    // TODO: customize the format? Use colors? Save more info?
-    if logs.len() == logs.capacity() {
-        logs.pop_front();
+    push(Record::now(
+        record.level(),
+        record.target(),
+        format!("{}", record.args()),
+    ));
+}
+
+// Adds a record to the queue, without waiting for the lock.
+fn push(record: Record) {
+    if CAPACITY.load(Ordering::Relaxed) == 0 {
+        return;
     }
-    logs.push_back(Record {
-        level: record.level(),
-        message: format!("{}", record.args()),
-        time: time::OffsetDateTime::now_local()
-            .unwrap_or_else(|_| time::OffsetDateTime::now_utc()),
-    });
+
+    // The channel is unbounded: this never blocks.
+    let _ = PENDING.0.send(record);
+
+    // If the logs are busy, the next reader will add the pending records.
+    if let Ok(mut logs) = LOGS.try_lock() {
+        add_pending(&mut logs);
+    }
+
+    // Show the new record in any debug view.
+    crate::request_redraw();
+}
+
+// Moves the pending records to the logs, dropping the oldest ones if needed.
+fn add_pending(logs: &mut VecDeque<Record>) {
+    let capacity = CAPACITY.load(Ordering::Relaxed);
+    for record in PENDING.1.try_iter() {
+        while !logs.is_empty() && logs.len() >= capacity {
+            logs.pop_front();
+        }
+        if capacity > 0 {
+            logs.push_back(record);
+        }
+    }
+}
+
+/// Locks cursive's log queue, and returns it.
+///
+/// Includes the records logged while the queue was locked.
+pub fn logs() -> MutexGuard<'static, VecDeque<Record>> {
+    let mut logs = LOGS.lock().unwrap();
+    add_pending(&mut logs);
+    logs
 }
 
 impl log::Log for CursiveLogger {
@@ -62,9 +144,10 @@ impl log::Log for CursiveLogger {
 ///
 /// Use a [`DebugView`](crate::views::DebugView) to see the logs, or use
 /// [`Cursive::toggle_debug_console()`](crate::Cursive::toggle_debug_console()).
+///
+/// Use [`builder()`] for more options.
 pub fn init() {
-    // TODO: Configure the deque size?
-    reserve_logs(1_000);
+    reserve_logs(capacity());
 
     // This will panic if `set_logger` was already called.
     log::set_logger(&LOGGER).unwrap();
@@ -79,15 +162,300 @@ pub fn init() {
 ///
 /// An easier alternative might be to use [`init()`].
 pub fn get_logger() -> CursiveLogger {
-    reserve_logs(1_000);
+    reserve_logs(capacity());
     CursiveLogger
 }
 
-/// Adds `n` more entries to cursive's log queue.
+/// Allocates room for `n` more entries in cursive's log queue.
 ///
-/// Most of the time you don't need to use this directly.
-///
-/// You should call this if you're not using `init()` nor `get_logger()`.
+/// This does not change how many records are kept: see [`set_capacity()`].
 pub fn reserve_logs(n: usize) {
     LOGS.lock().unwrap().reserve(n);
 }
+
+/// Sets the maximum number of records kept in cursive's log queue.
+///
+/// When full, the oldest records are dropped first. Defaults to 1000.
+pub fn set_capacity(capacity: usize) {
+    CAPACITY.store(capacity, Ordering::Relaxed);
+
+    let mut logs = logs();
+    let excess = logs.len().saturating_sub(capacity);
+    logs.drain(..excess);
+}
+
+/// Returns the maximum number of records kept in cursive's log queue.
+pub fn capacity() -> usize {
+    CAPACITY.load(Ordering::Relaxed)
+}
+
+/// Returns a builder for a configurable logger.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use cursive_core::logger;
+///
+/// logger::builder()
+///     .level(log::LevelFilter::Info)
+///     .target_level("my_app::net", log::LevelFilter::Trace)
+///     .init();
+/// ```
+pub fn builder() -> Builder {
+    Builder::new()
+}
+
+/// Configures a [`Logger`].
+///
+/// By default, it stores every record in cursive's log queue, like
+/// [`CursiveLogger`].
+pub struct Builder {
+    retain: bool,
+    forward: Vec<Box<dyn log::Log>>,
+    level: log::LevelFilter,
+    target_levels: Vec<(String, log::LevelFilter)>,
+}
+
+impl Builder {
+    /// Creates a new builder, with the default configuration.
+    pub fn new() -> Self {
+        Builder {
+            retain: true,
+            forward: Vec::new(),
+            level: log::LevelFilter::Trace,
+            target_levels: Vec::new(),
+        }
+    }
+
+    /// Sets whether records are stored in cursive's log queue.
+    ///
+    /// Disable it to only forward records to other loggers.
+    #[must_use]
+    pub fn retain(mut self, retain: bool) -> Self {
+        self.retain = retain;
+        self
+    }
+
+    /// Also sends records to the given logger.
+    ///
+    /// Can be called several times to forward to several loggers.
+    #[must_use]
+    pub fn forward_to(mut self, logger: Box<dyn log::Log>) -> Self {
+        self.forward.push(logger);
+        self
+    }
+
+    /// Sets the most verbose level logged, for targets without a specific
+    /// level.
+    #[must_use]
+    pub fn level(mut self, level: log::LevelFilter) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Sets the most verbose level logged for the given target.
+    ///
+    /// This also applies to nested targets: the level for `app` applies to
+    /// `app::net`. The most specific target wins.
+    #[must_use]
+    pub fn target_level<S: Into<String>>(
+        mut self,
+        target: S,
+        level: log::LevelFilter,
+    ) -> Self {
+        self.target_levels.push((target.into(), level));
+        self
+    }
+
+    /// Builds the logger.
+    pub fn build(self) -> Logger {
+        let mut target_levels = self.target_levels;
+        // Most specific targets first.
+        target_levels
+            .sort_by_key(|(target, _)| std::cmp::Reverse(target.len()));
+
+        Logger {
+            retain: self.retain,
+            forward: self.forward,
+            level: self.level,
+            target_levels,
+        }
+    }
+
+    /// Builds the logger, and sets it as the global logger.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a global logger was already set.
+    pub fn init(self) {
+        let logger = self.build();
+        if logger.retain {
+            reserve_logs(capacity());
+        }
+
+        log::set_max_level(logger.max_level());
+        log::set_boxed_logger(Box::new(logger)).unwrap();
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Logger configured with a [`Builder`].
+pub struct Logger {
+    retain: bool,
+    forward: Vec<Box<dyn log::Log>>,
+    level: log::LevelFilter,
+    // Sorted from the most specific target.
+    target_levels: Vec<(String, log::LevelFilter)>,
+}
+
+impl Logger {
+    /// Returns the most verbose level logged for the given target.
+    pub fn level_for(&self, target: &str) -> log::LevelFilter {
+        self.target_levels
+            .iter()
+            .find(|(prefix, _)| is_in_target(target, prefix))
+            .map_or(self.level, |&(_, level)| level)
+    }
+
+    /// Returns the most verbose level logged for any target.
+    pub fn max_level(&self) -> log::LevelFilter {
+        self.target_levels
+            .iter()
+            .map(|&(_, level)| level)
+            .fold(self.level, std::cmp::max)
+    }
+}
+
+// Returns `true` if `target` is `prefix`, or is nested in it.
+fn is_in_target(target: &str, prefix: &str) -> bool {
+    match target.strip_prefix(prefix) {
+        Some(rest) => rest.is_empty() || rest.starts_with("::"),
+        None => false,
+    }
+}
+
+impl log::Log for Logger {
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        metadata.level() <= self.level_for(metadata.target())
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        if !log::Log::enabled(self, record.metadata()) {
+            return;
+        }
+
+        if self.retain {
+            log(record);
+        }
+        for logger in &self.forward {
+            if logger.enabled(record.metadata()) {
+                logger.log(record);
+            }
+        }
+    }
+
+    fn flush(&self) {
+        for logger in &self.forward {
+            logger.flush();
+        }
+    }
+}
+
+/// A `tracing` layer storing events in cursive's log queue.
+///
+/// Events are prefixed with the names of the spans they are in, like
+/// `request:parse: message`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use tracing_subscriber::prelude::*;
+///
+/// tracing_subscriber::registry()
+///     .with(cursive_core::logger::TracingLayer)
+///     .init();
+/// ```
+#[cfg(feature = "tracing")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "tracing")))]
+pub struct TracingLayer;
+
+#[cfg(feature = "tracing")]
+impl<S> tracing_subscriber::Layer<S> for TracingLayer
+where
+    S: tracing_core::Subscriber
+        + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+{
+    fn on_event(
+        &self,
+        event: &tracing_core::Event<'_>,
+        ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        let metadata = event.metadata();
+
+        let level = match *metadata.level() {
+            tracing_core::Level::ERROR => log::Level::Error,
+            tracing_core::Level::WARN => log::Level::Warn,
+            tracing_core::Level::INFO => log::Level::Info,
+            tracing_core::Level::DEBUG => log::Level::Debug,
+            _ => log::Level::Trace,
+        };
+
+        let mut message = String::new();
+        if let Some(scope) = ctx.event_scope(event) {
+            for span in scope.from_root() {
+                message.push_str(span.name());
+                message.push_str(": ");
+            }
+        }
+
+        let mut visitor = MessageVisitor(&mut message);
+        event.record(&mut visitor);
+
+        push(Record::now(level, metadata.target(), message));
+    }
+}
+
+// Writes the fields of a `tracing` event, starting with the message.
+#[cfg(feature = "tracing")]
+struct MessageVisitor<'a>(&'a mut String);
+
+#[cfg(feature = "tracing")]
+impl tracing_core::field::Visit for MessageVisitor<'_> {
+    fn record_debug(
+        &mut self,
+        field: &tracing_core::field::Field,
+        value: &dyn std::fmt::Debug,
+    ) {
+        use std::fmt::Write;
+
+        if field.name() == "message" {
+            let _ = write!(self.0, "{:?}", value);
+        } else {
+            let _ = write!(self.0, " {}={:?}", field.name(), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn target_levels() {
+        let logger = builder()
+            .level(log::LevelFilter::Warn)
+            .target_level("app", log::LevelFilter::Info)
+            .target_level("app::net", log::LevelFilter::Trace)
+            .build();
+
+        assert_eq!(logger.level_for("app::net::tcp"), log::LevelFilter::Trace);
+        assert_eq!(logger.level_for("app::ui"), log::LevelFilter::Info);
+        assert_eq!(logger.level_for("application"), log::LevelFilter::Warn);
+        assert_eq!(logger.max_level(), log::LevelFilter::Trace);
+    }
+}
@@ -13,10 +13,26 @@
 //! [`Tree`]: struct.Tree.html
 //! [menubar]: ../struct.Cursive.html#method.menubar
 
-use crate::{event::Callback, Cursive, With};
+use crate::{
+    event::{ArcCallback, Callback, Event},
+    views::SelectView,
+    Cursive, With,
+};
+use std::any::Any;
+use std::collections::hash_map::{Entry, HashMap};
 use std::rc::Rc;
 
+/// Separates the labels of a path to a leaf, in flattened menus.
+pub(crate) const PATH_SEPARATOR: &str = " > ";
+
 /// Root of a menu tree.
+///
+/// Menu callbacks use `Rc`, so a `Tree` is not `Send`. To prepare a menu from
+/// a background thread, create its callbacks there with [`Tree::leaf_arc`]
+/// or [`ArcCallback`], send them to the UI thread (for example through
+/// [`Cursive::cb_sink`]) and build the tree there.
+///
+/// [`ArcCallback`]: crate::event::ArcCallback
 #[derive(Default, Clone)]
 pub struct Tree {
     /// Menu items
@@ -36,6 +52,8 @@ pub enum Item {
         ///
         /// Disabled items cannot be selected and are displayed grayed out.
         enabled: bool,
+        /// Optional settings for this entry.
+        extras: ItemExtras,
     },
 
     /// Sub-menu with a label.
@@ -48,23 +66,187 @@ pub enum Item {
         ///
         /// Disabled items cannot be selected and are displayed grayed out.
         enabled: bool,
+        /// Optional settings for this entry.
+        extras: ItemExtras,
     },
 
     /// Delimiter without a label.
     Delimiter,
 }
 
+/// Optional settings of a menu item.
+///
+/// They are usually changed through the methods of [`Item`]. More settings
+/// may be added later, so this can only be created with `Default`.
+#[derive(Clone, Default)]
+#[non_exhaustive]
+pub struct ItemExtras {
+    /// Arbitrary data attached to a leaf.
+    ///
+    /// See [`Item::as_leaf_data`].
+    pub data: Option<Rc<dyn Any>>,
+    /// Event running the callback of a leaf, even while the menu is closed.
+    ///
+    /// See [`Item::shortcut`].
+    pub shortcut: Option<Event>,
+    /// Callback to run when a subtree is opened.
+    ///
+    /// See [`Item::on_open`].
+    pub on_open: Option<Callback>,
+    /// Why this item is disabled.
+    ///
+    /// See [`Item::disabled_with_reason`].
+    pub disabled_reason: Option<String>,
+    /// Hint shown while this item is highlighted.
+    ///
+    /// See [`Item::with_help`].
+    pub help: Option<String>,
+}
+
 impl Item {
     /// Create a new leaf menu item.
     pub fn leaf<S, F>(label: S, cb: F) -> Self
     where
         S: Into<String>,
         F: 'static + Fn(&mut Cursive),
+    {
+        Self::leaf_callback(label, Callback::from_fn(cb))
+    }
+
+    /// Create a new leaf menu item from a thread-safe callback.
+    ///
+    /// The callback is wrapped in an [`ArcCallback`], so it can capture
+    /// `Arc`-shared data and be created on another thread.
+    ///
+    /// [`ArcCallback`]: crate::event::ArcCallback
+    pub fn leaf_arc<S, F>(label: S, cb: F) -> Self
+    where
+        S: Into<String>,
+        F: 'static + Fn(&mut Cursive) + Send + Sync,
+    {
+        Self::leaf_callback(label, ArcCallback::from_fn(cb))
+    }
+
+    /// Create a new leaf menu item from an existing callback.
+    ///
+    /// This accepts anything convertible into a [`Callback`], like an
+    /// [`ArcCallback`](crate::event::ArcCallback) created on another thread.
+    pub fn leaf_callback<S, C>(label: S, cb: C) -> Self
+    where
+        S: Into<String>,
+        C: Into<Callback>,
     {
         let label = label.into();
-        let cb = Callback::from_fn(cb);
+        let cb = cb.into();
         let enabled = true;
-        Item::Leaf { label, cb, enabled }
+        let extras = ItemExtras::default();
+        Item::Leaf {
+            label,
+            cb,
+            enabled,
+            extras,
+        }
+    }
+
+    /// Returns the optional settings of this item.
+    ///
+    /// Returns `None` for delimiters.
+    pub fn extras(&self) -> Option<&ItemExtras> {
+        match *self {
+            Item::Leaf { ref extras, .. }
+            | Item::Subtree { ref extras, .. } => Some(extras),
+            Item::Delimiter => None,
+        }
+    }
+
+    /// Returns a mutable reference to the optional settings of this item.
+    ///
+    /// Returns `None` for delimiters.
+    pub fn extras_mut(&mut self) -> Option<&mut ItemExtras> {
+        match *self {
+            Item::Leaf { ref mut extras, .. }
+            | Item::Subtree { ref mut extras, .. } => Some(extras),
+            Item::Delimiter => None,
+        }
+    }
+
+    /// Create a new leaf menu item, with some attached data.
+    ///
+    /// The data can be retrieved with [`Item::as_leaf_data`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::menu::Item;
+    ///
+    /// let item = Item::leaf_with_data("Open", 42u32, |_| ());
+    /// assert_eq!(item.as_leaf_data::<u32>(), Some(&42));
+    /// assert_eq!(item.as_leaf_data::<String>(), None);
+    /// ```
+    pub fn leaf_with_data<S, T, F>(label: S, data: T, cb: F) -> Self
+    where
+        S: Into<String>,
+        T: 'static,
+        F: 'static + Fn(&mut Cursive),
+    {
+        Self::leaf(label, cb).with(|item| {
+            if let Item::Leaf { ref mut extras, .. } = *item {
+                extras.data = Some(Rc::new(data));
+            }
+        })
+    }
+
+    /// Sets an event running the callback of this leaf.
+    ///
+    /// Once the tree is in the menubar, the event runs the callback even
+    /// while the menu is closed, unless a view or a global callback uses it
+    /// first. The shortcut is shown next to the label, and stops working
+    /// when the item is removed or disabled.
+    ///
+    /// Does nothing if `self` is not a leaf.
+    pub fn set_shortcut<E: Into<Event>>(&mut self, event: E) {
+        if let Item::Leaf { ref mut extras, .. } = *self {
+            extras.shortcut = Some(event.into());
+        }
+    }
+
+    /// Sets an event running the callback of this leaf.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::event::Event;
+    /// use cursive_core::menu::Item;
+    ///
+    /// let item = Item::leaf("Quit", |s| s.quit()).shortcut(Event::CtrlChar('q'));
+    /// assert_eq!(item.get_shortcut(), Some(&Event::CtrlChar('q')));
+    /// ```
+    #[must_use]
+    pub fn shortcut<E: Into<Event>>(self, event: E) -> Self {
+        self.with(|item| item.set_shortcut(event))
+    }
+
+    /// Returns the shortcut of this leaf, if any.
+    pub fn get_shortcut(&self) -> Option<&Event> {
+        match *self {
+            Item::Leaf { ref extras, .. } => extras.shortcut.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the data attached to this leaf, if it has the type `T`.
+    ///
+    /// Returns `None` if `self` is not a leaf, if no data is attached, or if
+    /// the data is of a different type.
+    pub fn as_leaf_data<T: 'static>(&self) -> Option<&T> {
+        match *self {
+            Item::Leaf { ref extras, .. } => {
+                extras.data.as_ref()?.downcast_ref()
+            }
+            _ => None,
+        }
     }
 
     /// Create a new subtree menu item.
@@ -75,13 +257,51 @@ impl Item {
         let label = label.into();
         let tree = Rc::new(tree);
         let enabled = true;
+        let extras = ItemExtras::default();
         Item::Subtree {
             label,
             tree,
             enabled,
+            extras,
+        }
+    }
+
+    /// Sets a callback to run when this subtree is opened.
+    ///
+    /// The callback runs after the subtree popup is shown, and only when it
+    /// is actually opened: selecting the item without opening it does not
+    /// trigger it.
+    ///
+    /// Does nothing if `self` is not a subtree.
+    pub fn set_on_open<F>(&mut self, cb: F)
+    where
+        F: 'static + Fn(&mut Cursive),
+    {
+        if let Item::Subtree { ref mut extras, .. } = *self {
+            extras.on_open = Some(Callback::from_fn(cb));
         }
     }
 
+    /// Sets a callback to run when this subtree is opened.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::menu::{Item, Tree};
+    ///
+    /// let recent = Item::subtree("Recent", Tree::new().leaf("a.txt", |_| ()))
+    ///     .on_open(|_| log::info!("Recent files opened"));
+    /// ```
+    #[must_use]
+    pub fn on_open<F>(self, cb: F) -> Self
+    where
+        F: 'static + Fn(&mut Cursive),
+    {
+        self.with(|item| item.set_on_open(cb))
+    }
+
     /// Returns the label for this item.
     ///
     /// Returns a vertical bar string if `self` is a delimiter.
@@ -97,11 +317,15 @@ impl Item {
     /// Returns true if this item is enabled.
     ///
     /// Only labels and subtrees can be enabled. Delimiters
+    ///
+    /// A subtree is also considered disabled when all its children are
+    /// disabled (see [`Tree::all_children_disabled`]).
     pub fn is_enabled(&self) -> bool {
         match *self {
-            Item::Leaf { enabled, .. } | Item::Subtree { enabled, .. } => {
-                enabled
-            }
+            Item::Leaf { enabled, .. } => enabled,
+            Item::Subtree {
+                enabled, ref tree, ..
+            } => enabled && !tree.all_children_disabled(),
             Item::Delimiter => false,
         }
     }
@@ -112,6 +336,86 @@ impl Item {
         self.with(Self::disable)
     }
 
+    /// Disable this item, explaining why.
+    ///
+    /// Unlike other disabled items, menu popups can highlight it, and then
+    /// show the reason.
+    ///
+    /// Does not affect delimiters.
+    pub fn disable_with_reason<S: Into<String>>(&mut self, reason: S) {
+        self.disable();
+        if let Some(extras) = self.extras_mut() {
+            extras.disabled_reason = Some(reason.into());
+        }
+    }
+
+    /// Return a disabled version of this item, explaining why.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::menu::Item;
+    ///
+    /// let item = Item::leaf("Save", |_| ())
+    ///     .disabled_with_reason("Requires an open file");
+    /// assert!(!item.is_enabled());
+    /// assert_eq!(item.disabled_reason(), Some("Requires an open file"));
+    /// ```
+    #[must_use]
+    pub fn disabled_with_reason<S: Into<String>>(self, reason: S) -> Self {
+        self.with(|item| item.disable_with_reason(reason))
+    }
+
+    /// Sets a hint shown while this item is highlighted in a menu.
+    ///
+    /// See [`Cursive::set_on_menu_help`](crate::Cursive::set_on_menu_help).
+    ///
+    /// Does not affect delimiters.
+    pub fn set_help<S: Into<String>>(&mut self, text: S) {
+        if let Some(extras) = self.extras_mut() {
+            extras.help = Some(text.into());
+        }
+    }
+
+    /// Sets a hint shown while this item is highlighted in a menu.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::menu::Item;
+    ///
+    /// let item = Item::leaf("Save", |_| ()).with_help("Save the file");
+    /// assert_eq!(item.help(), Some("Save the file"));
+    /// ```
+    #[must_use]
+    pub fn with_help<S: Into<String>>(self, text: S) -> Self {
+        self.with(|item| item.set_help(text))
+    }
+
+    /// Returns the hint shown while this item is highlighted, if any.
+    pub fn help(&self) -> Option<&str> {
+        self.extras()?.help.as_deref()
+    }
+
+    /// Returns why this item is disabled, if it is and a reason was given.
+    pub fn disabled_reason(&self) -> Option<&str> {
+        match *self {
+            Item::Leaf {
+                enabled: false,
+                ref extras,
+                ..
+            }
+            | Item::Subtree {
+                enabled: false,
+                ref extras,
+                ..
+            } => extras.disabled_reason.as_deref(),
+            _ => None,
+        }
+    }
+
     /// Disable this item.
     ///
     /// Disabled items cannot be selected and are shown grayed out.
@@ -144,6 +448,19 @@ impl Item {
         matches!(*self, Item::Subtree { .. })
     }
 
+    /// Returns an independent copy of the subtree, if applicable.
+    ///
+    /// Unlike cloning the item, which shares the subtree, this duplicates
+    /// every nested subtree (see [`Tree::deep_clone`]).
+    ///
+    /// Returns `None` if `self` is not a `Item::Subtree`.
+    pub fn clone_subtree(&self) -> Option<Tree> {
+        match *self {
+            Item::Subtree { ref tree, .. } => Some(tree.deep_clone()),
+            _ => None,
+        }
+    }
+
     /// Return a mutable reference to the subtree, if applicable.
     ///
     /// Returns `None` if `self` is not a `Item::Subtree`.
@@ -204,15 +521,79 @@ impl Tree {
         S: Into<String>,
         F: 'static + Fn(&mut Cursive),
     {
-        let label = label.into();
-        self.insert(
-            i,
-            Item::Leaf {
-                label,
-                cb: Callback::from_fn(cb),
-                enabled: true,
-            },
-        );
+        self.insert(i, Item::leaf(label, cb));
+    }
+
+    /// Adds a leaf with some attached data to the end of this tree.
+    ///
+    /// See [`Item::leaf_with_data`].
+    pub fn add_leaf_with_data<S, T, F>(&mut self, label: S, data: T, cb: F)
+    where
+        S: Into<String>,
+        T: 'static,
+        F: 'static + Fn(&mut Cursive),
+    {
+        self.add_item(Item::leaf_with_data(label, data, cb));
+    }
+
+    /// Adds a leaf with some attached data to the end of this tree.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn leaf_with_data<S, T, F>(self, label: S, data: T, cb: F) -> Self
+    where
+        S: Into<String>,
+        T: 'static,
+        F: 'static + Fn(&mut Cursive),
+    {
+        self.with(|menu| menu.add_leaf_with_data(label, data, cb))
+    }
+
+    /// Adds a actionnable leaf, with a shortcut, to the end of this tree.
+    ///
+    /// See [`Item::set_shortcut`].
+    pub fn add_leaf_with_shortcut<S, E, F>(
+        &mut self,
+        label: S,
+        event: E,
+        cb: F,
+    ) where
+        S: Into<String>,
+        E: Into<Event>,
+        F: 'static + Fn(&mut Cursive),
+    {
+        self.add_item(Item::leaf(label, cb).shortcut(event));
+    }
+
+    /// Adds a actionnable leaf, with a shortcut, to the end of this tree.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn leaf_with_shortcut<S, E, F>(self, label: S, event: E, cb: F) -> Self
+    where
+        S: Into<String>,
+        E: Into<Event>,
+        F: 'static + Fn(&mut Cursive),
+    {
+        self.with(|menu| menu.add_leaf_with_shortcut(label, event, cb))
+    }
+
+    /// Returns the callback of the first enabled leaf with this shortcut.
+    ///
+    /// Looks into nested subtrees, skipping disabled ones.
+    pub fn find_shortcut(&self, event: &Event) -> Option<Callback> {
+        self.children
+            .iter()
+            .filter(|item| item.is_enabled())
+            .find_map(|item| match *item {
+                Item::Leaf {
+                    ref cb, ref extras, ..
+                } if extras.shortcut.as_ref() == Some(event) => {
+                    Some(cb.clone())
+                }
+                Item::Subtree { ref tree, .. } => tree.find_shortcut(event),
+                _ => None,
+            })
     }
 
     /// Adds a actionnable leaf to the end of this tree - chainable variant.
@@ -225,17 +606,103 @@ impl Tree {
         self.with(|menu| menu.add_leaf(label, cb))
     }
 
+    /// Adds a leaf with a thread-safe callback to the end of this tree.
+    ///
+    /// See [`Item::leaf_arc`].
+    pub fn add_leaf_arc<S, F>(&mut self, label: S, cb: F)
+    where
+        S: Into<String>,
+        F: 'static + Fn(&mut Cursive) + Send + Sync,
+    {
+        self.add_item(Item::leaf_arc(label, cb));
+    }
+
+    /// Adds a leaf with a thread-safe callback to the end of this tree.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::menu::Tree;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let recent = Arc::new(Mutex::new(vec!["a.txt", "b.txt"]));
+    ///
+    /// // Callbacks prepared in a background task.
+    /// let entries: Vec<(String, _)> = std::thread::spawn(move || {
+    ///     let files = recent.lock().unwrap().clone();
+    ///     files
+    ///         .into_iter()
+    ///         .map(|file| {
+    ///             let recent = Arc::clone(&recent);
+    ///             let cb = move |_: &mut cursive_core::Cursive| {
+    ///                 recent.lock().unwrap().retain(|f| *f != file);
+    ///             };
+    ///             (file.to_string(), cb)
+    ///         })
+    ///         .collect()
+    /// })
+    /// .join()
+    /// .unwrap();
+    ///
+    /// let menu = entries
+    ///     .into_iter()
+    ///     .fold(Tree::new(), |menu, (label, cb)| menu.leaf_arc(label, cb));
+    /// assert_eq!(menu.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn leaf_arc<S, F>(self, label: S, cb: F) -> Self
+    where
+        S: Into<String>,
+        F: 'static + Fn(&mut Cursive) + Send + Sync,
+    {
+        self.with(|menu| menu.add_leaf_arc(label, cb))
+    }
+
+    /// Adds a leaf with an existing callback to the end of this tree.
+    ///
+    /// See [`Item::leaf_callback`].
+    pub fn add_leaf_callback<S, C>(&mut self, label: S, cb: C)
+    where
+        S: Into<String>,
+        C: Into<Callback>,
+    {
+        self.add_item(Item::leaf_callback(label, cb));
+    }
+
+    /// Adds a leaf with an existing callback to the end of this tree.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::event::ArcCallback;
+    /// use cursive_core::menu::Tree;
+    ///
+    /// let quit = std::thread::spawn(|| ArcCallback::from_fn(|s| s.quit()))
+    ///     .join()
+    ///     .unwrap();
+    ///
+    /// let menu = Tree::new().leaf_callback("Quit", quit);
+    /// ```
+    #[must_use]
+    pub fn leaf_callback<S, C>(self, label: S, cb: C) -> Self
+    where
+        S: Into<String>,
+        C: Into<Callback>,
+    {
+        self.with(|menu| menu.add_leaf_callback(label, cb))
+    }
+
     /// Inserts a subtree at the given position.
     pub fn insert_subtree<S>(&mut self, i: usize, label: S, tree: Tree)
     where
         S: Into<String>,
     {
         let label = label.into();
-        let tree = Item::Subtree {
-            label,
-            tree: Rc::new(tree),
-            enabled: true,
-        };
+        let tree = Item::subtree(label, tree);
         self.insert(i, tree);
     }
 
@@ -306,6 +773,141 @@ impl Tree {
     ///
     /// Returns `None` if no such label was found.
     pub fn find_position(&mut self, label: &str) -> Option<usize> {
+        self.position(label)
+    }
+
+    /// Returns the positions of the items along the given path of labels.
+    ///
+    /// The first position is in this tree, the next one in the subtree it
+    /// points to, and so on. Every label but the last one must name a
+    /// subtree.
+    ///
+    /// Returns `None` if the path is empty or doesn't lead to an item.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::menu::Tree;
+    ///
+    /// let tree = Tree::new().leaf("Quit", |_| ()).subtree(
+    ///     "Window",
+    ///     Tree::new().subtree("Documents", Tree::new().leaf("foo.txt", |_| ())),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     tree.find_position_by_path(&["Window", "Documents", "foo.txt"]),
+    ///     Some(vec![1, 0, 0])
+    /// );
+    /// assert_eq!(tree.find_position_by_path(&["Quit", "foo.txt"]), None);
+    /// ```
+    pub fn find_position_by_path(&self, path: &[&str]) -> Option<Vec<usize>> {
+        let (last, parents) = path.split_last()?;
+
+        let mut positions = Vec::with_capacity(path.len());
+        let mut tree = self;
+        for label in parents {
+            let i = tree.position(label)?;
+            tree = match tree.children[i] {
+                Item::Subtree { ref tree, .. } => tree,
+                _ => return None,
+            };
+            positions.push(i);
+        }
+        positions.push(tree.position(last)?);
+
+        Some(positions)
+    }
+
+    /// Returns the labels from this tree to the first item with the given
+    /// label, searching nested subtrees depth-first.
+    ///
+    /// The last label is the one searched for. Returns `None` if no item
+    /// has this label, at any depth.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::menu::Tree;
+    ///
+    /// let tree = Tree::new().subtree(
+    ///     "File",
+    ///     Tree::new().subtree("Recent", Tree::new().leaf("project.txt", |_| ())),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     tree.path_to("project.txt"),
+    ///     Some(vec!["File", "Recent", "project.txt"])
+    /// );
+    /// assert_eq!(tree.path_to("Nope"), None);
+    /// ```
+    pub fn path_to(&self, label: &str) -> Option<Vec<&str>> {
+        let mut path = Vec::new();
+        if self.collect_path_to(label, &mut path) {
+            Some(path)
+        } else {
+            None
+        }
+    }
+
+    // Pushes the path to `label` on `path`, and returns `true` if found.
+    fn collect_path_to<'a>(
+        &'a self,
+        label: &str,
+        path: &mut Vec<&'a str>,
+    ) -> bool {
+        for item in &self.children {
+            if item.is_delimiter() {
+                continue;
+            }
+            path.push(item.label());
+            if item.label() == label {
+                return true;
+            }
+            if let Item::Subtree { ref tree, .. } = *item {
+                if tree.collect_path_to(label, path) {
+                    return true;
+                }
+            }
+            path.pop();
+        }
+        false
+    }
+
+    /// Removes the item at the given path of labels, and returns it.
+    ///
+    /// Every label but the last one must name a subtree. Subtrees shared
+    /// with other trees are copied before being modified.
+    ///
+    /// Returns `None` if the path is empty or doesn't lead to an item.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::menu::Tree;
+    ///
+    /// let mut tree = Tree::new().subtree(
+    ///     "Window",
+    ///     Tree::new().subtree("Documents", Tree::new().leaf("foo.txt", |_| ())),
+    /// );
+    ///
+    /// let item = tree.remove_by_path(&["Window", "Documents", "foo.txt"]);
+    /// assert_eq!(item.unwrap().label(), "foo.txt");
+    /// assert!(tree.find_position_by_path(&["Window", "Documents"]).is_some());
+    /// ```
+    pub fn remove_by_path(&mut self, path: &[&str]) -> Option<Item> {
+        // Check the whole path first, so we don't copy subtrees for nothing.
+        let positions = self.find_position_by_path(path)?;
+        let (last, parents) = positions.split_last()?;
+
+        let mut tree = self;
+        for &i in parents {
+            tree = tree.get_subtree(i)?;
+        }
+        Some(tree.children.remove(*last))
+    }
+
+    // Returns the position of the first child with the given label.
+    fn position(&self, label: &str) -> Option<usize> {
         self.children
             .iter()
             .position(|child| child.label() == label)
@@ -328,4 +930,408 @@ impl Tree {
     pub fn is_empty(&self) -> bool {
         self.children.is_empty()
     }
+
+    /// Returns a copy of this tree that shares no subtree with it.
+    ///
+    /// Cloning a `Tree` keeps nested subtrees shared until one of them is
+    /// modified. This instead duplicates them all, so the copy can be used
+    /// as an independent template.
+    ///
+    /// Callbacks and leaf data are still shared.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::menu::{Item, Tree};
+    /// use std::rc::Rc;
+    ///
+    /// let file = Tree::new().leaf("Open", |_| ());
+    /// let template = Tree::new().subtree("File", file);
+    /// let copy = template.deep_clone();
+    ///
+    /// let subtree = |tree: &Tree| match tree.children[0] {
+    ///     Item::Subtree { ref tree, .. } => Rc::clone(tree),
+    ///     _ => unreachable!(),
+    /// };
+    /// assert!(!Rc::ptr_eq(&subtree(&template), &subtree(&copy)));
+    /// ```
+    pub fn deep_clone(&self) -> Tree {
+        let children = self
+            .children
+            .iter()
+            .map(|child| match *child {
+                Item::Subtree {
+                    ref label,
+                    ref tree,
+                    enabled,
+                    ref extras,
+                } => Item::Subtree {
+                    label: label.clone(),
+                    tree: Rc::new(tree.deep_clone()),
+                    enabled,
+                    extras: extras.clone(),
+                },
+                ref other => other.clone(),
+            })
+            .collect();
+        Tree { children }
+    }
+
+    /// Returns a flat list of the enabled leaves in this tree.
+    ///
+    /// Each leaf becomes an item labeled with its full path, like
+    /// `File > Save As`, and whose value is the list of labels in that path.
+    /// Submitting an item runs the callback of the leaf.
+    ///
+    /// The view is searchable: typing selects the first item containing the
+    /// typed text (see [`SelectView::set_searchable`]).
+    ///
+    /// If several leaves share the same path, only the first one is listed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::menu::Tree;
+    ///
+    /// let tree = Tree::new()
+    ///     .subtree("File", Tree::new().leaf("Open", |_| ()))
+    ///     .leaf("Quit", |s| s.quit());
+    ///
+    /// let select = tree.flatten_to_select();
+    /// assert_eq!(select.len(), 2);
+    /// assert_eq!(
+    ///     select.get_item(0).map(|(label, _)| label),
+    ///     Some("File > Open")
+    /// );
+    /// ```
+    pub fn flatten_to_select(&self) -> SelectView<Vec<String>> {
+        let mut select = SelectView::new().searchable();
+        let mut callbacks = HashMap::new();
+        for (path, cb) in self.leaves() {
+            if let Entry::Vacant(entry) = callbacks.entry(path.clone()) {
+                select.add_item(path.join(PATH_SEPARATOR), path);
+                entry.insert(cb);
+            }
+        }
+        select.set_on_submit(move |s, path: &Vec<String>| {
+            if let Some(cb) = callbacks.get(path) {
+                cb(s);
+            }
+        });
+        select
+    }
+
+    /// Returns the enabled leaves in this tree, with their path of labels.
+    pub(crate) fn leaves(&self) -> Vec<(Vec<String>, Callback)> {
+        let mut leaves = Vec::new();
+        self.collect_leaves(&mut Vec::new(), &mut leaves);
+        leaves
+    }
+
+    fn collect_leaves(
+        &self,
+        path: &mut Vec<String>,
+        leaves: &mut Vec<(Vec<String>, Callback)>,
+    ) {
+        for item in &self.children {
+            if !item.is_enabled() {
+                continue;
+            }
+            path.push(item.label().to_string());
+            match *item {
+                Item::Leaf { ref cb, .. } => {
+                    leaves.push((path.clone(), cb.clone()))
+                }
+                Item::Subtree { ref tree, .. } => {
+                    tree.collect_leaves(path, leaves)
+                }
+                Item::Delimiter => (),
+            }
+            path.pop();
+        }
+    }
+
+    /// Visits every item but delimiters, depth-first.
+    ///
+    /// The callback receives the depth of the item (`0` for children of
+    /// this tree), the labels from this tree to the item (ending with its own
+    /// label), and the item itself. Subtrees are visited before their
+    /// children.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::menu::Tree;
+    ///
+    /// let tree = Tree::new()
+    ///     .subtree("File", Tree::new().leaf("Open", |_| ()).delimiter())
+    ///     .leaf("Quit", |_| ());
+    ///
+    /// let mut paths = Vec::new();
+    /// tree.walk(|depth, path, _| paths.push((depth, path.join("/"))));
+    /// assert_eq!(
+    ///     paths,
+    ///     vec![
+    ///         (0, "File".to_string()),
+    ///         (1, "File/Open".to_string()),
+    ///         (0, "Quit".to_string()),
+    ///     ]
+    /// );
+    /// ```
+    pub fn walk<F>(&self, mut f: F)
+    where
+        F: FnMut(usize, &[&str], &Item),
+    {
+        self.walk_inner(&mut Vec::new(), &mut f);
+    }
+
+    fn walk_inner<'a, F>(&'a self, path: &mut Vec<&'a str>, f: &mut F)
+    where
+        F: FnMut(usize, &[&str], &Item),
+    {
+        for item in &self.children {
+            if item.is_delimiter() {
+                continue;
+            }
+            path.push(item.label());
+            f(path.len() - 1, path, item);
+            if let Item::Subtree { ref tree, .. } = *item {
+                tree.walk_inner(path, f);
+            }
+            path.pop();
+        }
+    }
+
+    /// Visits every item but delimiters, depth-first, allowing changes.
+    ///
+    /// Like [`Tree::walk`], but the labels are owned, since the items may be
+    /// changed. Children of a subtree are visited after the callback ran on
+    /// it. Subtrees shared with other trees are copied before being visited.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::menu::Tree;
+    ///
+    /// let mut tree = Tree::new()
+    ///     .subtree("File", Tree::new().leaf("Open", |_| ()))
+    ///     .leaf("Quit", |_| ());
+    ///
+    /// // Disable everything under "File".
+    /// tree.walk_mut(|_, path, item| {
+    ///     if path.len() > 1 && path[0] == "File" {
+    ///         item.disable();
+    ///     }
+    /// });
+    /// assert!(!tree.children[0].is_enabled());
+    /// ```
+    pub fn walk_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, &[String], &mut Item),
+    {
+        self.walk_mut_inner(&mut Vec::new(), &mut f);
+    }
+
+    fn walk_mut_inner<F>(&mut self, path: &mut Vec<String>, f: &mut F)
+    where
+        F: FnMut(usize, &[String], &mut Item),
+    {
+        for item in &mut self.children {
+            if item.is_delimiter() {
+                continue;
+            }
+            path.push(item.label().to_string());
+            f(path.len() - 1, path, item);
+            if let Some(tree) = item.as_subtree() {
+                tree.walk_mut_inner(path, f);
+            }
+            path.pop();
+        }
+    }
+
+    /// Returns `true` if every child of this tree is disabled.
+    ///
+    /// * Delimiters are ignored.
+    /// * Subtrees count as disabled if all their own children are.
+    /// * Returns `false` if this tree has no child other than delimiters.
+    ///
+    /// Subtrees where this returns `true` are shown grayed out and cannot
+    /// be opened.
+    pub fn all_children_disabled(&self) -> bool {
+        let mut children = self
+            .children
+            .iter()
+            .filter(|child| !child.is_delimiter())
+            .peekable();
+
+        children.peek().is_some() && children.all(|child| !child.is_enabled())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flatten_to_select() {
+        use crate::view::View;
+
+        let tree = Tree::new()
+            .subtree(
+                "File",
+                Tree::new()
+                    .leaf("Open", |_| ())
+                    .leaf("Save", |_| ())
+                    .leaf("Save", |_| ()),
+            )
+            .leaf("Quit", |_| ());
+
+        // The duplicate `File > Save` is only listed once.
+        let mut select = tree.flatten_to_select();
+        assert_eq!(select.len(), 3);
+        assert_eq!(
+            select.get_item(1),
+            Some(("File > Save", &vec!["File".to_string(), "Save".into()]))
+        );
+
+        select.on_event(Event::Char('q'));
+        assert_eq!(select.selected_id(), Some(2));
+    }
+
+    #[test]
+    fn all_children_disabled() {
+        let mut tree = Tree::new()
+            .leaf("Open", |_| ())
+            .delimiter()
+            .item(Item::leaf("Save", |_| ()).disabled());
+        assert!(!tree.all_children_disabled());
+
+        tree.find_item("Open").unwrap().disable();
+        assert!(tree.all_children_disabled());
+
+        // Only delimiters: nothing to disable.
+        assert!(!Tree::new().delimiter().all_children_disabled());
+
+        // Nested subtrees are disabled along with their children.
+        let root = Tree::new().subtree("File", tree);
+        assert!(!root.children[0].is_enabled());
+        assert!(root.all_children_disabled());
+    }
+
+    #[test]
+    fn remove_by_path_keeps_shared_trees() {
+        let documents = Tree::new().leaf("foo.txt", |_| ()).leaf("b", |_| ());
+        let template = Tree::new()
+            .subtree("Window", Tree::new().subtree("Documents", documents));
+
+        let mut tree = template.clone();
+        assert!(tree.remove_by_path(&["Window", "Nope"]).is_none());
+        assert!(tree.remove_by_path(&[]).is_none());
+
+        let item = tree.remove_by_path(&["Window", "Documents", "foo.txt"]);
+        assert_eq!(
+            item.map(|item| item.label().to_string()).as_deref(),
+            Some("foo.txt")
+        );
+
+        let path = ["Window", "Documents", "b"];
+        assert_eq!(tree.find_position_by_path(&path), Some(vec![0, 0, 0]));
+        // The original tree still has both documents.
+        assert_eq!(template.find_position_by_path(&path), Some(vec![0, 0, 1]));
+    }
+
+    #[test]
+    fn find_shortcut() {
+        let quit = Event::CtrlChar('q');
+        let mut tree = Tree::new().subtree(
+            "File",
+            Tree::new()
+                .leaf_with_shortcut("Quit", quit.clone(), |_| ())
+                .item(Item::leaf("Save", |_| ()).shortcut('s').disabled()),
+        );
+
+        assert!(tree.find_shortcut(&quit).is_some());
+        assert!(tree.find_shortcut(&Event::Char('s')).is_none());
+
+        tree.remove_by_path(&["File", "Quit"]);
+        assert!(tree.find_shortcut(&quit).is_none());
+    }
+
+    #[test]
+    fn deep_clone_is_independent() {
+        let template = Tree::new().subtree(
+            "File",
+            Tree::new().subtree("Recent", Tree::new().leaf("a", |_| ())),
+        );
+
+        let mut copy = template.children[0].clone_subtree().unwrap();
+        copy.find_subtree("Recent").unwrap().add_leaf("b", |_| ());
+
+        let mut template = template;
+        let file = template.find_subtree("File").unwrap();
+        assert_eq!(file.find_subtree("Recent").unwrap().len(), 1);
+        assert_eq!(copy.find_subtree("Recent").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn leaf_callback() {
+        use crate::event::ArcCallback;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let cb = std::thread::spawn(move || {
+            ArcCallback::from_fn(move |_| {
+                counter.fetch_add(1, Ordering::Relaxed);
+            })
+        })
+        .join()
+        .unwrap();
+
+        let tree = Tree::new().leaf_callback("Count", cb);
+        let mut siv = Cursive::new();
+        match tree.children[0] {
+            Item::Leaf { ref cb, .. } => cb(&mut siv),
+            _ => unreachable!(),
+        }
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn leaf_arc() {
+        use std::sync::{Arc, Mutex};
+
+        let files = Arc::new(Mutex::new(vec!["a.txt", "b.txt"]));
+
+        // Callbacks capturing shared data, created on another thread.
+        let shared = Arc::clone(&files);
+        let entries: Vec<_> = std::thread::spawn(move || {
+            ["a.txt", "b.txt"]
+                .iter()
+                .map(|&file| {
+                    let files = Arc::clone(&shared);
+                    let cb = move |_: &mut Cursive| {
+                        files.lock().unwrap().retain(|f| *f != file);
+                    };
+                    (file, cb)
+                })
+                .collect()
+        })
+        .join()
+        .unwrap();
+
+        let mut tree = Tree::new();
+        for (label, cb) in entries {
+            tree.add_leaf_arc(label, cb);
+        }
+        assert_eq!(tree.len(), 2);
+
+        let mut siv = Cursive::new();
+        match tree.children[0] {
+            Item::Leaf { ref cb, .. } => cb(&mut siv),
+            _ => unreachable!(),
+        }
+        assert_eq!(*files.lock().unwrap(), ["b.txt"]);
+    }
 }
@@ -0,0 +1,238 @@
+//! Transient messages shown on top of the views.
+//!
+//! Notifications are shown with [`Cursive::notify`] or
+//! [`Cursive::add_notification`]. They are stacked in a corner of the screen
+//! (see [`Cursive::set_notification_align`]), never take the focus, and
+//! don't block input to the views below.
+//!
+//! They disappear after their duration, or when clicked.
+//!
+//! [`Cursive::notify`]: crate::Cursive::notify
+//! [`Cursive::add_notification`]: crate::Cursive::add_notification
+//! [`Cursive::set_notification_align`]: crate::Cursive::set_notification_align
+use crate::align::{Align, VAlign};
+use crate::event::Callback;
+use crate::theme::{BaseColor, ColorStyle};
+use crate::utils::markup::StyledString;
+use crate::view::View;
+use crate::views::{Layer, Panel, TextView};
+use crate::{Cursive, Printer, Rect, Vec2};
+use std::time::Duration;
+use web_time::Instant;
+
+/// Maximum width of a notification, including borders.
+const MAX_WIDTH: usize = 40;
+
+/// Identifies a notification, as returned by
+/// [`Cursive::add_notification`](crate::Cursive::add_notification).
+pub type NotificationId = usize;
+
+/// Importance of a notification.
+///
+/// This changes the color of the notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Level {
+    /// Regular message.
+    #[default]
+    Info,
+    /// Something went well.
+    Success,
+    /// Something may need attention.
+    Warning,
+    /// Something went wrong.
+    Error,
+}
+
+impl Level {
+    fn color(self) -> ColorStyle {
+        match self {
+            Level::Info => ColorStyle::primary(),
+            Level::Success => ColorStyle::front(BaseColor::Green.dark()),
+            Level::Warning => ColorStyle::front(BaseColor::Yellow.dark()),
+            Level::Error => ColorStyle::front(BaseColor::Red.dark()),
+        }
+    }
+}
+
+/// A transient message.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::notification::{Level, Notification};
+/// use std::time::Duration;
+///
+/// let mut siv = cursive_core::Cursive::new();
+///
+/// siv.add_notification(
+///     Notification::new("Connection lost")
+///         .level(Level::Error)
+///         .duration(Duration::from_secs(10))
+///         .on_click(|s| s.quit()),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct Notification {
+    /// Message to show.
+    pub text: StyledString,
+
+    /// Importance of this notification.
+    pub level: Level,
+
+    /// How long the notification stays visible.
+    ///
+    /// If `None`, it stays until clicked.
+    pub duration: Option<Duration>,
+
+    /// Callback to run when the notification is clicked.
+    ///
+    /// The notification is dismissed in any case.
+    pub on_click: Option<Callback>,
+}
+
+impl Notification {
+    /// Creates a new notification, visible for 3 seconds.
+    pub fn new<S: Into<StyledString>>(text: S) -> Self {
+        Notification {
+            text: text.into(),
+            level: Level::Info,
+            duration: Some(Duration::from_secs(3)),
+            on_click: None,
+        }
+    }
+
+    /// Sets the importance of this notification.
+    #[must_use]
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Sets how long this notification stays visible.
+    #[must_use]
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Keeps this notification visible until it is clicked.
+    #[must_use]
+    pub fn persistent(mut self) -> Self {
+        self.duration = None;
+        self
+    }
+
+    /// Sets a callback to run when this notification is clicked.
+    #[must_use]
+    pub fn on_click<F>(mut self, cb: F) -> Self
+    where
+        F: 'static + Fn(&mut Cursive),
+    {
+        self.on_click = Some(Callback::from_fn(cb));
+        self
+    }
+}
+
+/// A notification being shown.
+pub(crate) struct Toast {
+    pub id: NotificationId,
+    pub on_click: Option<Callback>,
+    level: Level,
+    view: Layer<Panel<TextView>>,
+
+    // When the toast should disappear, if ever.
+    pub deadline: Option<Instant>,
+
+    // Where the toast was last drawn.
+    pub area: Rect,
+}
+
+impl Toast {
+    pub fn new(
+        id: NotificationId,
+        notification: Notification,
+        now: Instant,
+    ) -> Self {
+        Toast {
+            id,
+            deadline: notification.duration.map(|duration| now + duration),
+            on_click: notification.on_click,
+            level: notification.level,
+            view: Layer::new(Panel::new(TextView::new(notification.text))),
+            area: Rect::from_size((0, 0), (0, 0)),
+        }
+    }
+}
+
+/// Places the toasts in `area`, stacked from the corner given by `align`.
+pub(crate) fn layout(toasts: &mut [Toast], area: Rect, align: Align) {
+    let max_size = Vec2::new(MAX_WIDTH.min(area.width()), area.height());
+    let sizes: Vec<Vec2> = toasts
+        .iter_mut()
+        .map(|toast| {
+            let size = Vec2::min(toast.view.required_size(max_size), max_size);
+            toast.view.layout(size);
+            size
+        })
+        .collect();
+
+    let offsets = stack(&sizes, area, align);
+    for ((toast, size), offset) in toasts.iter_mut().zip(sizes).zip(offsets) {
+        toast.area = Rect::from_size(offset, size);
+    }
+}
+
+/// Draws the toasts where they were last placed.
+pub(crate) fn draw(toasts: &[Toast], printer: &Printer) {
+    for toast in toasts {
+        let printer = printer
+            .offset(toast.area.top_left())
+            .cropped(toast.area.size())
+            .focused(false);
+        printer.with_color(toast.level.color(), |printer| {
+            toast.view.draw(printer)
+        });
+    }
+}
+
+// Returns the top-left corner of each item, stacked from the corner given by
+// `align`.
+//
+// Items that don't fit anymore overlap at the far edge of `area`.
+fn stack(sizes: &[Vec2], area: Rect, align: Align) -> Vec<Vec2> {
+    let mut used = 0;
+    sizes
+        .iter()
+        .map(|size| {
+            let x = area.left() + align.h.get_offset(size.x, area.width());
+            let y = match align.v {
+                VAlign::Bottom => (area.bottom() + 1)
+                    .saturating_sub(used + size.y)
+                    .max(area.top()),
+                VAlign::Top | VAlign::Center => area.top() + used,
+            };
+            used += size.y;
+            Vec2::new(x, y)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stack_from_corner() {
+        let area = Rect::from_size((0, 1), (80, 20));
+        let sizes = [Vec2::new(10, 3), Vec2::new(20, 4)];
+
+        assert_eq!(
+            stack(&sizes, area, Align::bot_right()),
+            vec![Vec2::new(70, 18), Vec2::new(60, 14)]
+        );
+        assert_eq!(
+            stack(&sizes, area, Align::top_left()),
+            vec![Vec2::new(0, 1), Vec2::new(0, 4)]
+        );
+    }
+}
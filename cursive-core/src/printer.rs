@@ -4,17 +4,19 @@ use crate::backend::Backend;
 use crate::direction::Orientation;
 use crate::rect::Rect;
 use crate::theme::{
-    BorderStyle, ColorPair, ColorStyle, Effect, PaletteColor, Style, Theme,
+    BorderStyle, BoxStyle, ColorPair, ColorStyle, Effect, PaletteColor, Style,
+    Theme,
 };
 use crate::utils::lines::simple::{prefix, suffix};
+use crate::utils::markup::StyledString;
 use crate::with::With;
 use crate::Vec2;
 
+use crate::utils::width::TextWidth;
 use enumset::EnumSet;
 use std::cell::Cell;
 use std::cmp::min;
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
 
 /// Convenient interface to draw on a subset of the screen.
 ///
@@ -122,12 +124,12 @@ impl<'a, 'b> Printer<'a, 'b> {
     // We don't want people to start calling prints in parallel?
     /// Prints some text at the given position
     pub fn print<S: Into<Vec2>>(&self, start: S, text: &str) {
-        self.print_with_width(start, text, UnicodeWidthStr::width);
+        self.print_with_width(start, text, TextWidth::width);
     }
 
     /// Prints some text, using the given callback to compute width.
     ///
-    /// Mostly used with `UnicodeWidthStr::width`.
+    /// Mostly used with `TextWidth::width`.
     /// If you already know the width, you can give it as a constant instead.
     fn print_with_width<S, F>(&self, start: S, text: &str, width: F)
     where
@@ -291,6 +293,77 @@ impl<'a, 'b> Printer<'a, 'b> {
         self.backend.print_at_rep(start, repetitions, c);
     }
 
+    /// Prints a partially-filled horizontal bar in the given area.
+    ///
+    /// `fraction` is clamped to `0.0..=1.0`. The filled part uses the front
+    /// color of `filled_style`, the rest uses `empty_style`. Block glyphs
+    /// (`▏▎▍▌▋▊▉`) give a precision of 1/8th of a cell.
+    ///
+    /// Every row of `rect` gets the same bar.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Printer;
+    /// # use cursive_core::theme::{self, ColorStyle};
+    /// # use cursive_core::backend;
+    /// # use cursive_core::Rect;
+    /// # let b = backend::Dummy::init();
+    /// # let t = theme::load_default();
+    /// # let printer = Printer::new((10, 1), &t, &*b);
+    /// printer.print_hbar(
+    ///     Rect::from_size((0, 0), (10, 1)),
+    ///     0.42,
+    ///     ColorStyle::highlight(),
+    ///     ColorStyle::background(),
+    /// );
+    /// ```
+    pub fn print_hbar<F, E>(
+        &self,
+        rect: Rect,
+        fraction: f32,
+        filled_style: F,
+        empty_style: E,
+    ) where
+        F: Into<Style>,
+        E: Into<Style>,
+    {
+        let filled_style = filled_style.into();
+        let empty_style = empty_style.into();
+
+        let width = rect.width();
+        let (full, partial) = hbar_cells(width, fraction);
+
+        // The partial cell is drawn with the filled color on the empty back.
+        let partial_style = Style {
+            effects: filled_style.effects,
+            color: ColorStyle::new(
+                filled_style.color.front,
+                empty_style.color.back,
+            ),
+        };
+        let empty_start = full + usize::from(partial.is_some());
+
+        for y in rect.top()..=rect.bottom() {
+            let start = Vec2::new(rect.left(), y);
+            self.with_style(filled_style, |printer| {
+                printer.print_hline(start, full, "█");
+            });
+            if let Some(glyph) = partial {
+                self.with_style(partial_style, |printer| {
+                    printer.print(start + (full, 0), glyph);
+                });
+            }
+            self.with_style(empty_style, |printer| {
+                printer.print_hline(
+                    start + (empty_start, 0),
+                    width - empty_start,
+                    " ",
+                );
+            });
+        }
+    }
+
     /// Returns the color currently used by the parent view.
     pub fn current_color(&self) -> ColorPair {
         self.current_color.get()
@@ -438,6 +511,68 @@ impl<'a, 'b> Printer<'a, 'b> {
         });
     }
 
+    /// Draws a box with the given line style, and an optional title.
+    ///
+    /// Unlike `print_box`, this uses the current color and ignores the
+    /// theme's border style.
+    ///
+    /// The title is centered in the top border. It is not printed if it
+    /// does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Printer;
+    /// # use cursive_core::theme::{self, BoxStyle};
+    /// # use cursive_core::backend;
+    /// # use cursive_core::utils::markup::StyledString;
+    /// # let b = backend::Dummy::init();
+    /// # let t = theme::load_default();
+    /// # let printer = Printer::new((20, 5), &t, &*b);
+    /// let title = StyledString::plain("Stats");
+    /// printer.draw_box((0, 0), (20, 5), BoxStyle::Rounded, Some(&title));
+    /// ```
+    pub fn draw_box<T: Into<Vec2>, S: Into<Vec2>>(
+        &self,
+        origin: T,
+        size: S,
+        style: BoxStyle,
+        title: Option<&StyledString>,
+    ) {
+        let start = origin.into();
+        let size = size.into();
+
+        if size.x < 2 || size.y < 2 {
+            return;
+        }
+        let size = size - (1, 1);
+
+        let [top_left, top_right, bottom_left, bottom_right, h, v] =
+            style.chars();
+
+        self.print(start, top_left);
+        self.print(start + size.keep_x(), top_right);
+        self.print(start + size.keep_y(), bottom_left);
+        self.print(start + size, bottom_right);
+        self.print_hline(start + (1, 0), size.x - 1, h);
+        self.print_hline(start + (1, 0) + size.keep_y(), size.x - 1, h);
+        self.print_vline(start + (0, 1), size.y - 1, v);
+        self.print_vline(start + (0, 1) + size.keep_x(), size.y - 1, v);
+
+        if let Some(title) = title {
+            let len = title.width();
+            // Keep a space on each side of the title, inside the corners.
+            let available = size.x.saturating_sub(3);
+            if len == 0 || len > available {
+                return;
+            }
+            let x = 2 + (available - len) / 2;
+            self.print(start + (x - 1, 0), " ");
+            self.print_styled(start + (x, 0), title.into());
+            self.print(start + (x + len, 0), " ");
+        }
+    }
+
     /// Runs the given function using a color depending on the theme.
     ///
     /// * If the theme's borders is `None`, return without calling `f`.
@@ -656,3 +791,139 @@ impl<'a, 'b> Printer<'a, 'b> {
         })
     }
 }
+
+/// Partial block glyphs, from 1/8th to 7/8th of a cell.
+const PARTIAL_BLOCKS: [&str; 7] = ["▏", "▎", "▍", "▌", "▋", "▊", "▉"];
+
+/// Returns the number of full cells and the partial glyph (if any) needed to
+/// draw a bar of `width` cells filled to `fraction`.
+fn hbar_cells(width: usize, fraction: f32) -> (usize, Option<&'static str>) {
+    let fraction = if fraction.is_nan() {
+        0.0
+    } else {
+        fraction.clamp(0.0, 1.0)
+    };
+
+    // Count in eighths of a cell to get sub-cell precision.
+    let eighths = (fraction * (width * 8) as f32).round() as usize;
+    let eighths = min(eighths, width * 8);
+
+    let full = eighths / 8;
+    let partial = match eighths % 8 {
+        0 => None,
+        n => Some(PARTIAL_BLOCKS[n - 1]),
+    };
+
+    (full, partial)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hbar_cells_bounds() {
+        assert_eq!(hbar_cells(10, 0.0), (0, None));
+        assert_eq!(hbar_cells(10, 1.0), (10, None));
+        assert_eq!(hbar_cells(10, -1.0), (0, None));
+        assert_eq!(hbar_cells(10, 2.0), (10, None));
+        assert_eq!(hbar_cells(10, f32::NAN), (0, None));
+        assert_eq!(hbar_cells(0, 0.5), (0, None));
+    }
+
+    #[test]
+    fn hbar_cells_partial() {
+        // 3 cells at 50% is 12 eighths: 1 full cell and a half.
+        assert_eq!(hbar_cells(3, 0.5), (1, Some("▌")));
+        // 1/8th of a cell.
+        assert_eq!(hbar_cells(1, 0.125), (0, Some("▏")));
+        // 7/8th of a cell.
+        assert_eq!(hbar_cells(1, 0.875), (0, Some("▉")));
+        // Almost full rounds to the nearest eighth.
+        assert_eq!(hbar_cells(10, 0.999), (10, None));
+        assert_eq!(hbar_cells(10, 0.98), (9, Some("▊")));
+    }
+
+    // Backend keeping the printed characters in a grid.
+    struct Grid(std::cell::RefCell<Vec<Vec<char>>>);
+
+    impl Grid {
+        fn new(width: usize, height: usize) -> Self {
+            Grid(std::cell::RefCell::new(vec![vec![' '; width]; height]))
+        }
+
+        fn rows(&self) -> Vec<String> {
+            self.0
+                .borrow()
+                .iter()
+                .map(|row| row.iter().collect())
+                .collect()
+        }
+    }
+
+    impl Backend for Grid {
+        fn poll_event(&mut self) -> Option<crate::event::Event> {
+            None
+        }
+
+        fn set_title(&mut self, _: String) {}
+
+        fn refresh(&mut self) {}
+
+        fn has_colors(&self) -> bool {
+            false
+        }
+
+        fn screen_size(&self) -> Vec2 {
+            let rows = self.0.borrow();
+            Vec2::new(rows[0].len(), rows.len())
+        }
+
+        fn print_at(&self, pos: Vec2, text: &str) {
+            let mut rows = self.0.borrow_mut();
+            for (i, c) in text.chars().enumerate() {
+                rows[pos.y][pos.x + i] = c;
+            }
+        }
+
+        fn clear(&self, _: crate::theme::Color) {}
+
+        fn set_color(&self, colors: ColorPair) -> ColorPair {
+            colors
+        }
+
+        fn set_effect(&self, _: Effect) {}
+
+        fn unset_effect(&self, _: Effect) {}
+    }
+
+    #[test]
+    fn draw_box() {
+        let theme = crate::theme::load_default();
+        let grid = Grid::new(12, 4);
+        let printer = Printer::new((12, 4), &theme, &grid);
+
+        let title = StyledString::plain("Hi");
+        printer.draw_box((1, 0), (10, 4), BoxStyle::Rounded, Some(&title));
+        assert_eq!(
+            grid.rows(),
+            [
+                " ╭── Hi ──╮ ",
+                " │        │ ",
+                " │        │ ",
+                " ╰────────╯ "
+            ]
+        );
+
+        // Titles that do not fit are left out.
+        let grid = Grid::new(6, 2);
+        let printer = Printer::new((6, 2), &theme, &grid);
+        let title = StyledString::plain("Long");
+        printer.draw_box((0, 0), (6, 2), BoxStyle::Double, Some(&title));
+        assert_eq!(grid.rows(), ["╔════╗", "╚════╝"]);
+
+        // Boxes too small to have corners are not drawn.
+        printer.draw_box((0, 0), (1, 2), BoxStyle::Single, None);
+        assert_eq!(grid.rows(), ["╔════╗", "╚════╝"]);
+    }
+}
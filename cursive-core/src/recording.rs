@@ -0,0 +1,358 @@
+//! Record input events to a file, and replay them later.
+//!
+//! The file is a plain text format, with one event per line, preceded by the
+//! number of milliseconds elapsed since the start of the recording:
+//!
+//! ```text
+//! 0 Char 104
+//! 250 Key Enter
+//! 812 Mouse 0 0 12 4 Press Left
+//! ```
+use crate::event::{Event, Key, Modifier, MouseButton, MouseEvent};
+use crate::Vec2;
+use enumset::EnumSet;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, LineWriter, Write};
+use std::path::Path;
+use std::time::Duration;
+use web_time::Instant;
+
+/// Writes events to a file, as they are received.
+pub(crate) struct Recorder {
+    writer: LineWriter<File>,
+    start: Instant,
+}
+
+impl Recorder {
+    /// Creates (or truncates) the file at `path`.
+    pub fn create<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Recorder {
+            writer: LineWriter::new(File::create(path)?),
+            start: Instant::now(),
+        })
+    }
+
+    /// Appends an event to the file.
+    pub fn record(&mut self, event: &Event) -> io::Result<()> {
+        let elapsed = self.start.elapsed().as_millis();
+        writeln!(self.writer, "{} {}", elapsed, encode(event))
+    }
+}
+
+/// Reads all the events from a recording.
+pub(crate) fn load<P: AsRef<Path>>(
+    path: P,
+) -> io::Result<Vec<(Duration, Event)>> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut events = Vec::new();
+
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let event = parse_line(&line).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid event on line {}: {}", i + 1, line),
+            )
+        })?;
+        events.push(event);
+    }
+
+    Ok(events)
+}
+
+/// Divides the delay of each event by `speed`.
+///
+/// Returns an error if `speed` is not a positive number, or if it gives
+/// delays too long to represent.
+pub(crate) fn scale_delays(
+    events: &mut [(Duration, Event)],
+    speed: f32,
+) -> io::Result<()> {
+    let invalid = || {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid replay speed: {}", speed),
+        )
+    };
+    if !(speed.is_finite() && speed > 0.0) {
+        return Err(invalid());
+    }
+
+    for (time, _) in events {
+        let secs = time.as_secs_f64() / f64::from(speed);
+        if !(secs.is_finite() && secs < u64::MAX as f64) {
+            return Err(invalid());
+        }
+        *time = Duration::from_secs_f64(secs);
+    }
+
+    Ok(())
+}
+
+fn parse_line(line: &str) -> Option<(Duration, Event)> {
+    let mut tokens = line.split_whitespace();
+    let millis = tokens.next()?.parse().ok()?;
+    let event = decode(tokens)?;
+    Some((Duration::from_millis(millis), event))
+}
+
+const KEYS: [Key; 29] = [
+    Key::Enter,
+    Key::Tab,
+    Key::Backspace,
+    Key::Esc,
+    Key::Left,
+    Key::Right,
+    Key::Up,
+    Key::Down,
+    Key::Ins,
+    Key::Del,
+    Key::Home,
+    Key::End,
+    Key::PageUp,
+    Key::PageDown,
+    Key::PauseBreak,
+    Key::NumpadCenter,
+    Key::F0,
+    Key::F1,
+    Key::F2,
+    Key::F3,
+    Key::F4,
+    Key::F5,
+    Key::F6,
+    Key::F7,
+    Key::F8,
+    Key::F9,
+    Key::F10,
+    Key::F11,
+    Key::F12,
+];
+
+const BUTTONS: [MouseButton; 6] = [
+    MouseButton::Left,
+    MouseButton::Middle,
+    MouseButton::Right,
+    MouseButton::Button4,
+    MouseButton::Button5,
+    MouseButton::Other,
+];
+
+fn parse_key(name: &str) -> Option<Key> {
+    KEYS.iter()
+        .copied()
+        .find(|key| format!("{:?}", key) == name)
+}
+
+fn parse_button(name: &str) -> Option<MouseButton> {
+    BUTTONS
+        .iter()
+        .copied()
+        .find(|button| format!("{:?}", button) == name)
+}
+
+// Characters are stored as code points, to avoid any whitespace issue.
+fn parse_char(token: Option<&str>) -> Option<char> {
+    std::char::from_u32(token?.parse().ok()?)
+}
+
+// Modifiers are stored as a `+`-separated list, like `Ctrl+Shift`.
+fn encode_modifiers(modifiers: EnumSet<Modifier>) -> String {
+    let names: Vec<String> =
+        modifiers.iter().map(|m| format!("{:?}", m)).collect();
+    names.join("+")
+}
+
+fn parse_modifiers(token: Option<&str>) -> Option<EnumSet<Modifier>> {
+    let mut modifiers = EnumSet::new();
+    for name in token?.split('+') {
+        modifiers.insert(match name {
+            "Shift" => Modifier::Shift,
+            "Ctrl" => Modifier::Ctrl,
+            "Alt" => Modifier::Alt,
+            _ => return None,
+        });
+    }
+    Some(modifiers)
+}
+
+fn encode(event: &Event) -> String {
+    match *event {
+        Event::Char(c) => format!("Char {}", c as u32),
+        Event::CtrlChar(c) => format!("CtrlChar {}", c as u32),
+        Event::AltChar(c) => format!("AltChar {}", c as u32),
+        Event::Key(key) => format!("Key {:?}", key),
+        Event::Shift(key) => format!("Shift {:?}", key),
+        Event::Alt(key) => format!("Alt {:?}", key),
+        Event::AltShift(key) => format!("AltShift {:?}", key),
+        Event::Ctrl(key) => format!("Ctrl {:?}", key),
+        Event::CtrlShift(key) => format!("CtrlShift {:?}", key),
+        Event::CtrlAlt(key) => format!("CtrlAlt {:?}", key),
+        Event::ModifiedChar(c, modifiers) => {
+            format!(
+                "ModifiedChar {} {}",
+                encode_modifiers(modifiers),
+                c as u32
+            )
+        }
+        Event::ModifiedKey(key, modifiers) => {
+            format!("ModifiedKey {} {:?}", encode_modifiers(modifiers), key)
+        }
+        Event::Mouse {
+            offset,
+            position,
+            event,
+        } => {
+            let event = match event {
+                MouseEvent::Press(button) => format!("Press {:?}", button),
+                MouseEvent::Release(button) => {
+                    format!("Release {:?}", button)
+                }
+                MouseEvent::Hold(button) => format!("Hold {:?}", button),
+                MouseEvent::WheelUp => "WheelUp".to_string(),
+                MouseEvent::WheelDown => "WheelDown".to_string(),
+            };
+            format!(
+                "Mouse {} {} {} {} {}",
+                offset.x, offset.y, position.x, position.y, event
+            )
+        }
+        Event::Unknown(ref bytes) => {
+            let bytes: Vec<String> =
+                bytes.iter().map(|b| b.to_string()).collect();
+            format!("Unknown {}", bytes.join(" "))
+        }
+        Event::WindowResize => "WindowResize".to_string(),
+        Event::FocusLost => "FocusLost".to_string(),
+        Event::Refresh => "Refresh".to_string(),
+        Event::Exit => "Exit".to_string(),
+    }
+}
+
+fn decode<'a, I>(mut tokens: I) -> Option<Event>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let event = match tokens.next()? {
+        "Char" => Event::Char(parse_char(tokens.next())?),
+        "CtrlChar" => Event::CtrlChar(parse_char(tokens.next())?),
+        "AltChar" => Event::AltChar(parse_char(tokens.next())?),
+        "Key" => Event::Key(parse_key(tokens.next()?)?),
+        "Shift" => Event::Shift(parse_key(tokens.next()?)?),
+        "Alt" => Event::Alt(parse_key(tokens.next()?)?),
+        "AltShift" => Event::AltShift(parse_key(tokens.next()?)?),
+        "Ctrl" => Event::Ctrl(parse_key(tokens.next()?)?),
+        "CtrlShift" => Event::CtrlShift(parse_key(tokens.next()?)?),
+        "CtrlAlt" => Event::CtrlAlt(parse_key(tokens.next()?)?),
+        "ModifiedChar" => {
+            let modifiers = parse_modifiers(tokens.next())?;
+            Event::ModifiedChar(parse_char(tokens.next())?, modifiers)
+        }
+        "ModifiedKey" => {
+            let modifiers = parse_modifiers(tokens.next())?;
+            Event::ModifiedKey(parse_key(tokens.next()?)?, modifiers)
+        }
+        "Mouse" => {
+            let mut numbers = Vec::with_capacity(4);
+            for _ in 0..4 {
+                numbers.push(tokens.next()?.parse().ok()?);
+            }
+            let event = match tokens.next()? {
+                "Press" => MouseEvent::Press(parse_button(tokens.next()?)?),
+                "Release" => {
+                    MouseEvent::Release(parse_button(tokens.next()?)?)
+                }
+                "Hold" => MouseEvent::Hold(parse_button(tokens.next()?)?),
+                "WheelUp" => MouseEvent::WheelUp,
+                "WheelDown" => MouseEvent::WheelDown,
+                _ => return None,
+            };
+            Event::Mouse {
+                offset: Vec2::new(numbers[0], numbers[1]),
+                position: Vec2::new(numbers[2], numbers[3]),
+                event,
+            }
+        }
+        "Unknown" => {
+            let bytes: Result<Vec<u8>, _> =
+                tokens.by_ref().map(str::parse).collect();
+            Event::Unknown(bytes.ok()?)
+        }
+        "WindowResize" => Event::WindowResize,
+        "FocusLost" => Event::FocusLost,
+        "Refresh" => Event::Refresh,
+        "Exit" => Event::Exit,
+        _ => return None,
+    };
+
+    // Reject trailing garbage.
+    match tokens.next() {
+        Some(_) => None,
+        None => Some(event),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut events = vec![
+            Event::Char(' '),
+            Event::Char('é'),
+            Event::CtrlChar('a'),
+            Event::AltChar('x'),
+            Event::ModifiedChar('A', Modifier::Ctrl | Modifier::Shift),
+            Event::ModifiedKey(Key::Up, EnumSet::all()),
+            Event::Mouse {
+                offset: Vec2::new(1, 2),
+                position: Vec2::new(3, 4),
+                event: MouseEvent::Release(MouseButton::Right),
+            },
+            Event::Mouse {
+                offset: Vec2::zero(),
+                position: Vec2::new(5, 6),
+                event: MouseEvent::WheelDown,
+            },
+            Event::Unknown(vec![27, 91, 65]),
+            Event::Unknown(Vec::new()),
+            Event::WindowResize,
+            Event::Refresh,
+        ];
+        events.extend(KEYS.iter().map(|&key| Event::Key(key)));
+        events.extend(KEYS.iter().map(|&key| Event::CtrlShift(key)));
+
+        for event in events {
+            let line = format!("42 {}", encode(&event));
+            assert_eq!(
+                parse_line(&line),
+                Some((Duration::from_millis(42), event)),
+                "{}",
+                line
+            );
+        }
+    }
+
+    #[test]
+    fn replay_speed() {
+        let mut events = vec![(Duration::from_millis(500), Event::Refresh)];
+        scale_delays(&mut events, 2.0).unwrap();
+        assert_eq!(events[0].0, Duration::from_millis(250));
+
+        for &speed in &[0.0, -1.0, f32::NAN, f32::INFINITY, 1e-30] {
+            assert!(scale_delays(&mut events, speed).is_err(), "{}", speed);
+        }
+    }
+
+    #[test]
+    fn invalid_lines() {
+        assert_eq!(parse_line("12"), None);
+        assert_eq!(parse_line("abc Key Enter"), None);
+        assert_eq!(parse_line("12 Key Nope"), None);
+        assert_eq!(parse_line("12 Key Enter Enter"), None);
+        assert_eq!(parse_line("12 Mouse 1 2 3 Press Left"), None);
+    }
+}
@@ -0,0 +1,30 @@
+//! Tracks requests to draw the screen again.
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Incremented by every call to `request_redraw`.
+//
+// Each runner remembers the last value it has drawn, so several `Cursive`
+// roots can share it without stealing each other's requests.
+static GENERATION: AtomicUsize = AtomicUsize::new(0);
+
+/// Asks for the screen to be laid out and drawn again.
+///
+/// Cursive only redraws the screen when something happened: an event was
+/// consumed, a callback ran, or a redraw was requested. Views whose state
+/// changes outside of events (for example from another thread) should call
+/// this so the change becomes visible at the next frame.
+///
+/// This is cheap, and can be called from any thread. [`TextContent`],
+/// [`Counter`] and [`FloatCounter`] call it whenever they are modified.
+///
+/// [`TextContent`]: crate::views::TextContent
+/// [`Counter`]: crate::utils::Counter
+/// [`FloatCounter`]: crate::utils::FloatCounter
+pub fn request_redraw() {
+    GENERATION.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Returns the number of redraws requested so far.
+pub(crate) fn generation() -> usize {
+    GENERATION.load(Ordering::Relaxed)
+}
@@ -24,3 +24,33 @@ impl<S: Deref<Target = String>> From<S> for BorderStyle {
         }
     }
 }
+
+/// Specifies which lines are used to draw a box.
+///
+/// Used by [`Printer::draw_box`](crate::Printer::draw_box).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BoxStyle {
+    /// Single lines: `┌─┐`.
+    Single,
+    /// Double lines: `╔═╗`.
+    Double,
+    /// Single lines with rounded corners: `╭─╮`.
+    Rounded,
+    /// Thick lines: `┏━┓`.
+    Bold,
+}
+
+impl BoxStyle {
+    /// Returns the characters used to draw this style.
+    ///
+    /// In order: top-left, top-right, bottom-left, bottom-right corners,
+    /// then horizontal and vertical lines.
+    pub fn chars(self) -> [&'static str; 6] {
+        match self {
+            BoxStyle::Single => ["┌", "┐", "└", "┘", "─", "│"],
+            BoxStyle::Double => ["╔", "╗", "╚", "╝", "═", "║"],
+            BoxStyle::Rounded => ["╭", "╮", "╰", "╯", "─", "│"],
+            BoxStyle::Bold => ["┏", "┓", "┗", "┛", "━", "┃"],
+        }
+    }
+}
@@ -1,4 +1,67 @@
 use super::{BaseColor, Color, ColorPair, Palette, PaletteColor};
+use log::warn;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+thread_local! {
+    // Unknown names we already warned about.
+    //
+    // Drawing happens on a single thread, so this only needs a cheap lookup
+    // on each resolve.
+    static UNKNOWN_NAMES: RefCell<HashSet<ColorName>> =
+        RefCell::new(HashSet::new());
+}
+
+/// Name of a custom palette color, used by [`ColorType::Named`].
+///
+/// The name is stored inline, so it is cheap to copy, and can be up to
+/// [`ColorName::MAX_LEN`] bytes long.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ColorName {
+    len: u8,
+    bytes: [u8; ColorName::MAX_LEN],
+}
+
+impl ColorName {
+    /// Maximum length of a name, in bytes.
+    pub const MAX_LEN: usize = 22;
+
+    /// Creates a new color name.
+    ///
+    /// Returns `None` if `name` is longer than [`ColorName::MAX_LEN`] bytes.
+    pub fn new(name: &str) -> Option<Self> {
+        if name.len() > Self::MAX_LEN {
+            return None;
+        }
+
+        let mut bytes = [0; Self::MAX_LEN];
+        bytes[..name.len()].copy_from_slice(name.as_bytes());
+        Some(ColorName {
+            len: name.len() as u8,
+            bytes,
+        })
+    }
+
+    /// Returns the name as a string.
+    pub fn as_str(&self) -> &str {
+        // Always copied from a `&str`.
+        std::str::from_utf8(&self.bytes[..usize::from(self.len)]).unwrap()
+    }
+}
+
+impl fmt::Debug for ColorName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for ColorName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 
 /// Possible color style for a cell.
 ///
@@ -54,6 +117,13 @@ impl ColorStyle {
         Self::new(Color::TerminalDefault, Color::TerminalDefault)
     }
 
+    /// Uses the palette color called `name` as front color.
+    ///
+    /// See [`ColorType::from_name`].
+    pub fn from_name(name: &str) -> Self {
+        Self::front(ColorType::from_name(name))
+    }
+
     /// Application background, where no view is present.
     pub fn background() -> Self {
         Self::new(PaletteColor::Background, PaletteColor::Background)
@@ -99,6 +169,11 @@ impl ColorStyle {
         Self::new(PaletteColor::HighlightText, PaletteColor::HighlightInactive)
     }
 
+    /// Error text color, with default background.
+    pub fn error() -> Self {
+        Self::new(PaletteColor::Error, PaletteColor::View)
+    }
+
     /// Merge the style `b` over style `a`.
     ///
     /// This merges the front and back color types of `a` and `b`.
@@ -167,6 +242,12 @@ pub enum ColorType {
     /// Uses a direct color, independent of the current palette.
     Color(Color),
 
+    /// Uses a color from the application palette, by name.
+    ///
+    /// The name is looked up when drawing, so changing the theme updates
+    /// the color. See [`ColorType::from_name`].
+    Named(ColorName),
+
     /// Re-use the color from the parent.
     InheritParent,
 }
@@ -183,10 +264,70 @@ impl ColorType {
         match self {
             ColorType::Color(color) => color,
             ColorType::Palette(color) => color.resolve(palette),
+            ColorType::Named(name) => palette
+                .find_custom(name.as_str())
+                .copied()
+                .unwrap_or_else(|| {
+                    let first = UNKNOWN_NAMES
+                        .with(|names| names.borrow_mut().insert(name));
+                    if first {
+                        warn!("Unknown palette color: {}", name);
+                    }
+                    PaletteColor::Primary.resolve(palette)
+                }),
             ColorType::InheritParent => previous,
         }
     }
 
+    /// Refers to a palette color by name.
+    ///
+    /// Names of basic palette colors, like `"highlight"`, give a
+    /// `ColorType::Palette`. Any other name gives a `ColorType::Named`,
+    /// referring to a custom color from the palette. Names can use dots to
+    /// look inside namespaces, like `"log.warning"`.
+    ///
+    /// Unknown custom colors resolve to `PaletteColor::Primary`, and log a
+    /// warning the first time. So do names longer than
+    /// [`ColorName::MAX_LEN`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::theme::{
+    ///     Color, ColorName, ColorType, Palette, PaletteColor,
+    /// };
+    ///
+    /// let mut palette = Palette::default();
+    /// palette.set_color("warning", Color::parse("yellow").unwrap());
+    ///
+    /// let warning = ColorType::from_name("warning");
+    /// assert_eq!(
+    ///     warning,
+    ///     ColorType::Named(ColorName::new("warning").unwrap())
+    /// );
+    /// assert_eq!(
+    ///     warning.resolve(&palette, Color::TerminalDefault),
+    ///     Color::parse("yellow").unwrap()
+    /// );
+    ///
+    /// assert_eq!(
+    ///     ColorType::from_name("highlight"),
+    ///     ColorType::Palette(PaletteColor::Highlight)
+    /// );
+    /// ```
+    pub fn from_name(name: &str) -> Self {
+        match PaletteColor::from_str(name) {
+            Ok(color) => ColorType::Palette(color),
+            Err(_) => match ColorName::new(name) {
+                Some(name) => ColorType::Named(name),
+                None => {
+                    warn!("Palette color name too long: {}", name);
+                    ColorType::Palette(PaletteColor::Primary)
+                }
+            },
+        }
+    }
+
     /// Merge the color type `b` over the color type `a`.
     ///
     /// This returns `b`, unless `b = ColorType::InheritParent`,
@@ -210,3 +351,33 @@ impl From<PaletteColor> for ColorType {
         ColorType::Palette(color)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_names() {
+        let name = ColorName::new("log.warning").unwrap();
+        assert_eq!(name.as_str(), "log.warning");
+        assert_eq!(ColorName::new("").unwrap().as_str(), "");
+
+        let long = "x".repeat(ColorName::MAX_LEN);
+        assert_eq!(ColorName::new(&long).unwrap().as_str(), long);
+        assert_eq!(ColorName::new(&format!("{}x", long)), None);
+
+        // Names are resolved when drawing.
+        let mut palette = Palette::default();
+        let color = ColorType::from_name("warning");
+        let previous = Color::TerminalDefault;
+        assert_eq!(
+            color.resolve(&palette, previous),
+            palette[PaletteColor::Primary]
+        );
+        palette.set_color("warning", Color::Dark(BaseColor::Yellow));
+        assert_eq!(
+            color.resolve(&palette, previous),
+            Color::Dark(BaseColor::Yellow)
+        );
+    }
+}
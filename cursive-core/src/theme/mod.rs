@@ -41,6 +41,8 @@
 //!   Defaults to **blue**.
 //! * **`HighlightText`**: used to print primary text when highlighted
 //!   Defaults to **white**.
+//! * **`Error`**: used to print invalid content and error messages.
+//!   Defaults to **red**.
 //!
 //! A [`Palette`] then maps each of these to an actual [`Color`].
 //!
@@ -48,13 +50,16 @@
 //!
 //! # Color Types
 //!
-//! When drawing views, color can be picked in two way:
+//! When drawing views, color can be picked in three way:
 //!
 //! * An exact [`Color`] can be given directly
 //! * A [`PaletteColor`] entry can be given, which will fetch whatever color
 //!   is currently defined for this.
+//! * A custom palette color can be given by name, with
+//!   [`ColorType::from_name`]. It is also resolved with the current palette,
+//!   so content can use semantic names like `"warning"`.
 //!
-//! The [`ColorType`] enum abstract over these two choices.
+//! The [`ColorType`] enum abstract over these choices.
 //!
 //! [`ColorType`]: enum.ColorType.html
 //!
@@ -180,6 +185,15 @@
 //!     # Lower precision values can use only 3 digits.
 //!     highlight          = "#F00"
 //!     highlight_inactive = "#5555FF"
+//!
+//!     # Any other key defines a custom color, used with
+//!     # `ColorType::from_name` or `Style::from_name`.
+//!     warning = "yellow"
+//!
+//!     # Custom colors can be grouped in namespaces,
+//!     # and used as `"log.debug"`.
+//!     [colors.log]
+//!         debug = "blue"
 //! ```
 //!
 //! [`Color`]: ./enum.Color.html
@@ -202,10 +216,10 @@ mod effect;
 mod palette;
 mod style;
 
-pub use self::border_style::BorderStyle;
+pub use self::border_style::{BorderStyle, BoxStyle};
 pub use self::color::{BaseColor, Color};
 pub use self::color_pair::ColorPair;
-pub use self::color_style::{ColorStyle, ColorType};
+pub use self::color_style::{ColorName, ColorStyle, ColorType};
 pub use self::effect::Effect;
 pub use self::palette::{Palette, PaletteColor};
 pub use self::style::Style;
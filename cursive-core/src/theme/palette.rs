@@ -98,6 +98,27 @@ impl Palette {
         })
     }
 
+    /// Returns a custom color from this palette, looking inside namespaces.
+    ///
+    /// The path uses dots to separate namespaces, like `"log.warning"`.
+    ///
+    /// Returns `None` if the given path was not found.
+    pub fn find_custom<'a>(&'a self, path: &str) -> Option<&'a Color> {
+        let mut parts = path.split('.');
+        let mut node = self.custom.get(parts.next()?)?;
+        for part in parts {
+            node = match node {
+                PaletteNode::Namespace(map) => map.get(part)?,
+                PaletteNode::Color(_) => return None,
+            };
+        }
+
+        match node {
+            PaletteNode::Color(color) => Some(color),
+            PaletteNode::Namespace(_) => None,
+        }
+    }
+
     /// Returns a new palette where the given namespace has been merged.
     ///
     /// All values in the namespace will override previous values.
@@ -178,6 +199,7 @@ impl Extend<(PaletteColor, Color)> for Palette {
 /// * `Highlight` => `Dark(Red)`
 /// * `HighlightInactive` => `Dark(Blue)`
 /// * `HighlightText` => `Dark(White)`
+/// * `Error` => `Dark(Red)`
 impl Default for Palette {
     fn default() -> Palette {
         use self::PaletteColor::*;
@@ -197,6 +219,7 @@ impl Default for Palette {
                 Highlight => Dark(Red),
                 HighlightInactive => Dark(Blue),
                 HighlightText => Dark(White),
+                Error => Dark(Red),
             },
             custom: HashMap::default(),
         }
@@ -288,6 +311,8 @@ pub enum PaletteColor {
     HighlightInactive,
     /// Color used for highlighted text
     HighlightText,
+    /// Color used for invalid content and error messages.
+    Error,
 }
 
 impl PaletteColor {
@@ -315,6 +340,7 @@ impl FromStr for PaletteColor {
             "Highlight" | "highlight" => Highlight,
             "HighlightInactive" | "highlight_inactive" => HighlightInactive,
             "HighlightText" | "highlight_text" => HighlightText,
+            "Error" | "error" => Error,
             _ => return Err(NoSuchColor),
         })
     }
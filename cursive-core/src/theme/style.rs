@@ -34,6 +34,24 @@ impl Style {
         }
     }
 
+    /// Returns a new `Style` using the palette color called `name`.
+    ///
+    /// The color is looked up in the current theme when drawing, so
+    /// changing the theme restyles existing content. See
+    /// [`ColorType::from_name`] for how names are resolved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::theme::Style;
+    /// use cursive_core::utils::markup::StyledString;
+    ///
+    /// let text = StyledString::styled("Disk almost full", Style::from_name("warning"));
+    /// ```
+    pub fn from_name(name: &str) -> Self {
+        ColorStyle::from_name(name).into()
+    }
+
     /// Returns a new `Style` by merging all given styles.
     ///
     /// Will use the last non-`None` color, and will combine all effects.
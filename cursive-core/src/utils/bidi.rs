@@ -0,0 +1,351 @@
+//! Support for right-to-left and mixed-direction text.
+//!
+//! Text is stored in logical order (the order in which it is read), but
+//! right-to-left words, like Arabic or Hebrew, must be shown reversed on
+//! screen. This module reorders each line for display.
+//!
+//! By default, only right-to-left paragraphs are reordered: the line is
+//! reversed, except for left-to-right runs (latin words, numbers, ...) which
+//! keep their reading order. Left-to-right paragraphs are shown in logical
+//! order, even if they contain right-to-left words.
+//!
+//! With the `bidi` feature, lines are reordered using the full Unicode
+//! Bidirectional Algorithm from the `unicode-bidi` crate, which also
+//! handles right-to-left words inside left-to-right text.
+
+use crate::align::TextDirection;
+use crate::theme::Style;
+use crate::utils::width::TextWidth;
+use crate::{Printer, Vec2};
+
+/// Returns `true` if `c` is a strong right-to-left character.
+///
+/// This covers Hebrew, Arabic, Syriac, Thaana, N'Ko and their presentation
+/// forms.
+pub fn is_rtl(c: char) -> bool {
+    matches!(c,
+        '\u{0590}'..='\u{08FF}'
+        | '\u{FB1D}'..='\u{FDFF}'
+        | '\u{FE70}'..='\u{FEFF}'
+        | '\u{10800}'..='\u{10FFF}'
+        | '\u{1E800}'..='\u{1EFFF}')
+}
+
+/// Returns `true` if `c` is a strong left-to-right character.
+///
+/// Digits are included, since numbers are always read left-to-right.
+pub fn is_ltr(c: char) -> bool {
+    c.is_alphanumeric() && !is_rtl(c)
+}
+
+// Returns the mirrored version of a bracket, if any.
+fn mirror(text: &str) -> Option<&'static str> {
+    Some(match text {
+        "(" => ")",
+        ")" => "(",
+        "[" => "]",
+        "]" => "[",
+        "{" => "}",
+        "}" => "{",
+        "<" => ">",
+        ">" => "<",
+        _ => return None,
+    })
+}
+
+/// Returns `true` if `text` contains any right-to-left character.
+pub fn has_rtl(text: &str) -> bool {
+    text.chars().any(is_rtl)
+}
+
+/// Returns the direction of the first strong character in `text`.
+///
+/// Defaults to left-to-right if there is no strong character.
+pub fn paragraph_direction(text: &str) -> TextDirection {
+    first_strong(text.chars()).unwrap_or(TextDirection::Ltr)
+}
+
+fn first_strong<I>(chars: I) -> Option<TextDirection>
+where
+    I: IntoIterator<Item = char>,
+{
+    chars.into_iter().find_map(|c| {
+        if is_rtl(c) {
+            Some(TextDirection::Rtl)
+        } else if c.is_alphabetic() {
+            Some(TextDirection::Ltr)
+        } else {
+            None
+        }
+    })
+}
+
+/// Reorders a line of graphemes from logical order to visual order, for a
+/// right-to-left paragraph.
+///
+/// This is equivalent to `reorder(items, TextDirection::Rtl)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive_core::utils::bidi::reorder_rtl;
+/// let mut line: Vec<(&str, ())> =
+///     ["א", "ב", " ", "4", "2", "!"].iter().map(|&g| (g, ())).collect();
+/// reorder_rtl(&mut line);
+///
+/// let visual: String = line.iter().map(|&(g, _)| g).collect();
+/// assert_eq!(visual, "!42 בא");
+/// ```
+pub fn reorder_rtl<T>(items: &mut [(&str, T)]) {
+    reorder(items, TextDirection::Rtl);
+}
+
+/// Reorders a line of graphemes from logical order to visual order.
+///
+/// Each item is a grapheme with some attached data (a style, or its
+/// position in the text, for instance). `direction` is the direction of the
+/// paragraph.
+///
+/// Right-to-left runs are reversed, and brackets inside them are mirrored.
+/// Numbers are always shown left-to-right.
+///
+/// Without the `bidi` feature, left-to-right paragraphs are left in logical
+/// order.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::align::TextDirection;
+/// use cursive_core::utils::bidi::reorder;
+///
+/// let text = ["I", " ", "s", "a", "w", " ", "ש", "ל", "ו", "ם", "."];
+/// let mut line: Vec<(&str, usize)> =
+///     text.iter().enumerate().map(|(i, &g)| (g, i)).collect();
+/// reorder(&mut line, TextDirection::Ltr);
+///
+/// let visual: String = line.iter().map(|&(g, _)| g).collect();
+/// if cfg!(feature = "bidi") {
+///     assert_eq!(visual, "I saw םולש.");
+///     // The attached data follows each grapheme.
+///     assert_eq!(line[6], ("ם", 9));
+/// } else {
+///     assert_eq!(visual, "I saw שלום.");
+/// }
+/// ```
+pub fn reorder<T>(items: &mut [(&str, T)], direction: TextDirection) {
+    let direction = match direction {
+        TextDirection::Auto => {
+            first_strong(items.iter().flat_map(|&(text, _)| text.chars()))
+                .unwrap_or(TextDirection::Ltr)
+        }
+        direction => direction,
+    };
+    let rtl = direction == TextDirection::Rtl;
+
+    // Fast path: nothing to reorder.
+    if !rtl
+        && !items
+            .iter()
+            .any(|&(text, _)| needs_reorder(text, direction))
+    {
+        return;
+    }
+
+    reorder_paragraph(items, rtl);
+}
+
+/// Returns `true` if `text` needs to be reordered before being displayed.
+///
+/// `direction` should already be resolved.
+pub(crate) fn needs_reorder(text: &str, direction: TextDirection) -> bool {
+    direction == TextDirection::Rtl
+        || (cfg!(feature = "bidi") && has_rtl(text))
+}
+
+/// Reverses a right-to-left paragraph, except for left-to-right runs.
+///
+/// Left-to-right paragraphs are left untouched.
+#[cfg(not(feature = "bidi"))]
+fn reorder_paragraph<T>(items: &mut [(&str, T)], rtl: bool) {
+    if !rtl {
+        return;
+    }
+
+    // Find the left-to-right runs, as `(start, end)` inclusive ranges.
+    // Neutral characters between two LTR characters belong to the run.
+    let mut runs = Vec::new();
+    let mut run: Option<(usize, usize)> = None;
+    for (i, &(text, _)) in items.iter().enumerate() {
+        if text.starts_with(is_ltr) {
+            run = Some(match run {
+                Some((start, _)) => (start, i),
+                None => (i, i),
+            });
+        } else if text.starts_with(is_rtl) {
+            runs.extend(run.take());
+        }
+    }
+    runs.extend(run);
+
+    // Brackets outside of LTR runs are mirrored.
+    let mut runs_iter = runs.iter().peekable();
+    for (i, item) in items.iter_mut().enumerate() {
+        while matches!(runs_iter.peek(), Some(&&(_, end)) if end < i) {
+            runs_iter.next();
+        }
+        let in_run =
+            matches!(runs_iter.peek(), Some(&&(start, _)) if start <= i);
+        if !in_run {
+            if let Some(mirrored) = mirror(item.0) {
+                item.0 = mirrored;
+            }
+        }
+    }
+
+    let n = items.len();
+    items.reverse();
+    for &(start, end) in &runs {
+        items[n - 1 - end..n - start].reverse();
+    }
+}
+
+/// Reorders a paragraph using the levels from the `unicode-bidi` crate.
+#[cfg(feature = "bidi")]
+fn reorder_paragraph<T>(items: &mut [(&str, T)], rtl: bool) {
+    let graphemes: Vec<&str> = items.iter().map(|&(text, _)| text).collect();
+    let mut levels = levels(&graphemes, rtl);
+
+    // Brackets in right-to-left runs are mirrored.
+    for (item, &level) in items.iter_mut().zip(&levels) {
+        if level % 2 == 1 {
+            if let Some(mirrored) = mirror(item.0) {
+                item.0 = mirrored;
+            }
+        }
+    }
+
+    // Rule L2: from the highest level to the lowest odd level, reverse any
+    // run at this level or higher.
+    let highest = levels.iter().copied().max().unwrap_or(0);
+    let lowest_odd = levels.iter().copied().min().unwrap_or(0) | 1;
+    for level in (lowest_odd..=highest).rev() {
+        let mut i = 0;
+        while i < levels.len() {
+            if levels[i] < level {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < levels.len() && levels[i] >= level {
+                i += 1;
+            }
+            items[start..i].reverse();
+            levels[start..i].reverse();
+        }
+    }
+}
+
+/// Prints styled text on a single row, in visual order.
+///
+/// `direction` should already be resolved.
+pub(crate) fn print_styled<'a, I>(
+    printer: &Printer,
+    start: Vec2,
+    spans: I,
+    direction: TextDirection,
+) where
+    I: IntoIterator<Item = (&'a str, Style)>,
+{
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let mut graphemes: Vec<(&str, Style)> = spans
+        .into_iter()
+        .flat_map(|(text, style)| {
+            text.graphemes(true).map(move |g| (g, style))
+        })
+        .collect();
+    reorder(&mut graphemes, direction);
+
+    let mut x = start.x;
+    for (g, style) in graphemes {
+        printer.with_style(style, |printer| {
+            printer.print((x, start.y), g);
+        });
+        x += g.width();
+    }
+}
+
+/// Returns the embedding level of each grapheme.
+///
+/// Even levels are left-to-right, odd levels are right-to-left.
+#[cfg(feature = "bidi")]
+fn levels(graphemes: &[&str], rtl: bool) -> Vec<u8> {
+    use unicode_bidi::{BidiInfo, Level};
+
+    let text = graphemes.concat();
+    let level = if rtl { Level::rtl() } else { Level::ltr() };
+    let info = BidiInfo::new(&text, Some(level));
+
+    let mut byte = 0;
+    graphemes
+        .iter()
+        .map(|g| {
+            let level = info.levels[byte].number();
+            byte += g.len();
+            level
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unicode_segmentation::UnicodeSegmentation;
+
+    fn visual(graphemes: &[&str]) -> String {
+        let mut line: Vec<(&str, ())> =
+            graphemes.iter().map(|&g| (g, ())).collect();
+        reorder_rtl(&mut line);
+        line.iter().map(|&(g, _)| g).collect()
+    }
+
+    #[test]
+    fn reorder() {
+        assert_eq!(visual(&[]), "");
+        assert_eq!(visual(&["ש", "ל", "ו", "ם"]), "םולש");
+
+        // Latin words keep their order, including the spaces between them.
+        assert_eq!(
+            visual(&["א", " ", "a", "b", " ", "c", " ", "ב"]),
+            "ב ab c א"
+        );
+
+        // Brackets are mirrored, except inside latin runs.
+        assert_eq!(visual(&["(", "א", ")"]), "(א)");
+        assert_eq!(visual(&["a", "(", "b", ")", "c"]), "a(b)c");
+    }
+
+    #[test]
+    fn mixed_directions() {
+        let line = |text: &str, direction| {
+            let mut items: Vec<(&str, ())> =
+                text.graphemes(true).map(|g| (g, ())).collect();
+            super::reorder(&mut items, direction);
+            items.iter().map(|&(g, _)| g).collect::<String>()
+        };
+
+        // Right-to-left words inside left-to-right text are only reordered
+        // with the full algorithm.
+        let expected = if cfg!(feature = "bidi") {
+            "a (ג) בא b"
+        } else {
+            "a אב (ג) b"
+        };
+        assert_eq!(line("a אב (ג) b", TextDirection::Ltr), expected);
+        // Numbers inside right-to-left text stay in order.
+        assert_eq!(line("אב 123 ג", TextDirection::Rtl), "ג 123 בא");
+        // Left-to-right paragraphs without right-to-left text are unchanged.
+        assert_eq!(line("(a) 1 b", TextDirection::Ltr), "(a) 1 b");
+        assert_eq!(line("אב cd", TextDirection::Auto), "cd בא");
+    }
+}
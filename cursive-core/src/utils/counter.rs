@@ -1,16 +1,29 @@
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
 
 /// Atomic counter used by [`ProgressBar`].
 ///
+/// Clones share the same value, and the same [`on_tick`](Counter::on_tick)
+/// callback.
+///
+/// To share an existing `Arc<AtomicUsize>`, use `Counter::from`. Changes
+/// made directly to the atomic don't run the callback.
+///
 /// [`ProgressBar`]: crate::views::ProgressBar
 #[derive(Clone, Debug)]
-pub struct Counter(pub Arc<AtomicUsize>);
+pub struct Counter(pub Arc<AtomicUsize>, Arc<OnTick<usize>>);
+
+impl From<Arc<AtomicUsize>> for Counter {
+    fn from(value: Arc<AtomicUsize>) -> Self {
+        Counter(value, Arc::default())
+    }
+}
 
 impl Counter {
     /// Creates a new `Counter` starting with the given value.
     pub fn new(value: usize) -> Self {
-        Counter(Arc::new(AtomicUsize::new(value)))
+        Counter(Arc::new(AtomicUsize::new(value)), Arc::default())
     }
 
     /// Retrieves the current progress value.
@@ -21,10 +34,214 @@ impl Counter {
     /// Sets the current progress value.
     pub fn set(&self, value: usize) {
         self.0.store(value, Ordering::Relaxed);
+        self.1.call(value);
     }
 
     /// Increase the current progress by `ticks`.
     pub fn tick(&self, ticks: usize) {
-        self.0.fetch_add(ticks, Ordering::Relaxed);
+        let value = self.0.fetch_add(ticks, Ordering::Relaxed);
+        self.1.call(value.wrapping_add(ticks));
+    }
+
+    /// Sets a callback to run whenever the value changes.
+    ///
+    /// The callback receives the new value. It runs on the thread that
+    /// changed the value, so it should be quick: for example, it can send a
+    /// callback to the `cb_sink` to request a redraw.
+    ///
+    /// This replaces any previous callback, for every clone of this counter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::utils::Counter;
+    ///
+    /// let siv = cursive_core::Cursive::new();
+    /// let cb_sink = siv.cb_sink().clone();
+    ///
+    /// let counter = Counter::new(0);
+    /// counter.on_tick(move |value| {
+    ///     if value % 100 == 0 {
+    ///         cb_sink.send(Box::new(|_| ())).ok();
+    ///     }
+    /// });
+    /// counter.tick(100);
+    /// ```
+    pub fn on_tick<F>(&self, cb: F)
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        self.1.set(Some(Arc::new(cb)));
+    }
+
+    /// Removes the callback set with [`Counter::on_tick`].
+    pub fn clear_on_tick(&self) {
+        self.1.set(None);
+    }
+}
+
+/// Atomic floating-point counter.
+///
+/// Like [`Counter`], for progress that is not a whole number. Used by a
+/// [`ProgressBar`] with [`ProgressBar::with_float_value`].
+///
+/// [`ProgressBar`]: crate::views::ProgressBar
+/// [`ProgressBar::with_float_value`]: crate::views::ProgressBar::with_float_value
+#[derive(Clone, Debug)]
+pub struct FloatCounter(Arc<AtomicU64>, Arc<OnTick<f64>>);
+
+impl FloatCounter {
+    /// Creates a new `FloatCounter` starting with the given value.
+    pub fn new(value: f64) -> Self {
+        FloatCounter(Arc::new(AtomicU64::new(value.to_bits())), Arc::default())
+    }
+
+    /// Retrieves the current progress value.
+    pub fn get(&self) -> f64 {
+        f64::from_bits(self.0.load(Ordering::Relaxed))
+    }
+
+    /// Sets the current progress value.
+    pub fn set(&self, value: f64) {
+        self.0.store(value.to_bits(), Ordering::Relaxed);
+        self.1.call(value);
+    }
+
+    /// Increase the current progress by `ticks`.
+    pub fn tick(&self, ticks: f64) {
+        let mut current = self.0.load(Ordering::Relaxed);
+        loop {
+            let value = f64::from_bits(current) + ticks;
+            match self.0.compare_exchange_weak(
+                current,
+                value.to_bits(),
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => {
+                    self.1.call(value);
+                    return;
+                }
+                Err(actual) => current = actual,
+            }
+        }
+    }
+
+    /// Sets a callback to run whenever the value changes.
+    ///
+    /// See [`Counter::on_tick`].
+    pub fn on_tick<F>(&self, cb: F)
+    where
+        F: Fn(f64) + Send + Sync + 'static,
+    {
+        self.1.set(Some(Arc::new(cb)));
+    }
+
+    /// Removes the callback set with [`FloatCounter::on_tick`].
+    pub fn clear_on_tick(&self) {
+        self.1.set(None);
+    }
+}
+
+type TickCb<T> = dyn Fn(T) + Send + Sync;
+
+/// Callback shared by the clones of a counter.
+struct OnTick<T> {
+    // Lets `call` skip the lock when no callback is set.
+    is_set: AtomicBool,
+    cb: RwLock<Option<Arc<TickCb<T>>>>,
+}
+
+impl<T> OnTick<T> {
+    fn set(&self, cb: Option<Arc<TickCb<T>>>) {
+        let mut guard = self.cb.write().unwrap();
+        self.is_set.store(cb.is_some(), Ordering::Release);
+        *guard = cb;
+    }
+
+    fn call(&self, value: T) {
+        // The value changed: the progress bars need to be drawn again.
+        crate::request_redraw();
+
+        if !self.is_set.load(Ordering::Acquire) {
+            return;
+        }
+
+        // Don't hold the lock while running the callback.
+        let cb = self.cb.read().unwrap().clone();
+        if let Some(cb) = cb {
+            cb(value);
+        }
+    }
+}
+
+impl<T> Default for OnTick<T> {
+    fn default() -> Self {
+        OnTick {
+            is_set: AtomicBool::new(false),
+            cb: RwLock::new(None),
+        }
+    }
+}
+
+impl<T> fmt::Debug for OnTick<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OnTick")
+            .field("is_set", &self.is_set)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn on_tick_is_shared() {
+        let counter = Counter::new(0);
+        let last = Arc::new(AtomicUsize::new(0));
+
+        let last_clone = Arc::clone(&last);
+        counter
+            .clone()
+            .on_tick(move |value| last_clone.store(value, Ordering::Relaxed));
+
+        counter.tick(3);
+        counter.tick(4);
+        assert_eq!(last.load(Ordering::Relaxed), 7);
+
+        counter.clear_on_tick();
+        counter.set(1);
+        assert_eq!(last.load(Ordering::Relaxed), 7);
+    }
+
+    #[test]
+    fn float_tick() {
+        let counter = FloatCounter::new(0.25);
+        let last = Arc::new(AtomicU64::new(0));
+
+        let last_clone = Arc::clone(&last);
+        counter.clone().on_tick(move |value| {
+            last_clone.store(value.to_bits(), Ordering::Relaxed)
+        });
+
+        counter.tick(0.5);
+        assert_eq!(counter.get(), 0.75);
+        assert_eq!(f64::from_bits(last.load(Ordering::Relaxed)), 0.75);
+
+        counter.set(0.5);
+        assert_eq!(f64::from_bits(last.load(Ordering::Relaxed)), 0.5);
+
+        counter.clear_on_tick();
+        counter.set(1.0);
+        assert_eq!(f64::from_bits(last.load(Ordering::Relaxed)), 0.5);
+    }
+
+    #[test]
+    fn from_atomic() {
+        let value = Arc::new(AtomicUsize::new(3));
+        let counter = Counter::from(Arc::clone(&value));
+        counter.tick(2);
+        assert_eq!(value.load(Ordering::Relaxed), 5);
     }
 }
@@ -9,8 +9,9 @@ mod row;
 
 pub use self::lines_iterator::LinesIterator;
 pub use self::row::Row;
+use crate::utils::width::TextWidth;
+use std::borrow::Cow;
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
 
 /// The length and width of a part of a string.
 pub struct Span {
@@ -114,5 +115,176 @@ pub fn simple_prefix(text: &str, width: usize) -> Span {
     prefix(text.graphemes(true), width, "")
 }
 
+/// Positions of the tab stops used to expand tabs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TabStops {
+    /// A tab stop every `n` columns.
+    ///
+    /// If `n` is `0`, tabs are removed.
+    Every(usize),
+
+    /// Tab stops at the given columns, in increasing order.
+    ///
+    /// Past the last stop, each tab is replaced by a single space.
+    At(Vec<usize>),
+
+    /// Tab stops computed from the content, so that tab-separated cells line
+    /// up in columns (like `column -t`).
+    ///
+    /// Each column is as wide as its widest cell, plus a 2-spaces gap.
+    Auto,
+}
+
+impl TabStops {
+    /// Computes the tab stops aligning the tab-separated cells of `text`.
+    ///
+    /// The last cell of each line does not contribute to the column widths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cursive_core::utils::lines::simple::TabStops;
+    /// let stops = TabStops::from_columns("a\tbb\tc\nddd\te\tf", 2);
+    /// assert_eq!(stops, TabStops::At(vec![5, 9]));
+    /// ```
+    pub fn from_columns(text: &str, gap: usize) -> Self {
+        let mut widths: Vec<usize> = Vec::new();
+        for line in text.lines() {
+            let mut cells = line.split('\t');
+            // The last cell is not followed by a tab.
+            cells.next_back();
+            for (i, cell) in cells.enumerate() {
+                let width = cell.width();
+                match widths.get_mut(i) {
+                    Some(w) => *w = usize::max(*w, width),
+                    None => widths.push(width),
+                }
+            }
+        }
+
+        let mut column = 0;
+        TabStops::At(
+            widths
+                .into_iter()
+                .map(|width| {
+                    column += width + gap;
+                    column
+                })
+                .collect(),
+        )
+    }
+
+    /// Resolves `Auto` tab stops for the given text.
+    ///
+    /// Other variants are returned as-is.
+    pub fn resolve(&self, text: &str) -> Cow<'_, Self> {
+        match *self {
+            TabStops::Auto => Cow::Owned(Self::from_columns(text, 2)),
+            _ => Cow::Borrowed(self),
+        }
+    }
+
+    // Returns the number of spaces replacing a tab at the given column.
+    //
+    // `Auto` must have been resolved first.
+    fn spaces_at(&self, column: usize) -> usize {
+        match *self {
+            TabStops::Every(0) => 0,
+            TabStops::Every(n) => n - column % n,
+            TabStops::At(ref stops) => stops
+                .iter()
+                .find(|&&stop| stop > column)
+                .map_or(1, |stop| stop - column),
+            TabStops::Auto => 1,
+        }
+    }
+}
+
+/// Replaces tabs with spaces, up to the next tab stop.
+///
+/// Tab stops are placed every `tab_width` columns. `start_column` is the
+/// column of the first character of `text`; it is reset after each newline.
+///
+/// If `tab_width` is `0`, tabs are removed.
+///
+/// Returns the text unchanged if it contains no tab.
+///
+/// # Examples
+///
+/// ```
+/// # use cursive_core::utils::lines::simple::expand_tabs;
+/// assert_eq!(expand_tabs("a\tbc\td", 4, 0), "a   bc  d");
+/// assert_eq!(expand_tabs("\tx", 4, 2), "  x");
+/// ```
+pub fn expand_tabs(
+    text: &str,
+    tab_width: usize,
+    start_column: usize,
+) -> Cow<'_, str> {
+    let mut column = start_column;
+    expand_tabs_from(text, &TabStops::Every(tab_width), &mut column)
+}
+
+/// Replaces tabs with spaces, up to the next of the given tab stops.
+///
+/// `TabStops::Auto` is resolved from `text` itself.
+///
+/// # Examples
+///
+/// ```
+/// # use cursive_core::utils::lines::simple::{expand_tab_stops, TabStops};
+/// let stops = TabStops::At(vec![4, 10]);
+/// assert_eq!(expand_tab_stops("a\tb\tc", &stops), "a   b     c");
+///
+/// let text = "name\tsize\nfoo.txt\t12";
+/// assert_eq!(
+///     expand_tab_stops(text, &TabStops::Auto),
+///     "name     size\nfoo.txt  12"
+/// );
+/// ```
+pub fn expand_tab_stops<'a>(text: &'a str, stops: &TabStops) -> Cow<'a, str> {
+    let mut column = 0;
+    expand_tabs_from(text, &stops.resolve(text), &mut column)
+}
+
+/// Same as `expand_tabs`, but updates `column` to the column after `text`.
+///
+/// `Auto` tab stops must have been resolved first.
+pub(crate) fn expand_tabs_from<'a>(
+    text: &'a str,
+    stops: &TabStops,
+    column: &mut usize,
+) -> Cow<'a, str> {
+    if !text.contains('\t') {
+        // Still need to track the column for the next call.
+        *column = match text.rfind('\n') {
+            Some(i) => text[i + 1..].width(),
+            None => *column + text.width(),
+        };
+        return Cow::Borrowed(text);
+    }
+
+    let mut result = String::with_capacity(text.len());
+    for grapheme in text.graphemes(true) {
+        match grapheme {
+            "\t" => {
+                let spaces = stops.spaces_at(*column);
+                result.push_str(&" ".repeat(spaces));
+                *column += spaces;
+            }
+            "\n" | "\r\n" => {
+                result.push_str(grapheme);
+                *column = 0;
+            }
+            _ => {
+                result.push_str(grapheme);
+                *column += grapheme.width();
+            }
+        }
+    }
+
+    Cow::Owned(result)
+}
+
 #[cfg(test)]
 mod tests;
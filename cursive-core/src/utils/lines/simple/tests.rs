@@ -16,3 +16,33 @@ fn test_lines() {
 
     assert_eq!(rows.len(), 3);
 }
+
+#[test]
+fn test_expand_tabs() {
+    use super::expand_tabs;
+
+    assert_eq!(expand_tabs("no tabs", 8, 0), "no tabs");
+    assert_eq!(expand_tabs("\tx", 8, 0), "        x");
+    assert_eq!(expand_tabs("abc\tx", 4, 0), "abc x");
+    assert_eq!(expand_tabs("abcd\tx", 4, 0), "abcd    x");
+    assert_eq!(expand_tabs("ab\tc\nd\te", 4, 0), "ab  c\nd   e");
+    assert_eq!(expand_tabs("a\tb", 0, 0), "ab");
+    assert_eq!(expand_tabs("\tb", 4, 3), " b");
+}
+
+#[test]
+fn test_expand_tab_stops() {
+    use super::{expand_tab_stops, TabStops};
+
+    let stops = TabStops::At(vec![3, 8]);
+    assert_eq!(expand_tab_stops("a\tb\tc\td", &stops), "a  b    c d");
+    assert_eq!(expand_tab_stops("abcd\te", &stops), "abcd    e");
+    assert_eq!(expand_tab_stops("a\tb", &TabStops::At(vec![])), "a b");
+
+    let text = "a\tbbb\tc\ndddd\te\tf\ng";
+    assert_eq!(TabStops::from_columns(text, 1), TabStops::At(vec![5, 9]));
+    assert_eq!(
+        expand_tab_stops(text, &TabStops::Auto),
+        "a     bbb  c\ndddd  e    f\ng"
+    );
+}
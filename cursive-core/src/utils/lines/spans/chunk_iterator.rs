@@ -1,9 +1,9 @@
 use super::chunk::Chunk;
 use super::segment::Segment;
 use crate::utils::span::SpannedText;
+use crate::utils::width::TextWidth as _;
 use std::rc::Rc;
 use unicode_segmentation::UnicodeSegmentation as _;
-use unicode_width::UnicodeWidthStr as _;
 use xi_unicode::LineBreakLeafIter;
 
 /// Iterator that returns non-breakable chunks of text.
@@ -5,10 +5,31 @@ use super::row::Row;
 use super::segment::Segment;
 use super::segment_merge_iterator::SegmentMergeIterator;
 use crate::utils::span::SpannedText;
+use crate::utils::width::TextWidth;
 use std::iter::Peekable;
 use std::rc::Rc;
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
+
+/// How lines of text are wrapped.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum WrapPolicy {
+    /// Wrap lines between words.
+    ///
+    /// Words too long for a row are broken between any two characters,
+    /// filling the row.
+    #[default]
+    Word,
+    /// Wrap lines between words.
+    ///
+    /// Words too long for a row are broken after a `/`, `-`, `_` or `.`
+    /// when possible, like in paths or URLs, or between any two characters
+    /// otherwise.
+    WordOrPunctuation,
+    /// Only break lines at newlines.
+    ///
+    /// Rows may then be wider than the available width.
+    NoWrap,
+}
 
 /// Generates rows of text in constrainted width.
 ///
@@ -30,6 +51,12 @@ where
     /// If `true`, keep a blank cell at the end of lines
     /// when a whitespace or newline should be.
     show_spaces: bool,
+
+    /// How to break lines.
+    policy: WrapPolicy,
+
+    /// If `true`, keep a cell for a hyphen when a word is split.
+    hyphenate: bool,
 }
 
 impl<S> LinesIterator<S>
@@ -46,6 +73,77 @@ where
             width,
             chunk_offset: ChunkPart::default(),
             show_spaces: false,
+            policy: WrapPolicy::default(),
+            hyphenate: false,
+        }
+    }
+
+    /// Sets the wrap policy.
+    ///
+    /// Defaults to `WrapPolicy::Word`.
+    #[must_use]
+    pub fn wrap_policy(mut self, policy: WrapPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Keep a cell for a hyphen when a word is split in the middle.
+    ///
+    /// Such rows are marked as [`hyphenated`](Row::hyphenated), and their
+    /// width includes the hyphen.
+    #[must_use]
+    pub fn hyphenate(mut self) -> Self {
+        self.hyphenate = true;
+        self
+    }
+
+    // Choose how many graphemes of a long word go in the current row.
+    //
+    // Returns the number of graphemes, and whether a hyphen is needed.
+    fn split_word(&self, graphemes: &[(&str, usize)]) -> (usize, bool) {
+        let mut fitting = 0;
+        let mut width = 0;
+        for &(_, w) in graphemes {
+            if width + w > self.width {
+                break;
+            }
+            width += w;
+            fitting += 1;
+        }
+
+        if fitting == 0 || fitting == graphemes.len() {
+            return (fitting, false);
+        }
+
+        if self.policy == WrapPolicy::WordOrPunctuation {
+            // Prefer breaking after some punctuation, like in paths or URLs.
+            if let Some(i) = graphemes[..fitting]
+                .iter()
+                .rposition(|&(g, _)| is_break_point(g))
+            {
+                return (i + 1, false);
+            }
+        }
+
+        if !self.hyphenate {
+            return (fitting, false);
+        }
+
+        // Keep a cell for the hyphen.
+        let mut split = fitting;
+        let mut split_width = width;
+        while split > 1 && split_width + 1 > self.width {
+            split -= 1;
+            split_width -= graphemes[split].1;
+        }
+
+        if split_width < self.width
+            && is_word_part(graphemes[split - 1].0)
+            && is_word_part(graphemes[split].0)
+        {
+            (split, true)
+        } else {
+            (fitting, false)
         }
     }
 
@@ -68,7 +166,10 @@ where
 
     fn next(&mut self) -> Option<Row> {
         // Let's build a beautiful row.
-        let allowed_width = if self.show_spaces {
+        let allowed_width = if self.policy == WrapPolicy::NoWrap {
+            // Take everything until the next newline.
+            usize::MAX
+        } else if self.show_spaces {
             // Remove 1 from the available space, if possible.
             // But only for regular words.
             // If we have to split a chunk, forget about that.
@@ -82,6 +183,8 @@ where
 
         // println!("Chunks..: {:?}", chunks);
 
+        let mut hyphenated = false;
+
         if chunks.is_empty() {
             // Desperate action to make something fit:
             // Look at the current chunk. We'll try to return a part of it.
@@ -95,9 +198,13 @@ where
                     chunk.remove_front(self.chunk_offset);
 
                     // Try to fit part of it?
+                    // We never split a grapheme, so combining marks stay
+                    // with their base character.
                     let source = self.source.as_ref();
-                    let graphemes =
-                        chunk.segments.iter().flat_map(move |seg| {
+                    let graphemes: Vec<(&str, Segment)> = chunk
+                        .segments
+                        .iter()
+                        .flat_map(move |seg| {
                             let mut offset = seg.start;
 
                             let text = seg.resolve_plain(source);
@@ -107,29 +214,39 @@ where
                                 let start = offset;
                                 let end = offset + g.len();
                                 offset = end;
-                                Chunk {
+                                let segment = Segment {
                                     width,
-                                    segments: vec![Segment {
-                                        width,
-                                        span_id: seg.span_id,
-                                        start,
-                                        end,
-                                    }],
-                                    hard_stop: false,
-                                    ends_with_space: false, // should we?
-                                }
+                                    span_id: seg.span_id,
+                                    start,
+                                    end,
+                                };
+                                (g, segment)
                             })
-                        });
-                    chunks = prefix(
-                        &mut graphemes.peekable(),
-                        self.width,
-                        &mut ChunkPart::default(),
-                    );
-
-                    if chunks.is_empty() {
+                        })
+                        .collect();
+
+                    let widths: Vec<(&str, usize)> = graphemes
+                        .iter()
+                        .map(|&(g, ref segment)| (g, segment.width))
+                        .collect();
+                    let (count, hyphen) = self.split_word(&widths);
+
+                    if count == 0 {
                         // Seriously? After everything we did for you?
                         return None;
                     }
+                    hyphenated = hyphen;
+
+                    chunks = graphemes
+                        .into_iter()
+                        .take(count)
+                        .map(|(_, segment)| Chunk {
+                            width: segment.width,
+                            segments: vec![segment],
+                            hard_stop: false,
+                            ends_with_space: false, // should we?
+                        })
+                        .collect();
 
                     // We are going to return a part of a chunk.
                     // So remember what we selected,
@@ -153,9 +270,10 @@ where
         let is_wrapped = !chunks.last().map(|c| c.hard_stop).unwrap_or(true)
             && self.iter.peek().is_some();
 
-        let width = chunks.iter().map(|c| c.width).sum();
+        let width = chunks.iter().map(|c| c.width).sum::<usize>()
+            + usize::from(hyphenated);
 
-        assert!(width <= self.width);
+        assert!(self.policy == WrapPolicy::NoWrap || width <= self.width);
 
         // Concatenate all segments
         let segments = SegmentMergeIterator::new(
@@ -169,6 +287,17 @@ where
             segments,
             width,
             is_wrapped,
+            hyphenated,
         })
     }
 }
+
+// Long words can be broken after these.
+fn is_break_point(grapheme: &str) -> bool {
+    matches!(grapheme, "/" | "-" | "_" | ".")
+}
+
+// A hyphen is only needed when splitting letters or digits.
+fn is_word_part(grapheme: &str) -> bool {
+    matches!(grapheme.chars().next(), Some(c) if c.is_alphanumeric())
+}
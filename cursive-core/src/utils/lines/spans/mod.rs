@@ -4,6 +4,48 @@
 //!
 //! Computed rows will include a list of span segments.
 //! Each segment include the source span ID, and start/end byte offsets.
+//!
+//! This is the engine used by [`TextView`](crate::views::TextView), and can
+//! be used to draw wrapped styled text in other views.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursive_core::theme::Effect;
+//! use cursive_core::utils::lines::spans::LinesIterator;
+//! use cursive_core::utils::markup::StyledString;
+//!
+//! let mut text = StyledString::plain("Some ");
+//! text.append_styled("bold", Effect::Bold);
+//! text.append_plain(" text to wrap");
+//!
+//! for row in LinesIterator::new(&text, 10) {
+//!     for span in row.spans(&text) {
+//!         // Print `span.content` with the style `span.attr`...
+//!     }
+//! }
+//!
+//! let rows: Vec<String> = LinesIterator::new(&text, 10)
+//!     .map(|row| row.spans(&text).map(|span| span.content).collect())
+//!     .collect();
+//! assert_eq!(rows, ["Some bold ", "text to ", "wrap"]);
+//! ```
+//!
+//! # Line breaks
+//!
+//! Lines break according to the Unicode line breaking algorithm, so most
+//! characters behave as expected:
+//!
+//! * A soft hyphen (`U+00AD`) is a possible break point. It has no width,
+//!   and stays at the end of the row when the line breaks there; no hyphen
+//!   is added to the row.
+//! * A no-break space (`U+00A0`) joins the words around it. If the joined
+//!   words do not fit in a row, they are cut like any long word.
+//! * Lines can break between wide characters, like CJK ideographs. A row
+//!   never ends in the middle of a character, so it may be one cell
+//!   narrower than the available width.
+//! * Grapheme clusters, like a letter with combining marks, or an emoji
+//!   sequence, are never split.
 mod chunk;
 mod chunk_iterator;
 mod lines_iterator;
@@ -15,6 +57,6 @@ mod segment_merge_iterator;
 #[cfg(test)]
 mod tests;
 
-pub use self::lines_iterator::LinesIterator;
+pub use self::lines_iterator::{LinesIterator, WrapPolicy};
 pub use self::row::Row;
 pub use self::segment::Segment;
@@ -10,6 +10,11 @@ pub struct Row {
     pub width: usize,
     /// Whether or not this text was wrapped onto the next line
     pub is_wrapped: bool,
+    /// Whether a word was split at the end of this row.
+    ///
+    /// A hyphen should then be shown after the text.
+    /// It is included in `width`.
+    pub hyphenated: bool,
 }
 
 impl Row {
@@ -17,14 +22,29 @@ impl Row {
     pub fn resolve<'a, T, S>(&self, source: S) -> Vec<Span<'a, T>>
     where
         S: Into<SpannedStr<'a, T>>,
+    {
+        self.spans(source).collect()
+    }
+
+    /// Iterates on the spans of this row.
+    ///
+    /// `source` should be the text this row was computed from. Empty spans
+    /// are skipped.
+    pub fn spans<'a, 'b, T, S>(
+        &'b self,
+        source: S,
+    ) -> impl Iterator<Item = Span<'a, T>> + 'b
+    where
+        S: Into<SpannedStr<'a, T>>,
+        T: 'a,
+        'a: 'b,
     {
         let source = source.into();
 
         self.segments
             .iter()
-            .map(|seg| seg.resolve(&source))
+            .map(move |seg| seg.resolve(&source))
             .filter(|span| !span.content.is_empty())
-            .collect()
     }
 
     /// Returns indices in the source string, if possible.
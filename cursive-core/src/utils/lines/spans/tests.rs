@@ -1,4 +1,4 @@
-use super::LinesIterator;
+use super::{LinesIterator, WrapPolicy};
 use crate::theme::{Effect, Style};
 use crate::utils::markup::StyledString;
 use crate::utils::span::Span;
@@ -91,3 +91,110 @@ fn test_line_breaks() {
         ]
     );
 }
+
+fn split(
+    text: &StyledString,
+    width: usize,
+    policy: WrapPolicy,
+) -> Vec<String> {
+    LinesIterator::new(text, width)
+        .wrap_policy(policy)
+        .hyphenate()
+        .map(|row| {
+            let mut line: String =
+                row.resolve(text).iter().map(|span| span.content).collect();
+            if row.hyphenated {
+                line.push('-');
+            }
+            line
+        })
+        .collect()
+}
+
+#[test]
+fn test_long_words() {
+    let name = StyledString::plain("see some_long_name");
+    assert_eq!(
+        split(&name, 8, WrapPolicy::WordOrPunctuation),
+        ["see ", "some_", "long_", "name"]
+    );
+    assert_eq!(
+        split(&name, 8, WrapPolicy::Word),
+        ["see ", "some_lo-", "ng_name"]
+    );
+    assert_eq!(split(&name, 8, WrapPolicy::NoWrap), ["see some_long_name"]);
+
+    // Combining marks stay with their base character.
+    let word = StyledString::plain("e\u{301}e\u{301}e\u{301}e\u{301}");
+    assert_eq!(
+        split(&word, 3, WrapPolicy::Word),
+        ["e\u{301}e\u{301}-", "e\u{301}e\u{301}"]
+    );
+
+    // Span boundaries are kept across the split.
+    let mut styled = StyledString::plain("ab");
+    styled.append(StyledString::styled("cdef", Effect::Bold));
+    let rows: Vec<_> = LinesIterator::new(&styled, 3)
+        .map(|row| row.resolve(&styled))
+        .collect();
+    assert_eq!(rows[0].len(), 2);
+    assert_eq!(rows[0][1].content, "c");
+    assert_eq!(rows[0][1].attr, &Style::from(Effect::Bold));
+}
+
+// Returns the content and width of each row.
+fn rows(text: &str, width: usize) -> Vec<(String, usize)> {
+    let text = StyledString::plain(text);
+    LinesIterator::new(&text, width)
+        .map(|row| {
+            let line = row.spans(&text).map(|span| span.content).collect();
+            (line, row.width)
+        })
+        .collect()
+}
+
+#[test]
+fn test_soft_hyphen() {
+    // The soft hyphen is a break point, kept at the end of the row.
+    assert_eq!(
+        rows("foo\u{ad}bar baz", 5),
+        [
+            ("foo\u{ad}".into(), 3),
+            ("bar ".into(), 4),
+            ("baz".into(), 3)
+        ]
+    );
+    assert_eq!(rows("foo\u{ad}bar", 10), [("foo\u{ad}bar".into(), 6)]);
+}
+
+#[test]
+fn test_no_break_space() {
+    assert_eq!(
+        rows("a\u{a0}b c\u{a0}d", 4),
+        [("a\u{a0}b ".into(), 4), ("c\u{a0}d".into(), 3)]
+    );
+
+    // Too long for a row, it is cut like any other word.
+    assert_eq!(
+        rows("a\u{a0}bcdef", 3),
+        [("a\u{a0}b".into(), 3), ("cde".into(), 3), ("f".into(), 1)]
+    );
+}
+
+#[test]
+fn test_wide_chars() {
+    // Lines can break between ideographs, which are never cut in half.
+    assert_eq!(
+        rows("日本語のテキスト", 5),
+        [
+            ("日本".into(), 4),
+            ("語の".into(), 4),
+            ("テキ".into(), 4),
+            ("スト".into(), 4)
+        ]
+    );
+    assert_eq!(
+        rows("ab 日本語", 5),
+        [("ab 日".into(), 5), ("本語".into(), 4)]
+    );
+}
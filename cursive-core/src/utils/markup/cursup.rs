@@ -0,0 +1,174 @@
+//! Parse semantic markup tags.
+//!
+//! Text is styled with tags named after palette colors, like
+//! `<warning>text</warning>`. Each tag uses [`Style::from_name`], so the
+//! actual color is looked up in the current theme when drawing.
+//!
+//! * Tags can be nested, like `<title>Disk <warning>full</warning></title>`.
+//!   Inner tags take precedence.
+//! * A closing tag also closes any tag opened after it. Tags left open run
+//!   to the end of the text.
+//! * `</>` closes the last opened tag.
+//! * Tag names can contain letters, digits, `_`, `-` and `.`. Anything else
+//!   after a `<`, like in `a < b`, is shown as-is.
+//! * `\<` and `\\` escape a literal `<` or `\`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursive_core::theme::Style;
+//! use cursive_core::utils::markup::cursup;
+//!
+//! let text = cursup::parse("Status: <warning>degraded</warning>");
+//! assert_eq!(text.source(), "Status: degraded");
+//!
+//! let spans: Vec<_> = text.spans().map(|span| *span.attr).collect();
+//! assert_eq!(spans, vec![Style::none(), Style::from_name("warning")]);
+//! ```
+
+use crate::theme::Style;
+use crate::utils::markup::StyledString;
+
+/// Parses the given markup text.
+///
+/// See the [module documentation](self) for the syntax.
+pub fn parse(input: &str) -> StyledString {
+    let mut out = StyledString::new();
+
+    // Names of the opened tags, innermost last.
+    let mut tags: Vec<&str> = Vec::new();
+    // Text waiting to be appended with the current style.
+    let mut text = String::new();
+
+    let mut rest = input;
+    while let Some(i) = rest.find(['<', '\\']) {
+        text.push_str(&rest[..i]);
+        rest = &rest[i..];
+
+        if rest.starts_with('\\') {
+            match rest[1..].chars().next() {
+                Some(c @ ('<' | '\\')) => {
+                    text.push(c);
+                    rest = &rest[2..];
+                }
+                _ => {
+                    text.push('\\');
+                    rest = &rest[1..];
+                }
+            }
+            continue;
+        }
+
+        match Tag::parse(rest) {
+            Some((tag, length)) => {
+                flush(&mut out, &mut text, &tags);
+                match tag {
+                    Tag::Open(name) => tags.push(name),
+                    Tag::Close(None) => {
+                        tags.pop();
+                    }
+                    Tag::Close(Some(name)) => {
+                        if let Some(i) = tags.iter().rposition(|&t| t == name)
+                        {
+                            tags.truncate(i);
+                        }
+                    }
+                }
+                rest = &rest[length..];
+            }
+            None => {
+                text.push('<');
+                rest = &rest[1..];
+            }
+        }
+    }
+    text.push_str(rest);
+    flush(&mut out, &mut text, &tags);
+
+    out
+}
+
+// Appends the pending text to `out`, styled by the opened tags.
+fn flush(out: &mut StyledString, text: &mut String, tags: &[&str]) {
+    if text.is_empty() {
+        return;
+    }
+
+    let style = tags
+        .iter()
+        .map(|name| Style::from_name(name))
+        .fold(Style::none(), Style::combine);
+    out.append_styled(std::mem::take(text), style);
+}
+
+/// A markup tag.
+enum Tag<'a> {
+    Open(&'a str),
+    // `None` for `</>`.
+    Close(Option<&'a str>),
+}
+
+impl<'a> Tag<'a> {
+    // Parses a tag at the start of `input`, and returns its length.
+    fn parse(input: &'a str) -> Option<(Self, usize)> {
+        let end = input.find('>')?;
+        let content = &input[1..end];
+
+        let (name, closing) = match content.strip_prefix('/') {
+            Some(name) => (name, true),
+            None => (content, false),
+        };
+
+        let valid = name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'));
+        if !valid {
+            return None;
+        }
+
+        let tag = match (closing, name.is_empty()) {
+            (false, true) => return None,
+            (false, false) => Tag::Open(name),
+            (true, true) => Tag::Close(None),
+            (true, false) => Tag::Close(Some(name)),
+        };
+
+        Some((tag, end + 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Returns the text of each span, with its style.
+    fn spans(text: &StyledString) -> Vec<(&str, Style)> {
+        text.spans()
+            .map(|span| (span.content, *span.attr))
+            .collect()
+    }
+
+    #[test]
+    fn nested_tags() {
+        let text = parse("<a>x <b>y</b> z</a><b>w</>");
+        let a = Style::from_name("a");
+        let b = Style::from_name("b");
+        assert_eq!(
+            spans(&text),
+            vec![("x ", a), ("y", a.combine(b)), (" z", a), ("w", b)]
+        );
+
+        // Closing an outer tag closes the inner one too.
+        let text = parse("<a><b>x</a>y");
+        assert_eq!(
+            spans(&text),
+            vec![("x", a.combine(b)), ("y", Style::none())]
+        );
+    }
+
+    #[test]
+    fn literal_text() {
+        let text = parse(r"a < b, \<a> \\ <a b>");
+        assert_eq!(spans(&text), vec![(r"a < b, <a> \ <a b>", Style::none())]);
+    }
+}
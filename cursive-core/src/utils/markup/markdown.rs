@@ -10,8 +10,8 @@ use crate::theme::{Effect, Style};
 use crate::utils::markup::{StyledIndexedSpan, StyledString};
 use crate::utils::span::IndexedCow;
 
+use crate::utils::width::TextWidth;
 use pulldown_cmark::{self, CowStr, Event, Tag};
-use unicode_width::UnicodeWidthStr;
 
 /// Parses the given string as markdown text.
 pub fn parse<S>(input: S) -> StyledString
@@ -122,6 +122,7 @@ impl<'a> Iterator for Parser<'a> {
                         content: IndexedCow::from_cow(text, self.input),
                         attr: Style::merge(&self.stack),
                         width,
+                        attrs: None,
                     });
                 }
                 Event::TaskListMarker(checked) => {
@@ -0,0 +1,421 @@
+//! Render a small subset of markdown.
+//!
+//! Needs the `markdown` feature to be enabled.
+//!
+//! Unlike the [`markdown`](super::markdown) module, this uses the theme
+//! palette, and only supports what is usually found in help texts:
+//!
+//! * `*emphasis*` or `_emphasis_`, shown in italic.
+//! * `**strong**` or `__strong__`, shown in bold. Emphasis can be nested,
+//!   like `**bold _and italic_**` or `***both***`.
+//! * `` `code` `` spans, shown with the secondary palette color. Their
+//!   content is not parsed.
+//! * Headings, from `# Title` to `###### Title`, shown in bold with the
+//!   primary title color.
+//! * Bullet lists (`-`, `*` or `+`) and ordered lists (`1.`), nested with
+//!   two spaces of indentation.
+//! * Horizontal rules, like `---`, `***` or `___`.
+//! * Backslash escapes, like `\*`, for any ASCII punctuation.
+//!
+//! Lines in a paragraph are joined, and blank lines are kept. Anything else,
+//! like links or code blocks, is shown as-is.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursive_core::utils::markup::markdown_lite;
+//!
+//! let text = markdown_lite::parse("# Help\n\nPress `q` to **quit**.");
+//! assert_eq!(text.source(), "Help\n\nPress q to quit.");
+//! ```
+#![cfg(feature = "markdown")]
+#![cfg_attr(feature = "doc-cfg", doc(cfg(feature = "markdown")))]
+
+use crate::theme::{Effect, PaletteColor, Style};
+use crate::utils::markup::StyledString;
+
+// Width of horizontal rules.
+const RULE_WIDTH: usize = 20;
+
+/// Parses the given markdown text.
+///
+/// See the [module documentation](self) for the supported subset.
+pub fn parse(input: &str) -> StyledString {
+    let mut out = StyledString::new();
+
+    // `true` if the last block can be continued by a paragraph line.
+    let mut joinable = false;
+    // `true` if we saw a blank line since the last block.
+    let mut blank = false;
+
+    for line in input.lines() {
+        let content = line.trim();
+        if content.is_empty() {
+            blank = !out.is_empty();
+            joinable = false;
+            continue;
+        }
+
+        let block = Block::parse(line);
+
+        if let Block::Paragraph(text) = block {
+            if joinable {
+                out.append_plain(" ");
+                parse_inline(text, &mut vec![Style::none()], &mut out);
+                continue;
+            }
+        }
+
+        if !out.is_empty() {
+            out.append_plain(if blank { "\n\n" } else { "\n" });
+        }
+        blank = false;
+        joinable = block.is_joinable();
+
+        block.render(&mut out);
+    }
+
+    out
+}
+
+/// A single line of markdown.
+enum Block<'a> {
+    Heading(&'a str),
+    Rule,
+    Bullet {
+        level: usize,
+        text: &'a str,
+    },
+    Ordered {
+        level: usize,
+        number: &'a str,
+        text: &'a str,
+    },
+    Paragraph(&'a str),
+}
+
+impl<'a> Block<'a> {
+    fn parse(line: &'a str) -> Self {
+        let content = line.trim_start();
+        let indent: usize = line[..line.len() - content.len()]
+            .chars()
+            .map(|c| if c == '\t' { 4 } else { 1 })
+            .sum();
+        let level = indent / 2;
+
+        let hashes = content.chars().take_while(|&c| c == '#').count();
+        if (1..=6).contains(&hashes) {
+            let rest = &content[hashes..];
+            if rest.is_empty() || rest.starts_with(' ') {
+                return Block::Heading(rest.trim());
+            }
+        }
+
+        if is_rule(content) {
+            return Block::Rule;
+        }
+
+        for bullet in &["- ", "* ", "+ "] {
+            if let Some(text) = content.strip_prefix(bullet) {
+                return Block::Bullet {
+                    level,
+                    text: text.trim_start(),
+                };
+            }
+        }
+
+        let digits = content.chars().take_while(char::is_ascii_digit).count();
+        if digits > 0 {
+            let rest = &content[digits..];
+            if rest.starts_with(". ") || rest.starts_with(") ") {
+                return Block::Ordered {
+                    level,
+                    number: &content[..digits],
+                    text: rest[2..].trim_start(),
+                };
+            }
+        }
+
+        Block::Paragraph(content.trim_end())
+    }
+
+    // Paragraphs and list items continue on the next line.
+    fn is_joinable(&self) -> bool {
+        !matches!(self, Block::Heading(_) | Block::Rule)
+    }
+
+    fn render(self, out: &mut StyledString) {
+        match self {
+            Block::Heading(text) => {
+                let heading = Style::from(Effect::Bold)
+                    .combine(PaletteColor::TitlePrimary);
+                parse_inline(text, &mut vec![heading], out);
+            }
+            Block::Rule => {
+                out.append_styled(
+                    "─".repeat(RULE_WIDTH),
+                    PaletteColor::Secondary,
+                );
+            }
+            Block::Bullet { level, text } => {
+                out.append_plain(format!("{}• ", "  ".repeat(level)));
+                parse_inline(text, &mut vec![Style::none()], out);
+            }
+            Block::Ordered {
+                level,
+                number,
+                text,
+            } => {
+                out.append_plain(format!(
+                    "{}{}. ",
+                    "  ".repeat(level),
+                    number
+                ));
+                parse_inline(text, &mut vec![Style::none()], out);
+            }
+            Block::Paragraph(text) => {
+                parse_inline(text, &mut vec![Style::none()], out);
+            }
+        }
+    }
+}
+
+// Returns `true` for lines like `---`, `* * *` or `___`.
+fn is_rule(content: &str) -> bool {
+    let mut chars = content.chars().filter(|c| !c.is_whitespace());
+    let first = match chars.next() {
+        Some(c @ '-') | Some(c @ '*') | Some(c @ '_') => c,
+        _ => return false,
+    };
+    let mut count = 1;
+    for c in chars {
+        if c != first {
+            return false;
+        }
+        count += 1;
+    }
+    count >= 3
+}
+
+// Appends `text` to `out`, with the inline styles applied.
+//
+// `stack` contains the styles of the enclosing spans.
+fn parse_inline(text: &str, stack: &mut Vec<Style>, out: &mut StyledString) {
+    let chars: Vec<char> = text.chars().collect();
+    parse_chars(&chars, stack, out);
+}
+
+fn parse_chars(
+    chars: &[char],
+    stack: &mut Vec<Style>,
+    out: &mut StyledString,
+) {
+    let mut buffer = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c == '\\' {
+            match chars.get(i + 1) {
+                Some(&next) if next.is_ascii_punctuation() => {
+                    buffer.push(next);
+                    i += 2;
+                }
+                _ => {
+                    buffer.push(c);
+                    i += 1;
+                }
+            }
+            continue;
+        }
+
+        let run = run_length(chars, i);
+
+        if c == '`' {
+            if let Some(end) = find_code_end(chars, i + run, run) {
+                flush(&mut buffer, stack, out);
+                let code = code_content(&chars[i + run..end]);
+                stack.push(Style::from(PaletteColor::Secondary));
+                out.append_styled(code, Style::merge(stack));
+                stack.pop();
+                i = end + run;
+            } else {
+                buffer.extend(&chars[i..i + run]);
+                i += run;
+            }
+            continue;
+        }
+
+        if (c == '*' || c == '_') && run <= 3 && can_open(chars, i, run) {
+            if let Some(end) = find_closing(chars, i + run, c, run) {
+                flush(&mut buffer, stack, out);
+                stack.push(match run {
+                    1 => Style::from(Effect::Italic),
+                    2 => Style::from(Effect::Bold),
+                    _ => Style::from(Effect::Bold).combine(Effect::Italic),
+                });
+                parse_chars(&chars[i + run..end], stack, out);
+                stack.pop();
+                i = end + run;
+                continue;
+            }
+        }
+
+        if c == '*' || c == '_' {
+            // Not a delimiter: keep the whole run as text.
+            buffer.extend(&chars[i..i + run]);
+            i += run;
+        } else {
+            buffer.push(c);
+            i += 1;
+        }
+    }
+
+    flush(&mut buffer, stack, out);
+}
+
+// Appends the buffered text, if any.
+fn flush(buffer: &mut String, stack: &[Style], out: &mut StyledString) {
+    if !buffer.is_empty() {
+        out.append_styled(std::mem::take(buffer), Style::merge(stack));
+    }
+}
+
+// Number of identical characters starting at `i`.
+fn run_length(chars: &[char], i: usize) -> usize {
+    chars[i..].iter().take_while(|&&c| c == chars[i]).count()
+}
+
+// Returns `true` if the delimiter run at `i` can start an emphasis.
+fn can_open(chars: &[char], i: usize, run: usize) -> bool {
+    let next_is_text = match chars.get(i + run) {
+        Some(next) => !next.is_whitespace(),
+        None => false,
+    };
+    // Underscores inside words are not delimiters, like in `snake_case`.
+    let in_word = chars[i] == '_' && i > 0 && chars[i - 1].is_alphanumeric();
+    next_is_text && !in_word
+}
+
+// Finds the delimiter run closing an emphasis opened before `start`.
+fn find_closing(
+    chars: &[char],
+    start: usize,
+    delimiter: char,
+    length: usize,
+) -> Option<usize> {
+    let mut i = start;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\\' {
+            i += 2;
+            continue;
+        }
+
+        let run = run_length(chars, i);
+        if c == '`' {
+            // Delimiters in code spans don't count.
+            i = find_code_end(chars, i + run, run).unwrap_or(i) + run;
+            continue;
+        }
+
+        if c == delimiter && run == length && i > start {
+            let after_text = !chars[i - 1].is_whitespace();
+            let in_word = delimiter == '_'
+                && matches!(chars.get(i + run), Some(c) if c.is_alphanumeric());
+            if after_text && !in_word {
+                return Some(i);
+            }
+        }
+        i += run;
+    }
+    None
+}
+
+// Finds the run of exactly `length` backticks closing a code span.
+fn find_code_end(
+    chars: &[char],
+    start: usize,
+    length: usize,
+) -> Option<usize> {
+    let mut i = start;
+    while i < chars.len() {
+        let run = run_length(chars, i);
+        if chars[i] == '`' && run == length {
+            return Some(i);
+        }
+        i += run;
+    }
+    None
+}
+
+// Content of a code span, without the padding spaces.
+fn code_content(chars: &[char]) -> String {
+    let code: String = chars.iter().collect();
+    let padded =
+        code.len() > 2 && code.starts_with(' ') && code.ends_with(' ');
+    if padded {
+        code[1..code.len() - 1].to_string()
+    } else {
+        code
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Returns the text of each span, with its style.
+    fn spans(text: &StyledString) -> Vec<(&str, Style)> {
+        text.spans()
+            .map(|span| (span.content, *span.attr))
+            .collect()
+    }
+
+    #[test]
+    fn nested_emphasis() {
+        let text = parse("*a **b** c*");
+        let italic = Style::from(Effect::Italic);
+        assert_eq!(
+            spans(&text),
+            vec![
+                ("a ", italic),
+                ("b", italic.combine(Effect::Bold)),
+                (" c", italic),
+            ]
+        );
+
+        let text = parse("***both*** snake_case_name");
+        assert_eq!(
+            spans(&text),
+            vec![
+                ("both", Style::from(Effect::Bold).combine(Effect::Italic)),
+                (" snake_case_name", Style::none()),
+            ]
+        );
+    }
+
+    #[test]
+    fn escapes_and_code() {
+        let text = parse(r"\*not\* `*code*` 2 * 3");
+        let code = Style::from(PaletteColor::Secondary);
+        assert_eq!(
+            spans(&text),
+            vec![
+                ("*not* ", Style::none()),
+                ("*code*", code),
+                (" 2 * 3", Style::none()),
+            ]
+        );
+    }
+
+    #[test]
+    fn blocks() {
+        let text =
+            parse("# Title\nSome\ntext\n\n- one\n  - nested\n1. first\n\n---");
+        assert_eq!(
+            text.source(),
+            "Title\nSome text\n\n• one\n  • nested\n1. first\n\n────────────────────"
+        );
+    }
+}
@@ -1,8 +1,10 @@
 //! Parse various text markup formats.
 //!
-//! Each module is optional and relies on a feature.
+//! Most modules are optional and rely on a feature.
 
+pub mod cursup;
 pub mod markdown;
+pub mod markdown_lite;
 
 use crate::theme::Style;
 use crate::utils::span::{IndexedSpan, Span, SpannedString};
@@ -0,0 +1,31 @@
+use crate::view::View;
+use crate::Vec2;
+
+/// Computes the size `view` requires within `constraint`.
+///
+/// This runs a layout pass, like when the view is shown: the view is asked
+/// for its required size, then laid out in this size (limited by the
+/// constraint). The view is then ready to be drawn or queried, for example
+/// with `important_area`.
+///
+/// The returned size may exceed the constraint, if the view cannot shrink
+/// enough.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::utils::measure;
+/// use cursive_core::views::TextView;
+/// use cursive_core::Vec2;
+///
+/// let mut view = TextView::new("Hello world");
+/// assert_eq!(measure(&mut view, Vec2::new(20, 10)), Vec2::new(11, 1));
+///
+/// // Text is wrapped when there is not enough room.
+/// assert_eq!(measure(&mut view, Vec2::new(5, 10)), Vec2::new(5, 2));
+/// ```
+pub fn measure<V: View + ?Sized>(view: &mut V, constraint: Vec2) -> Vec2 {
+    let size = view.required_size(constraint);
+    view.layout(size.or_min(constraint));
+    size
+}
@@ -1,12 +1,18 @@
 //! Toolbox to make text layout easier.
 
+pub mod bidi;
 mod counter;
 #[macro_use]
 mod immutify;
 pub mod lines;
 pub mod markup;
+mod measure;
 mod reader;
 pub mod span;
+pub mod width;
+mod writer;
 
-pub use self::counter::Counter;
+pub use self::counter::{Counter, FloatCounter};
+pub use self::measure::measure;
 pub use self::reader::ProgressReader;
+pub use self::writer::ProgressWriter;
@@ -1,11 +1,129 @@
 use crate::utils::Counter;
+use std::fmt;
 use std::io::{self, Read};
+use std::sync::Arc;
+
+// Default number of bytes between two counter updates.
+//
+// The counter is updated on every read or write, unless asked otherwise.
+const DEFAULT_BATCH_SIZE: usize = 1;
+
+/// Callback run when a transfer is complete, with the total number of bytes.
+type DoneCb = dyn Fn(usize) + Send + Sync;
+
+/// Batches the updates to a progress counter.
+///
+/// Shared by `ProgressReader` and `ProgressWriter`.
+///
+/// Pending bytes are added to the counter when the ticker is dropped.
+pub(super) struct Ticker {
+    counter: Counter,
+    batch_size: usize,
+    // Bytes not yet added to the counter.
+    pending: usize,
+    // Bytes transferred so far.
+    total: usize,
+    on_eof: Option<Arc<DoneCb>>,
+    done: bool,
+}
+
+impl Ticker {
+    pub(super) fn new(counter: Counter) -> Self {
+        Ticker {
+            counter,
+            batch_size: DEFAULT_BATCH_SIZE,
+            pending: 0,
+            total: 0,
+            on_eof: None,
+            done: false,
+        }
+    }
+
+    pub(super) fn set_batch_size(&mut self, batch_size: usize) {
+        self.batch_size = batch_size;
+    }
+
+    pub(super) fn set_on_eof<F>(&mut self, on_eof: F)
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        self.on_eof = Some(Arc::new(on_eof));
+    }
+
+    pub(super) fn add(&mut self, n: usize) {
+        self.pending += n;
+        self.total += n;
+        if self.pending >= self.batch_size {
+            self.flush();
+        }
+    }
+
+    /// Adds any pending bytes to the counter.
+    pub(super) fn flush(&mut self) {
+        if self.pending > 0 {
+            self.counter.tick(self.pending);
+            self.pending = 0;
+        }
+    }
+
+    /// Flushes the counter and runs the `on_eof` callback, only once.
+    pub(super) fn finish(&mut self) {
+        self.flush();
+        if !self.done {
+            self.done = true;
+            if let Some(ref on_eof) = self.on_eof {
+                on_eof(self.total);
+            }
+        }
+    }
+
+    pub(super) fn into_counter(mut self) -> Counter {
+        self.flush();
+        self.counter.clone()
+    }
+}
+
+impl Clone for Ticker {
+    fn clone(&self) -> Self {
+        // Pending bytes are only added to the counter by the original.
+        Ticker {
+            counter: self.counter.clone(),
+            batch_size: self.batch_size,
+            pending: 0,
+            total: self.total,
+            on_eof: self.on_eof.clone(),
+            done: self.done,
+        }
+    }
+}
+
+impl Drop for Ticker {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+impl fmt::Debug for Ticker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ticker")
+            .field("counter", &self.counter)
+            .field("batch_size", &self.batch_size)
+            .field("pending", &self.pending)
+            .field("total", &self.total)
+            .field("done", &self.done)
+            .finish()
+    }
+}
 
 /// Wrapper around a `Read` that reports the progress made.
 ///
 /// Used to monitor a file downloading or other slow IO task
 /// in a progress bar.
 ///
+/// The counter is updated on every read by default. To avoid updating it on
+/// every small read, see [`ProgressReader::set_batch_size`]. Bytes still
+/// pending are added to the counter when the reader is dropped.
+///
 /// # Examples
 ///
 /// ```rust,no_run
@@ -33,7 +151,7 @@ use std::io::{self, Read};
 #[derive(Clone, Debug)]
 pub struct ProgressReader<R: Read> {
     reader: R,
-    counter: Counter,
+    ticker: Ticker,
 }
 
 impl<R: Read> ProgressReader<R> {
@@ -44,19 +162,153 @@ impl<R: Read> ProgressReader<R> {
     /// You should make sure the progress bar knows how
     /// many bytes should be received.
     pub fn new(counter: Counter, reader: R) -> Self {
-        ProgressReader { reader, counter }
+        ProgressReader {
+            reader,
+            ticker: Ticker::new(counter),
+        }
+    }
+
+    /// Sets the number of bytes to read before updating the counter.
+    ///
+    /// Defaults to `1`, which updates the counter on every read.
+    pub fn set_batch_size(&mut self, batch_size: usize) {
+        self.ticker.set_batch_size(batch_size);
+    }
+
+    /// Sets the number of bytes to read before updating the counter.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.set_batch_size(batch_size);
+        self
+    }
+
+    /// Sets a callback to run when the end of the reader is reached.
+    ///
+    /// The callback receives the total number of bytes read. It runs once,
+    /// after the counter was updated with the last bytes.
+    ///
+    /// This is useful when the total size was not known in advance, for
+    /// example to set the maximum of a `ProgressBar` to the final value.
+    pub fn set_on_eof<F>(&mut self, on_eof: F)
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        self.ticker.set_on_eof(on_eof);
+    }
+
+    /// Sets a callback to run when the end of the reader is reached.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use cursive_core::utils::{Counter, ProgressReader};
+    /// use cursive_core::views::ProgressBar;
+    ///
+    /// let mut siv = cursive_core::Cursive::new();
+    /// let cb_sink = siv.cb_sink().clone();
+    ///
+    /// let file = std::fs::File::open("large_file").unwrap();
+    /// let reader = ProgressReader::new(Counter::new(0), file).on_eof(
+    ///     move |total| {
+    ///         cb_sink
+    ///             .send(Box::new(move |s| {
+    ///                 s.call_on_name("progress", |bar: &mut ProgressBar| {
+    ///                     bar.set_max(total)
+    ///                 });
+    ///             }))
+    ///             .unwrap();
+    ///     },
+    /// );
+    /// ```
+    #[must_use]
+    pub fn on_eof<F>(mut self, on_eof: F) -> Self
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        self.set_on_eof(on_eof);
+        self
     }
 
     /// Unwraps this `ProgressReader`, returning the reader and counter.
+    ///
+    /// Any pending progress is added to the counter first.
     pub fn deconstruct(self) -> (R, Counter) {
-        (self.reader, self.counter)
+        (self.reader, self.ticker.into_counter())
     }
 }
 
 impl<R: Read> Read for ProgressReader<R> {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         let result = self.reader.read(buf)?;
-        self.counter.tick(result);
+        if result == 0 && !buf.is_empty() {
+            self.ticker.finish();
+        } else {
+            self.ticker.add(result);
+        }
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn flush_on_drop() {
+        let counter = Counter::new(0);
+        let mut reader =
+            ProgressReader::new(counter.clone(), io::Cursor::new([0u8; 10]))
+                .batch_size(100);
+
+        let mut buf = [0u8; 3];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(counter.get(), 0);
+
+        // Clones don't count the pending bytes again.
+        let clone = reader.clone();
+        drop(reader);
+        assert_eq!(counter.get(), 3);
+        drop(clone);
+        assert_eq!(counter.get(), 3);
+
+        // By default, every read is counted right away.
+        let mut reader =
+            ProgressReader::new(counter.clone(), io::Cursor::new([0u8; 10]));
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(counter.get(), 6);
+    }
+
+    #[test]
+    fn batches_and_eof() {
+        let counter = Counter::new(0);
+        let eof = Arc::new(AtomicUsize::new(0));
+
+        let eof_clone = Arc::clone(&eof);
+        let mut reader =
+            ProgressReader::new(counter.clone(), io::Cursor::new([0u8; 10]))
+                .batch_size(4)
+                .on_eof(move |total| {
+                    eof_clone.fetch_add(total, Ordering::Relaxed);
+                });
+
+        let mut buf = [0u8; 3];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(counter.get(), 0);
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(counter.get(), 6);
+
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest).unwrap();
+        assert_eq!(counter.get(), 10);
+        assert_eq!(eof.load(Ordering::Relaxed), 10);
+
+        // The callback only runs once.
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+        assert_eq!(eof.load(Ordering::Relaxed), 10);
+    }
+}
@@ -2,9 +2,16 @@
 //!
 //! This module defines various structs describing a span of text from a
 //! larger string.
+use crate::utils::lines::simple::TabStops;
+use crate::utils::width::TextWidth;
+use std::any::Any;
 use std::borrow::Cow;
+use std::cmp::{max, min};
+use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::iter::FromIterator;
-use unicode_width::UnicodeWidthStr;
+use std::sync::Arc;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// A string with associated spans.
 ///
@@ -244,6 +251,287 @@ impl<T> SpannedString<T> {
         self.trim_start();
     }
 
+    /// Removes leading and trailing whitespace from the text.
+    ///
+    /// Unlike [`trim`](Self::trim), this changes the content of the spans.
+    /// The source is compacted.
+    pub fn trim_whitespace(&mut self)
+    where
+        T: Clone,
+    {
+        let (start, end) = {
+            let text: String = self.spans().map(|span| span.content).collect();
+            let trimmed = text.trim_start();
+            let start = text.len() - trimmed.len();
+            (start, start + trimmed.trim_end().len())
+        };
+        *self = self.slice(start..end);
+    }
+
+    /// Returns a copy of the given byte range of the text.
+    ///
+    /// The range indexes the concatenated content of the spans. Spans
+    /// crossing the range are cut, and keep their attribute.
+    ///
+    /// # Panics
+    ///
+    /// If the range is out of bounds, or does not fall on char boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::utils::markup::StyledString;
+    /// use cursive_core::theme::Effect;
+    ///
+    /// let mut text = StyledString::plain("Hello ");
+    /// text.append_styled("world", Effect::Bold);
+    ///
+    /// let slice = text.slice(3..8);
+    /// assert_eq!(slice.source(), "lo wo");
+    /// assert_eq!(slice.spans().count(), 2);
+    /// ```
+    #[must_use]
+    pub fn slice(&self, range: std::ops::Range<usize>) -> Self
+    where
+        T: Clone,
+    {
+        self.check_range(&range);
+
+        let mut result = Self::new();
+        for ((offset, span), raw) in self.spans_with_offsets().zip(&self.spans)
+        {
+            let end = offset + span.content.len();
+            let start = max(offset, range.start);
+            let stop = min(end, range.end);
+            if start < stop {
+                result.append(Self::single_span_like(
+                    &span.content[start - offset..stop - offset],
+                    raw,
+                ));
+            }
+        }
+        result
+    }
+
+    /// Splits the text in two at the given byte index.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds, or is not on a char boundary.
+    #[must_use]
+    pub fn split_at(&self, index: usize) -> (Self, Self)
+    where
+        T: Clone,
+    {
+        let len = self.content_len();
+        self.check_range(&(index..len));
+        (self.slice(0..index), self.slice(index..len))
+    }
+
+    /// Replaces the given byte range of the text with `text`.
+    ///
+    /// The new text uses the attribute of the span at the start of the
+    /// range, or of the last span when the range is at the end. Other spans
+    /// keep their attribute. The source is compacted.
+    ///
+    /// # Panics
+    ///
+    /// If the range is out of bounds, or does not fall on char boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::utils::markup::StyledString;
+    /// use cursive_core::theme::Effect;
+    ///
+    /// let mut text = StyledString::plain("Hello ");
+    /// text.append_styled("world", Effect::Bold);
+    ///
+    /// text.replace_range(6..11, "there");
+    /// assert_eq!(text.source(), "Hello there");
+    /// assert_eq!(text.spans().last().unwrap().content, "there");
+    /// ```
+    pub fn replace_range(&mut self, range: std::ops::Range<usize>, text: &str)
+    where
+        T: Clone + Default,
+    {
+        let len = self.content_len();
+        self.check_range(&range);
+
+        let attr = self
+            .spans_with_offsets()
+            .find(|(offset, span)| range.start < offset + span.content.len())
+            .or_else(|| self.spans_with_offsets().last())
+            .map(|(_, span)| span.attr.clone())
+            .unwrap_or_default();
+
+        let mut result = self.slice(0..range.start);
+        if !text.is_empty() {
+            result.append(Self::single_span(text, attr));
+        }
+        result.append(self.slice(range.end..len));
+        *self = result;
+    }
+
+    /// Returns a copy of this text cut to fit in `max_width` cells.
+    ///
+    /// If the text is too wide, it is cut between grapheme clusters, and
+    /// `ellipsis` is appended with the attribute of the first span removed.
+    /// Retained spans keep their attribute. If `ellipsis` itself is wider
+    /// than `max_width`, the text is cut without it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::utils::markup::StyledString;
+    /// use cursive_core::theme::Effect;
+    ///
+    /// let mut text = StyledString::plain("Hello ");
+    /// text.append_styled("world", Effect::Bold);
+    ///
+    /// let short = text.truncated(8, "…");
+    /// assert_eq!(short.source(), "Hello w…");
+    /// assert_eq!(short.spans().nth(1).unwrap().content, "w");
+    /// assert_eq!(short.spans().last().unwrap().content, "…");
+    ///
+    /// // Wide characters are never cut in half.
+    /// let text = StyledString::plain("日本語");
+    /// assert_eq!(text.truncated(4, "…").source(), "日…");
+    /// ```
+    #[must_use]
+    pub fn truncated(&self, max_width: usize, ellipsis: &str) -> Self
+    where
+        T: Clone,
+    {
+        if self.width() <= max_width {
+            return self.clone();
+        }
+
+        let ellipsis_width = ellipsis.width();
+        let (budget, ellipsis) = match max_width.checked_sub(ellipsis_width) {
+            Some(budget) => (budget, ellipsis),
+            None => (max_width, ""),
+        };
+
+        // Find the first grapheme that doesn't fit.
+        let mut width = 0;
+        let mut cut = None;
+        'spans: for ((offset, span), raw) in
+            self.spans_with_offsets().zip(&self.spans)
+        {
+            for (i, grapheme) in span.content.grapheme_indices(true) {
+                width += grapheme.width();
+                if width > budget {
+                    cut = Some((offset + i, raw));
+                    break 'spans;
+                }
+            }
+        }
+
+        // Every grapheme fits: the span widths were larger than the text.
+        let (cut, raw) = match cut {
+            Some(cut) => cut,
+            None => return self.clone(),
+        };
+        let mut result = self.slice(0..cut);
+        if !ellipsis.is_empty() {
+            result.append(Self::single_span_like(ellipsis, raw));
+        }
+        result
+    }
+
+    // Iterates on the resolved spans, with their offset in the text.
+    fn spans_with_offsets(
+        &self,
+    ) -> impl Iterator<Item = (usize, Span<'_, T>)> {
+        self.spans().scan(0, |offset, span| {
+            let start = *offset;
+            *offset += span.content.len();
+            Some((start, span))
+        })
+    }
+
+    // Length of the text, as the content of all spans.
+    fn content_len(&self) -> usize {
+        self.spans().map(|span| span.content.len()).sum()
+    }
+
+    // Panics if `range` can't be used to slice the text.
+    fn check_range(&self, range: &std::ops::Range<usize>) {
+        let len = self.content_len();
+        assert!(
+            range.start <= range.end && range.end <= len,
+            "range {:?} is out of bounds for a text of length {}",
+            range,
+            len
+        );
+
+        for index in [range.start, range.end] {
+            let on_boundary =
+                self.spans_with_offsets().all(|(offset, span)| {
+                    index < offset
+                        || index > offset + span.content.len()
+                        || span.content.is_char_boundary(index - offset)
+                });
+            assert!(
+                on_boundary,
+                "byte index {} is not a char boundary",
+                index
+            );
+        }
+    }
+
+    // Returns a single span with the attribute and attrs of `span`.
+    fn single_span_like<S>(source: S, span: &IndexedSpan<T>) -> Self
+    where
+        S: Into<String>,
+        T: Clone,
+    {
+        let mut result = Self::single_span(source, span.attr.clone());
+        result.spans[0].attrs = span.attrs.clone();
+        result
+    }
+
+    /// Attaches application data to every span of this string.
+    ///
+    /// The data is kept when this string is appended to another one, and
+    /// can be retrieved from a [`TextView`] with
+    /// [`set_on_span_click`](crate::views::TextView::set_on_span_click).
+    ///
+    /// [`TextView`]: crate::views::TextView
+    pub fn set_attrs(&mut self, attrs: SpanAttrs) {
+        for span in &mut self.spans {
+            span.attrs = Some(attrs.clone());
+        }
+    }
+
+    /// Attaches application data to every span of this string.
+    ///
+    /// Chainable variant of [`set_attrs`](Self::set_attrs).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::utils::markup::StyledString;
+    /// use cursive_core::utils::span::SpanAttrs;
+    ///
+    /// struct Url(&'static str);
+    ///
+    /// let mut text = StyledString::plain("See ");
+    /// text.append(
+    ///     StyledString::plain("the docs")
+    ///         .with_attrs(SpanAttrs::new().with_value(Url("https://docs.rs"))),
+    /// );
+    ///
+    /// let attrs = text.spans_raw()[1].attrs.as_ref().unwrap();
+    /// assert_eq!(attrs.get::<Url>().unwrap().0, "https://docs.rs");
+    /// ```
+    #[must_use]
+    pub fn with_attrs(mut self, attrs: SpanAttrs) -> Self {
+        self.set_attrs(attrs);
+        self
+    }
+
     /// Returns a new SpannedString with a single span.
     pub fn single_span<S>(source: S, attr: T) -> Self
     where
@@ -256,6 +544,42 @@ impl<T> SpannedString<T> {
         Self::with_spans(source, spans)
     }
 
+    /// Returns a copy of this string with tabs replaced by spaces.
+    ///
+    /// Tab stops are placed every `tab_width` columns, starting from the
+    /// beginning of each line. If `tab_width` is `0`, tabs are removed.
+    #[must_use]
+    pub fn expand_tabs(&self, tab_width: usize) -> Self
+    where
+        T: Clone,
+    {
+        self.expand_tab_stops(&TabStops::Every(tab_width))
+    }
+
+    /// Returns a copy of this string with tabs replaced by spaces.
+    ///
+    /// Each tab is expanded up to the next of the given tab stops.
+    /// `TabStops::Auto` is computed from the entire string.
+    #[must_use]
+    pub fn expand_tab_stops(&self, stops: &TabStops) -> Self
+    where
+        T: Clone,
+    {
+        let stops = stops.resolve(&self.source);
+        let mut column = 0;
+        self.spans
+            .iter()
+            .map(|span| {
+                let content = crate::utils::lines::simple::expand_tabs_from(
+                    span.content.resolve(&self.source),
+                    &stops,
+                    &mut column,
+                );
+                Self::single_span_like(content.into_owned(), span)
+            })
+            .collect()
+    }
+
     /// Appends the given `StyledString` to `self`.
     pub fn append<S>(&mut self, other: S)
     where
@@ -390,6 +714,95 @@ pub struct IndexedSpan<T> {
 
     /// Width of the text for this span.
     pub width: usize,
+
+    /// Application data attached to this span, if any.
+    pub attrs: Option<SpanAttrs>,
+}
+
+/// Application data attached to a span, like an URL or an ID.
+///
+/// This is a small map holding at most one value of each type.
+///
+/// Values are shared when the span is cloned. Two `SpanAttrs` are equal if
+/// they share the same values.
+#[derive(Clone, Default)]
+pub struct SpanAttrs {
+    values: Vec<Arc<dyn Any + Send + Sync>>,
+}
+
+impl SpanAttrs {
+    /// Returns an empty map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a value, replacing any previous value of the same type.
+    pub fn insert<V>(&mut self, value: V)
+    where
+        V: Any + Send + Sync,
+    {
+        let value = Arc::new(value);
+        match self.values.iter().position(|v| v.is::<V>()) {
+            Some(i) => self.values[i] = value,
+            None => self.values.push(value),
+        }
+    }
+
+    /// Inserts a value, replacing any previous value of the same type.
+    ///
+    /// Chainable variant of [`insert`](Self::insert).
+    #[must_use]
+    pub fn with_value<V>(mut self, value: V) -> Self
+    where
+        V: Any + Send + Sync,
+    {
+        self.insert(value);
+        self
+    }
+
+    /// Returns the value of type `V`, if any.
+    pub fn get<V: Any>(&self) -> Option<&V> {
+        self.values.iter().find_map(|v| v.downcast_ref())
+    }
+
+    /// Returns the number of values in this map.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if this map is empty.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    // Addresses of the values, used for comparison and hashing.
+    fn addresses(&self) -> impl Iterator<Item = *const u8> + '_ {
+        self.values.iter().map(|v| Arc::as_ptr(v) as *const u8)
+    }
+}
+
+impl PartialEq for SpanAttrs {
+    fn eq(&self, other: &Self) -> bool {
+        self.addresses().eq(other.addresses())
+    }
+}
+
+impl Eq for SpanAttrs {}
+
+impl Hash for SpanAttrs {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for address in self.addresses() {
+            address.hash(state);
+        }
+    }
+}
+
+impl fmt::Debug for SpanAttrs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpanAttrs")
+            .field("len", &self.len())
+            .finish()
+    }
 }
 
 impl<T> AsRef<IndexedCow> for IndexedSpan<T> {
@@ -473,6 +886,7 @@ impl<T> IndexedSpan<T> {
             },
             attr,
             width: content.width(),
+            attrs: None,
         }
     }
 
@@ -483,6 +897,7 @@ impl<T> IndexedSpan<T> {
             content: IndexedCow::Owned(content),
             attr,
             width,
+            attrs: None,
         }
     }
 }
@@ -592,3 +1007,100 @@ impl IndexedCow {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Returns the content of each span, with its attribute.
+    fn spans(text: &SpannedString<u8>) -> Vec<(&str, u8)> {
+        text.spans()
+            .map(|span| (span.content, *span.attr))
+            .collect()
+    }
+
+    fn input() -> SpannedString<u8> {
+        let mut text = SpannedString::single_span("  ab", 1);
+        text.append(SpannedString::single_span("cd  ", 2));
+        text
+    }
+
+    #[test]
+    fn attrs_are_kept() {
+        let attrs = SpanAttrs::new().with_value(42u32);
+        let mut text = SpannedString::single_span("a\tb", 1);
+        text.append(SpannedString::single_span("cd", 2).with_attrs(attrs));
+
+        let attrs_of = |text: &SpannedString<i32>| -> Vec<Option<u32>> {
+            text.spans_raw()
+                .iter()
+                .map(|span| span.attrs.as_ref()?.get::<u32>().copied())
+                .collect()
+        };
+        assert_eq!(attrs_of(&text), vec![None, Some(42)]);
+        assert_eq!(attrs_of(&text.expand_tabs(4)), vec![None, Some(42)]);
+        assert_eq!(attrs_of(&text.slice(2..4)), vec![None, Some(42)]);
+    }
+
+    #[test]
+    fn replace_range() {
+        let mut text = input();
+        text.replace_range(3..5, "XYZ");
+        assert_eq!(spans(&text), vec![("  a", 1), ("XYZ", 1), ("d  ", 2)]);
+
+        // Inserting at the end uses the last attribute.
+        text.replace_range(9..9, "!");
+        assert_eq!(text.source(), "  aXYZd  !");
+        assert_eq!(spans(&text).last(), Some(&("!", 2)));
+    }
+
+    #[test]
+    fn split_and_trim() {
+        let (left, right) = input().split_at(3);
+        assert_eq!(spans(&left), vec![("  a", 1)]);
+        assert_eq!(spans(&right), vec![("b", 1), ("cd  ", 2)]);
+
+        let mut text = input();
+        text.trim_whitespace();
+        assert_eq!(spans(&text), vec![("ab", 1), ("cd", 2)]);
+    }
+
+    #[test]
+    fn truncated() {
+        let text = input();
+        assert_eq!(text.truncated(8, "…"), text);
+        assert_eq!(
+            spans(&text.truncated(5, "…")),
+            vec![("  ab", 1), ("…", 2)]
+        );
+        assert_eq!(
+            spans(&text.truncated(4, "...")),
+            vec![(" ", 1), ("...", 1)]
+        );
+        assert_eq!(spans(&text.truncated(2, "...")), vec![("  ", 1)]);
+
+        // Wide characters and grapheme clusters are kept whole.
+        let text = SpannedString::single_span("日本👨\u{200D}👩語", 0);
+        assert_eq!(text.truncated(5, "…").source(), "日本…");
+        assert_eq!(text.truncated(7, "…").source(), "日本👨\u{200D}👩…");
+        assert_eq!(text.truncated(4, "").source(), "日本");
+
+        // Span widths disagreeing with the content don't cause a cut.
+        let text = SpannedString::with_spans(
+            "ab",
+            vec![IndexedSpan {
+                content: IndexedCow::Borrowed { start: 0, end: 2 },
+                attr: 0,
+                width: 10,
+                attrs: None,
+            }],
+        );
+        assert_eq!(text.truncated(4, "…"), text);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a char boundary")]
+    fn slice_inside_char() {
+        let _ = SpannedString::single_span("é", 0).slice(0..1);
+    }
+}
@@ -0,0 +1,172 @@
+//! Compute the width of text on a terminal.
+//!
+//! This builds on the `unicode-width` crate, but measures each extended
+//! grapheme cluster as a single unit:
+//!
+//! * Emoji sequences, like `👨‍👩‍👧` (joined with zero-width joiners), `👍🏽`
+//!   (with a skin tone modifier), `❤️` (with an emoji presentation selector)
+//!   or flags like `🇫🇷`, are shown as a single wide glyph.
+//! * Other clusters, like a letter followed by combining marks, are as wide
+//!   as the sum of their characters.
+//!
+//! Characters with an ambiguous East Asian width are one cell wide by
+//! default. CJK terminals usually show them two cells wide: use
+//! [`set_ambiguous_width`] to match this.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursive_core::utils::width::TextWidth;
+//!
+//! assert_eq!("abc".width(), 3);
+//! assert_eq!("👨‍👩‍👧".width(), 2);
+//! assert_eq!("e\u{301}".width(), 1);
+//! ```
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthChar;
+
+const ZWJ: char = '\u{200D}';
+const TEXT_PRESENTATION: char = '\u{FE0E}';
+const EMOJI_PRESENTATION: char = '\u{FE0F}';
+
+// `true` if ambiguous characters are shown two cells wide.
+static AMBIGUOUS_WIDE: AtomicBool = AtomicBool::new(false);
+
+/// Width of characters with an ambiguous East Asian width.
+///
+/// These include many symbols, like `±` or `①`, and box drawing
+/// characters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AmbiguousWidth {
+    /// Ambiguous characters are one cell wide, like on most terminals.
+    #[default]
+    Narrow,
+    /// Ambiguous characters are two cells wide, like on CJK terminals.
+    Wide,
+}
+
+/// Sets the width of characters with an ambiguous East Asian width.
+///
+/// This setting is global, and is used by every width computation in this
+/// crate. Views should be laid out again after changing it.
+pub fn set_ambiguous_width(width: AmbiguousWidth) {
+    AMBIGUOUS_WIDE.store(width == AmbiguousWidth::Wide, Ordering::Relaxed);
+}
+
+/// Returns the width of characters with an ambiguous East Asian width.
+pub fn ambiguous_width() -> AmbiguousWidth {
+    if AMBIGUOUS_WIDE.load(Ordering::Relaxed) {
+        AmbiguousWidth::Wide
+    } else {
+        AmbiguousWidth::Narrow
+    }
+}
+
+/// Text with a width on the terminal.
+///
+/// This replaces `unicode_width::UnicodeWidthStr`, taking grapheme clusters
+/// and the [ambiguous width](set_ambiguous_width) into account.
+pub trait TextWidth {
+    /// Returns the number of cells needed to show this text.
+    fn width(&self) -> usize;
+}
+
+impl TextWidth for str {
+    fn width(&self) -> usize {
+        // Fast path: ASCII characters are their own graphemes.
+        if self.is_ascii() {
+            return unicode_width::UnicodeWidthStr::width(self);
+        }
+
+        self.graphemes(true).map(grapheme_width).sum()
+    }
+}
+
+/// Returns the width of a single character.
+///
+/// Control characters have a width of 0.
+pub fn char_width(c: char) -> usize {
+    width_with(c, ambiguous_width())
+}
+
+fn width_with(c: char, ambiguous: AmbiguousWidth) -> usize {
+    let width = match ambiguous {
+        AmbiguousWidth::Narrow => c.width(),
+        AmbiguousWidth::Wide => c.width_cjk(),
+    };
+    width.unwrap_or(0)
+}
+
+/// Returns the width of a single extended grapheme cluster.
+///
+/// Follows the guidance from Unicode TR#51: emoji sequences are shown as a
+/// single glyph, so they are as wide as their widest character. Other
+/// clusters are as wide as the sum of their characters.
+pub fn grapheme_width(grapheme: &str) -> usize {
+    if grapheme.contains(EMOJI_PRESENTATION) {
+        return 2;
+    }
+
+    if grapheme.contains(TEXT_PRESENTATION) {
+        return grapheme.chars().next().map_or(0, |c| char_width(c).min(1));
+    }
+
+    if is_emoji_sequence(grapheme) {
+        return grapheme.chars().map(char_width).max().unwrap_or(0).max(2);
+    }
+
+    grapheme.chars().map(char_width).sum()
+}
+
+// Returns `true` for ZWJ sequences, modifier sequences and flags.
+fn is_emoji_sequence(grapheme: &str) -> bool {
+    let mut chars = grapheme.chars();
+    let first = match chars.next() {
+        Some(c) => c,
+        None => return false,
+    };
+
+    if is_regional_indicator(first) {
+        return matches!(chars.next(), Some(c) if is_regional_indicator(c));
+    }
+
+    // A ZWJ also joins some non-emoji characters, like in Indic scripts.
+    char_width(first) == 2
+        && grapheme.chars().any(|c| c == ZWJ || is_emoji_modifier(c))
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&c)
+}
+
+fn is_emoji_modifier(c: char) -> bool {
+    ('\u{1F3FB}'..='\u{1F3FF}').contains(&c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn emoji_sequences() {
+        // Family: man, woman, girl.
+        assert_eq!("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}".width(), 2);
+        // Thumbs up with a skin tone.
+        assert_eq!("\u{1F44D}\u{1F3FD}".width(), 2);
+        // Heart with emoji presentation.
+        assert_eq!("\u{2764}\u{FE0F}".width(), 2);
+        // Flags, next to each other.
+        assert_eq!("\u{1F1EB}\u{1F1F7}\u{1F1EF}\u{1F1F5}".width(), 4);
+        // Combining marks are still zero-width.
+        assert_eq!("a\u{301}b".width(), 2);
+    }
+
+    #[test]
+    fn ambiguous() {
+        assert_eq!(width_with('±', AmbiguousWidth::Narrow), 1);
+        assert_eq!(width_with('±', AmbiguousWidth::Wide), 2);
+        assert_eq!(width_with('a', AmbiguousWidth::Wide), 1);
+    }
+}
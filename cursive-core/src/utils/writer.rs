@@ -0,0 +1,115 @@
+use crate::utils::reader::Ticker;
+use crate::utils::Counter;
+use std::io::{self, Write};
+
+/// Wrapper around a `Write` that reports the progress made.
+///
+/// This is the counterpart of [`ProgressReader`](super::ProgressReader),
+/// used to monitor an upload or other slow IO task in a progress bar.
+///
+/// The counter is updated on every write by default. See
+/// [`ProgressWriter::set_batch_size`] to update it less often. Bytes still
+/// pending are added to the counter when the writer is dropped.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::io::Write;
+/// use cursive_core::utils::{Counter, ProgressWriter};
+///
+/// let file = std::fs::File::create("large_file").unwrap();
+/// let counter = Counter::new(0);
+/// let mut writer = ProgressWriter::new(counter.clone(), file)
+///     .on_eof(|total| println!("Wrote {} bytes", total));
+///
+/// writer.write_all(&[0; 1_000_000]).unwrap();
+/// writer.finish().unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct ProgressWriter<W: Write> {
+    writer: W,
+    ticker: Ticker,
+}
+
+impl<W: Write> ProgressWriter<W> {
+    /// Creates a new `ProgressWriter` around `writer`.
+    ///
+    /// `counter` will be updated with the number of bytes written.
+    pub fn new(counter: Counter, writer: W) -> Self {
+        ProgressWriter {
+            writer,
+            ticker: Ticker::new(counter),
+        }
+    }
+
+    /// Sets the number of bytes to write before updating the counter.
+    ///
+    /// Defaults to `1`, which updates the counter on every write.
+    pub fn set_batch_size(&mut self, batch_size: usize) {
+        self.ticker.set_batch_size(batch_size);
+    }
+
+    /// Sets the number of bytes to write before updating the counter.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.set_batch_size(batch_size);
+        self
+    }
+
+    /// Sets a callback to run when writing is complete.
+    ///
+    /// The callback receives the total number of bytes written. It runs once,
+    /// from [`ProgressWriter::finish`].
+    pub fn set_on_eof<F>(&mut self, on_eof: F)
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        self.ticker.set_on_eof(on_eof);
+    }
+
+    /// Sets a callback to run when writing is complete.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn on_eof<F>(mut self, on_eof: F) -> Self
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        self.set_on_eof(on_eof);
+        self
+    }
+
+    /// Flushes the writer, and reports that writing is complete.
+    ///
+    /// Any pending progress is added to the counter, then the `on_eof`
+    /// callback runs.
+    pub fn finish(mut self) -> io::Result<(W, Counter)> {
+        self.writer.flush()?;
+        self.ticker.finish();
+        Ok((self.writer, self.ticker.into_counter()))
+    }
+
+    /// Unwraps this `ProgressWriter`, returning the writer and counter.
+    ///
+    /// Any pending progress is added to the counter first, but the `on_eof`
+    /// callback does not run.
+    pub fn deconstruct(self) -> (W, Counter) {
+        (self.writer, self.ticker.into_counter())
+    }
+}
+
+impl<W: Write> Write for ProgressWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let result = self.writer.write(buf)?;
+        self.ticker.add(result);
+        Ok(result)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()?;
+        self.ticker.flush();
+        Ok(())
+    }
+}
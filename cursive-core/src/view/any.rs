@@ -24,6 +24,9 @@ pub trait AnyView {
     /// let text: Box<TextView> = boxed.as_boxed_any().downcast().unwrap();
     /// ```
     fn as_boxed_any(self: Box<Self>) -> Box<dyn Any>;
+
+    /// Upcasts self to a `&dyn View`.
+    fn as_view(&self) -> &dyn View;
 }
 
 impl<T: View> AnyView for T {
@@ -40,6 +43,10 @@ impl<T: View> AnyView for T {
     fn as_boxed_any(self: Box<Self>) -> Box<dyn Any> {
         self
     }
+
+    fn as_view(&self) -> &dyn View {
+        self
+    }
 }
 
 impl dyn AnyView {
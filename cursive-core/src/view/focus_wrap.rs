@@ -0,0 +1,18 @@
+/// Behaviour of the Tab key when the focus reaches the last (or first) view.
+///
+/// Used by [`LinearLayout`] and [`Dialog`].
+///
+/// [`LinearLayout`]: crate::views::LinearLayout
+/// [`Dialog`]: crate::views::Dialog
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FocusWrap {
+    /// The event is ignored, so the parent view can move the focus further.
+    ///
+    /// This is the default.
+    #[default]
+    Propagate,
+    /// The focus goes back to the first (or last) view.
+    Wrap,
+    /// The focus stays on the current view, and the event is consumed.
+    Stop,
+}
@@ -85,6 +85,7 @@ mod view_wrapper;
 // Essentials components
 mod any;
 mod finder;
+mod focus_wrap;
 mod margins;
 mod position;
 mod size_cache;
@@ -99,21 +100,24 @@ mod into_boxed_view;
 mod nameable;
 mod resizable;
 mod scrollable;
+mod searchable;
 
 // That one is deprecated
 mod scroll_base;
 
 pub use self::any::AnyView;
 pub use self::finder::{Finder, Selector};
+pub use self::focus_wrap::FocusWrap;
 pub use self::into_boxed_view::IntoBoxedView;
 pub use self::margins::Margins;
-pub use self::nameable::Nameable;
+pub use self::nameable::{Nameable, TypedName};
 pub use self::position::{Offset, Position};
 pub use self::resizable::Resizable;
 pub use self::scroll::ScrollStrategy;
 #[allow(deprecated)]
 pub use self::scroll_base::ScrollBase;
 pub use self::scrollable::Scrollable;
+pub use self::searchable::{SearchHit, SearchMatch, Searchable};
 pub use self::size_cache::SizeCache;
 pub use self::size_constraint::SizeConstraint;
 pub use self::view_trait::{CannotFocus, View, ViewNotFound};
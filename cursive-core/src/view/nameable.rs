@@ -1,5 +1,64 @@
 use crate::view::View;
 use crate::views::NamedView;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Name for a view of a known type.
+///
+/// Using a `TypedName` instead of a plain string makes sure the same type is
+/// used when naming a view and when looking it up, and avoids typos when it
+/// is defined once as a constant.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive_core::Cursive;
+/// # use cursive_core::views::TextView;
+/// use cursive_core::view::{Nameable, TypedName};
+///
+/// const STATUS: TypedName<TextView> = TypedName::new("status");
+///
+/// let mut siv = Cursive::new();
+/// siv.add_layer(TextView::new("Ready").with_typed_name(STATUS));
+///
+/// // No need to specify the type here.
+/// let mut status = siv.find_typed_name(STATUS).unwrap();
+/// status.set_content("Working...");
+/// ```
+pub struct TypedName<V> {
+    name: &'static str,
+    _view: PhantomData<fn() -> V>,
+}
+
+impl<V> TypedName<V> {
+    /// Creates a new name for views of type `V`.
+    pub const fn new(name: &'static str) -> Self {
+        TypedName {
+            name,
+            _view: PhantomData,
+        }
+    }
+
+    /// Returns the name itself.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+// Manual implementations, to avoid requiring `V: Clone`.
+impl<V> Clone for TypedName<V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V> Copy for TypedName<V> {}
+
+impl<V> fmt::Debug for TypedName<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TypedName").field(&self.name).finish()
+    }
+}
 
 /// Makes a view wrappable in an [`NamedView`].
 ///
@@ -39,6 +98,15 @@ pub trait Nameable: View + Sized {
     fn with_name<S: Into<String>>(self, name: S) -> NamedView<Self> {
         NamedView::new(name, self)
     }
+
+    /// Wraps this view into a `NamedView` with the given typed name.
+    ///
+    /// The view can then be found with [`Cursive::find_typed_name`].
+    ///
+    /// [`Cursive::find_typed_name`]: crate::Cursive::find_typed_name
+    fn with_typed_name(self, name: TypedName<Self>) -> NamedView<Self> {
+        NamedView::new(name.name(), self)
+    }
 }
 
 /// Any `View` implements this trait.
@@ -1,3 +1,4 @@
+use crate::align::{Align, HAlign, VAlign};
 use crate::Vec2;
 use crate::XY;
 use std::cmp::min;
@@ -23,6 +24,42 @@ impl Position {
         Position::new(Offset::Parent(offset.x), Offset::Parent(offset.y))
     }
 
+    /// Returns a position as a percentage (from `0` to `100`) of the free
+    /// space on both axis.
+    ///
+    /// `(0, 0)` is the top-left corner, and `(100, 100)` the bottom-right
+    /// corner.
+    pub fn percent(x: u8, y: u8) -> Self {
+        Position::new(Offset::Percent(x), Offset::Percent(y))
+    }
+
+    /// Returns a position aligned to a side or corner of the screen.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::align::Align;
+    /// # use cursive_core::view::Position;
+    /// let position = Position::aligned(Align::bot_right());
+    /// assert_eq!(
+    ///     position.compute_offset((10, 2), (80, 24), (0, 0)),
+    ///     (70, 22).into(),
+    /// );
+    /// ```
+    pub fn aligned(align: Align) -> Self {
+        let x = match align.h {
+            HAlign::Left => Offset::Absolute(0),
+            HAlign::Center => Offset::Center,
+            HAlign::Right => Offset::Percent(100),
+        };
+        let y = match align.v {
+            VAlign::Top => Offset::Absolute(0),
+            VAlign::Center => Offset::Center,
+            VAlign::Bottom => Offset::Percent(100),
+        };
+        Position::new(x, y)
+    }
+
     /// Computes the offset required to draw a view.
     ///
     /// When drawing a view with `size` in a container with `available`,
@@ -63,6 +100,12 @@ pub enum Offset {
     ///
     /// If this is the first layer, behaves like `Absolute`.
     Parent(isize),
+
+    /// Place the view at a percentage (from `0` to `100`) of the free space.
+    ///
+    /// `Percent(0)` is the start, `Percent(50)` the center, and
+    /// `Percent(100)` the end.
+    Percent(u8),
 }
 
 impl Offset {
@@ -82,6 +125,10 @@ impl Offset {
                 Offset::Parent(offset) => {
                     min((parent as isize + offset) as usize, available - size)
                 }
+                Offset::Percent(percent) => {
+                    let percent = min(percent, 100) as usize;
+                    ((available - size) * percent + 50) / 100
+                }
             }
         }
     }
@@ -103,4 +150,18 @@ mod tests {
         assert_eq!(Vec2::new(0, 0), c.compute_offset((5, 3), (5, 3), (0, 0)));
         assert_eq!(Vec2::new(0, 0), c.compute_offset((5, 3), (3, 1), (0, 0)));
     }
+
+    #[test]
+    fn test_percent() {
+        let p = Position::percent(100, 50);
+        assert_eq!(Vec2::new(4, 1), p.compute_offset((1, 1), (5, 3), (0, 0)));
+        assert_eq!(Vec2::new(0, 0), p.compute_offset((5, 3), (5, 3), (0, 0)));
+
+        // Values above 100 are clamped.
+        let p = Position::percent(25, 200);
+        assert_eq!(
+            Vec2::new(5, 10),
+            p.compute_offset((0, 0), (20, 10), (0, 0))
+        );
+    }
 }
@@ -74,6 +74,43 @@ pub trait Resizable: View + Sized {
     fn min_height(self, min_height: usize) -> ResizedView<Self> {
         ResizedView::with_min_height(min_height, self)
     }
+
+    /// Wraps `self` in a `ResizedView` sized between `min` and `max`.
+    fn clamped_size<S: Into<Vec2>, U: Into<Vec2>>(
+        self,
+        min: S,
+        max: U,
+    ) -> ResizedView<Self> {
+        ResizedView::with_clamped_size(min, max, self)
+    }
+
+    /// Wraps `self` in a `ResizedView` with a width between `min` and `max`.
+    fn clamped_width(self, min: usize, max: usize) -> ResizedView<Self> {
+        ResizedView::with_clamped_width(min, max, self)
+    }
+
+    /// Wraps `self` in a `ResizedView` with a height between `min` and `max`.
+    fn clamped_height(self, min: usize, max: usize) -> ResizedView<Self> {
+        ResizedView::with_clamped_height(min, max, self)
+    }
+
+    /// Wraps `self` in a `ResizedView` taking the given fractions of the
+    /// available space.
+    fn ratio_size(self, ratio: (f32, f32)) -> ResizedView<Self> {
+        ResizedView::with_ratio_size(ratio, self)
+    }
+
+    /// Wraps `self` in a `ResizedView` taking the given fraction of the
+    /// available width.
+    fn ratio_width(self, width: f32) -> ResizedView<Self> {
+        ResizedView::with_ratio_width(width, self)
+    }
+
+    /// Wraps `self` in a `ResizedView` taking the given fraction of the
+    /// available height.
+    fn ratio_height(self, height: f32) -> ResizedView<Self> {
+        ResizedView::with_ratio_height(height, self)
+    }
 }
 
 impl<T: View> Resizable for T {}
@@ -1,8 +1,8 @@
 use std::cmp::min;
 
 use crate::{
-    direction::Orientation,
-    event::{AnyCb, Event},
+    direction::{Absolute, Orientation},
+    event::{AnyCb, Event, Key},
     printer::Printer,
     rect::Rect,
     theme::ColorStyle,
@@ -102,6 +102,12 @@ pub struct Core {
 
     /// Defines how to update the offset when the view size changes.
     scroll_strategy: ScrollStrategy,
+
+    /// Offset shift to apply once the new content size is known.
+    pending_shift: Vec2,
+
+    /// Modifier for the arrow keys that always scroll horizontally.
+    horizontal_scroll_key: fn(Key) -> Event,
 }
 
 impl Default for Core {
@@ -123,6 +129,8 @@ impl Core {
             thumb_grab: None,
             size_cache: None,
             scroll_strategy: ScrollStrategy::KeepRow,
+            pending_shift: Vec2::zero(),
+            horizontal_scroll_key: Event::Shift,
         }
     }
 
@@ -249,6 +257,10 @@ impl Core {
 
     /// Makes sure the viewport is within the content.
     pub(crate) fn update_offset(&mut self) {
+        // Content may have been inserted before the viewport.
+        self.offset = self.offset + self.pending_shift;
+        self.pending_shift = Vec2::zero();
+
         // Keep the offset in the valid range.
         self.offset = self.offset.or_min(
             self.inner_size.saturating_sub(self.last_available_size()),
@@ -384,6 +396,37 @@ impl Core {
         self.offset = offset.into().or_min(max_offset);
     }
 
+    /// Shifts the scroll offset by `delta` on the next layout phase.
+    ///
+    /// This is useful when content was inserted before the current viewport
+    /// (for example older messages prepended to a chat history): the new
+    /// content size is only known after the next layout, at which point the
+    /// offset is moved so the visible rows stay in place.
+    pub fn shift_offset_on_layout<S>(&mut self, delta: S)
+    where
+        S: Into<Vec2>,
+    {
+        self.pending_shift = self.pending_shift + delta.into();
+    }
+
+    /// Returns the distance between the viewport and the given edge of the
+    /// content.
+    ///
+    /// For example, `Absolute::Up` returns the number of rows above the
+    /// viewport, and `Absolute::Down` the number of rows below it.
+    ///
+    /// Returns `0` for `Absolute::None`.
+    pub fn distance_to_edge(&self, edge: Absolute) -> usize {
+        let max_offset = self.max_offset();
+        match edge {
+            Absolute::Left => self.offset.x,
+            Absolute::Up => self.offset.y,
+            Absolute::Right => max_offset.x.saturating_sub(self.offset.x),
+            Absolute::Down => max_offset.y.saturating_sub(self.offset.y),
+            Absolute::None => 0,
+        }
+    }
+
     /// Controls whether this view can scroll vertically.
     ///
     /// Defaults to `true`.
@@ -400,6 +443,39 @@ impl Core {
         self.invalidate_cache();
     }
 
+    /// Sets the modifier for arrow keys that scroll horizontally.
+    ///
+    /// `<Left>` and `<Right>` scroll horizontally when the content ignores
+    /// them. With this modifier (for example `Event::Ctrl`), they scroll even
+    /// if the content would use them.
+    ///
+    /// Only applies when horizontal scrolling is enabled.
+    ///
+    /// Defaults to `Event::Shift`.
+    pub fn set_horizontal_scroll_key(&mut self, modifier: fn(Key) -> Event) {
+        self.horizontal_scroll_key = modifier;
+    }
+
+    /// Returns the modifier for arrow keys that scroll horizontally.
+    pub fn get_horizontal_scroll_key(&self) -> fn(Key) -> Event {
+        self.horizontal_scroll_key
+    }
+
+    /// Returns `true` if `event` is a modified arrow key scrolling towards
+    /// `key`, and we can scroll in that direction.
+    pub(crate) fn is_horizontal_scroll(
+        &self,
+        event: &Event,
+        key: Key,
+    ) -> bool {
+        let can_scroll = match key {
+            Key::Left => self.can_scroll_left(),
+            Key::Right => self.can_scroll_right(),
+            _ => false,
+        };
+        can_scroll && *event == (self.horizontal_scroll_key)(key)
+    }
+
     /// Controls whether this view can scroll vertically.
     ///
     /// Defaults to `true`.
@@ -218,6 +218,22 @@ pub fn on_event<Model: ?Sized>(
     mut on_event: impl FnMut(&mut Model, Event) -> EventResult,
     mut important_area: impl FnMut(&Model, Vec2) -> Rect,
 ) -> EventResult {
+    // Modified arrows scroll horizontally, before the content sees them.
+    let scroller = get_scroller(model);
+    let scrolled = if scroller.is_horizontal_scroll(&event, Key::Left) {
+        scroller.scroll_left(1);
+        true
+    } else if scroller.is_horizontal_scroll(&event, Key::Right) {
+        scroller.scroll_right(1);
+        true
+    } else {
+        false
+    };
+    if scrolled {
+        scroller.set_scroll_strategy(scroll::ScrollStrategy::KeepRow);
+        return EventResult::Consumed(None);
+    }
+
     let mut relative_event = event.clone();
     let inside = get_scroller(model).is_event_inside(&mut relative_event);
     let result = if inside {
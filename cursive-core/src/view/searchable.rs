@@ -0,0 +1,142 @@
+use crate::event::{Callback, EventResult};
+
+/// A view with some content that can be searched.
+///
+/// Views implementing this trait can be registered with
+/// [`Cursive::register_searchable`], and will then be included in the
+/// results of [`Cursive::search`].
+///
+/// [`Cursive::register_searchable`]: crate::Cursive::register_searchable
+/// [`Cursive::search`]: crate::Cursive::search
+pub trait Searchable {
+    /// Returns all the places where `query` appears in this view.
+    ///
+    /// An empty query should not match anything.
+    fn search(&self, query: &str) -> Vec<SearchMatch>;
+
+    /// Makes the given match visible, if possible.
+    ///
+    /// `found` was returned by a previous call to `search()`.
+    ///
+    /// Default implementation does nothing.
+    fn reveal(&mut self, found: &SearchMatch) -> EventResult {
+        let _ = found;
+        EventResult::Ignored
+    }
+}
+
+/// A single match, as returned by [`Searchable::search`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchMatch {
+    /// Location of the match in the view.
+    ///
+    /// The meaning depends on the view: a line number for a `TextView`, an
+    /// item index for a `SelectView`, ...
+    pub position: usize,
+
+    /// Text around the match, to show to the user.
+    pub snippet: String,
+}
+
+impl SearchMatch {
+    /// Creates a new match.
+    pub fn new<S: Into<String>>(position: usize, snippet: S) -> Self {
+        SearchMatch {
+            position,
+            snippet: snippet.into(),
+        }
+    }
+
+    /// Returns `true` if `text` contains `query`, ignoring case.
+    ///
+    /// An empty query never matches.
+    pub fn matches(text: &str, query: &str) -> bool {
+        !query.is_empty()
+            && text.to_lowercase().contains(&query.to_lowercase())
+    }
+
+    /// Returns a match for each line of `text` containing `query`.
+    ///
+    /// The position of each match is the line number, starting at 0.
+    pub fn in_lines(text: &str, query: &str) -> Vec<Self> {
+        text.lines()
+            .enumerate()
+            .filter(|&(_, line)| Self::matches(line, query))
+            .map(|(i, line)| SearchMatch::new(i, line.trim()))
+            .collect()
+    }
+}
+
+/// A match in a named view, as returned by [`Cursive::search`].
+///
+/// [`Cursive::search`]: crate::Cursive::search
+#[derive(Clone)]
+pub struct SearchHit {
+    /// Name of the view where the match was found.
+    pub name: String,
+
+    /// Location of the match in this view.
+    pub found: SearchMatch,
+
+    /// Callback revealing the match, and focusing the view if possible.
+    pub callback: Callback,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::Nameable;
+    use crate::views::{LinearLayout, SelectView, TextView};
+    use crate::Cursive;
+
+    #[test]
+    fn in_lines_ignores_case() {
+        let text = "First line\n  Second LINE  \nthird";
+        assert_eq!(
+            SearchMatch::in_lines(text, "line"),
+            vec![
+                SearchMatch::new(0, "First line"),
+                SearchMatch::new(1, "Second LINE"),
+            ]
+        );
+        assert!(SearchMatch::in_lines(text, "").is_empty());
+    }
+
+    #[test]
+    fn global_search() {
+        let mut siv = Cursive::new();
+        siv.add_layer(
+            LinearLayout::vertical()
+                .child(TextView::new("apple\nbanana").with_name("text"))
+                .child(
+                    SelectView::<u32>::new()
+                        .item("cherry", 1)
+                        .item("pineapple", 2)
+                        .with_name("select"),
+                ),
+        );
+        siv.register_searchable::<TextView>("text");
+        siv.register_searchable::<SelectView<u32>>("select");
+
+        let hits = siv.search("APPLE");
+        let found: Vec<_> =
+            hits.iter().map(|hit| (&hit.name[..], &hit.found)).collect();
+        assert_eq!(
+            found,
+            vec![
+                ("text", &SearchMatch::new(0, "apple")),
+                ("select", &SearchMatch::new(1, "pineapple")),
+            ]
+        );
+
+        // Following the hit selects the item.
+        (hits[1].callback)(&mut siv);
+        let selected = siv
+            .call_on_name("select", |v: &mut SelectView<u32>| v.selected_id())
+            .unwrap();
+        assert_eq!(selected, Some(1));
+
+        siv.unregister_searchable("text");
+        assert_eq!(siv.search("apple").len(), 1);
+    }
+}
@@ -1,11 +1,12 @@
-use std::cmp::min;
+use std::cmp::{max, min};
+use std::hash::{Hash, Hasher};
 
 /// Single-dimensional constraint on a view size.
 ///
 /// This describes a possible behaviour for a [`ResizedView`].
 ///
 /// [`ResizedView`]: crate::views::ResizedView
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy)]
 pub enum SizeConstraint {
     /// No constraint imposed, the child view's response is used.
     Free,
@@ -17,6 +18,52 @@ pub enum SizeConstraint {
     AtMost(usize),
     /// Returns the maximum of the included value and the child view's size.
     AtLeast(usize),
+    /// Returns the child view's size, clamped between the two values.
+    ///
+    /// The first value is the minimum, the second is the maximum. If the
+    /// minimum is larger than the maximum, the minimum wins.
+    Between(usize, usize),
+    /// Takes the given fraction of the available space, no matter what the
+    /// child needs.
+    ///
+    /// The value is clamped to `0.0..=1.0`.
+    Ratio(f32),
+}
+
+// `f32` is neither `Eq` nor `Hash`: compare ratios by their bits instead.
+impl PartialEq for SizeConstraint {
+    fn eq(&self, other: &Self) -> bool {
+        use SizeConstraint::*;
+
+        match (*self, *other) {
+            (Free, Free) | (Full, Full) => true,
+            (Fixed(a), Fixed(b))
+            | (AtMost(a), AtMost(b))
+            | (AtLeast(a), AtLeast(b)) => a == b,
+            (Between(a, b), Between(c, d)) => a == c && b == d,
+            (Ratio(a), Ratio(b)) => a.to_bits() == b.to_bits(),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for SizeConstraint {}
+
+impl Hash for SizeConstraint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match *self {
+            SizeConstraint::Free | SizeConstraint::Full => (),
+            SizeConstraint::Fixed(value)
+            | SizeConstraint::AtMost(value)
+            | SizeConstraint::AtLeast(value) => value.hash(state),
+            SizeConstraint::Between(min_value, max_value) => {
+                min_value.hash(state);
+                max_value.hash(state);
+            }
+            SizeConstraint::Ratio(ratio) => ratio.to_bits().hash(state),
+        }
+    }
 }
 
 impl SizeConstraint {
@@ -29,9 +76,10 @@ impl SizeConstraint {
             | SizeConstraint::Full
             | SizeConstraint::AtLeast(_) => available,
             // If the available space is too small, always give in.
-            SizeConstraint::Fixed(value) | SizeConstraint::AtMost(value) => {
-                min(value, available)
-            }
+            SizeConstraint::Fixed(value)
+            | SizeConstraint::AtMost(value)
+            | SizeConstraint::Between(_, value) => min(value, available),
+            SizeConstraint::Ratio(ratio) => scale(ratio, available),
         }
     }
 
@@ -44,7 +92,22 @@ impl SizeConstraint {
             SizeConstraint::AtMost(value) if result > value => value,
             SizeConstraint::Fixed(value) => value,
             SizeConstraint::Full if available > result => available,
+            SizeConstraint::Between(min_value, max_value) => {
+                max(min(result, max_value), min_value)
+            }
+            // The ratio was already applied by `available()`.
+            SizeConstraint::Ratio(_) => available,
             _ => result,
         }
     }
 }
+
+// Returns the given fraction of `available`.
+fn scale(ratio: f32, available: usize) -> usize {
+    let ratio = if ratio.is_nan() {
+        0.0
+    } else {
+        ratio.clamp(0.0, 1.0)
+    };
+    min((ratio * available as f32).round() as usize, available)
+}
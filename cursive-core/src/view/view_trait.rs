@@ -1,5 +1,6 @@
 use crate::direction::Direction;
 use crate::event::{AnyCb, Event, EventResult};
+use crate::help::Binding;
 use crate::rect::Rect;
 use crate::view::{AnyView, Selector};
 use crate::Printer;
@@ -63,6 +64,15 @@ pub trait View: Any + AnyView {
         true
     }
 
+    /// Called by a scrolling parent before `layout()`, with the part of the
+    /// view that will be visible.
+    ///
+    /// Views with many children can use it to only lay out and draw what is
+    /// actually visible. It only applies to the next `layout()` call.
+    ///
+    /// Default implementation is a no-op.
+    fn set_viewport(&mut self, _: Rect) {}
+
     /// Returns the minimum size the view requires with the given restrictions.
     ///
     /// This is the main way a view communicate its size to its parent.
@@ -157,6 +167,38 @@ pub trait View: Any + AnyView {
     fn type_name(&self) -> &'static str {
         std::any::type_name::<Self>()
     }
+
+    /// Runs a closure on each direct child of this view.
+    ///
+    /// The closure is also given the area of the child (relative to this
+    /// view) from the last layout, when it is known.
+    ///
+    /// This is only used to inspect the view tree, for example with
+    /// [`debug_tree`](#method.debug_tree).
+    ///
+    /// View groups should implement this to visit each children.
+    ///
+    /// Default implementation is a no-op.
+    fn debug_children(&self, _: &mut dyn FnMut(&dyn View, Option<Rect>)) {}
+
+    /// Returns a name identifying this particular view, if any.
+    ///
+    /// This is only used to inspect the view tree.
+    ///
+    /// Default implementation returns `None`.
+    fn debug_name(&self) -> Option<&str> {
+        None
+    }
+
+    /// Runs a closure on each documented key binding of this view.
+    ///
+    /// This is used by [`Cursive::show_help`](crate::Cursive::show_help).
+    ///
+    /// Default implementation visits the children (see
+    /// [`debug_children`](#method.debug_children)).
+    fn key_help(&self, f: &mut dyn FnMut(&Binding)) {
+        self.debug_children(&mut |child, _| child.key_help(f));
+    }
 }
 
 impl dyn View {
@@ -185,4 +227,99 @@ impl dyn View {
     pub fn is<T: Any>(&self) -> bool {
         self.as_any().is::<T>()
     }
+
+    /// Returns a description of this view and all its descendants.
+    ///
+    /// Each line shows a view, indented under its parent, with its type,
+    /// its name (for a `NamedView`), and its size and offset from the last
+    /// layout when they are known. Offsets are relative to the parent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::view::{Nameable, View};
+    /// # use cursive_core::views::{LinearLayout, TextView};
+    /// let mut layout = LinearLayout::vertical()
+    ///     .child(TextView::new("Hello").with_name("hello"));
+    /// layout.layout((10, 1).into());
+    ///
+    /// let tree = (&layout as &dyn View).debug_tree();
+    /// assert_eq!(
+    ///     tree,
+    ///     "LinearLayout\n  NamedView<TextView> \"hello\" 10x1 at (0, 0)\n    TextView\n"
+    /// );
+    /// ```
+    pub fn debug_tree(&self) -> String {
+        let mut tree = String::new();
+        write_tree(&mut tree, self, None, 0);
+        tree
+    }
+}
+
+// Appends a view and its descendants to `tree`.
+fn write_tree(
+    tree: &mut String,
+    view: &dyn View,
+    area: Option<Rect>,
+    depth: usize,
+) {
+    use std::fmt::Write;
+
+    tree.push_str(&"  ".repeat(depth));
+    tree.push_str(&short_type_name(view.type_name()));
+    if let Some(name) = view.debug_name() {
+        write!(tree, " {:?}", name).unwrap();
+    }
+    if let Some(area) = area {
+        let (size, offset) = (area.size(), area.top_left());
+        write!(
+            tree,
+            " {}x{} at ({}, {})",
+            size.x, size.y, offset.x, offset.y
+        )
+        .unwrap();
+    }
+    tree.push('\n');
+
+    view.debug_children(&mut |child, area| {
+        write_tree(tree, child, area, depth + 1)
+    });
+}
+
+// Removes the module paths from a type name.
+//
+// `a::B<c::D>` becomes `B<D>`.
+fn short_type_name(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    // Start of the current path in `result`.
+    let mut start = 0;
+    let mut chars = name.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            result.truncate(start);
+        } else {
+            result.push(c);
+            if !(c.is_alphanumeric() || c == '_') {
+                start = result.len();
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_names() {
+        assert_eq!(short_type_name("a::b::C"), "C");
+        assert_eq!(
+            short_type_name("a::B<c::D, (e::F, &dyn g::H)>"),
+            "B<D, (F, &dyn H)>"
+        );
+    }
 }
@@ -1,8 +1,9 @@
 use crate::{
     direction::Direction,
     event::{AnyCb, Event, EventResult},
+    help::Binding,
     rect::Rect,
-    view::{CannotFocus, Selector, View, ViewNotFound},
+    view::{AnyView, CannotFocus, Selector, View, ViewNotFound},
     Printer, Vec2,
 };
 
@@ -101,11 +102,34 @@ pub trait ViewWrapper: 'static {
         self.with_view(View::needs_relayout).unwrap_or(true)
     }
 
+    /// Wraps the `set_viewport` method.
+    fn wrap_set_viewport(&mut self, viewport: Rect) {
+        self.with_view_mut(|v| v.set_viewport(viewport));
+    }
+
     /// Wraps the `important_area` method.
     fn wrap_important_area(&self, size: Vec2) -> Rect {
         self.with_view(|v| v.important_area(size))
             .unwrap_or_else(|| Rect::from_size(Vec2::zero(), size))
     }
+
+    /// Wraps the `debug_children` method.
+    ///
+    /// The default implementation visits the inner view, with an unknown
+    /// area.
+    fn wrap_debug_children(&self, f: &mut dyn FnMut(&dyn View, Option<Rect>)) {
+        self.with_view(|v| f(v.as_view(), None));
+    }
+
+    /// Wraps the `debug_name` method.
+    fn wrap_debug_name(&self) -> Option<&str> {
+        None
+    }
+
+    /// Wraps the `key_help` method.
+    fn wrap_key_help(&self, f: &mut dyn FnMut(&Binding)) {
+        self.with_view(|v| v.key_help(f));
+    }
 }
 
 // The main point of implementing ViewWrapper is to have View for free.
@@ -145,6 +169,10 @@ impl<T: ViewWrapper> View for T {
         self.wrap_needs_relayout()
     }
 
+    fn set_viewport(&mut self, viewport: Rect) {
+        self.wrap_set_viewport(viewport)
+    }
+
     fn focus_view(
         &mut self,
         selector: &Selector<'_>,
@@ -155,6 +183,18 @@ impl<T: ViewWrapper> View for T {
     fn important_area(&self, size: Vec2) -> Rect {
         self.wrap_important_area(size)
     }
+
+    fn debug_children(&self, f: &mut dyn FnMut(&dyn View, Option<Rect>)) {
+        self.wrap_debug_children(f)
+    }
+
+    fn debug_name(&self) -> Option<&str> {
+        self.wrap_debug_name()
+    }
+
+    fn key_help(&self, f: &mut dyn FnMut(&Binding)) {
+        self.wrap_key_help(f)
+    }
 }
 
 /// Convenient macro to implement the [`ViewWrapper`] trait.
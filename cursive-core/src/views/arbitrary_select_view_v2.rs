@@ -1,3 +1,4 @@
+use crate::utils::width::TextWidth;
 use crate::{
     direction,
     event::{AnyCb, Callback, Event, EventResult, Key},
@@ -7,7 +8,6 @@ use crate::{
 };
 use log::debug;
 use std::rc::Rc;
-use unicode_width::UnicodeWidthStr;
 
 /// Represents a child from a [`ListView`].
 pub enum ListChild {
@@ -245,7 +245,7 @@ impl ListView {
         self.children
             .iter()
             .map(ListChild::label)
-            .map(UnicodeWidthStr::width)
+            .map(TextWidth::width)
             .max()
             .unwrap_or(0)
     }
@@ -346,7 +346,7 @@ impl View for ListView {
             .children
             .iter()
             .map(ListChild::label)
-            .map(UnicodeWidthStr::width)
+            .map(TextWidth::width)
             .max()
             .unwrap_or(0);
 
@@ -366,7 +366,7 @@ impl View for ListView {
             .children
             .iter()
             .map(ListChild::label)
-            .map(UnicodeWidthStr::width)
+            .map(TextWidth::width)
             .max()
             .unwrap_or(0);
 
@@ -0,0 +1,227 @@
+use crate::align::{Align, HAlign, VAlign};
+use crate::event::{Event, EventResult};
+use crate::rect::Rect;
+use crate::theme::ColorStyle;
+use crate::view::{View, ViewWrapper};
+use crate::Printer;
+use crate::Vec2;
+use crate::With;
+
+/// Wrapper view keeping its child at a fixed aspect ratio.
+///
+/// The child gets the largest area with the given `width / height` ratio
+/// that fits in the space available. The remaining space is painted with the
+/// view background, and the child is aligned inside it (centered by
+/// default).
+///
+/// Terminal cells are usually about twice as tall as they are wide. The
+/// cell aspect multiplier can correct for this: with a cell aspect of `2.0`,
+/// a "square" (ratio `1.0`) of 10 rows will be 20 columns wide.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive_core::views::{AspectRatioView, Canvas};
+/// // A visually square canvas.
+/// let view = AspectRatioView::new(Canvas::new(()), 1.0).cell_aspect(2.0);
+/// ```
+pub struct AspectRatioView<V> {
+    view: V,
+
+    // Requested `width / height` ratio.
+    ratio: f32,
+
+    // Width of a cell divided by its height.
+    cell_aspect: f32,
+
+    align: Align,
+
+    // Position of the child, computed during layout.
+    child_offset: Vec2,
+    child_size: Vec2,
+}
+
+impl<V> AspectRatioView<V> {
+    /// Wraps `view` in a new `AspectRatioView`.
+    ///
+    /// `ratio` is the desired `width / height` ratio, and should be positive.
+    pub fn new(view: V, ratio: f32) -> Self {
+        AspectRatioView {
+            view,
+            ratio,
+            cell_aspect: 1.0,
+            align: Align::center(),
+            child_offset: Vec2::zero(),
+            child_size: Vec2::zero(),
+        }
+    }
+
+    /// Sets the desired `width / height` ratio.
+    pub fn set_ratio(&mut self, ratio: f32) {
+        self.ratio = ratio;
+    }
+
+    /// Returns the desired `width / height` ratio.
+    pub fn get_ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    /// Sets the number of columns making up a visual unit of height.
+    ///
+    /// Defaults to `1.0`. Use `2.0` to correct for the usual shape of
+    /// terminal cells.
+    pub fn set_cell_aspect(&mut self, cell_aspect: f32) {
+        self.cell_aspect = cell_aspect;
+    }
+
+    /// Sets the number of columns making up a visual unit of height.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn cell_aspect(self, cell_aspect: f32) -> Self {
+        self.with(|s| s.set_cell_aspect(cell_aspect))
+    }
+
+    /// Sets the alignment of the child in the remaining space.
+    pub fn set_align(&mut self, align: Align) {
+        self.align = align;
+    }
+
+    /// Sets the alignment of the child in the remaining space.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn align(self, align: Align) -> Self {
+        self.with(|s| s.set_align(align))
+    }
+
+    /// Aligns the child to the top-left corner of the available space.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn top_left(self) -> Self {
+        self.align(Align::new(HAlign::Left, VAlign::Top))
+    }
+
+    // Ratio in columns per row.
+    fn cell_ratio(&self) -> Option<f32> {
+        let ratio = self.ratio * self.cell_aspect;
+        if ratio.is_finite() && ratio > 0.0 {
+            Some(ratio)
+        } else {
+            None
+        }
+    }
+
+    // Largest size with the right ratio that fits in `available`.
+    fn fit(&self, available: Vec2) -> Vec2 {
+        let ratio = match self.cell_ratio() {
+            Some(ratio) => ratio,
+            None => return available,
+        };
+
+        let height = (available.x as f32 / ratio).round() as usize;
+        if height <= available.y {
+            Vec2::new(available.x, height)
+        } else {
+            let width = (available.y as f32 * ratio).round() as usize;
+            Vec2::new(width.min(available.x), available.y)
+        }
+    }
+
+    // Smallest size with the right ratio that contains `size`.
+    fn expand(&self, size: Vec2) -> Vec2 {
+        let ratio = match self.cell_ratio() {
+            Some(ratio) => ratio,
+            None => return size,
+        };
+
+        let height = (size.x as f32 / ratio).round() as usize;
+        if height >= size.y {
+            Vec2::new(size.x, height)
+        } else {
+            Vec2::new((size.y as f32 * ratio).round() as usize, size.y)
+        }
+    }
+
+    inner_getters!(self.view: V);
+}
+
+impl<V: View> ViewWrapper for AspectRatioView<V> {
+    wrap_impl!(self.view: V);
+
+    fn wrap_required_size(&mut self, req: Vec2) -> Vec2 {
+        let natural = self.view.required_size(self.fit(req));
+
+        // If the child already has the right shape, this is a no-op.
+        let size = self.expand(natural);
+        if size.fits_in(req) {
+            size
+        } else {
+            self.fit(req)
+        }
+    }
+
+    fn wrap_layout(&mut self, size: Vec2) {
+        self.child_size = self.fit(size);
+        self.child_offset = Vec2::new(
+            self.align.h.get_offset(self.child_size.x, size.x),
+            self.align.v.get_offset(self.child_size.y, size.y),
+        );
+        self.view.layout(self.child_size);
+    }
+
+    fn wrap_draw(&self, printer: &Printer) {
+        printer.with_color(ColorStyle::primary(), |printer| {
+            for y in 0..printer.size.y {
+                printer.print_hline((0, y), printer.size.x, " ");
+            }
+        });
+
+        let printer =
+            &printer.offset(self.child_offset).cropped(self.child_size);
+        self.view.draw(printer);
+    }
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        self.view.on_event(event.relativized(self.child_offset))
+    }
+
+    fn wrap_important_area(&self, _: Vec2) -> Rect {
+        self.view.important_area(self.child_size) + self.child_offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::Resizable;
+    use crate::views::DummyView;
+
+    #[test]
+    fn fit_ratio() {
+        let mut view = AspectRatioView::new(DummyView, 2.0);
+
+        assert_eq!(view.fit(Vec2::new(20, 20)), Vec2::new(20, 10));
+        assert_eq!(view.fit(Vec2::new(20, 5)), Vec2::new(10, 5));
+
+        view.layout(Vec2::new(20, 5));
+        assert_eq!(view.child_offset, Vec2::new(5, 0));
+
+        // A square of 10 rows is 20 columns wide.
+        let view = AspectRatioView::new(DummyView, 1.0).cell_aspect(2.0);
+        assert_eq!(view.fit(Vec2::new(40, 10)), Vec2::new(20, 10));
+    }
+
+    #[test]
+    fn natural_size() {
+        // The child already has the right ratio.
+        let mut view = AspectRatioView::new(DummyView.fixed_size((8, 4)), 2.0);
+        assert_eq!(view.required_size(Vec2::new(30, 30)), Vec2::new(8, 4));
+
+        // The child is too narrow: grow it.
+        let mut view = AspectRatioView::new(DummyView.fixed_size((4, 4)), 2.0);
+        assert_eq!(view.required_size(Vec2::new(30, 30)), Vec2::new(8, 4));
+        assert_eq!(view.required_size(Vec2::new(6, 30)), Vec2::new(6, 3));
+    }
+}
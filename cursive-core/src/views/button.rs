@@ -1,13 +1,13 @@
+use crate::utils::width::TextWidth;
 use crate::{
     align::HAlign,
     direction::Direction,
     event::*,
     rect::Rect,
-    theme::ColorStyle,
+    theme::{ColorStyle, Effect},
     view::{CannotFocus, View},
-    Cursive, Printer, Vec2,
+    Cursive, Printer, Vec2, With,
 };
-use unicode_width::UnicodeWidthStr;
 
 /// Simple text label with a callback when <Enter> is pressed.
 ///
@@ -22,6 +22,11 @@ use unicode_width::UnicodeWidthStr;
 /// ```
 pub struct Button {
     label: String,
+    icon: Option<char>,
+    // `true` if the label was wrapped in brackets by `new` or `set_label`.
+    bracketed: bool,
+    // Access key, in lowercase, and its byte position in the label.
+    mnemonic: Option<(char, Option<usize>)>,
     callback: Callback,
     enabled: bool,
     last_size: Vec2,
@@ -39,7 +44,59 @@ impl Button {
         S: Into<String>,
     {
         let label = label.into();
-        Self::new_raw(format!("<{}>", label), cb)
+        Self::new_raw(format!("<{}>", label), cb).with(|b| b.bracketed = true)
+    }
+
+    /// Creates a new button with an icon before the label.
+    ///
+    /// The icon is shown inside the brackets, in bold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::Button;
+    ///
+    /// let button = Button::with_icon('+', "Add", |_| ());
+    /// assert_eq!(button.label(), "<Add>");
+    /// assert_eq!(button.get_icon(), Some('+'));
+    /// ```
+    pub fn with_icon<F, S>(icon: char, label: S, cb: F) -> Self
+    where
+        F: 'static + Fn(&mut Cursive),
+        S: Into<String>,
+    {
+        Self::new(label, cb).with(|b| b.set_icon(icon))
+    }
+
+    /// Creates a new button with an access key marked in the label.
+    ///
+    /// The character following a `&` in the label becomes the mnemonic of
+    /// this button, and is shown underlined. Use `&&` for a literal `&`.
+    ///
+    /// In a [`Dialog`](crate::views::Dialog), pressing Alt and the mnemonic
+    /// activates the button, even if another view is focused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::Button;
+    ///
+    /// let button = Button::with_mnemonic("&Save && Quit", |s| s.quit());
+    /// assert_eq!(button.label(), "<Save & Quit>");
+    /// assert_eq!(button.get_mnemonic(), Some('s'));
+    /// ```
+    pub fn with_mnemonic<F, S>(label: S, cb: F) -> Self
+    where
+        F: 'static + Fn(&mut Cursive),
+        S: Into<String>,
+    {
+        let (label, mnemonic) = parse_mnemonic(&label.into());
+        let mut button = Self::new(label, cb);
+        if let Some((c, pos)) = mnemonic {
+            // Skip the opening bracket.
+            button.mnemonic = Some((c, Some(pos + 1)));
+        }
+        button
     }
 
     /// Creates a new button without angle brackets.
@@ -57,6 +114,9 @@ impl Button {
     {
         Button {
             label: label.into(),
+            icon: None,
+            bracketed: false,
+            mnemonic: None,
             callback: Callback::from_fn(cb),
             enabled: true,
             last_size: Vec2::zero(),
@@ -106,6 +166,7 @@ impl Button {
         S: Into<String>,
     {
         self.set_label_raw(format!("<{}>", label.into()));
+        self.bracketed = true;
     }
 
     /// Sets the label exactly to the given value.
@@ -116,11 +177,116 @@ impl Button {
         S: Into<String>,
     {
         self.label = label.into();
+        self.bracketed = false;
+        if let Some((c, _)) = self.mnemonic {
+            self.set_mnemonic(c);
+        }
+        self.invalidate();
+    }
+
+    /// Sets the access key for this button.
+    ///
+    /// The first occurrence of this character in the label, if any, is
+    /// shown underlined. The key is not case-sensitive.
+    ///
+    /// Only a [`Dialog`](crate::views::Dialog) uses the mnemonic to activate
+    /// its buttons. A disabled button ignores its mnemonic.
+    pub fn set_mnemonic(&mut self, mnemonic: char) {
+        let c = mnemonic.to_lowercase().next().unwrap_or(mnemonic);
+        let pos = self
+            .label
+            .char_indices()
+            .find(|&(_, l)| l.to_lowercase().eq(c.to_lowercase()))
+            .map(|(pos, _)| pos);
+        self.mnemonic = Some((c, pos));
+    }
+
+    /// Sets the access key for this button.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn mnemonic(self, mnemonic: char) -> Self {
+        self.with(|s| s.set_mnemonic(mnemonic))
+    }
+
+    /// Removes the access key from this button, if any.
+    pub fn clear_mnemonic(&mut self) {
+        self.mnemonic = None;
+    }
+
+    /// Returns the access key for this button, in lowercase.
+    pub fn get_mnemonic(&self) -> Option<char> {
+        self.mnemonic.map(|(c, _)| c)
+    }
+
+    /// Sets an icon to show before the label.
+    ///
+    /// The icon is followed by a space, and is shown in bold.
+    ///
+    /// For bracketed labels (as created by [`Button::new`]), the icon is
+    /// placed inside the brackets.
+    pub fn set_icon(&mut self, icon: char) {
+        self.icon = Some(icon);
+        self.invalidate();
+    }
+
+    /// Removes the icon from this button, if any.
+    pub fn remove_icon(&mut self) {
+        self.icon = None;
         self.invalidate();
     }
 
+    /// Returns the icon shown before the label, if any.
+    pub fn get_icon(&self) -> Option<char> {
+        self.icon
+    }
+
+    // Prints a part of the label, starting at byte `start` in the label.
+    //
+    // Underlines the mnemonic if it is in this part.
+    fn print_label(
+        &self,
+        printer: &Printer,
+        x: usize,
+        text: &str,
+        start: usize,
+    ) {
+        let pos = match self.mnemonic {
+            Some((_, Some(pos)))
+                if pos >= start && pos < start + text.len() =>
+            {
+                pos - start
+            }
+            _ => {
+                printer.print((x, 0), text);
+                return;
+            }
+        };
+
+        let (head, tail) = text.split_at(pos);
+        let len = tail.chars().next().map_or(0, char::len_utf8);
+        let (key, tail) = tail.split_at(len);
+
+        printer.print((x, 0), head);
+        let x = x + head.width();
+        printer.with_effect(Effect::Underline, |printer| {
+            printer.print((x, 0), key);
+        });
+        printer.print((x + key.width(), 0), tail);
+    }
+
+    // Returns the icon, followed by a space.
+    fn icon_text(&self) -> Option<String> {
+        self.icon.map(|icon| format!("{} ", icon))
+    }
+
+    // Returns the total width of the button, including the icon.
+    fn width(&self) -> usize {
+        self.label.width() + self.icon_text().map_or(0, |icon| icon.width())
+    }
+
     fn req_size(&self) -> Vec2 {
-        Vec2::new(self.label.width(), 1)
+        Vec2::new(self.width(), 1)
     }
 
     fn invalidate(&mut self) {
@@ -142,11 +308,27 @@ impl View for Button {
             ColorStyle::primary()
         };
 
-        let offset =
-            HAlign::Center.get_offset(self.label.width(), printer.size.x);
+        let offset = HAlign::Center.get_offset(self.width(), printer.size.x);
 
         printer.with_color(style, |printer| {
-            printer.print((offset, 0), &self.label);
+            let icon = match self.icon_text() {
+                Some(icon) => icon,
+                None => {
+                    self.print_label(printer, offset, &self.label, 0);
+                    return;
+                }
+            };
+
+            // The icon goes right after the opening bracket, if any.
+            let split = if self.bracketed { 1 } else { 0 };
+            let (head, tail) = self.label.split_at(split);
+
+            printer.print((offset, 0), head);
+            let offset = offset + head.width();
+            printer.with_effect(Effect::Bold, |printer| {
+                printer.print((offset, 0), &icon);
+            });
+            self.print_label(printer, offset + icon.width(), tail, split);
         });
     }
 
@@ -167,7 +349,7 @@ impl View for Button {
 
         // eprintln!("{:?}", event);
         // eprintln!("{:?}", self.req_size());
-        let width = self.label.width();
+        let width = self.width();
         let self_offset = HAlign::Center.get_offset(width, self.last_size.x);
         match event {
             Event::Key(Key::Enter) => {
@@ -194,7 +376,7 @@ impl View for Button {
     }
 
     fn important_area(&self, view_size: Vec2) -> Rect {
-        let width = self.label.width();
+        let width = self.width();
         let offset = HAlign::Center.get_offset(width, view_size.x);
 
         Rect::from_size((offset, 0), (width, 1))
@@ -204,3 +386,53 @@ impl View for Button {
         self.invalidated
     }
 }
+
+// Removes the `&` markers from the label.
+//
+// Returns the mnemonic, in lowercase, and its byte position in the label.
+fn parse_mnemonic(label: &str) -> (String, Option<(char, usize)>) {
+    let mut result = String::with_capacity(label.len());
+    let mut mnemonic = None;
+
+    let mut chars = label.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek().copied()) {
+            ('&', Some('&')) => {
+                chars.next();
+                result.push('&');
+            }
+            ('&', Some(next)) => {
+                chars.next();
+                if mnemonic.is_none() {
+                    let key = next.to_lowercase().next().unwrap_or(next);
+                    mnemonic = Some((key, result.len()));
+                }
+                result.push(next);
+            }
+            _ => result.push(c),
+        }
+    }
+
+    (result, mnemonic)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mnemonic_markers() {
+        assert_eq!(parse_mnemonic("&Quit"), ("Quit".into(), Some(('q', 0))));
+        assert_eq!(
+            parse_mnemonic("Fish && &Chips"),
+            ("Fish & Chips".into(), Some(('c', 7)))
+        );
+        assert_eq!(parse_mnemonic("Trailing&"), ("Trailing&".into(), None));
+
+        let mut button = Button::new("Open file", |_| ());
+        button.set_mnemonic('F');
+        assert_eq!(button.mnemonic, Some(('f', Some(6))));
+        button.set_label("Reload");
+        assert_eq!(button.mnemonic, Some(('f', None)));
+    }
+}
@@ -5,9 +5,11 @@ use crate::{
     view::{CannotFocus, Selector, View, ViewNotFound},
     Printer, Vec2, With,
 };
+use std::rc::Rc;
 
 // Define this type separately to appease the Clippy god
 type CallOnAny<T> = Box<dyn for<'a> FnMut(&mut T, &Selector, AnyCb<'a>)>;
+type OnEvent<T> = Box<dyn FnMut(&mut T, Event, Vec2) -> EventResult>;
 
 /// A blank view that forwards calls to closures.
 ///
@@ -18,7 +20,7 @@ type CallOnAny<T> = Box<dyn for<'a> FnMut(&mut T, &Selector, AnyCb<'a>)>;
 /// ```rust
 /// use cursive_core::event::{Event, EventResult, Key};
 /// use cursive_core::views::{Canvas, Dialog};
-/// use unicode_width::UnicodeWidthStr; // To get the width of some text.
+/// use cursive_core::utils::width::TextWidth; // To get the width of some text.
 ///
 /// // Build a canvas around a string.
 /// let state = String::new();
@@ -42,11 +44,17 @@ type CallOnAny<T> = Box<dyn for<'a> FnMut(&mut T, &Selector, AnyCb<'a>)>;
 ///     })
 ///     .with_required_size(|text, _constraints| (text.width(), 1).into());
 /// ```
+///
+/// Use [`Canvas::builder`] to only set the closures you need, with defaults
+/// more suited to custom widgets.
 pub struct Canvas<T> {
     state: T,
 
+    // Size given during the last layout.
+    last_size: Vec2,
+
     draw: Box<dyn Fn(&T, &Printer)>,
-    on_event: Box<dyn FnMut(&mut T, Event) -> EventResult>,
+    on_event: OnEvent<T>,
     required_size: Box<dyn FnMut(&mut T, Vec2) -> Vec2>,
     layout: Box<dyn FnMut(&mut T, Vec2)>,
     take_focus:
@@ -81,8 +89,9 @@ impl<T> Canvas<T> {
     pub fn new(state: T) -> Self {
         Canvas {
             state,
+            last_size: Vec2::zero(),
             draw: Box::new(|_, _| ()),
-            on_event: Box::new(|_, _| EventResult::Ignored),
+            on_event: Box::new(|_, _, _| EventResult::Ignored),
             required_size: Box::new(|_, _| Vec2::new(1, 1)),
             layout: Box::new(|_, _| ()),
             take_focus: Box::new(|_, _| Err(CannotFocus)),
@@ -95,11 +104,160 @@ impl<T> Canvas<T> {
         }
     }
 
+    /// Returns a builder for a canvas around the given state.
+    ///
+    /// Unlike [`Canvas::new`], the canvas takes all the available space by
+    /// default. It still refuses focus, unless a `take_focus` closure is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::event::{Event, EventResult, MouseEvent};
+    /// use cursive_core::views::Canvas;
+    ///
+    /// // Remember where the user clicked, and draw a mark there.
+    /// let canvas = Canvas::builder(None)
+    ///     .draw(|mark: &Option<cursive_core::Vec2>, printer| {
+    ///         if let Some(mark) = *mark {
+    ///             printer.print(mark, "X");
+    ///         }
+    ///     })
+    ///     .on_event(|mark, event, size| match event {
+    ///         Event::Mouse {
+    ///             offset,
+    ///             position,
+    ///             event: MouseEvent::Press(_),
+    ///         } => {
+    ///             *mark = position
+    ///                 .checked_sub(offset)
+    ///                 .filter(|p| p.fits_in(size - (1, 1)));
+    ///             EventResult::Consumed(None)
+    ///         }
+    ///         _ => EventResult::Ignored,
+    ///     })
+    ///     .take_focus(|_, _| Ok(EventResult::Consumed(None)))
+    ///     .build();
+    /// ```
+    pub fn builder(state: T) -> CanvasBuilder<T> {
+        CanvasBuilder::new(state)
+    }
+
+    /// Gets a reference to the inner state.
+    pub fn state(&self) -> &T {
+        &self.state
+    }
+
     /// Gets a mutable reference to the inner state.
     pub fn state_mut(&mut self) -> &mut T {
         &mut self.state
     }
 
+    /// Consumes this canvas and returns the inner state.
+    pub fn into_state(self) -> T {
+        self.state
+    }
+
+    /// Returns the size given during the last layout.
+    pub fn last_size(&self) -> Vec2 {
+        self.last_size
+    }
+
+    /// Adapts this canvas to a state wrapping the current one.
+    ///
+    /// `wrap` builds the new state from the current one. `get` and
+    /// `get_mut` then give access to the current state from the new one, so
+    /// the existing closures keep working. New closures can then be set on
+    /// the returned canvas, for example to compose several canvases.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::views::Canvas;
+    ///
+    /// struct Counter {
+    ///     count: usize,
+    ///     label: &'static str,
+    /// }
+    ///
+    /// let canvas = Canvas::new(0usize)
+    ///     .with_draw(|count, printer| printer.print((0, 0), &count.to_string()))
+    ///     .map_state(
+    ///         |count| Counter { count, label: "Clicks" },
+    ///         |counter: &Counter| &counter.count,
+    ///         |counter: &mut Counter| &mut counter.count,
+    ///     );
+    ///
+    /// assert_eq!(canvas.state().label, "Clicks");
+    /// ```
+    pub fn map_state<U, W, G, M>(
+        self,
+        wrap: W,
+        get: G,
+        get_mut: M,
+    ) -> Canvas<U>
+    where
+        T: 'static,
+        W: FnOnce(T) -> U,
+        G: 'static + Fn(&U) -> &T,
+        M: 'static + Fn(&mut U) -> &mut T,
+    {
+        let get = Rc::new(get);
+        let get_mut = Rc::new(get_mut);
+
+        let Canvas {
+            state,
+            last_size,
+            draw,
+            mut on_event,
+            mut required_size,
+            mut layout,
+            mut take_focus,
+            needs_relayout,
+            mut focus_view,
+            mut call_on_any,
+            important_area,
+        } = self;
+
+        Canvas {
+            state: wrap(state),
+            last_size,
+            draw: Box::new({
+                let get = Rc::clone(&get);
+                move |u, printer| draw(get(u), printer)
+            }),
+            on_event: Box::new({
+                let get_mut = Rc::clone(&get_mut);
+                move |u, event, size| on_event(get_mut(u), event, size)
+            }),
+            required_size: Box::new({
+                let get_mut = Rc::clone(&get_mut);
+                move |u, constraint| required_size(get_mut(u), constraint)
+            }),
+            layout: Box::new({
+                let get_mut = Rc::clone(&get_mut);
+                move |u, size| layout(get_mut(u), size)
+            }),
+            take_focus: Box::new({
+                let get_mut = Rc::clone(&get_mut);
+                move |u, source| take_focus(get_mut(u), source)
+            }),
+            needs_relayout: Box::new({
+                let get = Rc::clone(&get);
+                move |u| needs_relayout(get(u))
+            }),
+            focus_view: Box::new({
+                let get_mut = Rc::clone(&get_mut);
+                move |u, selector| focus_view(get_mut(u), selector)
+            }),
+            call_on_any: Box::new(move |u, selector, cb| {
+                call_on_any(get_mut(u), selector, cb)
+            }),
+            important_area: Box::new(move |u, size| {
+                important_area(get(u), size)
+            }),
+        }
+    }
+
     /// Sets the closure for `draw(&Printer)`.
     pub fn set_draw<F>(&mut self, f: F)
     where
@@ -120,11 +278,11 @@ impl<T> Canvas<T> {
     }
 
     /// Sets the closure for `on_event(Event)`.
-    pub fn set_on_event<F>(&mut self, f: F)
+    pub fn set_on_event<F>(&mut self, mut f: F)
     where
         F: 'static + FnMut(&mut T, Event) -> EventResult,
     {
-        self.on_event = Box::new(f);
+        self.on_event = Box::new(move |state, event, _| f(state, event));
     }
 
     /// Sets the closure for `on_event(Event)`.
@@ -282,7 +440,7 @@ impl<T: 'static> View for Canvas<T> {
     }
 
     fn on_event(&mut self, event: Event) -> EventResult {
-        (self.on_event)(&mut self.state, event)
+        (self.on_event)(&mut self.state, event, self.last_size)
     }
 
     fn required_size(&mut self, constraint: Vec2) -> Vec2 {
@@ -290,6 +448,7 @@ impl<T: 'static> View for Canvas<T> {
     }
 
     fn layout(&mut self, size: Vec2) {
+        self.last_size = size;
         (self.layout)(&mut self.state, size);
     }
 
@@ -319,3 +478,137 @@ impl<T: 'static> View for Canvas<T> {
         (self.call_on_any)(&mut self.state, selector, cb);
     }
 }
+
+/// Builds a [`Canvas`], setting only the needed closures.
+///
+/// Created with [`Canvas::builder`]. Closures that are not set use these
+/// defaults:
+///
+/// * `draw` and `layout` do nothing.
+/// * `on_event` ignores all events.
+/// * `required_size` returns the constraint: the canvas takes all the
+///   available space.
+/// * `take_focus` refuses the focus.
+/// * `important_area` is the entire canvas.
+pub struct CanvasBuilder<T> {
+    canvas: Canvas<T>,
+}
+
+impl<T> CanvasBuilder<T> {
+    fn new(state: T) -> Self {
+        CanvasBuilder {
+            canvas: Canvas::new(state)
+                .with_required_size(|_, constraint| constraint),
+        }
+    }
+
+    /// Sets the closure drawing the canvas.
+    #[must_use]
+    pub fn draw<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(&T, &Printer),
+    {
+        self.canvas.set_draw(f);
+        self
+    }
+
+    /// Sets the closure handling events.
+    ///
+    /// It also receives the size given during the last layout, to find
+    /// what is under the mouse for example.
+    #[must_use]
+    pub fn on_event<F>(mut self, f: F) -> Self
+    where
+        F: 'static + FnMut(&mut T, Event, Vec2) -> EventResult,
+    {
+        self.canvas.on_event = Box::new(f);
+        self
+    }
+
+    /// Sets the closure computing the required size.
+    #[must_use]
+    pub fn required_size<F>(mut self, f: F) -> Self
+    where
+        F: 'static + FnMut(&mut T, Vec2) -> Vec2,
+    {
+        self.canvas.set_required_size(f);
+        self
+    }
+
+    /// Sets the closure called with the final size of the canvas.
+    #[must_use]
+    pub fn layout<F>(mut self, f: F) -> Self
+    where
+        F: 'static + FnMut(&mut T, Vec2),
+    {
+        self.canvas.set_layout(f);
+        self
+    }
+
+    /// Sets the closure deciding whether the canvas takes the focus.
+    #[must_use]
+    pub fn take_focus<F>(mut self, f: F) -> Self
+    where
+        F: 'static
+            + FnMut(&mut T, Direction) -> Result<EventResult, CannotFocus>,
+    {
+        self.canvas.set_take_focus(f);
+        self
+    }
+
+    /// Sets the closure returning the important area of the canvas.
+    #[must_use]
+    pub fn important_area<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(&T, Vec2) -> Rect,
+    {
+        self.canvas.set_important_area(f);
+        self
+    }
+
+    /// Builds the canvas.
+    ///
+    /// Other closures can still be set on the canvas, like `needs_relayout`
+    /// or `call_on_any`.
+    pub fn build(self) -> Canvas<T> {
+        self.canvas
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{MouseButton, MouseEvent};
+
+    #[test]
+    fn builder_defaults() {
+        let mut canvas = Canvas::builder(()).build();
+        assert_eq!(canvas.required_size(Vec2::new(7, 3)), Vec2::new(7, 3));
+        assert!(canvas.take_focus(Direction::none()).is_err());
+    }
+
+    #[test]
+    fn event_gets_size() {
+        let mut canvas = Canvas::builder(Vec::new())
+            .on_event(|clicks: &mut Vec<(Vec2, Vec2)>, event, size| {
+                if let Some(position) = event.mouse_position() {
+                    clicks.push((position, size));
+                }
+                EventResult::Consumed(None)
+            })
+            .build()
+            .map_state(|clicks| (clicks, 0), |s| &s.0, |s| &mut s.0);
+
+        canvas.layout(Vec2::new(10, 4));
+        canvas.on_event(Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(2, 1),
+            event: MouseEvent::Press(MouseButton::Left),
+        });
+        assert_eq!(canvas.last_size(), Vec2::new(10, 4));
+        assert_eq!(
+            canvas.state().0,
+            vec![(Vec2::new(2, 1), Vec2::new(10, 4))]
+        );
+    }
+}
@@ -9,6 +9,11 @@ use std::rc::Rc;
 
 /// Checkable box.
 ///
+/// Shown as `[ ]` or `[X]`, and toggled with `<Space>`, `<Enter>` or a click.
+///
+/// The checkbox has no label: put it next to a `TextView` in a
+/// `LinearLayout`, or use a `ListView` to build a checklist.
+///
 /// # Examples
 ///
 /// ```
@@ -17,6 +22,22 @@ use std::rc::Rc;
 ///
 /// let checkbox = Checkbox::new().checked().with_name("check");
 /// ```
+///
+/// A checklist:
+///
+/// ```
+/// use cursive_core::views::{Checkbox, ListView};
+///
+/// let list = ListView::new()
+///     .child("Milk", Checkbox::new())
+///     .child("Eggs", Checkbox::new().checked())
+///     .child(
+///         "Bread",
+///         Checkbox::new().on_change(|_, checked| {
+///             println!("Bread: {}", checked);
+///         }),
+///     );
+/// ```
 pub struct Checkbox {
     checked: bool,
     enabled: bool,
@@ -11,11 +11,34 @@ use crate::{
 /// (when the focus exits this view, it will come back the other side).
 ///
 /// It can be configured to wrap Tab (and Shift+Tab) keys, and/or Arrow keys.
+/// Horizontal (left/right) and vertical (up/down) arrows are configured
+/// independently.
+///
+/// It can also act as a focus trap: navigation keys ignored by the wrapped
+/// view are then consumed, so focus never leaves it. This is what modal
+/// dialogs usually need.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive_core::views::{CircularFocus, Dialog, TextView};
+/// let dialog = CircularFocus::new(
+///     Dialog::around(TextView::new("Are you sure?"))
+///         .button("Yes", |s| s.quit())
+///         .button("No", |s| {
+///             s.pop_layer();
+///         }),
+/// )
+/// .wrap_tab()
+/// .wrap_arrows(true, false)
+/// .trap_focus(true);
+/// ```
 pub struct CircularFocus<T: View> {
     view: T,
     wrap_tab: bool,
     wrap_up_down: bool,
     wrap_left_right: bool,
+    trap_focus: bool,
 }
 
 impl<T: View> CircularFocus<T> {
@@ -29,6 +52,7 @@ impl<T: View> CircularFocus<T> {
             wrap_tab: false,
             wrap_left_right: false,
             wrap_up_down: false,
+            trap_focus: false,
         }
     }
 
@@ -52,10 +76,19 @@ impl<T: View> CircularFocus<T> {
         self.wrap_up_down
     }
 
-    /// Make this view now wrap focus around when arrow keys are pressed.
+    /// Returns `true` if this view keeps the focus from escaping.
+    pub fn traps_focus(&self) -> bool {
+        self.trap_focus
+    }
+
+    /// Configures focus wrapping for the arrow keys.
+    ///
+    /// `horizontal` controls the left/right keys,
+    /// and `vertical` the up/down keys.
     #[must_use]
-    pub fn wrap_arrows(self) -> Self {
-        self.with_wrap_arrows(true)
+    pub fn wrap_arrows(self, horizontal: bool, vertical: bool) -> Self {
+        self.with_wrap_left_right(horizontal)
+            .with_wrap_up_down(vertical)
     }
 
     /// Make this view now wrap focus around when the up/down keys are pressed.
@@ -125,14 +158,44 @@ impl<T: View> CircularFocus<T> {
         self.wrap_left_right = wrap_left_right;
     }
 
+    /// Prevents the focus from leaving this view.
+    ///
+    /// When enabled, Tab, Shift+Tab and arrow keys are always consumed, even
+    /// when the wrapped view ignores them and no wrapping is configured for
+    /// them. They never reach the parent view or global callbacks.
+    pub fn set_trap_focus(&mut self, trap_focus: bool) {
+        self.trap_focus = trap_focus;
+    }
+
+    /// Prevents the focus from leaving this view.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn trap_focus(self, trap_focus: bool) -> Self {
+        self.with(|s| s.set_trap_focus(trap_focus))
+    }
+
     inner_getters!(self.view: T);
 }
 
+fn is_navigation(event: &Event) -> bool {
+    matches!(
+        *event,
+        Event::Key(Key::Tab)
+            | Event::Shift(Key::Tab)
+            | Event::Key(Key::Left)
+            | Event::Key(Key::Right)
+            | Event::Key(Key::Up)
+            | Event::Key(Key::Down)
+    )
+}
+
 impl<T: View> ViewWrapper for CircularFocus<T> {
     wrap_impl!(self.view: T);
 
     fn wrap_on_event(&mut self, event: Event) -> EventResult {
-        match (self.view.on_event(event.clone()), event) {
+        let trapped = self.trap_focus && is_navigation(&event);
+        let result = match (self.view.on_event(event.clone()), event) {
             (EventResult::Ignored, Event::Key(Key::Tab)) if self.wrap_tab => {
                 // Focus comes back!
                 self.view
@@ -180,6 +243,57 @@ impl<T: View> ViewWrapper for CircularFocus<T> {
                     .unwrap_or(EventResult::Ignored)
             }
             (other, _) => other,
+        };
+
+        match result {
+            EventResult::Ignored if trapped => EventResult::Consumed(None),
+            other => other,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::{Button, LinearLayout};
+
+    fn buttons() -> LinearLayout {
+        LinearLayout::horizontal()
+            .child(Button::new("A", |_| ()))
+            .child(Button::new("B", |_| ()))
+            .child(Button::new("C", |_| ()))
+    }
+
+    #[test]
+    fn wrap_left_right() {
+        let mut view = CircularFocus::new(buttons()).wrap_arrows(true, false);
+        assert!(view.wraps_left_right());
+        assert!(!view.wraps_up_down());
+
+        view.get_inner_mut().set_focus_index(2).unwrap();
+        assert!(view.on_event(Event::Key(Key::Right)).is_consumed());
+        assert_eq!(view.get_inner().get_focus_index(), 0);
+
+        assert!(view.on_event(Event::Key(Key::Left)).is_consumed());
+        assert_eq!(view.get_inner().get_focus_index(), 2);
+
+        // Vertical arrows are not wrapped.
+        assert!(!view.on_event(Event::Key(Key::Down)).is_consumed());
+    }
+
+    #[test]
+    fn trap_focus() {
+        let mut view = CircularFocus::new(buttons()).trap_focus(true);
+        assert!(view.traps_focus());
+
+        view.get_inner_mut().set_focus_index(2).unwrap();
+        assert!(view.on_event(Event::Key(Key::Tab)).is_consumed());
+        assert_eq!(view.get_inner().get_focus_index(), 2);
+
+        assert!(view.on_event(Event::Key(Key::Down)).is_consumed());
+        assert_eq!(view.get_inner().get_focus_index(), 2);
+
+        // Other events still go through.
+        assert!(!view.on_event(Event::Char('a')).is_consumed());
+    }
+}
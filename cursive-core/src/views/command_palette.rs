@@ -0,0 +1,311 @@
+use crate::{
+    direction::Direction,
+    event::{Callback, Event, EventResult, Key},
+    menu::{self, PATH_SEPARATOR},
+    rect::Rect,
+    theme::{ColorStyle, Effect, Style},
+    utils::markup::StyledString,
+    view::{CannotFocus, View},
+    views::{menu_popup::fuzzy_match, EditView, ScrollView, SelectView},
+    Cursive, Printer, Vec2,
+};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Maximum number of commands visible at once.
+const MAX_VISIBLE: usize = 10;
+
+/// An entry in a [`CommandPalette`].
+#[derive(Clone)]
+pub struct Command {
+    /// Name of the command. This is what the query is matched against.
+    pub name: String,
+
+    /// Short description, shown next to the name.
+    pub description: String,
+
+    /// Callback to run when the command is selected.
+    pub cb: Callback,
+}
+
+impl Command {
+    /// Creates a new command.
+    pub fn new<N, D, F>(name: N, description: D, cb: F) -> Self
+    where
+        N: Into<String>,
+        D: Into<String>,
+        F: 'static + Fn(&mut Cursive),
+    {
+        Command {
+            name: name.into(),
+            description: description.into(),
+            cb: Callback::from_fn(cb),
+        }
+    }
+}
+
+/// Searchable list of commands.
+///
+/// Shows a query input above the commands matching it. Matches are fuzzy:
+/// the characters of the query must appear in order in the command name.
+///
+/// <Enter> runs the selected command, and <Esc> closes the palette. Both
+/// expect the palette to be the top layer.
+///
+/// Usually shown with [`Cursive::show_command_palette`], which includes
+/// the registered commands and the menubar entries.
+///
+/// [`Cursive::show_command_palette`]: crate::Cursive::show_command_palette
+pub struct CommandPalette {
+    query: EditView,
+    list: ScrollView<SelectView<usize>>,
+
+    commands: Rc<Vec<Command>>,
+
+    // How many times each command was run, by name.
+    usage: HashMap<String, usize>,
+}
+
+impl CommandPalette {
+    /// Creates a new palette with the given commands.
+    pub fn new(commands: Vec<Command>) -> Self {
+        Self::with_usage(commands, HashMap::new())
+    }
+
+    /// Creates a new palette, ranking the most used commands first.
+    ///
+    /// `usage` gives how many times each command was run, by name.
+    pub fn with_usage(
+        commands: Vec<Command>,
+        usage: HashMap<String, usize>,
+    ) -> Self {
+        let commands = Rc::new(commands);
+
+        let mut select = SelectView::new();
+        select.set_on_submit({
+            let commands = Rc::clone(&commands);
+            move |s: &mut Cursive, &i: &usize| {
+                let command = &commands[i];
+                s.pop_layer();
+                s.record_command_usage(&command.name);
+                (command.cb)(s);
+            }
+        });
+
+        let mut palette = CommandPalette {
+            query: EditView::new(),
+            list: ScrollView::new(select),
+            commands,
+            usage,
+        };
+        palette.update_list();
+        palette
+    }
+
+    /// Returns the current query.
+    pub fn get_query(&self) -> Rc<String> {
+        self.query.get_content()
+    }
+
+    /// Returns the name of the selected command, if any.
+    pub fn selected(&self) -> Option<&str> {
+        self.list
+            .get_inner()
+            .selection()
+            .map(|i| self.commands[*i].name.as_str())
+    }
+
+    // Shows the commands matching the current query, best first.
+    fn update_list(&mut self) {
+        let query = self.query.get_content();
+
+        let mut matches: Vec<(usize, Vec<usize>, i64)> = self
+            .commands
+            .iter()
+            .enumerate()
+            .filter_map(|(i, command)| {
+                let positions = fuzzy_match(&command.name, &query)?;
+                let score = score(&command.name, &positions);
+                Some((i, positions, score))
+            })
+            .collect();
+
+        // Stable sort: ties keep the registration order.
+        matches.sort_by_key(|&(i, _, score)| {
+            let usage = self.usage.get(&self.commands[i].name);
+            (-score, std::cmp::Reverse(usage.copied().unwrap_or(0)))
+        });
+
+        let select = self.list.get_inner_mut();
+        select.clear();
+        for (i, positions, _) in matches {
+            select.add_item(label(&self.commands[i], &positions), i);
+        }
+        self.list.scroll_to_top();
+    }
+
+    fn list_height(&self) -> usize {
+        usize::min(self.list.get_inner().len(), MAX_VISIBLE)
+    }
+}
+
+// Returns the label for a command, with the matched characters underlined.
+fn label(command: &Command, positions: &[usize]) -> StyledString {
+    let mut label = StyledString::new();
+    for (i, c) in command.name.chars().enumerate() {
+        if positions.contains(&i) {
+            label.append_styled(c.to_string(), Effect::Underline);
+        } else {
+            label.append_plain(c.to_string());
+        }
+    }
+    if !command.description.is_empty() {
+        label.append_plain("  ");
+        label.append_styled(
+            &command.description,
+            Style::from(ColorStyle::secondary()),
+        );
+    }
+    label
+}
+
+// Ranks a match: higher is better.
+//
+// Favors consecutive characters, characters at the start of words, and
+// matches close to the start of the name.
+fn score(name: &str, positions: &[usize]) -> i64 {
+    let chars: Vec<char> = name.chars().collect();
+
+    let mut score = 0;
+    for (n, &i) in positions.iter().enumerate() {
+        score += 1;
+        if n > 0 && positions[n - 1] + 1 == i {
+            score += 4;
+        }
+        if i == 0 || !chars[i - 1].is_alphanumeric() {
+            score += 3;
+        }
+    }
+    score - positions.first().map_or(0, |&i| i as i64)
+}
+
+/// Returns a command for every enabled leaf in `tree`.
+///
+/// Command names are the full path to the leaf, like `File > Save As`.
+pub(crate) fn menu_commands(tree: &menu::Tree) -> Vec<Command> {
+    tree.leaves()
+        .into_iter()
+        .map(|(path, cb)| Command {
+            name: path.join(PATH_SEPARATOR),
+            description: String::new(),
+            cb,
+        })
+        .collect()
+}
+
+impl View for CommandPalette {
+    fn draw(&self, printer: &Printer) {
+        self.query.draw(&printer.cropped((printer.size.x, 1)));
+        self.list.draw(&printer.offset((0, 1)));
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.query.layout(Vec2::new(size.x, 1));
+        self.list.layout(size.saturating_sub((0, 1)));
+    }
+
+    fn required_size(&mut self, constraint: Vec2) -> Vec2 {
+        let list = self.list.required_size(constraint.saturating_sub((0, 1)));
+        Vec2::new(
+            usize::max(list.x, 40),
+            1 + usize::min(list.y, self.list_height()),
+        )
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        match event {
+            Event::Key(Key::Esc) => {
+                return EventResult::with_cb(|s| {
+                    s.pop_layer();
+                });
+            }
+            Event::Key(Key::Up)
+            | Event::Key(Key::Down)
+            | Event::Key(Key::PageUp)
+            | Event::Key(Key::PageDown)
+            | Event::Key(Key::Enter) => return self.list.on_event(event),
+            Event::Mouse { .. } => {
+                return self.list.on_event(event.relativized((0, 1)));
+            }
+            _ => (),
+        }
+
+        let query = self.query.get_content();
+        let result = self.query.on_event(event);
+        if self.query.get_content() != query {
+            self.update_list();
+        }
+        result
+    }
+
+    fn take_focus(
+        &mut self,
+        _: Direction,
+    ) -> Result<EventResult, CannotFocus> {
+        Ok(EventResult::Consumed(None))
+    }
+
+    fn important_area(&self, size: Vec2) -> Rect {
+        Rect::from_size((0, 0), (size.x, 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn score_favors_word_starts() {
+        let name = "File > Save As";
+        let start = fuzzy_match(name, "sa").unwrap();
+        assert_eq!(start, vec![7, 8]);
+
+        // Same characters, but scattered.
+        assert!(score(name, &start) > score(name, &[7, 13]));
+        assert!(score("Save", &[0, 1]) > score(name, &start));
+    }
+
+    #[test]
+    fn menu_paths() {
+        let tree = menu::Tree::new()
+            .subtree(
+                "File",
+                menu::Tree::new()
+                    .leaf("Open", |_| ())
+                    .delimiter()
+                    .item(menu::Item::leaf("Save", |_| ()).disabled()),
+            )
+            .leaf("Quit", |_| ());
+
+        let names: Vec<_> = menu_commands(&tree)
+            .into_iter()
+            .map(|command| command.name)
+            .collect();
+        assert_eq!(names, vec!["File > Open", "Quit"]);
+    }
+
+    #[test]
+    fn ranks_matches() {
+        let mut palette = CommandPalette::new(vec![
+            Command::new("Select all", "", |_| ()),
+            Command::new("Save", "", |_| ()),
+            Command::new("Quit", "", |_| ()),
+        ]);
+        assert_eq!(palette.selected(), Some("Select all"));
+
+        palette.on_event(Event::Char('s'));
+        palette.on_event(Event::Char('a'));
+        assert_eq!(palette.list.get_inner().len(), 2);
+        assert_eq!(palette.selected(), Some("Save"));
+    }
+}
@@ -1,20 +1,104 @@
+use crate::direction::Direction;
+use crate::event::{Event, EventResult};
 use crate::logger;
-use crate::theme;
-use crate::view::View;
+use crate::theme::{self, ColorStyle, Effect};
+use crate::view::{CannotFocus, View};
 use crate::Printer;
 use crate::Vec2;
+use crate::With;
 
-use unicode_width::UnicodeWidthStr;
+use crate::utils::width::TextWidth;
+
+// Width of "23:59:59.123 | [ERROR] "
+const PREFIX_WIDTH: usize = 23;
 
 /// View used for debugging, showing logs.
+///
+/// Records can be filtered by level and by target, and matches of a search
+/// string are highlighted.
+///
+/// When focused, keys `1` to `5` only show records from `Error` up to
+/// `Trace`.
+///
+/// Each level uses a color from the theme palette, with the keys
+/// `log_error`, `log_warn`, `log_info`, `log_debug` and `log_trace`.
 pub struct DebugView {
     // TODO: wrap log lines if needed, and save the line splits here.
+    max_level: log::LevelFilter,
+    target_filter: String,
+    search: String,
 }
 
 impl DebugView {
     /// Creates a new DebugView.
     pub fn new() -> Self {
-        DebugView {}
+        DebugView {
+            max_level: log::LevelFilter::Trace,
+            target_filter: String::new(),
+            search: String::new(),
+        }
+    }
+
+    /// Only shows records at this level or more severe.
+    pub fn set_max_level(&mut self, max_level: log::LevelFilter) {
+        self.max_level = max_level;
+    }
+
+    /// Only shows records at this level or more severe.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn max_level(self, max_level: log::LevelFilter) -> Self {
+        self.with(|s| s.set_max_level(max_level))
+    }
+
+    /// Returns the most verbose level shown.
+    pub fn get_max_level(&self) -> log::LevelFilter {
+        self.max_level
+    }
+
+    /// Only shows records whose target contains the given string.
+    ///
+    /// An empty string shows all targets.
+    pub fn set_target_filter<S: Into<String>>(&mut self, filter: S) {
+        self.target_filter = filter.into();
+    }
+
+    /// Only shows records whose target contains the given string.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn target_filter<S: Into<String>>(self, filter: S) -> Self {
+        self.with(|s| s.set_target_filter(filter))
+    }
+
+    /// Highlights the given string in messages.
+    ///
+    /// An empty string highlights nothing.
+    pub fn set_search<S: Into<String>>(&mut self, search: S) {
+        self.search = search.into();
+    }
+
+    /// Highlights the given string in messages.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn search<S: Into<String>>(self, search: S) -> Self {
+        self.with(|s| s.set_search(search))
+    }
+
+    fn is_visible(&self, record: &logger::Record) -> bool {
+        record.level <= self.max_level
+            && record.target.contains(&self.target_filter)
+    }
+
+    // Text printed before the message.
+    fn target_prefix(record: &logger::Record) -> String {
+        if record.target.is_empty() {
+            String::new()
+        } else {
+            format!("{}: ", record.target)
+        }
     }
 }
 
@@ -24,55 +108,84 @@ impl Default for DebugView {
     }
 }
 
+fn level_color(level: log::Level, palette: &theme::Palette) -> theme::Color {
+    let (key, default) = match level {
+        log::Level::Error => ("log_error", theme::BaseColor::Red.dark()),
+        log::Level::Warn => ("log_warn", theme::BaseColor::Yellow.dark()),
+        log::Level::Info => ("log_info", theme::BaseColor::Black.light()),
+        log::Level::Debug => ("log_debug", theme::BaseColor::Green.dark()),
+        log::Level::Trace => ("log_trace", theme::BaseColor::Blue.dark()),
+    };
+    palette.custom(key).copied().unwrap_or(default)
+}
+
+// Returns the byte ranges of `search` in `text`.
+fn find_matches(text: &str, search: &str) -> Vec<(usize, usize)> {
+    if search.is_empty() {
+        return Vec::new();
+    }
+    text.match_indices(search)
+        .map(|(start, m)| (start, start + m.len()))
+        .collect()
+}
+
 impl View for DebugView {
     fn draw(&self, printer: &Printer) {
-        let logs = logger::LOGS.lock().unwrap();
+        let logs = logger::logs();
+        let records: Vec<&logger::Record> = logs
+            .iter()
+            .filter(|record| self.is_visible(record))
+            .collect();
+
         // Only print the last logs, so skip what doesn't fit
-        let skipped = logs.len().saturating_sub(printer.size.y);
+        let skipped = records.len().saturating_sub(printer.size.y);
 
         let format = time::format_description::parse(
             "[hour]:[minute]:[second].[subsecond digits:3]",
         )
         .unwrap();
 
-        for (i, record) in logs.iter().skip(skipped).enumerate() {
-            // TODO: Apply style to message? (Ex: errors in bold?)
+        for (i, record) in records.iter().skip(skipped).enumerate() {
             // TODO: customizable time format? (24h/AM-PM)
             let formatted = record
                 .time
                 .format(&format)
                 .unwrap_or_else(|_| String::new());
-            printer.print(
-                (0, i),
-                &format!("{} | [     ] {}", formatted, record.message),
-            );
-            let color = match record.level {
-                log::Level::Error => theme::BaseColor::Red.dark(),
-                log::Level::Warn => theme::BaseColor::Yellow.dark(),
-                log::Level::Info => theme::BaseColor::Black.light(),
-                log::Level::Debug => theme::BaseColor::Green.dark(),
-                log::Level::Trace => theme::BaseColor::Blue.dark(),
-            };
+            printer.print((0, i), &format!("{} | [     ] ", formatted));
+
+            let color = level_color(record.level, &printer.theme.palette);
             printer.with_color(color.into(), |printer| {
                 printer.print((16, i), &format!("{:5}", record.level))
             });
+
+            let target = Self::target_prefix(record);
+            printer.with_color(ColorStyle::secondary(), |printer| {
+                printer.print((PREFIX_WIDTH, i), &target);
+            });
+
+            let x = PREFIX_WIDTH + target.width();
+            printer.print((x, i), &record.message);
+            for (start, end) in find_matches(&record.message, &self.search) {
+                let offset = record.message[..start].width();
+                printer.with_effect(Effect::Reverse, |printer| {
+                    printer
+                        .print((x + offset, i), &record.message[start..end]);
+                });
+            }
         }
     }
 
     fn required_size(&mut self, _constraint: Vec2) -> Vec2 {
-        // TODO: read the logs, and compute the required size to print it.
-        let logs = logger::LOGS.lock().unwrap();
-
-        let level_width = 8; // Width of "[ERROR] "
-        let time_width = 16; // Width of "23:59:59.123 | "
+        let logs = logger::logs();
+        let records = logs.iter().filter(|record| self.is_visible(record));
 
         // The longest line sets the width
-        let w = logs
-            .iter()
-            .map(|record| record.message.width() + level_width + time_width)
-            .max()
-            .unwrap_or(1);
-        let h = logs.len();
+        let (w, h) = records.fold((1, 0), |(w, h), record| {
+            let width = PREFIX_WIDTH
+                + Self::target_prefix(record).width()
+                + record.message.width();
+            (usize::max(w, width), h + 1)
+        });
 
         Vec2::new(w, h)
     }
@@ -80,4 +193,55 @@ impl View for DebugView {
     fn layout(&mut self, _size: Vec2) {
         // Uh?
     }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        let max_level = match event {
+            Event::Char('1') => log::LevelFilter::Error,
+            Event::Char('2') => log::LevelFilter::Warn,
+            Event::Char('3') => log::LevelFilter::Info,
+            Event::Char('4') => log::LevelFilter::Debug,
+            Event::Char('5') => log::LevelFilter::Trace,
+            _ => return EventResult::Ignored,
+        };
+        self.set_max_level(max_level);
+        EventResult::Consumed(None)
+    }
+
+    fn take_focus(
+        &mut self,
+        _: Direction,
+    ) -> Result<EventResult, CannotFocus> {
+        Ok(EventResult::Consumed(None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(level: log::Level, target: &str) -> logger::Record {
+        logger::Record::new(
+            level,
+            target.to_string(),
+            time::OffsetDateTime::UNIX_EPOCH,
+            String::new(),
+        )
+    }
+
+    #[test]
+    fn filters() {
+        let mut view = DebugView::new().target_filter("net");
+        view.on_event(Event::Char('2'));
+        assert_eq!(view.get_max_level(), log::LevelFilter::Warn);
+
+        assert!(view.is_visible(&record(log::Level::Error, "app::net")));
+        assert!(!view.is_visible(&record(log::Level::Info, "app::net")));
+        assert!(!view.is_visible(&record(log::Level::Error, "app::ui")));
+    }
+
+    #[test]
+    fn search_matches() {
+        assert_eq!(find_matches("a ab ab", "ab"), vec![(2, 4), (5, 7)]);
+        assert!(find_matches("abc", "").is_empty());
+    }
 }
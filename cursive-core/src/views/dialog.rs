@@ -1,19 +1,25 @@
+use crate::utils::width::TextWidth;
 use crate::{
     align::*,
     direction::{Absolute, Direction, Relative},
-    event::{AnyCb, Event, EventResult, Key},
+    event::{
+        AnyCb, Event, EventResult, Key, Modifier, MouseButton, MouseEvent,
+    },
     rect::Rect,
     theme::ColorStyle,
     utils::markup::StyledString,
     view::{
-        CannotFocus, IntoBoxedView, Margins, Selector, View, ViewNotFound,
+        CannotFocus, FocusWrap, IntoBoxedView, Margins, Scrollable, Selector,
+        View, ViewNotFound,
+    },
+    views::{
+        BoxedView, Button, DummyView, Form, FormData, LastSizeView, NamedView,
+        ReaderView, TextView,
     },
-    views::{BoxedView, Button, DummyView, LastSizeView, TextView},
-    Cursive, Printer, Vec2, With,
+    Cursive, Printer, Vec2, With, XY,
 };
 use std::cell::Cell;
 use std::cmp::{max, min};
-use unicode_width::UnicodeWidthStr;
 
 /// Identifies currently focused element in [`Dialog`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -27,6 +33,8 @@ pub enum DialogFocus {
 struct ChildButton {
     button: LastSizeView<Button>,
     offset: Cell<Vec2>,
+    // Event that directly activates this button.
+    shortcut: Option<Event>,
 }
 
 impl ChildButton {
@@ -34,13 +42,27 @@ impl ChildButton {
     where
         F: 'static + Fn(&mut Cursive),
     {
+        Self::around(Button::new(label, cb))
+    }
+
+    fn around(button: Button) -> Self {
         ChildButton {
-            button: LastSizeView::new(Button::new(label, cb)),
+            button: LastSizeView::new(button),
             offset: Cell::new(Vec2::zero()),
+            shortcut: None,
         }
     }
 }
 
+// Returns a short hint for the given shortcut, to be shown in a label.
+fn shortcut_hint(event: &Event) -> Option<String> {
+    match *event {
+        Event::Char(c) => Some(c.to_uppercase().collect()),
+        Event::Key(key) => Some(format!("{:?}", key)),
+        _ => None,
+    }
+}
+
 /// Popup-like view with a main content, and optional buttons under it.
 ///
 /// # Examples
@@ -76,10 +98,44 @@ pub struct Dialog {
     // How to align the buttons under the view.
     align: Align,
 
+    // What to do when Tab is pressed on the last button.
+    focus_wrap: FocusWrap,
+
+    // Builds the event activating a button from its mnemonic.
+    mnemonic_event: fn(char) -> Event,
+
+    // If `true`, the user can resize the dialog.
+    resizable: bool,
+
+    // Size chosen by the user, if the dialog was resized.
+    user_size: Option<Vec2>,
+
+    // The dialog never gets smaller than this, including borders.
+    min_size: Vec2,
+
+    // Ongoing mouse resize, if any.
+    resize_drag: Option<ResizeDrag>,
+
+    // Size from the last layout, including borders.
+    last_size: Vec2,
+
     // `true` when we needs to relayout
     invalidated: bool,
 }
 
+// A resize started by dragging the bottom or right border.
+#[derive(Clone, Copy, Debug)]
+struct ResizeDrag {
+    // Absolute position where the drag started.
+    start: Vec2,
+
+    // Size of the dialog when the drag started.
+    start_size: Vec2,
+
+    // Axes being resized: `x` for the right border, `y` for the bottom one.
+    axes: XY<bool>,
+}
+
 new_default!(Dialog);
 
 impl Dialog {
@@ -101,6 +157,13 @@ impl Dialog {
             padding: Margins::lr(1, 1),
             borders: Margins::lrtb(1, 1, 1, 1),
             align: Align::top_right(),
+            focus_wrap: FocusWrap::default(),
+            mnemonic_event: Event::AltChar,
+            resizable: false,
+            user_size: None,
+            min_size: Vec2::zero(),
+            resize_drag: None,
+            last_size: Vec2::zero(),
             invalidated: true,
         }
     }
@@ -190,6 +253,66 @@ impl Dialog {
         Self::around(TextView::new(text))
     }
 
+    /// Convenient method to create a dialog showing long text.
+    ///
+    /// The text is shown in a [`ReaderView`], which limits its width for
+    /// better readability, inside a `ScrollView`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::Dialog;
+    ///
+    /// let dialog = Dialog::text_reader("Once upon a time...")
+    ///     .dismiss_button("Close");
+    /// ```
+    pub fn text_reader<S: Into<StyledString>>(text: S) -> Self {
+        Self::around(ReaderView::new(TextView::new(text)).scrollable())
+    }
+
+    /// Convenient method to create a dialog around a form.
+    ///
+    /// The dialog gets an `Ok` button, which runs the form validation. If
+    /// every rule passes, `cb` is called with the collected values.
+    /// Otherwise, errors are shown next to the invalid fields.
+    ///
+    /// The form is wrapped in a [`NamedView`] called `name`, which the button
+    /// uses to find it again. It must be unique among the visible views.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::{Checkbox, Dialog, EditView, Form};
+    ///
+    /// let form = Form::new()
+    ///     .field("name", EditView::new())
+    ///     .field("admin", Checkbox::new());
+    ///
+    /// let dialog = Dialog::form("signup", form, |s, data| {
+    ///     let name = data.get_str("name").unwrap_or_default().to_string();
+    ///     s.pop_layer();
+    ///     s.add_layer(Dialog::info(format!("Welcome, {}!", name)));
+    /// });
+    /// ```
+    pub fn form<S, F>(name: S, form: Form, cb: F) -> Self
+    where
+        S: Into<String>,
+        F: 'static + Fn(&mut Cursive, &FormData),
+    {
+        let name = name.into();
+        Self::around(NamedView::new(name.clone(), form)).button(
+            "Ok",
+            move |s| {
+                let data = s
+                    .call_on_name(&name, Form::validated_data)
+                    .and_then(|data| data);
+                if let Some(data) = data {
+                    cb(s, &data);
+                }
+            },
+        )
+    }
+
     /// Convenient method to create an infobox.
     ///
     /// It will contain the given text and a `Ok` dismiss button.
@@ -225,6 +348,100 @@ impl Dialog {
         self.invalidate();
     }
 
+    /// Adds a button with an access key marked in the label.
+    ///
+    /// The character following a `&` is the mnemonic of the button: it is
+    /// underlined, and pressing Alt with this key activates the button from
+    /// anywhere in the dialog. See [`Button::with_mnemonic`].
+    pub fn add_mnemonic_button<F, S>(&mut self, label: S, cb: F)
+    where
+        F: 'static + Fn(&mut Cursive),
+        S: Into<String>,
+    {
+        self.buttons
+            .push(ChildButton::around(Button::with_mnemonic(label, cb)));
+        self.invalidate();
+    }
+
+    /// Adds a button with an access key marked in the label.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::event::{Event, EventResult};
+    /// use cursive_core::view::View;
+    /// use cursive_core::views::{Dialog, EditView};
+    ///
+    /// let mut dialog = Dialog::around(EditView::new())
+    ///     .mnemonic_button("&Ok", |s| s.quit())
+    ///     .mnemonic_button("&Cancel", |s| {
+    ///         s.pop_layer();
+    ///     });
+    ///
+    /// // The button is activated even though the edit view has focus.
+    /// let result = dialog.on_event(Event::AltChar('c'));
+    /// assert!(matches!(result, EventResult::Consumed(Some(_))));
+    /// ```
+    #[must_use]
+    pub fn mnemonic_button<F, S>(self, label: S, cb: F) -> Self
+    where
+        F: 'static + Fn(&mut Cursive),
+        S: Into<String>,
+    {
+        self.with(|s| s.add_mnemonic_button(label, cb))
+    }
+
+    /// Adds a button activated by the given shortcut.
+    ///
+    /// Pressing the shortcut anywhere in the dialog triggers the button, as
+    /// long as the focused view does not use this event itself. The shortcut
+    /// is shown after the label, like `<Yes (Y)>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::Dialog;
+    ///
+    /// let dialog = Dialog::text("Delete this file?")
+    ///     .button_with_key("Yes", 'y', |s| s.quit())
+    ///     .button_with_key("No", 'n', |s| {
+    ///         s.pop_layer();
+    ///     });
+    /// ```
+    pub fn add_button_with_key<F, S, E>(&mut self, label: S, key: E, cb: F)
+    where
+        F: 'static + Fn(&mut Cursive),
+        S: Into<String>,
+        E: Into<Event>,
+    {
+        let key = key.into();
+        let label = label.into();
+        let label = match shortcut_hint(&key) {
+            Some(hint) => format!("{} ({})", label, hint),
+            None => label,
+        };
+
+        let mut button = ChildButton::new(label, cb);
+        button.shortcut = Some(key);
+        self.buttons.push(button);
+        self.invalidate();
+    }
+
+    /// Adds a button activated by the given shortcut.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn button_with_key<F, S, E>(self, label: S, key: E, cb: F) -> Self
+    where
+        F: 'static + Fn(&mut Cursive),
+        S: Into<String>,
+        E: Into<Event>,
+    {
+        self.with(|s| s.add_button_with_key(label, key, cb))
+    }
+
     /// Returns the number of buttons on this dialog.
     pub fn buttons_len(&self) -> usize {
         self.buttons.len()
@@ -444,6 +661,178 @@ impl Dialog {
         self.padding.right = padding;
     }
 
+    /// Sets what happens when Tab is pressed on the last button (or
+    /// Shift+Tab on the content).
+    ///
+    /// Defaults to `FocusWrap::Propagate`.
+    pub fn set_focus_wrap(&mut self, focus_wrap: FocusWrap) {
+        self.focus_wrap = focus_wrap;
+    }
+
+    /// Sets what happens when Tab is pressed on the last button.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::view::FocusWrap;
+    /// use cursive_core::views::{Dialog, EditView};
+    ///
+    /// // Tab cycles between the edit view and the buttons.
+    /// let dialog = Dialog::around(EditView::new())
+    ///     .button("Ok", |s| s.quit())
+    ///     .dismiss_button("Cancel")
+    ///     .focus_wrap(FocusWrap::Wrap);
+    /// ```
+    #[must_use]
+    pub fn focus_wrap(self, focus_wrap: FocusWrap) -> Self {
+        self.with(|s| s.set_focus_wrap(focus_wrap))
+    }
+
+    /// Sets the event used to activate buttons from their mnemonic.
+    ///
+    /// Defaults to `Event::AltChar`, so Alt and the mnemonic activates a
+    /// button. Shift may be held as well. See [`Button::with_mnemonic`].
+    pub fn set_mnemonic_event(&mut self, mnemonic_event: fn(char) -> Event) {
+        self.mnemonic_event = mnemonic_event;
+    }
+
+    /// Sets the event used to activate buttons from their mnemonic.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::event::{Event, EventResult};
+    /// use cursive_core::view::View;
+    /// use cursive_core::views::{Dialog, EditView};
+    ///
+    /// let mut dialog = Dialog::around(EditView::new())
+    ///     .mnemonic_button("&Ok", |s| s.quit())
+    ///     .mnemonic_event(Event::CtrlChar);
+    ///
+    /// let result = dialog.on_event(Event::CtrlChar('o'));
+    /// assert!(matches!(result, EventResult::Consumed(Some(_))));
+    ///
+    /// // Disabled buttons ignore their mnemonic.
+    /// dialog.buttons_mut().for_each(|button| button.disable());
+    /// let result = dialog.on_event(Event::CtrlChar('o'));
+    /// assert!(matches!(result, EventResult::Ignored));
+    /// ```
+    #[must_use]
+    pub fn mnemonic_event(self, mnemonic_event: fn(char) -> Event) -> Self {
+        self.with(|s| s.set_mnemonic_event(mnemonic_event))
+    }
+
+    /// Lets the user resize this dialog.
+    ///
+    /// The dialog can then be resized by dragging its right border, bottom
+    /// border or bottom-right corner with the mouse, or with `Ctrl` and the
+    /// arrow keys when the content ignores them. It never grows larger than
+    /// the available space, or smaller than its borders and buttons.
+    ///
+    /// Dialogs are not resizable by default.
+    pub fn set_resizable(&mut self, resizable: bool) {
+        self.resizable = resizable;
+        if !resizable {
+            self.resize_drag = None;
+        }
+    }
+
+    /// Lets the user resize this dialog.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::traits::Scrollable;
+    /// use cursive_core::views::{Dialog, TextView};
+    ///
+    /// let dialog = Dialog::around(TextView::new("Logs...").scrollable())
+    ///     .title("Logs")
+    ///     .resizable(true);
+    /// ```
+    #[must_use]
+    pub fn resizable(self, resizable: bool) -> Self {
+        self.with(|s| s.set_resizable(resizable))
+    }
+
+    /// Returns `true` if the user can resize this dialog.
+    pub fn is_resizable(&self) -> bool {
+        self.resizable
+    }
+
+    /// Forgets the size chosen by the user.
+    ///
+    /// The dialog is then sized after its content again.
+    pub fn reset_size(&mut self) {
+        self.user_size = None;
+        self.invalidate();
+    }
+
+    /// Sets the minimum size of this dialog, including its borders.
+    ///
+    /// The dialog grows to this size even if its content is smaller, as long
+    /// as there is enough space available.
+    ///
+    /// Defaults to `(0, 0)`.
+    pub fn set_min_size<S: Into<Vec2>>(&mut self, min_size: S) {
+        self.min_size = min_size.into();
+        self.invalidate();
+    }
+
+    /// Sets the minimum size of this dialog, including its borders.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::{Dialog, TextView};
+    ///
+    /// let dialog = Dialog::around(TextView::new("OK?"))
+    ///     .button("Yes", |s| s.quit())
+    ///     .min_size((40, 7));
+    /// ```
+    #[must_use]
+    pub fn min_size<S: Into<Vec2>>(self, min_size: S) -> Self {
+        self.with(|s| s.set_min_size(min_size))
+    }
+
+    /// Sets the minimum width of this dialog, including its borders.
+    pub fn set_min_width(&mut self, min_width: usize) {
+        self.set_min_size((min_width, self.min_size.y));
+    }
+
+    /// Sets the minimum width of this dialog, including its borders.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn min_width(self, min_width: usize) -> Self {
+        self.with(|s| s.set_min_width(min_width))
+    }
+
+    /// Sets the minimum height of this dialog, including its borders.
+    pub fn set_min_height(&mut self, min_height: usize) {
+        self.set_min_size((self.min_size.x, min_height));
+    }
+
+    /// Sets the minimum height of this dialog, including its borders.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn min_height(self, min_height: usize) -> Self {
+        self.with(|s| s.set_min_height(min_height))
+    }
+
+    /// Returns the minimum size of this dialog.
+    pub fn get_min_size(&self) -> Vec2 {
+        self.min_size
+    }
+
     /// Iterate the buttons of this dialog.
     pub fn buttons(&self) -> impl Iterator<Item = &Button> {
         self.buttons.iter().map(|b| &b.button.view)
@@ -498,7 +887,15 @@ impl Dialog {
         ) {
             EventResult::Ignored => {
                 if self.buttons.is_empty() {
-                    EventResult::Ignored
+                    match event {
+                        Event::Key(Key::Tab) => {
+                            self.wrap_focus(Direction::front())
+                        }
+                        Event::Shift(Key::Tab) => {
+                            self.wrap_focus(Direction::back())
+                        }
+                        _ => EventResult::Ignored,
+                    }
                 } else {
                     match event {
                         Event::Key(Key::Down) | Event::Key(Key::Tab) => {
@@ -510,6 +907,9 @@ impl Dialog {
                             EventResult::Consumed(None)
                                 .and(self.content.on_event(Event::FocusLost))
                         }
+                        Event::Shift(Key::Tab) => {
+                            self.wrap_focus(Direction::back())
+                        }
                         _ => EventResult::Ignored,
                     }
                 }
@@ -518,6 +918,43 @@ impl Dialog {
         }
     }
 
+    // Tab reached the end of the line, coming from `source`.
+    //
+    // Moves the focus back to the other end if `focus_wrap` says so.
+    fn wrap_focus(&mut self, source: Direction) -> EventResult {
+        match self.focus_wrap {
+            FocusWrap::Propagate => EventResult::Ignored,
+            FocusWrap::Stop => EventResult::Consumed(None),
+            FocusWrap::Wrap => {
+                let to_content =
+                    source == Direction::front() || self.buttons.is_empty();
+                if to_content {
+                    if let Ok(res) = self.content.take_focus(source) {
+                        self.focus = DialogFocus::Content;
+                        return EventResult::Consumed(None).and(res);
+                    }
+                }
+
+                if self.buttons.is_empty() {
+                    return EventResult::Consumed(None);
+                }
+
+                let result = match self.focus {
+                    DialogFocus::Content => {
+                        self.content.on_event(Event::FocusLost)
+                    }
+                    DialogFocus::Button(_) => EventResult::Ignored,
+                };
+                self.focus = if source == Direction::front() {
+                    DialogFocus::Button(0)
+                } else {
+                    DialogFocus::Button(self.buttons.len() - 1)
+                };
+                EventResult::Consumed(None).and(result)
+            }
+        }
+    }
+
     // An event is received while a button is in focus
     fn on_event_button(
         &mut self,
@@ -554,7 +991,7 @@ impl Dialog {
                             self.focus = DialogFocus::Content;
                             res
                         } else {
-                            EventResult::Ignored
+                            self.wrap_focus(Direction::back())
                         }
                     }
                     Event::Shift(Key::Tab) => {
@@ -572,7 +1009,7 @@ impl Dialog {
                             ) =>
                     {
                         // End of the line
-                        EventResult::Ignored
+                        self.wrap_focus(Direction::front())
                     }
                     Event::Key(Key::Tab) => {
                         // Otherwise, jump to the next button.
@@ -600,6 +1037,138 @@ impl Dialog {
         }
     }
 
+    // Activates the first enabled button with a mnemonic matching `event`.
+    fn on_event_mnemonic(&mut self, event: &Event) -> EventResult {
+        // Mnemonics are lowercase, and Shift only changes the case.
+        let event = match event.char() {
+            Some(c) => Event::from_char(
+                c.to_lowercase().next().unwrap_or(c),
+                event.modifiers() - Modifier::Shift,
+            ),
+            None => return EventResult::Ignored,
+        };
+
+        let mnemonic_event = self.mnemonic_event;
+        self.buttons
+            .iter_mut()
+            .map(|button| &mut button.button.view)
+            .filter(|button| button.is_enabled())
+            .find(|button| {
+                button.get_mnemonic().map(mnemonic_event).as_ref()
+                    == Some(&event)
+            })
+            .map(|button| button.on_event(Event::Key(Key::Enter)))
+            .unwrap_or(EventResult::Ignored)
+    }
+
+    // An event was ignored by the focused element: look for a button shortcut.
+    fn on_event_shortcut(&mut self, event: &Event) -> EventResult {
+        self.buttons
+            .iter_mut()
+            .find(|button| button.shortcut.as_ref() == Some(event))
+            .map(|button| button.button.on_event(Event::Key(Key::Enter)))
+            .unwrap_or(EventResult::Ignored)
+    }
+
+    // Handles mouse drags on the bottom and right borders.
+    fn on_event_resize_drag(&mut self, event: &Event) -> Option<EventResult> {
+        if !self.resizable {
+            return None;
+        }
+
+        match *event {
+            Event::Mouse {
+                offset,
+                position,
+                event: MouseEvent::Press(MouseButton::Left),
+            } => {
+                let pos = position.checked_sub(offset)?;
+                if !pos.fits_in_rect(Vec2::zero(), self.last_size) {
+                    return None;
+                }
+
+                let axes =
+                    (pos + (1, 1)).zip_map(self.last_size, |a, b| a == b);
+                if !axes.any() {
+                    return None;
+                }
+
+                self.resize_drag = Some(ResizeDrag {
+                    start: position,
+                    start_size: self.last_size,
+                    axes,
+                });
+                Some(EventResult::consumed())
+            }
+            Event::Mouse {
+                position,
+                event: MouseEvent::Hold(MouseButton::Left),
+                ..
+            } => {
+                let drag = self.resize_drag?;
+                let delta = (position.signed() - drag.start.signed())
+                    .zip_map(drag.axes, |d, keep| if keep { d } else { 0 });
+                self.resize(drag.start_size.saturating_add(delta));
+                Some(EventResult::consumed())
+            }
+            Event::Mouse {
+                event: MouseEvent::Release(MouseButton::Left),
+                ..
+            } => self.resize_drag.take().map(|_| EventResult::consumed()),
+            _ => None,
+        }
+    }
+
+    // An event was ignored by the content: look for a resize key.
+    fn on_event_resize_key(&mut self, event: &Event) -> EventResult {
+        if !self.resizable {
+            return EventResult::Ignored;
+        }
+
+        let delta = match *event {
+            Event::Ctrl(Key::Left) => (-1, 0),
+            Event::Ctrl(Key::Right) => (1, 0),
+            Event::Ctrl(Key::Up) => (0, -1),
+            Event::Ctrl(Key::Down) => (0, 1),
+            _ => return EventResult::Ignored,
+        };
+
+        let size = self.user_size.unwrap_or(self.last_size);
+        self.resize(size.saturating_add(delta));
+        EventResult::consumed()
+    }
+
+    // Sets the size chosen by the user.
+    fn resize(&mut self, size: Vec2) {
+        self.user_size = Some(Vec2::max(size, self.min_resize_size()));
+        self.invalidate();
+    }
+
+    // Smallest size the user can resize the dialog to.
+    fn min_resize_size(&self) -> Vec2 {
+        let buttons_width = self
+            .buttons
+            .iter()
+            .map(|button| button.button.size.x + 1)
+            .sum::<usize>()
+            .saturating_sub(1);
+        let buttons_height = self
+            .buttons
+            .iter()
+            .map(|button| button.button.size.y + 1)
+            .max()
+            .unwrap_or(0);
+
+        let mut size = Vec2::new(buttons_width, buttons_height + 1)
+            + self.padding.combined()
+            + self.borders.combined();
+        if !self.title.is_empty() {
+            size.x = max(size.x, self.title.width() + 6);
+        }
+        size = Vec2::max(size, self.min_size);
+        size
+    }
+
     fn draw_buttons(&self, printer: &Printer) -> Option<usize> {
         let mut buttons_height = 0;
         // Current horizontal position of the next button we'll draw.
@@ -789,10 +1358,18 @@ impl View for Dialog {
             inner_size.x = max(inner_size.x, self.title.width() + 6);
         }
 
-        inner_size
+        // The user knows best, but the minimum size still applies.
+        if let Some(size) = self.user_size {
+            return Vec2::min(Vec2::max(size, self.min_size), req);
+        }
+
+        // Grow to the minimum size, if there is enough room.
+        Vec2::max(inner_size, Vec2::min(self.min_size, req))
     }
 
     fn layout(&mut self, mut size: Vec2) {
+        self.last_size = size;
+
         // Padding and borders are taken, sorry.
         // TODO: handle border-less themes?
         let taken = self.borders.combined() + self.padding.combined();
@@ -818,17 +1395,34 @@ impl View for Dialog {
     }
 
     fn on_event(&mut self, event: Event) -> EventResult {
+        // Dragging a border resizes the dialog.
+        if let Some(res) = self.on_event_resize_drag(&event) {
+            return res;
+        }
+
         // First: some mouse events can instantly change the focus.
         let res = self
             .check_focus_grab(&event)
             .unwrap_or(EventResult::Ignored);
 
-        res.and(match self.focus {
+        // Mnemonics take precedence over the focused view.
+        if let EventResult::Consumed(cb) = self.on_event_mnemonic(&event) {
+            return res.and(EventResult::Consumed(cb));
+        }
+
+        let result = match self.focus {
             // If we are on the content, we can only go down.
             // TODO: Careful if/when we add buttons elsewhere on the dialog!
-            DialogFocus::Content => self.on_event_content(event),
+            DialogFocus::Content => self.on_event_content(event.clone()),
             // If we are on a button, we have more choice
-            DialogFocus::Button(i) => self.on_event_button(event, i),
+            DialogFocus::Button(i) => self.on_event_button(event.clone(), i),
+        };
+
+        res.and(match result {
+            EventResult::Ignored => self
+                .on_event_shortcut(&event)
+                .or_else(|| self.on_event_resize_key(&event)),
+            result => result,
         })
     }
 
@@ -929,6 +1523,18 @@ impl View for Dialog {
         self.content.focus_view(selector)
     }
 
+    fn debug_children(&self, f: &mut dyn FnMut(&dyn View, Option<Rect>)) {
+        let offset = self.borders.top_left() + self.padding.top_left();
+        let area = Rect::from_size(offset, self.content.size);
+        f(&*self.content.view, Some(area));
+
+        for button in &self.buttons {
+            let area =
+                Rect::from_size(button.offset.get(), button.button.size);
+            f(&button.button.view, Some(area));
+        }
+    }
+
     fn important_area(&self, _: Vec2) -> Rect {
         // Only the content is important.
         // TODO: if a button is focused, return the button position instead.
@@ -941,3 +1547,203 @@ impl View for Dialog {
         self.invalidated || self.content.needs_relayout()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::Resizable;
+
+    // Lays out the dialog like a layer in a 40x20 screen.
+    fn layout(dialog: &mut Dialog) -> Vec2 {
+        let size = dialog.required_size(Vec2::new(40, 20));
+        dialog.layout(size);
+        size
+    }
+
+    fn mouse(event: MouseEvent, position: (usize, usize)) -> Event {
+        Event::Mouse {
+            offset: Vec2::new(5, 5),
+            position: Vec2::from(position) + (5, 5),
+            event,
+        }
+    }
+
+    #[test]
+    fn resize_with_mouse() {
+        let mut dialog =
+            Dialog::around(DummyView.fixed_size((10, 3))).resizable(true);
+        assert_eq!(layout(&mut dialog), Vec2::new(14, 5));
+
+        // Drag the bottom-right corner.
+        dialog.on_event(mouse(MouseEvent::Press(MouseButton::Left), (13, 4)));
+        dialog.on_event(mouse(MouseEvent::Hold(MouseButton::Left), (20, 6)));
+        assert_eq!(layout(&mut dialog), Vec2::new(21, 7));
+        dialog.on_event(mouse(MouseEvent::Hold(MouseButton::Left), (60, 40)));
+        assert_eq!(layout(&mut dialog), Vec2::new(40, 20));
+        dialog
+            .on_event(mouse(MouseEvent::Release(MouseButton::Left), (60, 40)));
+
+        // Drag the right border: only the width changes.
+        dialog.on_event(mouse(MouseEvent::Press(MouseButton::Left), (39, 3)));
+        dialog.on_event(mouse(MouseEvent::Hold(MouseButton::Left), (0, 0)));
+        assert_eq!(layout(&mut dialog), Vec2::new(4, 20));
+        dialog.on_event(mouse(MouseEvent::Release(MouseButton::Left), (0, 0)));
+
+        // Holding the mouse elsewhere does nothing.
+        dialog.on_event(mouse(MouseEvent::Hold(MouseButton::Left), (10, 10)));
+        assert_eq!(layout(&mut dialog), Vec2::new(4, 20));
+
+        dialog.reset_size();
+        assert_eq!(layout(&mut dialog), Vec2::new(14, 5));
+    }
+
+    #[test]
+    fn resize_with_keys() {
+        let mut dialog = Dialog::around(DummyView.fixed_size((10, 3)))
+            .title("Title")
+            .button("Ok", |_| ());
+        assert_eq!(layout(&mut dialog), Vec2::new(14, 7));
+
+        // Not resizable by default.
+        assert!(!dialog.on_event(Event::Ctrl(Key::Right)).is_consumed());
+
+        dialog.set_resizable(true);
+        dialog.on_event(Event::Ctrl(Key::Right));
+        dialog.on_event(Event::Ctrl(Key::Down));
+        assert_eq!(layout(&mut dialog), Vec2::new(15, 8));
+
+        // The title and the buttons must still fit.
+        for _ in 0..20 {
+            dialog.on_event(Event::Ctrl(Key::Left));
+            dialog.on_event(Event::Ctrl(Key::Up));
+        }
+        assert_eq!(layout(&mut dialog), Vec2::new(11, 5));
+    }
+
+    #[test]
+    fn min_size() {
+        let mut dialog = Dialog::around(TextView::new("OK?")).min_width(20);
+        assert_eq!(dialog.required_size(Vec2::new(80, 24)), Vec2::new(20, 3));
+
+        dialog.set_min_height(6);
+        assert_eq!(dialog.required_size(Vec2::new(80, 24)), Vec2::new(20, 6));
+
+        // Never larger than the available space.
+        assert_eq!(dialog.required_size(Vec2::new(10, 4)), Vec2::new(10, 4));
+
+        // Larger content is not constrained.
+        dialog.set_min_size((4, 2));
+        assert_eq!(dialog.required_size(Vec2::new(80, 24)), Vec2::new(7, 3));
+    }
+
+    #[test]
+    fn min_size_after_resize() {
+        let mut dialog = Dialog::around(TextView::new("OK?")).resizable(true);
+        layout(&mut dialog);
+        dialog.on_event(Event::Ctrl(Key::Right));
+        assert_eq!(layout(&mut dialog), Vec2::new(8, 3));
+
+        // A size picked by the user is still grown to the minimum size.
+        dialog.set_min_size((20, 6));
+        assert_eq!(layout(&mut dialog), Vec2::new(20, 6));
+        assert_eq!(dialog.required_size(Vec2::new(10, 4)), Vec2::new(10, 4));
+    }
+
+    fn focus_dialog(focus_wrap: FocusWrap) -> Dialog {
+        let mut dialog = Dialog::around(crate::views::EditView::new())
+            .button("Ok", |_| ())
+            .button("Cancel", |_| ())
+            .focus_wrap(focus_wrap);
+        layout(&mut dialog);
+        dialog
+    }
+
+    #[test]
+    fn focus_wrap_propagate() {
+        let mut dialog = focus_dialog(FocusWrap::Propagate);
+        dialog.set_focus(DialogFocus::Button(1));
+        assert!(!dialog.on_event(Event::Key(Key::Tab)).is_consumed());
+        assert_eq!(dialog.focus(), DialogFocus::Button(1));
+
+        dialog.set_focus(DialogFocus::Content);
+        assert!(!dialog.on_event(Event::Shift(Key::Tab)).is_consumed());
+        assert_eq!(dialog.focus(), DialogFocus::Content);
+    }
+
+    #[test]
+    fn focus_wrap_stop() {
+        let mut dialog = focus_dialog(FocusWrap::Stop);
+        dialog.set_focus(DialogFocus::Button(1));
+        assert!(dialog.on_event(Event::Key(Key::Tab)).is_consumed());
+        assert_eq!(dialog.focus(), DialogFocus::Button(1));
+
+        dialog.set_focus(DialogFocus::Content);
+        assert!(dialog.on_event(Event::Shift(Key::Tab)).is_consumed());
+        assert_eq!(dialog.focus(), DialogFocus::Content);
+    }
+
+    #[test]
+    fn focus_wrap_wrap() {
+        let mut dialog = focus_dialog(FocusWrap::Wrap);
+        dialog.set_focus(DialogFocus::Button(1));
+        assert!(dialog.on_event(Event::Key(Key::Tab)).is_consumed());
+        assert_eq!(dialog.focus(), DialogFocus::Content);
+
+        assert!(dialog.on_event(Event::Shift(Key::Tab)).is_consumed());
+        assert_eq!(dialog.focus(), DialogFocus::Button(1));
+
+        // Without focusable content, Tab cycles through the buttons.
+        let mut dialog = Dialog::around(TextView::new("Sure?"))
+            .button("Ok", |_| ())
+            .button("Cancel", |_| ())
+            .focus_wrap(FocusWrap::Wrap);
+        layout(&mut dialog);
+        dialog.set_focus(DialogFocus::Button(1));
+        assert!(dialog.on_event(Event::Key(Key::Tab)).is_consumed());
+        assert_eq!(dialog.focus(), DialogFocus::Button(0));
+
+        assert!(dialog.on_event(Event::Shift(Key::Tab)).is_consumed());
+        assert_eq!(dialog.focus(), DialogFocus::Button(1));
+    }
+
+    #[test]
+    fn mnemonic_with_shift() {
+        let mut dialog = Dialog::around(crate::views::EditView::new())
+            .mnemonic_button("&Ok", |_| ())
+            .mnemonic_button("&Cancel", |_| ());
+        layout(&mut dialog);
+
+        assert!(dialog.on_event(Event::AltChar('o')).is_consumed());
+        assert!(dialog.on_event(Event::AltChar('C')).is_consumed());
+        assert!(dialog
+            .on_event(Event::from_char('O', Modifier::Alt | Modifier::Shift))
+            .is_consumed());
+        assert!(!dialog.on_event(Event::AltChar('x')).is_consumed());
+    }
+
+    #[test]
+    fn button_shortcuts() {
+        let mut dialog = Dialog::around(crate::views::EditView::new())
+            .button_with_key("Yes", 'y', |s| s.set_user_data("yes"))
+            .button_with_key("No", Key::Esc, |s| s.set_user_data("no"))
+            .button_with_key("Help", Event::CtrlChar('h'), |_| ());
+        layout(&mut dialog);
+
+        let labels: Vec<_> = dialog.buttons().map(Button::label).collect();
+        assert_eq!(labels, ["<Yes (Y)>", "<No (Esc)>", "<Help>"]);
+
+        // The focused edit view uses the character first.
+        let mut siv = Cursive::new();
+        assert_eq!(dialog.focus(), DialogFocus::Content);
+        dialog.on_event(Event::Char('y')).process(&mut siv);
+        assert_eq!(siv.user_data::<&str>(), None);
+
+        dialog.set_focus(DialogFocus::Button(2));
+        dialog.on_event(Event::Char('y')).process(&mut siv);
+        assert_eq!(siv.user_data::<&str>(), Some(&mut "yes"));
+        dialog.on_event(Event::Key(Key::Esc)).process(&mut siv);
+        assert_eq!(siv.user_data::<&str>(), Some(&mut "no"));
+
+        assert!(!dialog.on_event(Event::Char('x')).is_consumed());
+    }
+}
@@ -0,0 +1,765 @@
+use crate::direction::Direction;
+use crate::event::{Event, EventResult, Key};
+use crate::theme::{BaseColor, ColorStyle};
+use crate::utils::width::TextWidth;
+use crate::view::{CannotFocus, View};
+use crate::Printer;
+use crate::Vec2;
+use crate::With;
+
+use std::cmp::{max, min};
+use std::ops::Range;
+
+/// How a [`DiffView`] shows the changes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DiffLayout {
+    /// A single column, with removed lines before added lines.
+    #[default]
+    Unified,
+    /// The old text on the left, and the new text on the right.
+    SideBySide,
+}
+
+/// Shows the line-level differences between two texts.
+///
+/// Added lines are shown in green, removed lines in red. Like `diff -u`,
+/// only the unchanged lines close to a change are shown, and each group of
+/// changes starts with a `@@ -1,4 +1,5 @@` header.
+///
+/// When lines are too long for the view, the Left, Right, Home and End keys
+/// scroll horizontally. For vertical scrolling, wrap this view in a
+/// [`ScrollView`](crate::views::ScrollView).
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::view::Scrollable;
+/// use cursive_core::views::{DiffLayout, DiffView};
+///
+/// let view = DiffView::new("a\nb\nc\n", "a\nB\nc\n")
+///     .layout(DiffLayout::SideBySide)
+///     .context_lines(1)
+///     .scrollable();
+/// ```
+pub struct DiffView {
+    old: Vec<String>,
+    new: Vec<String>,
+
+    // Full edit script from `old` to `new`.
+    edits: Vec<Edit>,
+
+    layout: DiffLayout,
+    context_lines: usize,
+
+    // Rows to show, computed from the edits.
+    rows: Vec<Row>,
+
+    // Horizontal scroll offset of the text.
+    offset_x: usize,
+    last_size: Vec2,
+}
+
+// Width of the `+ ` gutter before each line.
+const GUTTER: usize = 2;
+
+impl DiffView {
+    /// Creates a new `DiffView` showing the changes from `old` to `new`.
+    ///
+    /// By default, changes are shown in a unified layout, with 3 lines of
+    /// context.
+    pub fn new(old: &str, new: &str) -> Self {
+        let old: Vec<String> = old.lines().map(String::from).collect();
+        let new: Vec<String> = new.lines().map(String::from).collect();
+        let edits = diff(&old, &new);
+
+        DiffView {
+            old,
+            new,
+            edits,
+            layout: DiffLayout::default(),
+            context_lines: 3,
+            rows: Vec::new(),
+            offset_x: 0,
+            last_size: Vec2::zero(),
+        }
+        .with(Self::compute_rows)
+    }
+
+    /// Sets the layout used to show the changes.
+    pub fn set_layout(&mut self, layout: DiffLayout) {
+        self.layout = layout;
+        self.compute_rows();
+    }
+
+    /// Sets the layout used to show the changes.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn layout(self, layout: DiffLayout) -> Self {
+        self.with(|s| s.set_layout(layout))
+    }
+
+    /// Returns the layout used to show the changes.
+    pub fn get_layout(&self) -> DiffLayout {
+        self.layout
+    }
+
+    /// Sets the number of unchanged lines to show around each change.
+    ///
+    /// Use `usize::MAX` to show every line. Defaults to 3.
+    pub fn set_context_lines(&mut self, context_lines: usize) {
+        self.context_lines = context_lines;
+        self.compute_rows();
+    }
+
+    /// Sets the number of unchanged lines to show around each change.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn context_lines(self, context_lines: usize) -> Self {
+        self.with(|s| s.set_context_lines(context_lines))
+    }
+
+    /// Returns the number of unchanged lines shown around each change.
+    pub fn get_context_lines(&self) -> usize {
+        self.context_lines
+    }
+
+    /// Returns `true` if the two texts are identical.
+    pub fn is_unchanged(&self) -> bool {
+        self.edits
+            .iter()
+            .all(|edit| matches!(edit, Edit::Equal(..)))
+    }
+
+    fn compute_rows(&mut self) {
+        self.rows.clear();
+        for hunk in hunks(&self.edits, self.context_lines) {
+            let edits = &self.edits[hunk];
+            self.rows.push(Row::Header(header(edits)));
+            match self.layout {
+                DiffLayout::Unified => unified_rows(edits, &mut self.rows),
+                DiffLayout::SideBySide => split_rows(edits, &mut self.rows),
+            }
+        }
+        self.offset_x = min(self.offset_x, self.max_offset());
+    }
+
+    fn text(&self, line: Line) -> &str {
+        if line.new {
+            &self.new[line.index]
+        } else {
+            &self.old[line.index]
+        }
+    }
+
+    // Width of the longest line, without the gutter.
+    fn text_width(&self) -> usize {
+        self.rows
+            .iter()
+            .flat_map(|row| match *row {
+                Row::Header(_) => [None, None],
+                Row::Unified(line) => [Some(line), None],
+                Row::Split(left, right) => [left, right],
+            })
+            .flatten()
+            .map(|line| self.text(line).width())
+            .max()
+            .unwrap_or(0)
+    }
+
+    // Columns available for the text of a single line.
+    fn visible_width(&self, width: usize) -> usize {
+        match self.layout {
+            DiffLayout::Unified => width.saturating_sub(GUTTER),
+            DiffLayout::SideBySide => {
+                (width.saturating_sub(1) / 2).saturating_sub(GUTTER)
+            }
+        }
+    }
+
+    fn max_offset(&self) -> usize {
+        let visible = self.visible_width(self.last_size.x);
+        self.text_width().saturating_sub(visible)
+    }
+
+    fn draw_line(&self, printer: &Printer, y: usize, line: Line) {
+        let (marker, color) = match line.kind {
+            Kind::Context => (" ", None),
+            Kind::Removed => ("-", Some(BaseColor::Red.dark())),
+            Kind::Added => ("+", Some(BaseColor::Green.dark())),
+        };
+
+        let draw = |printer: &Printer| {
+            printer.print((0, y), marker);
+            printer
+                .offset((GUTTER, 0))
+                .content_offset((self.offset_x, 0))
+                .print((0, y), self.text(line));
+        };
+
+        match color {
+            Some(color) => printer.with_color(ColorStyle::front(color), draw),
+            None => draw(printer),
+        }
+    }
+}
+
+impl View for DiffView {
+    fn draw(&self, printer: &Printer) {
+        let half = printer.size.x.saturating_sub(1) / 2;
+        let left = printer.cropped((half, printer.size.y));
+        let right = printer.offset((half + 1, 0));
+
+        for (y, row) in self.rows.iter().enumerate() {
+            match *row {
+                Row::Header(ref header) => {
+                    printer.with_color(ColorStyle::secondary(), |printer| {
+                        printer.print((0, y), header);
+                    });
+                }
+                Row::Unified(line) => self.draw_line(printer, y, line),
+                Row::Split(left_line, right_line) => {
+                    if let Some(line) = left_line {
+                        self.draw_line(&left, y, line);
+                    }
+                    printer.print((half, y), "│");
+                    if let Some(line) = right_line {
+                        self.draw_line(&right, y, line);
+                    }
+                }
+            }
+        }
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.last_size = size;
+        self.offset_x = min(self.offset_x, self.max_offset());
+    }
+
+    fn required_size(&mut self, _: Vec2) -> Vec2 {
+        let line_width = GUTTER + self.text_width();
+        let width = match self.layout {
+            DiffLayout::Unified => line_width,
+            DiffLayout::SideBySide => 2 * line_width + 1,
+        };
+        let header_width = self
+            .rows
+            .iter()
+            .map(|row| match *row {
+                Row::Header(ref header) => header.width(),
+                _ => 0,
+            })
+            .max()
+            .unwrap_or(0);
+
+        Vec2::new(max(width, header_width), self.rows.len())
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        let max_offset = self.max_offset();
+        match event {
+            Event::Key(Key::Left) if self.offset_x > 0 => {
+                self.offset_x -= 1;
+            }
+            Event::Key(Key::Right) if self.offset_x < max_offset => {
+                self.offset_x += 1;
+            }
+            Event::Key(Key::Home) if self.offset_x > 0 => {
+                self.offset_x = 0;
+            }
+            Event::Key(Key::End) if self.offset_x < max_offset => {
+                self.offset_x = max_offset;
+            }
+            _ => return EventResult::Ignored,
+        }
+        EventResult::Consumed(None)
+    }
+
+    fn take_focus(
+        &mut self,
+        _: Direction,
+    ) -> Result<EventResult, CannotFocus> {
+        // Only focusable when there is something to scroll.
+        (self.max_offset() > 0)
+            .then(EventResult::consumed)
+            .ok_or(CannotFocus)
+    }
+}
+
+/// A single step in the edit script.
+///
+/// Indices refer to lines in the old and new texts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Edit {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Context,
+    Removed,
+    Added,
+}
+
+/// A line from either text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Line {
+    kind: Kind,
+    // `true` if `index` refers to the new text.
+    new: bool,
+    index: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Row {
+    Header(String),
+    Unified(Line),
+    Split(Option<Line>, Option<Line>),
+}
+
+/// Computes the shortest edit script from `a` to `b`.
+///
+/// This is the O(ND) algorithm from Eugene W. Myers, "An O(ND) Difference
+/// Algorithm and Its Variations", in its linear space variant: the script
+/// is split around a "middle snake" found by searching from both ends.
+fn diff<T: PartialEq>(a: &[T], b: &[T]) -> Vec<Edit> {
+    let max_d = max_d(a.len(), b.len());
+    let mut forward = Diagonals::new(max_d);
+    let mut backward = Diagonals::new(max_d);
+
+    let mut edits = Vec::new();
+    conquer(
+        a,
+        0..a.len(),
+        b,
+        0..b.len(),
+        &mut forward,
+        &mut backward,
+        &mut edits,
+    );
+    edits
+}
+
+// Upper bound on the number of steps to find a middle snake.
+fn max_d(n: usize, m: usize) -> usize {
+    (n + m) / 2 + 2
+}
+
+/// Furthest `x` reached on each diagonal `k = x - y`.
+struct Diagonals {
+    offset: isize,
+    v: Vec<usize>,
+}
+
+impl Diagonals {
+    fn new(max_d: usize) -> Self {
+        Diagonals {
+            offset: max_d as isize + 1,
+            v: vec![0; 2 * max_d + 3],
+        }
+    }
+}
+
+impl std::ops::Index<isize> for Diagonals {
+    type Output = usize;
+
+    fn index(&self, k: isize) -> &usize {
+        &self.v[(k + self.offset) as usize]
+    }
+}
+
+impl std::ops::IndexMut<isize> for Diagonals {
+    fn index_mut(&mut self, k: isize) -> &mut usize {
+        &mut self.v[(k + self.offset) as usize]
+    }
+}
+
+/// Appends the edit script from `a[a_range]` to `b[b_range]` to `edits`.
+fn conquer<T: PartialEq>(
+    a: &[T],
+    mut a_range: Range<usize>,
+    b: &[T],
+    mut b_range: Range<usize>,
+    forward: &mut Diagonals,
+    backward: &mut Diagonals,
+    edits: &mut Vec<Edit>,
+) {
+    // Common prefix and suffix are not part of the search.
+    while !a_range.is_empty()
+        && !b_range.is_empty()
+        && a[a_range.start] == b[b_range.start]
+    {
+        edits.push(Edit::Equal(a_range.start, b_range.start));
+        a_range.start += 1;
+        b_range.start += 1;
+    }
+    let mut suffix = 0;
+    while !a_range.is_empty()
+        && !b_range.is_empty()
+        && a[a_range.end - 1] == b[b_range.end - 1]
+    {
+        a_range.end -= 1;
+        b_range.end -= 1;
+        suffix += 1;
+    }
+
+    if a_range.is_empty() {
+        edits.extend(b_range.clone().map(Edit::Insert));
+    } else if b_range.is_empty() {
+        edits.extend(a_range.clone().map(Edit::Delete));
+    } else if let Some((x, y)) =
+        middle_snake(a, a_range.clone(), b, b_range.clone(), forward, backward)
+    {
+        conquer(
+            a,
+            a_range.start..x,
+            b,
+            b_range.start..y,
+            forward,
+            backward,
+            edits,
+        );
+        conquer(
+            a,
+            x..a_range.end,
+            b,
+            y..b_range.end,
+            forward,
+            backward,
+            edits,
+        );
+    } else {
+        edits.extend(a_range.clone().map(Edit::Delete));
+        edits.extend(b_range.clone().map(Edit::Insert));
+    }
+
+    edits.extend(
+        (0..suffix).map(|i| Edit::Equal(a_range.end + i, b_range.end + i)),
+    );
+}
+
+/// Finds a point on the middle snake of an optimal path.
+///
+/// Splitting the ranges at this point gives two smaller problems.
+fn middle_snake<T: PartialEq>(
+    a: &[T],
+    a_range: Range<usize>,
+    b: &[T],
+    b_range: Range<usize>,
+    forward: &mut Diagonals,
+    backward: &mut Diagonals,
+) -> Option<(usize, usize)> {
+    let n = a_range.len();
+    let m = b_range.len();
+    let delta = n as isize - m as isize;
+    let odd = delta & 1 == 1;
+
+    forward[1] = 0;
+    backward[1] = 0;
+
+    for d in 0..max_d(n, m) as isize {
+        // Forward search, from the top-left corner.
+        for k in (-d..=d).rev().step_by(2) {
+            let mut x =
+                if k == -d || (k != d && forward[k - 1] < forward[k + 1]) {
+                    forward[k + 1]
+                } else {
+                    forward[k - 1] + 1
+                };
+            let y = (x as isize - k) as usize;
+            let (x0, y0) = (x, y);
+            let mut y = y;
+            while x < n
+                && y < m
+                && a[a_range.start + x] == b[b_range.start + y]
+            {
+                x += 1;
+                y += 1;
+            }
+            forward[k] = x;
+
+            if odd
+                && (k - delta).abs() < d
+                && forward[k] + backward[-(k - delta)] >= n
+            {
+                return Some((a_range.start + x0, b_range.start + y0));
+            }
+        }
+
+        // Backward search, from the bottom-right corner.
+        for k in (-d..=d).rev().step_by(2) {
+            let mut x =
+                if k == -d || (k != d && backward[k - 1] < backward[k + 1]) {
+                    backward[k + 1]
+                } else {
+                    backward[k - 1] + 1
+                };
+            let mut y = (x as isize - k) as usize;
+            while x < n
+                && y < m
+                && a[a_range.end - x - 1] == b[b_range.end - y - 1]
+            {
+                x += 1;
+                y += 1;
+            }
+            backward[k] = x;
+
+            if !odd
+                && (k - delta).abs() <= d
+                && backward[k] + forward[-(k - delta)] >= n
+            {
+                return Some((a_range.end - x, b_range.end - y));
+            }
+        }
+    }
+
+    None
+}
+
+/// Groups the edits into hunks, with `context` unchanged lines around each
+/// change.
+///
+/// Returns the range of each hunk in `edits`.
+fn hunks(edits: &[Edit], context: usize) -> Vec<std::ops::Range<usize>> {
+    let changes: Vec<usize> = edits
+        .iter()
+        .enumerate()
+        .filter(|(_, edit)| !matches!(edit, Edit::Equal(..)))
+        .map(|(i, _)| i)
+        .collect();
+
+    let mut hunks: Vec<std::ops::Range<usize>> = Vec::new();
+    for i in changes {
+        let start = i.saturating_sub(context);
+        let end =
+            min(i.saturating_add(context).saturating_add(1), edits.len());
+        match hunks.last_mut() {
+            // Merge hunks that touch each other.
+            Some(last) if start <= last.end => last.end = max(last.end, end),
+            _ => hunks.push(start..end),
+        }
+    }
+    hunks
+}
+
+/// Returns the header for a hunk, like `@@ -1,4 +1,5 @@`.
+fn header(edits: &[Edit]) -> String {
+    let mut old_start = None;
+    let mut new_start = None;
+    let mut old_len = 0;
+    let mut new_len = 0;
+
+    for edit in edits {
+        match *edit {
+            Edit::Equal(i, j) => {
+                old_start.get_or_insert(i);
+                new_start.get_or_insert(j);
+                old_len += 1;
+                new_len += 1;
+            }
+            Edit::Delete(i) => {
+                old_start.get_or_insert(i);
+                old_len += 1;
+            }
+            Edit::Insert(j) => {
+                new_start.get_or_insert(j);
+                new_len += 1;
+            }
+        }
+    }
+
+    // Like `diff -u`, empty ranges point to the line before them.
+    let range = |start: Option<usize>, len: usize| match start {
+        Some(start) if len > 0 => format!("{},{}", start + 1, len),
+        _ => format!("{},0", start.unwrap_or(0)),
+    };
+
+    format!(
+        "@@ -{} +{} @@",
+        range(old_start, old_len),
+        range(new_start, new_len)
+    )
+}
+
+fn unified_rows(edits: &[Edit], rows: &mut Vec<Row>) {
+    rows.extend(edits.iter().map(|edit| {
+        Row::Unified(match *edit {
+            Edit::Equal(i, _) => Line {
+                kind: Kind::Context,
+                new: false,
+                index: i,
+            },
+            Edit::Delete(i) => Line {
+                kind: Kind::Removed,
+                new: false,
+                index: i,
+            },
+            Edit::Insert(j) => Line {
+                kind: Kind::Added,
+                new: true,
+                index: j,
+            },
+        })
+    }));
+}
+
+fn split_rows(edits: &[Edit], rows: &mut Vec<Row>) {
+    let mut removed = Vec::new();
+    let mut added = Vec::new();
+
+    // Removed and added lines are shown next to each other.
+    let flush = |removed: &mut Vec<Line>,
+                 added: &mut Vec<Line>,
+                 rows: &mut Vec<Row>| {
+        let len = max(removed.len(), added.len());
+        rows.extend((0..len).map(|i| {
+            Row::Split(removed.get(i).copied(), added.get(i).copied())
+        }));
+        removed.clear();
+        added.clear();
+    };
+
+    for edit in edits {
+        match *edit {
+            Edit::Equal(i, j) => {
+                flush(&mut removed, &mut added, rows);
+                rows.push(Row::Split(
+                    Some(Line {
+                        kind: Kind::Context,
+                        new: false,
+                        index: i,
+                    }),
+                    Some(Line {
+                        kind: Kind::Context,
+                        new: true,
+                        index: j,
+                    }),
+                ));
+            }
+            Edit::Delete(i) => removed.push(Line {
+                kind: Kind::Removed,
+                new: false,
+                index: i,
+            }),
+            Edit::Insert(j) => added.push(Line {
+                kind: Kind::Added,
+                new: true,
+                index: j,
+            }),
+        }
+    }
+    flush(&mut removed, &mut added, rows);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<&str> {
+        text.lines().collect()
+    }
+
+    #[test]
+    fn shortest_script() {
+        let edits =
+            diff(&lines("a\nb\nc\na\nb\nb\na"), &lines("c\nb\na\nb\na\nc"));
+        // The example from the paper has an edit distance of 5.
+        let changes = edits
+            .iter()
+            .filter(|edit| !matches!(edit, Edit::Equal(..)))
+            .count();
+        assert_eq!(changes, 5);
+
+        assert_eq!(diff::<&str>(&[], &[]), vec![]);
+        assert_eq!(diff(&["a"], &[]), vec![Edit::Delete(0)]);
+        assert_eq!(diff(&[], &["a"]), vec![Edit::Insert(0)]);
+    }
+
+    // Length of the longest common subsequence, the slow way.
+    fn lcs(a: &[u8], b: &[u8]) -> usize {
+        let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+        for i in 0..a.len() {
+            for j in 0..b.len() {
+                table[i + 1][j + 1] = if a[i] == b[j] {
+                    table[i][j] + 1
+                } else {
+                    max(table[i][j + 1], table[i + 1][j])
+                };
+            }
+        }
+        table[a.len()][b.len()]
+    }
+
+    #[test]
+    fn minimal_and_valid_scripts() {
+        // Small pseudo-random sequences over a small alphabet.
+        let mut seed = 42u32;
+        let mut next = move || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (seed >> 16) as usize
+        };
+
+        for _ in 0..200 {
+            let a: Vec<u8> =
+                (0..next() % 20).map(|_| (next() % 4) as u8).collect();
+            let b: Vec<u8> =
+                (0..next() % 20).map(|_| (next() % 4) as u8).collect();
+            let edits = diff(&a, &b);
+
+            // Following the script turns `a` into `b`.
+            let (mut x, mut y) = (0, 0);
+            for edit in &edits {
+                match *edit {
+                    Edit::Equal(i, j) => {
+                        assert_eq!((i, j), (x, y));
+                        assert_eq!(a[i], b[j]);
+                        x += 1;
+                        y += 1;
+                    }
+                    Edit::Delete(i) => {
+                        assert_eq!(i, x);
+                        x += 1;
+                    }
+                    Edit::Insert(j) => {
+                        assert_eq!(j, y);
+                        y += 1;
+                    }
+                }
+            }
+            assert_eq!((x, y), (a.len(), b.len()));
+
+            let equal = edits
+                .iter()
+                .filter(|edit| matches!(edit, Edit::Equal(..)))
+                .count();
+            assert_eq!(equal, lcs(&a, &b), "{:?} -> {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn hunks_and_headers() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n";
+        let new = "1\n2\nthree\n4\n5\n6\n7\n8\n9\nten\n";
+
+        let view = DiffView::new(old, new).context_lines(1);
+        let headers: Vec<&str> = view
+            .rows
+            .iter()
+            .filter_map(|row| match row {
+                Row::Header(header) => Some(header.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(headers, vec!["@@ -2,3 +2,3 @@", "@@ -9,1 +9,2 @@"]);
+        assert_eq!(view.rows.len(), 2 + 4 + 2);
+
+        // Changed lines are paired in the side-by-side layout.
+        let view = view.layout(DiffLayout::SideBySide);
+        assert_eq!(view.rows.len(), 2 + 3 + 2);
+        assert!(!view.is_unchanged());
+        assert!(DiffView::new(old, old).is_unchanged());
+    }
+}
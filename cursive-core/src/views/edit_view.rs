@@ -1,16 +1,30 @@
+use crate::utils::width::{char_width, TextWidth};
 use crate::{
+    align::TextDirection,
     direction::Direction,
     event::{Callback, Event, EventResult, Key, MouseEvent},
     rect::Rect,
     theme::{ColorStyle, Effect},
-    utils::lines::simple::{simple_prefix, simple_suffix},
+    utils::{bidi, lines::simple::expand_tabs},
     view::{CannotFocus, View},
     Cursive, Printer, Vec2, With,
 };
+use std::borrow::Cow;
 use std::cell::RefCell;
 use std::rc::Rc;
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+// Visible content of an `EditView`, in visual order.
+struct VisualLine<'a> {
+    // Each grapheme, with its byte position and display width.
+    graphemes: Vec<(&'a str, (usize, usize))>,
+    // Resolved direction of the content.
+    direction: TextDirection,
+    // Column of the first grapheme.
+    x: usize,
+    // Total width of the graphemes.
+    width: usize,
+}
 
 /// Closure type for callbacks when the content is modified.
 ///
@@ -23,6 +37,72 @@ pub type OnEdit = dyn Fn(&mut Cursive, &str, usize);
 /// Arguments are the `Cursive` and the content of the input.
 pub type OnSubmit = dyn Fn(&mut Cursive, &str);
 
+/// Closure type for validating the content.
+///
+/// Returns an error message if the content is invalid.
+pub type Validator = dyn Fn(&str) -> Result<(), String>;
+
+/// Key bindings for word-by-word editing in an [`EditView`].
+///
+/// The default bindings are:
+///
+/// * `Ctrl+Left` / `Ctrl+Right` to move to the previous / next word.
+/// * `Ctrl+Backspace` / `Ctrl+Del` to delete the previous / next word.
+/// * `Ctrl+A` / `Ctrl+E` to move to the start / end of the content.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WordNavigationKeys {
+    /// Moves the cursor to the start of the previous word.
+    pub word_left: Event,
+    /// Moves the cursor to the end of the next word.
+    pub word_right: Event,
+    /// Deletes from the start of the previous word to the cursor.
+    pub delete_word_left: Event,
+    /// Deletes from the cursor to the end of the next word.
+    pub delete_word_right: Event,
+    /// Moves the cursor to the start of the content.
+    pub home: Event,
+    /// Moves the cursor to the end of the content.
+    pub end: Event,
+}
+
+impl Default for WordNavigationKeys {
+    fn default() -> Self {
+        WordNavigationKeys {
+            word_left: Event::Ctrl(Key::Left),
+            word_right: Event::Ctrl(Key::Right),
+            delete_word_left: Event::Ctrl(Key::Backspace),
+            delete_word_right: Event::Ctrl(Key::Del),
+            home: Event::CtrlChar('a'),
+            end: Event::CtrlChar('e'),
+        }
+    }
+}
+
+// Returns `true` if this word-bound segment is an actual word.
+fn is_word(segment: &str) -> bool {
+    segment.chars().any(char::is_alphanumeric)
+}
+
+// Byte offset of the start of the word before `cursor`.
+fn prev_word_start(text: &str, cursor: usize) -> usize {
+    text[..cursor]
+        .split_word_bound_indices()
+        .rev()
+        .skip_while(|&(_, segment)| !is_word(segment))
+        .map(|(i, _)| i)
+        .next()
+        .unwrap_or(0)
+}
+
+// Byte offset of the end of the word after `cursor`.
+fn next_word_end(text: &str, cursor: usize) -> usize {
+    text[cursor..]
+        .split_word_bound_indices()
+        .find(|&(_, segment)| is_word(segment))
+        .map(|(i, segment)| cursor + i + segment.len())
+        .unwrap_or_else(|| text.len())
+}
+
 /// Input box where the user can enter and edit text.
 ///
 /// # Examples
@@ -102,15 +182,33 @@ pub struct EditView {
     /// Callback when <Enter> is pressed.
     on_submit: Option<Rc<OnSubmit>>,
 
+    /// Checks the content after each edit.
+    validator: Option<Rc<Validator>>,
+
+    /// Error message from the last validation, if it failed.
+    error: Option<String>,
+
     /// When `true`, only print `*` instead of the true content.
     secret: bool,
 
     /// Character to fill empty space
     filler: String,
 
+    /// If set, tabs are expanded to the next multiple of this width.
+    tab_width: Option<usize>,
+
+    /// Direction of the content, used to show right-to-left text.
+    direction: TextDirection,
+
     enabled: bool,
 
     style: ColorStyle,
+
+    /// Style used instead of `style` when the content is invalid.
+    error_style: ColorStyle,
+
+    /// Key bindings for word-by-word editing.
+    word_keys: WordNavigationKeys,
 }
 
 new_default!(EditView);
@@ -127,11 +225,17 @@ impl EditView {
             last_length: 0, // scrollable: false,
             on_edit: None,
             on_submit: None,
+            validator: None,
+            error: None,
             max_content_width: None,
             secret: false,
             filler: "_".to_string(),
+            tab_width: None,
+            direction: TextDirection::Ltr,
             enabled: true,
             style: ColorStyle::secondary(),
+            error_style: ColorStyle::error(),
+            word_keys: WordNavigationKeys::default(),
         }
     }
 
@@ -191,6 +295,50 @@ impl EditView {
         self.with(|s| s.set_filler(filler))
     }
 
+    /// Sets the width of tab stops for this view.
+    ///
+    /// With `Some(width)`, each `\t` is displayed as spaces up to the next
+    /// multiple of `width` columns. The content itself is not modified.
+    /// With `None` (the default), tabs are printed as-is.
+    pub fn set_tab_width(&mut self, tab_width: Option<usize>) {
+        self.tab_width = tab_width;
+        self.keep_cursor_in_view();
+    }
+
+    /// Sets the width of tab stops for this view.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn tab_width(self, tab_width: usize) -> Self {
+        self.with(|s| s.set_tab_width(Some(tab_width)))
+    }
+
+    /// Sets the direction of the content.
+    ///
+    /// Right-to-left words are always shown in visual order. With
+    /// `TextDirection::Rtl`, or with `TextDirection::Auto` if the content
+    /// starts with a right-to-left word, the content is also aligned to the
+    /// right.
+    ///
+    /// The cursor still moves in logical order: `Left` moves to the previous
+    /// character in the content, whatever its position on screen.
+    pub fn set_text_direction(&mut self, direction: TextDirection) {
+        self.direction = direction;
+    }
+
+    /// Sets the direction of the content.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn text_direction(self, direction: TextDirection) -> Self {
+        self.with(|s| s.set_text_direction(direction))
+    }
+
+    /// Returns the direction of the content.
+    pub fn get_text_direction(&self) -> TextDirection {
+        self.direction
+    }
+
     /// Sets the style used for this view.
     ///
     /// When the view is enabled, the style will be reversed.
@@ -210,6 +358,106 @@ impl EditView {
         self.with(|s| s.set_style(style))
     }
 
+    /// Sets the style used for this view when the content is invalid.
+    ///
+    /// When the view is enabled, the style will be reversed.
+    ///
+    /// Defaults to [`ColorStyle::error()`].
+    pub fn set_error_style(&mut self, style: ColorStyle) {
+        self.error_style = style;
+    }
+
+    /// Sets the style used for this view when the content is invalid.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn error_style(self, style: ColorStyle) -> Self {
+        self.with(|s| s.set_error_style(style))
+    }
+
+    /// Sets a function to validate the content.
+    ///
+    /// It is run on the current content, and again whenever the content is
+    /// modified (before the `on_edit` callback). If it returns an error, the
+    /// view is drawn with the error style, and the message is available
+    /// from [`error_message`](#method.error_message).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::views::EditView;
+    /// let mut view = EditView::new().validator(|text| {
+    ///     if text.chars().all(|c| c.is_ascii_digit()) {
+    ///         Ok(())
+    ///     } else {
+    ///         Err("Only digits are allowed".to_string())
+    ///     }
+    /// });
+    /// assert!(view.is_valid());
+    ///
+    /// view.set_content("12a");
+    /// assert_eq!(view.error_message(), Some("Only digits are allowed"));
+    /// ```
+    pub fn set_validator<F>(&mut self, validator: F)
+    where
+        F: Fn(&str) -> Result<(), String> + 'static,
+    {
+        self.validator = Some(Rc::new(validator));
+        self.validate();
+    }
+
+    /// Sets a function to validate the content.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn validator<F>(self, validator: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + 'static,
+    {
+        self.with(|v| v.set_validator(validator))
+    }
+
+    /// Removes the validation function, if any.
+    pub fn clear_validator(&mut self) {
+        self.validator = None;
+        self.error = None;
+    }
+
+    /// Returns `true` unless the validator rejected the current content.
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Returns the error message for the current content, if it is invalid.
+    pub fn error_message(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn validate(&mut self) {
+        self.error = self
+            .validator
+            .as_ref()
+            .and_then(|validator| validator(&self.content).err());
+    }
+
+    /// Sets the key bindings used for word-by-word editing.
+    pub fn set_word_navigation_keys(&mut self, keys: WordNavigationKeys) {
+        self.word_keys = keys;
+    }
+
+    /// Sets the key bindings used for word-by-word editing.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn word_navigation_keys(self, keys: WordNavigationKeys) -> Self {
+        self.with(|s| s.set_word_navigation_keys(keys))
+    }
+
+    /// Returns the key bindings used for word-by-word editing.
+    pub fn get_word_navigation_keys(&self) -> &WordNavigationKeys {
+        &self.word_keys
+    }
+
     /// Sets a mutable callback to be called whenever the content is modified.
     ///
     /// `callback` will be called with the view
@@ -363,6 +611,7 @@ impl EditView {
         self.content = Rc::new(content);
         self.offset = 0;
         self.set_cursor(len);
+        self.validate();
 
         self.make_edit_cb().unwrap_or_else(Callback::dummy)
     }
@@ -403,7 +652,7 @@ impl EditView {
             // Is that true? What about weird combined unicode thingies?
             // Also, say the user copy+paste some content, do we want to
             // stop halfway through a possibly split grapheme?
-            if ch.width().unwrap_or(0) + self.content.width() > width {
+            if char_width(ch) + self.content.width() > width {
                 // ABORT
                 return Callback::dummy();
             }
@@ -417,6 +666,7 @@ impl EditView {
         self.cursor += ch.len_utf8();
 
         self.keep_cursor_in_view();
+        self.validate();
 
         self.make_edit_cb().unwrap_or_else(Callback::dummy)
     }
@@ -432,6 +682,7 @@ impl EditView {
         for _ in Rc::make_mut(&mut self.content).drain(start..end) {}
 
         self.keep_cursor_in_view();
+        self.validate();
 
         self.make_edit_cb().unwrap_or_else(Callback::dummy)
     }
@@ -448,6 +699,51 @@ impl EditView {
         })
     }
 
+    // Handles the word navigation keys.
+    //
+    // Like the arrow keys, word moves and deletions that would do nothing
+    // are ignored, so the event can move the focus instead.
+    fn on_word_event(&mut self, event: &Event) -> Option<EventResult> {
+        let keys = &self.word_keys;
+        if *event == keys.word_left {
+            let cursor = prev_word_start(&self.content, self.cursor);
+            if cursor == self.cursor {
+                return Some(EventResult::Ignored);
+            }
+            self.set_cursor(cursor);
+        } else if *event == keys.word_right {
+            let cursor = next_word_end(&self.content, self.cursor);
+            if cursor == self.cursor {
+                return Some(EventResult::Ignored);
+            }
+            self.set_cursor(cursor);
+        } else if *event == keys.home {
+            self.set_cursor(0);
+        } else if *event == keys.end {
+            let len = self.content.len();
+            self.set_cursor(len);
+        } else if *event == keys.delete_word_left {
+            let start = prev_word_start(&self.content, self.cursor);
+            let len = self.cursor - start;
+            if len == 0 {
+                return Some(EventResult::Ignored);
+            }
+            self.cursor = start;
+            return Some(EventResult::Consumed(Some(self.remove(len))));
+        } else if *event == keys.delete_word_right {
+            let end = next_word_end(&self.content, self.cursor);
+            let len = end - self.cursor;
+            if len == 0 {
+                return Some(EventResult::Ignored);
+            }
+            return Some(EventResult::Consumed(Some(self.remove(len))));
+        } else {
+            return None;
+        }
+
+        Some(EventResult::Consumed(self.make_edit_cb()))
+    }
+
     fn keep_cursor_in_view(&mut self) {
         // keep cursor in [offset, offset+last_length] by changing offset
         // so keep offset in [last_length-cursor,cursor]
@@ -459,11 +755,7 @@ impl EditView {
             // So we're against the right wall.
             // Let's find how much space will be taken by the selection
             // (either a char, or _)
-            let c_len = self.content[self.cursor..]
-                .graphemes(true)
-                .map(UnicodeWidthStr::width)
-                .next()
-                .unwrap_or(1);
+            let c_len = self.grapheme_width(self.cursor).unwrap_or(1);
 
             // Now, we have to fit self.content[..self.cursor]
             // into self.last_length - c_len.
@@ -473,28 +765,192 @@ impl EditView {
                 None => return,
             };
             // Look at the content before the cursor (we will print its tail).
-            // From the end, count the length until we reach `available`.
-            // Then sum the byte lengths.
-            let suffix_length = simple_suffix(
-                &self.content[self.offset..self.cursor],
-                available,
-            )
-            .length;
-
-            assert!(suffix_length <= self.cursor);
-            self.offset = self.cursor - suffix_length;
+            // Find the longest tail that fits in `available`.
+            self.offset =
+                self.suffix_start(self.offset, self.cursor, available);
             // Make sure the cursor is in view
             assert!(self.cursor >= self.offset);
         }
 
         // If we have too much space
-        if self.content[self.offset..].width() < self.last_length {
+        let len = self.content.len();
+        if self.column(len) - self.column(self.offset) < self.last_length {
             assert!(self.last_length >= 1);
-            let suffix_length =
-                simple_suffix(&self.content, self.last_length - 1).length;
+            self.offset = self.suffix_start(0, len, self.last_length - 1);
+        }
+    }
+
+    // Display width of the given grapheme, starting at the given column.
+    fn display_width(&self, grapheme: &str, column: usize) -> usize {
+        match (grapheme, self.tab_width) {
+            ("\t", Some(0)) => 0,
+            ("\t", Some(tab_width)) => tab_width - column % tab_width,
+            _ => grapheme.width(),
+        }
+    }
+
+    // Display column of the given byte position in the content.
+    fn column(&self, byte: usize) -> usize {
+        let text = &self.content[..byte];
+        match self.tab_width {
+            Some(tab_width) => expand_tabs(text, tab_width, 0).width(),
+            None => text.width(),
+        }
+    }
+
+    // Display width of the grapheme starting at the given byte position.
+    //
+    // Returns `None` at the end of the content.
+    fn grapheme_width(&self, byte: usize) -> Option<usize> {
+        let grapheme = self.content[byte..].graphemes(true).next()?;
+        Some(self.display_width(grapheme, self.column(byte)))
+    }
+
+    // Returns the byte position and display column of each grapheme
+    // boundary, including the end of the content.
+    fn grapheme_columns(&self) -> Vec<(usize, usize)> {
+        let mut column = 0;
+        let mut result = vec![(0, 0)];
+        for (byte, grapheme) in self.content.grapheme_indices(true) {
+            column += self.display_width(grapheme, column);
+            result.push((byte + grapheme.len(), column));
+        }
+        result
+    }
+
+    // Returns the earliest position after `start` such that the content up
+    // to `end` fits in `width`.
+    fn suffix_start(&self, start: usize, end: usize, width: usize) -> usize {
+        let end_column = self.column(end);
+        self.grapheme_columns()
+            .into_iter()
+            .find(|&(byte, column)| {
+                byte >= start && byte <= end && end_column - column <= width
+            })
+            .map_or(end, |(byte, _)| byte)
+    }
+
+    // Returns the furthest position such that the content from `start` fits
+    // in `width`.
+    fn prefix_end(&self, start: usize, width: usize) -> usize {
+        let start_column = self.column(start);
+        self.grapheme_columns()
+            .into_iter()
+            .skip_while(|&(byte, _)| byte < start)
+            .take_while(|&(_, column)| column - start_column <= width)
+            .last()
+            .map_or(start, |(byte, _)| byte)
+    }
+
+    // Returns the byte range of the content currently visible.
+    fn visible_range(&self) -> (usize, usize) {
+        if self.column(self.content.len()) < self.last_length {
+            (0, self.content.len())
+        } else {
+            (self.offset, self.prefix_end(self.offset, self.last_length))
+        }
+    }
+
+    // Returns the visible graphemes in visual order, with their byte
+    // position in the content and their display width.
+    //
+    // Returns `None` when the visible text is shown in logical order.
+    fn visual_graphemes(&self) -> Option<VisualLine<'_>> {
+        let direction = self.direction.resolve(&self.content);
+        let (start, end) = self.visible_range();
+        let text = &self.content[start..end];
+        if self.secret || !bidi::needs_reorder(text, direction) {
+            return None;
+        }
+
+        let mut column = self.column(start);
+        let mut graphemes: Vec<_> = text
+            .grapheme_indices(true)
+            .map(|(byte, g)| {
+                let width = self.display_width(g, column);
+                column += width;
+                (g, (start + byte, width))
+            })
+            .collect();
+        bidi::reorder(&mut graphemes, direction);
+
+        let width = graphemes.iter().map(|&(_, (_, width))| width).sum();
+        // Right-to-left text is aligned to the right.
+        let x = match direction {
+            TextDirection::Rtl => self.last_length.saturating_sub(width),
+            _ => 0,
+        };
+
+        Some(VisualLine {
+            graphemes,
+            direction,
+            x,
+            width,
+        })
+    }
+
+    // Draws the content in visual order.
+    fn draw_visual(&self, printer: &Printer, line: &VisualLine<'_>) {
+        let filler_start = match line.direction {
+            TextDirection::Rtl => 0,
+            _ => line.width,
+        };
+        let filler_width = self.last_length.saturating_sub(line.width);
+        printer.print_hline(
+            (filler_start, 0),
+            filler_width / self.filler.width(),
+            self.filler.as_str(),
+        );
+
+        let mut x = line.x;
+        for &(g, (_, width)) in &line.graphemes {
+            if g == "\t" {
+                printer.print_hline((x, 0), width, " ");
+            } else {
+                printer.print((x, 0), g);
+            }
+            x += width;
+        }
+    }
+
+    // Returns the screen column of the cursor, and the text under it.
+    fn visual_cursor<'a>(&'a self, line: &VisualLine<'a>) -> (usize, &'a str) {
+        let mut x = line.x;
+        for &(g, (byte, width)) in &line.graphemes {
+            if byte == self.cursor {
+                return (x, if g == "\t" { " " } else { g });
+            }
+            x += width;
+        }
+
+        // The cursor is after the last character.
+        let x = match line.direction {
+            TextDirection::Rtl => line.x.saturating_sub(1),
+            _ => line.x + line.width,
+        };
+        (x, &self.filler)
+    }
+
+    // Returns the cursor position for a click at the given screen column.
+    fn visual_position(&self, line: &VisualLine<'_>, column: usize) -> usize {
+        let mut x = line.x;
+        for &(_, (byte, width)) in &line.graphemes {
+            if column < x + width {
+                return if column < x { self.content.len() } else { byte };
+            }
+            x += width;
+        }
+        self.content.len()
+    }
 
-            assert!(self.content.len() >= suffix_length);
-            self.offset = self.content.len() - suffix_length;
+    // Text to display between the given byte positions.
+    fn display(&self, start: usize, end: usize) -> Cow<'_, str> {
+        let text = &self.content[start..end];
+        match self.tab_width {
+            Some(tab_width) => {
+                expand_tabs(text, tab_width, self.column(start))
+            }
+            None => Cow::Borrowed(text),
         }
     }
 }
@@ -516,21 +972,32 @@ impl View for EditView {
             self.last_length, printer.size.x
         );
 
-        let width = self.content.width();
-        printer.with_color(self.style, |printer| {
+        let width = self.column(self.content.len());
+        let style = if self.is_valid() {
+            self.style
+        } else {
+            self.error_style
+        };
+        let visual = self.visual_graphemes();
+        printer.with_color(style, |printer| {
             let effect = if self.enabled && printer.enabled {
                 Effect::Reverse
             } else {
                 Effect::Simple
             };
             printer.with_effect(effect, |printer| {
-                if width < self.last_length {
+                if let Some(ref line) = visual {
+                    self.draw_visual(printer, line);
+                } else if width < self.last_length {
                     // No problem, everything fits.
                     assert!(printer.size.x >= width);
                     if self.secret {
                         printer.print_hline((0, 0), width, "*");
                     } else {
-                        printer.print((0, 0), &self.content);
+                        printer.print(
+                            (0, 0),
+                            &self.display(0, self.content.len()),
+                        );
                     }
                     let filler_len =
                         (printer.size.x - width) / self.filler.width();
@@ -540,27 +1007,13 @@ impl View for EditView {
                         self.filler.as_str(),
                     );
                 } else {
-                    let content = &self.content[self.offset..];
-                    let display_bytes = content
-                        .graphemes(true)
-                        .scan(0, |w, g| {
-                            *w += g.width();
-                            if *w > self.last_length {
-                                None
-                            } else {
-                                Some(g)
-                            }
-                        })
-                        .map(str::len)
-                        .sum();
-
-                    let content = &content[..display_bytes];
-                    let width = content.width();
+                    let end = self.prefix_end(self.offset, self.last_length);
+                    let width = self.column(end) - self.column(self.offset);
 
                     if self.secret {
                         printer.print_hline((0, 0), width, "*");
                     } else {
-                        printer.print((0, 0), content);
+                        printer.print((0, 0), &self.display(self.offset, end));
                     }
 
                     if width < self.last_length {
@@ -576,7 +1029,10 @@ impl View for EditView {
             });
 
             // Now print cursor
-            if printer.focused {
+            if let (true, Some(line)) = (printer.focused, &visual) {
+                let (x, c) = self.visual_cursor(line);
+                printer.print((x, 0), c);
+            } else if printer.focused {
                 let c: &str = if self.cursor == self.content.len() {
                     &self.filler
                 } else {
@@ -592,11 +1048,14 @@ impl View for EditView {
                         });
                     if self.secret {
                         make_small_stars(selected.width())
+                    } else if selected == "\t" && self.tab_width.is_some() {
+                        " "
                     } else {
                         selected
                     }
                 };
-                let offset = self.content[self.offset..self.cursor].width();
+                let offset =
+                    self.column(self.cursor) - self.column(self.offset);
                 printer.print((offset, 0), c);
             }
         });
@@ -617,11 +1076,13 @@ impl View for EditView {
         if !self.enabled {
             return EventResult::Ignored;
         }
+        if let Some(result) = self.on_word_event(&event) {
+            return result;
+        }
         match event {
             Event::Char(ch) => {
                 return EventResult::Consumed(Some(self.insert(ch)));
             }
-            // TODO: handle ctrl-key?
             Event::Key(Key::Home) => self.set_cursor(0),
             Event::Key(Key::End) => {
                 // When possible, NLL to the rescue!
@@ -676,12 +1137,10 @@ impl View for EditView {
                 offset,
             } if position.fits_in_rect(offset, (self.last_length, 1)) => {
                 if let Some(position) = position.checked_sub(offset) {
-                    self.cursor = self.offset
-                        + simple_prefix(
-                            &self.content[self.offset..],
-                            position.x,
-                        )
-                        .length;
+                    self.cursor = match self.visual_graphemes() {
+                        Some(line) => self.visual_position(&line, position.x),
+                        None => self.prefix_end(self.offset, position.x),
+                    };
                 }
             }
             _ => return EventResult::Ignored,
@@ -698,15 +1157,143 @@ impl View for EditView {
             1
         } else {
             // Otherwise look at the selected character.
-            self.content[self.cursor..]
-                .graphemes(true)
-                .next()
-                .unwrap()
-                .width()
+            self.grapheme_width(self.cursor).unwrap()
         };
 
-        let x = self.content[..self.cursor].width();
+        let x = self.column(self.cursor);
 
         Rect::from_size((x, 0), (char_width, 1))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn word_boundaries() {
+        let text = "hello, wide  world";
+
+        assert_eq!(prev_word_start(text, text.len()), 13);
+        assert_eq!(prev_word_start(text, 13), 7);
+        assert_eq!(prev_word_start(text, 9), 7);
+        assert_eq!(prev_word_start(text, 7), 0);
+        assert_eq!(prev_word_start(text, 0), 0);
+
+        assert_eq!(next_word_end(text, 0), 5);
+        assert_eq!(next_word_end(text, 5), 11);
+        assert_eq!(next_word_end(text, 11), text.len());
+        assert_eq!(next_word_end(text, text.len()), text.len());
+    }
+
+    #[test]
+    fn delete_words() {
+        let mut view = EditView::new().content("hello, wide world");
+
+        view.on_event(Event::Ctrl(Key::Backspace));
+        assert_eq!(&*view.get_content(), "hello, wide ");
+
+        view.on_event(Event::CtrlChar('a'));
+        view.on_event(Event::Ctrl(Key::Del));
+        assert_eq!(&*view.get_content(), ", wide ");
+    }
+
+    #[test]
+    fn word_moves_at_edges() {
+        let mut view = EditView::new().content("hello");
+
+        // The cursor starts at the end: nothing to move over.
+        assert!(!view.on_event(Event::Ctrl(Key::Right)).is_consumed());
+        assert!(!view.on_event(Event::Ctrl(Key::Del)).is_consumed());
+
+        assert!(view.on_event(Event::Ctrl(Key::Left)).is_consumed());
+        assert_eq!(view.cursor, 0);
+        assert!(!view.on_event(Event::Ctrl(Key::Left)).is_consumed());
+        assert!(!view.on_event(Event::Ctrl(Key::Backspace)).is_consumed());
+        assert_eq!(&*view.get_content(), "hello");
+    }
+
+    #[test]
+    fn visual_cursor() {
+        // With the `bidi` feature, the hebrew word is shown reversed, after
+        // the latin one. Otherwise, the text stays in logical order.
+        let mut view = EditView::new().content("ab שלום");
+        view.layout(Vec2::new(10, 1));
+        view.set_cursor(3);
+        if cfg!(feature = "bidi") {
+            let line = view.visual_graphemes().unwrap();
+            assert_eq!(view.visual_cursor(&line), (6, "ש"));
+            assert_eq!(view.visual_position(&line, 3), 9);
+        } else {
+            assert!(view.visual_graphemes().is_none());
+        }
+
+        // Right-to-left content is aligned to the right, and the cursor at
+        // the end is on its left.
+        let mut view = EditView::new()
+            .content("שלום")
+            .text_direction(TextDirection::Auto);
+        view.layout(Vec2::new(10, 1));
+        let line = view.visual_graphemes().unwrap();
+        assert_eq!(view.visual_cursor(&line), (5, "_"));
+        assert_eq!(view.visual_position(&line, 0), view.get_content().len());
+    }
+
+    fn digits(text: &str) -> Result<(), String> {
+        if text.chars().all(|c| c.is_ascii_digit()) {
+            Ok(())
+        } else {
+            Err("Only digits".to_string())
+        }
+    }
+
+    #[test]
+    fn validator_accepts() {
+        let mut view = EditView::new().content("12").validator(digits);
+        assert!(view.is_valid());
+
+        view.on_event(Event::Char('3'));
+        assert_eq!(&*view.get_content(), "123");
+        assert!(view.is_valid());
+        assert_eq!(view.error_message(), None);
+    }
+
+    #[test]
+    fn validator_rejects() {
+        let mut view = EditView::new().validator(digits);
+        view.set_content("1a");
+        assert!(!view.is_valid());
+        assert_eq!(view.error_message(), Some("Only digits"));
+
+        // Removing the validator clears the error.
+        view.clear_validator();
+        assert!(view.is_valid());
+    }
+
+    #[test]
+    fn validator_on_rejected_input() {
+        let mut siv = Cursive::new();
+        siv.set_user_data(0usize);
+
+        let mut view = EditView::new()
+            .validator(digits)
+            .on_edit(|s, _, _| *s.user_data::<usize>().unwrap() += 1);
+
+        // Rejected input is kept and flagged, and `on_edit` still runs.
+        let result = view.on_event(Event::Char('x'));
+        assert!(result.has_callback());
+        result.process(&mut siv);
+        assert_eq!(siv.user_data::<usize>(), Some(&mut 1));
+        assert_eq!(&*view.get_content(), "x");
+        assert!(!view.is_valid());
+
+        // Events that do not change the content leave the error alone.
+        assert!(!view.on_event(Event::Key(Key::Del)).has_callback());
+        assert_eq!(view.error_message(), Some("Only digits"));
+
+        // Fixing the content makes it valid again.
+        view.on_event(Event::Key(Key::Backspace)).process(&mut siv);
+        assert_eq!(siv.user_data::<usize>(), Some(&mut 2));
+        assert!(view.is_valid());
+    }
+}
@@ -1,15 +1,181 @@
 use crate::{
+    align::{HAlign, VAlign},
     direction::{Absolute, Direction, Relative},
     event::{AnyCb, Event, EventResult, Key},
     rect::Rect,
     view::{CannotFocus, IntoBoxedView, Selector, ViewNotFound},
-    {Printer, Vec2, View, With},
+    {Printer, Vec2, View, With, XY},
 };
 
+/// Describes where a child of a [`FixedLayout`] should be placed.
+///
+/// Except for `Absolute`, placements are resolved against the size of the
+/// layout every time it changes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Placement {
+    /// Fixed position and size.
+    Absolute(Rect),
+    /// Aligned in the layout, using the child's required size.
+    ///
+    /// `offset` is the distance from the aligned edges. It is ignored for
+    /// centered axes.
+    Aligned {
+        /// Horizontal alignment.
+        h: HAlign,
+        /// Vertical alignment.
+        v: VAlign,
+        /// Distance from the aligned edges.
+        offset: Vec2,
+    },
+    /// Centered in the layout, with a fixed size.
+    Centered(Vec2),
+    /// Position and size given as fractions of the layout size.
+    ///
+    /// Each value is clamped to `0.0..=1.0`.
+    Relative {
+        /// Horizontal position of the left edge.
+        x: f32,
+        /// Vertical position of the top edge.
+        y: f32,
+        /// Width.
+        w: f32,
+        /// Height.
+        h: f32,
+    },
+}
+
+impl Placement {
+    /// Places a child in the top-left corner, `offset` away from the edges.
+    pub fn top_left<V: Into<Vec2>>(offset: V) -> Self {
+        Self::aligned(HAlign::Left, VAlign::Top, offset)
+    }
+
+    /// Places a child in the top-right corner, `offset` away from the edges.
+    pub fn top_right<V: Into<Vec2>>(offset: V) -> Self {
+        Self::aligned(HAlign::Right, VAlign::Top, offset)
+    }
+
+    /// Places a child in the bottom-left corner, `offset` away from the
+    /// edges.
+    pub fn bottom_left<V: Into<Vec2>>(offset: V) -> Self {
+        Self::aligned(HAlign::Left, VAlign::Bottom, offset)
+    }
+
+    /// Places a child in the bottom-right corner, `offset` away from the
+    /// edges.
+    pub fn bottom_right<V: Into<Vec2>>(offset: V) -> Self {
+        Self::aligned(HAlign::Right, VAlign::Bottom, offset)
+    }
+
+    /// Places a child with the given alignment.
+    pub fn aligned<V: Into<Vec2>>(h: HAlign, v: VAlign, offset: V) -> Self {
+        Placement::Aligned {
+            h,
+            v,
+            offset: offset.into(),
+        }
+    }
+
+    /// Places a child of the given size in the center of the layout.
+    pub fn centered<V: Into<Vec2>>(size: V) -> Self {
+        Placement::Centered(size.into())
+    }
+
+    /// Places a child using fractions of the layout size.
+    ///
+    /// For example, `Placement::percent(0.25, 0.1, 0.5, 0.8)` uses half the
+    /// width, starting at a quarter of the layout.
+    pub fn percent(x: f32, y: f32, w: f32, h: f32) -> Self {
+        Placement::Relative { x, y, w, h }
+    }
+
+    /// Computes the area for a child in a layout of the given size.
+    ///
+    /// `required_size` is called to find the child's size when needed.
+    pub fn resolve<F>(&self, size: Vec2, required_size: F) -> Rect
+    where
+        F: FnOnce(Vec2) -> Vec2,
+    {
+        match *self {
+            Placement::Absolute(rect) => rect,
+            Placement::Aligned { h, v, offset } => {
+                let available = size.saturating_sub(offset);
+                let child_size = required_size(available).or_min(available);
+                let x = match h {
+                    HAlign::Left => offset.x,
+                    HAlign::Center => (size.x - child_size.x) / 2,
+                    HAlign::Right => available.x - child_size.x,
+                };
+                let y = match v {
+                    VAlign::Top => offset.y,
+                    VAlign::Center => (size.y - child_size.y) / 2,
+                    VAlign::Bottom => available.y - child_size.y,
+                };
+                Rect::from_size((x, y), child_size)
+            }
+            Placement::Centered(child_size) => {
+                let child_size = child_size.or_min(size);
+                Rect::from_size((size - child_size) / 2, child_size)
+            }
+            Placement::Relative { x, y, w, h } => {
+                let scale = |fraction: f32, length: usize| {
+                    (fraction.clamp(0.0, 1.0) * length as f32).round() as usize
+                };
+                let top_left = Vec2::new(scale(x, size.x), scale(y, size.y));
+                let child_size = Vec2::new(scale(w, size.x), scale(h, size.y))
+                    .or_min(size.saturating_sub(top_left));
+                Rect::from_size(top_left, child_size)
+            }
+        }
+    }
+
+    // Size the layout would need to fit this placement.
+    fn required_size<F>(&self, constraint: Vec2, required_size: F) -> Vec2
+    where
+        F: FnOnce(Vec2) -> Vec2,
+    {
+        match *self {
+            Placement::Absolute(rect) => rect.bottom_right() + (1, 1),
+            Placement::Aligned { offset, .. } => {
+                required_size(constraint.saturating_sub(offset))
+                    .or_min(constraint.saturating_sub(offset))
+                    + offset
+            }
+            Placement::Centered(size) => size,
+            Placement::Relative { w, h, .. } => {
+                // The child gets a fraction of the layout: grow the layout
+                // until that fraction fits the child.
+                let fractions = XY::new(w, h).map(|f| f.clamp(0.0, 1.0));
+                let available = constraint.zip_map(fractions, |c, f| {
+                    (f * c as f32).round() as usize
+                });
+                required_size(available)
+                    .zip_map(fractions, |length, f| {
+                        if f > 0.0 {
+                            (length as f32 / f).ceil() as usize
+                        } else {
+                            0
+                        }
+                    })
+                    .or_min(constraint)
+            }
+        }
+    }
+}
+
+impl From<Rect> for Placement {
+    fn from(rect: Rect) -> Self {
+        Placement::Absolute(rect)
+    }
+}
+
 /// Arranges its children in a fixed layout.
 ///
 /// Usually meant to use an external layout engine.
 ///
+/// Children can also be placed relative to the layout size using
+/// [`Placement`]. Later children are drawn on top of earlier ones.
+///
 /// # Examples
 ///
 /// ```rust
@@ -28,6 +194,14 @@ use crate::{
 ///         Button::new("Clickme", |s| s.quit()),
 ///     );
 /// ```
+///
+/// ```rust
+/// use cursive_core::views::{FixedLayout, Placement, TextView};
+///
+/// let hud = FixedLayout::new()
+///     .child(Placement::percent(0.0, 0.0, 1.0, 1.0), TextView::new("Map"))
+///     .child(Placement::bottom_right((1, 1)), TextView::new("HP: 100"));
+/// ```
 pub struct FixedLayout {
     children: Vec<Child>,
     focus: usize,
@@ -36,6 +210,8 @@ pub struct FixedLayout {
 /// Represents a child view inside the FixedLayout.
 struct Child {
     view: Box<dyn View>,
+    placement: Placement,
+    // Area resolved from the placement during the last layout.
     position: Rect,
 }
 
@@ -60,15 +236,34 @@ impl FixedLayout {
     }
 
     /// Adds a child. Chainable variant.
+    ///
+    /// `placement` can be a `Rect` for an absolute position.
     #[must_use]
-    pub fn child<V: IntoBoxedView>(self, position: Rect, view: V) -> Self {
-        self.with(|s| s.add_child(position, view))
+    pub fn child<P, V>(self, placement: P, view: V) -> Self
+    where
+        P: Into<Placement>,
+        V: IntoBoxedView,
+    {
+        self.with(|s| s.add_child(placement, view))
     }
 
     /// Adds a child.
-    pub fn add_child<V: IntoBoxedView>(&mut self, position: Rect, view: V) {
+    ///
+    /// `placement` can be a `Rect` for an absolute position.
+    pub fn add_child<P, V>(&mut self, placement: P, view: V)
+    where
+        P: Into<Placement>,
+        V: IntoBoxedView,
+    {
+        let placement = placement.into();
+        let position = match placement {
+            Placement::Absolute(rect) => rect,
+            // Will be resolved during layout.
+            _ => Rect::from_size((0, 0), (0, 0)),
+        };
         self.children.push(Child {
             view: view.into_boxed_view(),
+            placement,
             position,
         });
     }
@@ -126,7 +321,23 @@ impl FixedLayout {
 
     /// Sets the position for the given child.
     pub fn set_child_position(&mut self, i: usize, position: Rect) {
-        self.children[i].position = position;
+        self.set_child_placement(i, Placement::Absolute(position));
+    }
+
+    /// Sets the placement for the given child.
+    ///
+    /// It will be used from the next layout.
+    pub fn set_child_placement(&mut self, i: usize, placement: Placement) {
+        let child = &mut self.children[i];
+        child.placement = placement;
+        if let Placement::Absolute(rect) = placement {
+            child.position = rect;
+        }
+    }
+
+    /// Returns the placement of the given child.
+    pub fn get_child_placement(&self, i: usize) -> Option<Placement> {
+        self.children.get(i).map(|c| c.placement)
     }
 
     /// Removes a child.
@@ -255,12 +466,15 @@ impl FixedLayout {
                 Some(pos) => pos,
             };
 
+            // Later children are drawn on top: only the topmost child under
+            // the cursor can grab the focus.
             if let Some((i, res)) = self
                 .children
                 .iter_mut()
                 .enumerate()
-                .filter(|(_, c)| c.position.contains(position))
-                .find_map(Child::focuser(Direction::none()))
+                .rev()
+                .find(|(_, c)| c.position.contains(position))
+                .and_then(Child::focuser(Direction::none()))
             {
                 return Some(self.set_focus_unchecked(i).and(res));
             }
@@ -277,9 +491,12 @@ impl View for FixedLayout {
         }
     }
 
-    fn layout(&mut self, _size: Vec2) {
-        // TODO: re-compute children positions?
+    fn layout(&mut self, size: Vec2) {
         for child in &mut self.children {
+            let view = &mut child.view;
+            child.position = child
+                .placement
+                .resolve(size, |available| view.required_size(available));
             child.view.layout(child.position.size());
         }
     }
@@ -313,6 +530,12 @@ impl View for FixedLayout {
         })
     }
 
+    fn debug_children(&self, f: &mut dyn FnMut(&dyn View, Option<Rect>)) {
+        for child in &self.children {
+            f(&*child.view, Some(child.position));
+        }
+    }
+
     fn important_area(&self, size: Vec2) -> Rect {
         if self.is_empty() {
             return Rect::from_size((0, 0), size);
@@ -324,10 +547,15 @@ impl View for FixedLayout {
             + child.position.top_left()
     }
 
-    fn required_size(&mut self, _constraint: Vec2) -> Vec2 {
+    fn required_size(&mut self, constraint: Vec2) -> Vec2 {
         self.children
-            .iter()
-            .map(|c| c.position.bottom_right() + (1, 1))
+            .iter_mut()
+            .map(|c| {
+                let view = &mut c.view;
+                c.placement.required_size(constraint, |available| {
+                    view.required_size(available)
+                })
+            })
             .fold(Vec2::zero(), Vec2::max)
     }
 
@@ -387,3 +615,54 @@ impl View for FixedLayout {
         Err(ViewNotFound)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_placements() {
+        let size = Vec2::new(20, 10);
+        let required = |_| Vec2::new(4, 2);
+
+        assert_eq!(
+            Placement::bottom_right((1, 1)).resolve(size, required),
+            Rect::from_size((15, 7), (4, 2))
+        );
+        assert_eq!(
+            Placement::top_left((2, 1)).resolve(size, required),
+            Rect::from_size((2, 1), (4, 2))
+        );
+        assert_eq!(
+            Placement::centered((10, 4)).resolve(size, required),
+            Rect::from_size((5, 3), (10, 4))
+        );
+        assert_eq!(
+            Placement::percent(0.25, 0.1, 0.5, 0.8).resolve(size, required),
+            Rect::from_size((5, 1), (10, 8))
+        );
+
+        // Re-resolved against a smaller size.
+        assert_eq!(
+            Placement::centered((10, 4)).resolve(Vec2::new(8, 8), required),
+            Rect::from_size((0, 2), (8, 4))
+        );
+    }
+
+    #[test]
+    fn required_size() {
+        let required = |_| Vec2::new(4, 2);
+        let constraint = Vec2::new(20, 10);
+
+        assert_eq!(
+            Placement::percent(0.25, 0.0, 0.5, 1.0)
+                .required_size(constraint, required),
+            Vec2::new(8, 2)
+        );
+        assert_eq!(
+            Placement::percent(0.0, 0.0, 0.1, 0.0)
+                .required_size(constraint, required),
+            Vec2::new(20, 0)
+        );
+    }
+}
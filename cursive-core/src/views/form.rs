@@ -0,0 +1,384 @@
+use crate::{
+    view::{View, ViewWrapper},
+    views::{
+        Checkbox, EditView, ListChild, ListView, RowStatus, SelectView,
+        SliderView, TextArea,
+    },
+    With,
+};
+use std::any::Any;
+use std::rc::Rc;
+
+/// Value extracted from a field of a [`Form`].
+#[derive(Clone, Debug)]
+pub enum FormValue {
+    /// Text content, from an `EditView` or a `TextArea`.
+    String(String),
+    /// Boolean state, from a `Checkbox`.
+    Bool(bool),
+    /// Selected index, from a `SelectView` or a `SliderView`.
+    ///
+    /// `None` if nothing is selected.
+    Selection(Option<usize>),
+    /// Any other value, from a custom field.
+    Custom(Rc<dyn Any>),
+}
+
+impl FormValue {
+    /// Returns the text content, if this is a `String` value.
+    pub fn as_str(&self) -> Option<&str> {
+        match *self {
+            FormValue::String(ref s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the boolean state, if this is a `Bool` value.
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            FormValue::Bool(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    /// Returns the selected index, if this is a `Selection` value.
+    pub fn as_selection(&self) -> Option<usize> {
+        match *self {
+            FormValue::Selection(id) => id,
+            _ => None,
+        }
+    }
+
+    /// Returns the custom value, if this is a `Custom` value of type `T`.
+    pub fn as_custom<T: Any>(&self) -> Option<&T> {
+        match *self {
+            FormValue::Custom(ref value) => value.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// View that can be used as a field in a [`Form`].
+pub trait FormField: View {
+    /// Returns the current value of this field.
+    fn form_value(&self) -> FormValue;
+}
+
+impl FormField for EditView {
+    fn form_value(&self) -> FormValue {
+        FormValue::String(self.get_content().to_string())
+    }
+}
+
+impl FormField for TextArea {
+    fn form_value(&self) -> FormValue {
+        FormValue::String(self.get_content().to_string())
+    }
+}
+
+impl FormField for Checkbox {
+    fn form_value(&self) -> FormValue {
+        FormValue::Bool(self.is_checked())
+    }
+}
+
+impl<T: 'static> FormField for SelectView<T> {
+    fn form_value(&self) -> FormValue {
+        FormValue::Selection(self.selected_id())
+    }
+}
+
+impl FormField for SliderView {
+    fn form_value(&self) -> FormValue {
+        FormValue::Selection(Some(self.get_value()))
+    }
+}
+
+/// Values collected from a [`Form`], indexed by field name.
+///
+/// Fields are kept in the order they were added to the form.
+#[derive(Clone, Debug, Default)]
+pub struct FormData {
+    values: Vec<(String, FormValue)>,
+}
+
+impl FormData {
+    /// Returns the value of the given field.
+    pub fn get(&self, name: &str) -> Option<&FormValue> {
+        self.values
+            .iter()
+            .find(|&(field, _)| field == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the text content of the given field.
+    ///
+    /// Returns `None` if the field does not exist or is not a text field.
+    pub fn get_str(&self, name: &str) -> Option<&str> {
+        self.get(name).and_then(FormValue::as_str)
+    }
+
+    /// Returns the boolean state of the given field.
+    ///
+    /// Returns `None` if the field does not exist or is not a boolean field.
+    pub fn get_bool(&self, name: &str) -> Option<bool> {
+        self.get(name).and_then(FormValue::as_bool)
+    }
+
+    /// Returns the selected index of the given field.
+    ///
+    /// Returns `None` if the field does not exist, is not a selection field,
+    /// or if nothing is selected.
+    pub fn get_selection(&self, name: &str) -> Option<usize> {
+        self.get(name).and_then(FormValue::as_selection)
+    }
+
+    /// Returns an iterator on the `(name, value)` pairs.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &FormValue)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+
+    /// Returns the number of fields.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if there is no field.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+type Getter = Box<dyn Fn(&dyn View) -> Option<FormValue>>;
+type Validator = Box<dyn Fn(&FormValue) -> Result<(), String>>;
+
+struct Field {
+    name: String,
+    // Index of this field's row in the list.
+    row: usize,
+    getter: Getter,
+    validators: Vec<Validator>,
+}
+
+/// Labeled list of input views, collecting their values into a [`FormData`].
+///
+/// Each field is shown as a row of a [`ListView`], labeled with its name.
+///
+/// Validation errors are shown as a [`RowStatus::Error`] on the
+/// corresponding row.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive_core::views::{Checkbox, EditView, Form};
+/// let mut form = Form::new()
+///     .field("name", EditView::new().content("root"))
+///     .field("admin", Checkbox::new().checked())
+///     .validate("name", |value| match value.as_str() {
+///         Some("") => Err("Name cannot be empty".to_string()),
+///         _ => Ok(()),
+///     });
+///
+/// let data = form.validated_data().unwrap();
+/// assert_eq!(data.get_str("name"), Some("root"));
+/// assert_eq!(data.get_bool("admin"), Some(true));
+/// ```
+pub struct Form {
+    list: ListView,
+    fields: Vec<Field>,
+}
+
+new_default!(Form);
+
+impl Form {
+    /// Creates a new, empty form.
+    pub fn new() -> Self {
+        Form {
+            list: ListView::new(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Adds a field to the form.
+    ///
+    /// `name` is used both as the row label and as the key in `FormData`.
+    pub fn add_field<V: FormField>(&mut self, name: &str, view: V) {
+        self.add_custom_field(name, view, FormField::form_value);
+    }
+
+    /// Adds a field to the form.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn field<V: FormField>(self, name: &str, view: V) -> Self {
+        self.with(|s| s.add_field(name, view))
+    }
+
+    /// Adds a field using a custom function to extract its value.
+    ///
+    /// This can be used for views that do not implement [`FormField`].
+    pub fn add_custom_field<V, F>(&mut self, name: &str, view: V, getter: F)
+    where
+        V: View,
+        F: Fn(&V) -> FormValue + 'static,
+    {
+        self.fields.push(Field {
+            name: name.to_string(),
+            row: self.list.len(),
+            getter: Box::new(move |view| {
+                view.downcast_ref::<V>().map(&getter)
+            }),
+            validators: Vec::new(),
+        });
+        self.list.add_child(name, view);
+    }
+
+    /// Adds a field using a custom function to extract its value.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn custom_field<V, F>(self, name: &str, view: V, getter: F) -> Self
+    where
+        V: View,
+        F: Fn(&V) -> FormValue + 'static,
+    {
+        self.with(|s| s.add_custom_field(name, view, getter))
+    }
+
+    /// Adds a delimiter between fields.
+    pub fn add_delimiter(&mut self) {
+        self.list.add_delimiter();
+    }
+
+    /// Adds a delimiter between fields.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn delimiter(self) -> Self {
+        self.with(Self::add_delimiter)
+    }
+
+    /// Adds a validation rule to the given field.
+    ///
+    /// The rule is checked by `validated_data()`. If it returns an error,
+    /// the message is shown as the status of the field's row.
+    ///
+    /// Does nothing if no field has this name.
+    pub fn add_validator<F>(&mut self, name: &str, validator: F)
+    where
+        F: Fn(&FormValue) -> Result<(), String> + 'static,
+    {
+        if let Some(field) = self.fields.iter_mut().find(|f| f.name == name) {
+            field.validators.push(Box::new(validator));
+        }
+    }
+
+    /// Adds a validation rule to the given field.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn validate<F>(self, name: &str, validator: F) -> Self
+    where
+        F: Fn(&FormValue) -> Result<(), String> + 'static,
+    {
+        self.with(|s| s.add_validator(name, validator))
+    }
+
+    /// Returns the current value of every field, without validation.
+    pub fn data(&self) -> FormData {
+        let values = self
+            .fields
+            .iter()
+            .filter_map(|field| {
+                self.value(field).map(|value| (field.name.clone(), value))
+            })
+            .collect();
+
+        FormData { values }
+    }
+
+    /// Runs the validation rules, and returns the values if they all pass.
+    ///
+    /// The status of each row is updated with the first error found for
+    /// this field, or reset if every rule passed.
+    ///
+    /// Returns `None` if any rule failed.
+    pub fn validated_data(&mut self) -> Option<FormData> {
+        let data = self.data();
+        let mut valid = true;
+
+        for field in &self.fields {
+            let error = data.get(&field.name).and_then(|value| {
+                field.validators.iter().find_map(|v| v(value).err())
+            });
+
+            let status = match error {
+                Some(message) => {
+                    valid = false;
+                    RowStatus::Error(message)
+                }
+                None => RowStatus::Normal,
+            };
+            self.list.set_row_status(&field.name, status);
+        }
+
+        if valid {
+            Some(data)
+        } else {
+            None
+        }
+    }
+
+    fn value(&self, field: &Field) -> Option<FormValue> {
+        match *self.list.get_row(field.row) {
+            ListChild::Row(_, ref view) => (field.getter)(&**view),
+            ListChild::Delimiter => None,
+        }
+    }
+
+    inner_getters!(self.list: ListView);
+}
+
+impl ViewWrapper for Form {
+    wrap_impl!(self.list: ListView);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validation() {
+        let mut form = Form::new()
+            .field("name", EditView::new())
+            .delimiter()
+            .field("admin", Checkbox::new())
+            .validate("name", |value| match value.as_str() {
+                Some("") => Err("Required".to_string()),
+                _ => Ok(()),
+            });
+
+        assert!(form.validated_data().is_none());
+        assert_eq!(
+            form.get_inner().row_status("name"),
+            Some(&RowStatus::Error("Required".into()))
+        );
+
+        if let ListChild::Row(_, ref mut view) =
+            form.get_inner_mut().row_mut(0)
+        {
+            view.downcast_mut::<EditView>().unwrap().set_content("bob");
+        }
+
+        let data = form.validated_data().unwrap();
+        assert_eq!(data.get_str("name"), Some("bob"));
+        assert_eq!(data.get_bool("admin"), Some(false));
+        assert_eq!(data.len(), 2);
+        assert_eq!(
+            form.get_inner().row_status("name"),
+            Some(&RowStatus::Normal)
+        );
+    }
+}
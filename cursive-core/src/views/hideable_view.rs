@@ -1,7 +1,39 @@
-use crate::event::AnyCb;
+use crate::event::{AnyCb, Event, EventResult};
 use crate::view::{Selector, View, ViewWrapper};
+use crate::Printer;
 use crate::Vec2;
 use crate::With;
+use std::time::Duration;
+use web_time::Instant;
+
+// An ongoing show or hide animation.
+#[derive(Clone, Copy)]
+struct Animation {
+    start: Instant,
+    hiding: bool,
+}
+
+impl Animation {
+    // Returns the visible fraction of the view, from 0 to 1.
+    fn visible_fraction(&self, duration: Duration) -> f32 {
+        let progress = if duration.as_nanos() == 0 {
+            1.0
+        } else {
+            let elapsed = self.start.elapsed().as_secs_f32();
+            (elapsed / duration.as_secs_f32()).min(1.0)
+        };
+
+        if self.hiding {
+            1.0 - progress
+        } else {
+            progress
+        }
+    }
+
+    fn is_done(&self, duration: Duration) -> bool {
+        self.start.elapsed() >= duration
+    }
+}
 
 /// Wrapper around another view that can be hidden at will.
 ///
@@ -11,10 +43,22 @@ use crate::With;
 /// invisible view, will not take focus and will not accept input.
 ///
 /// It can be made visible again with `HideableView::unhide()`.
+///
+/// When animated (with `HideableView::set_animated()`), the view collapses
+/// or expands vertically over a short duration instead. While it runs, the
+/// view asks for a new frame each time it is laid out, and consumes
+/// `Event::Refresh`, so the animation moves on without any input.
 pub struct HideableView<V> {
     view: V,
     visible: bool,
     invalidated: bool,
+
+    animated: bool,
+    animation_duration: Duration,
+    animation: Option<Animation>,
+
+    // Height of the view when fully visible, used during animations.
+    full_height: usize,
 }
 
 new_default!(HideableView<V: Default>);
@@ -28,15 +72,82 @@ impl<V> HideableView<V> {
             view,
             visible: true,
             invalidated: true,
+            animated: false,
+            animation_duration: Duration::from_millis(150),
+            animation: None,
+            full_height: 0,
         }
     }
 
     /// Sets the visibility for this view.
+    ///
+    /// If the view is animated, this starts a show or hide animation.
     pub fn set_visible(&mut self, visible: bool) {
+        if self.animated && visible != self.visible {
+            // Start from the current state, if an animation is running.
+            let fraction = match self.animation {
+                Some(animation) => {
+                    animation.visible_fraction(self.animation_duration)
+                }
+                None if visible => 0.0,
+                None => 1.0,
+            };
+            let progress = if visible { fraction } else { 1.0 - fraction };
+            let elapsed = self.animation_duration.mul_f32(progress);
+            let now = Instant::now();
+
+            self.animation = Some(Animation {
+                start: now.checked_sub(elapsed).unwrap_or(now),
+                hiding: !visible,
+            });
+        }
+
         self.visible = visible;
         self.invalidate();
     }
 
+    /// Enables or disables animations when showing or hiding the view.
+    ///
+    /// Defaults to `false`.
+    pub fn set_animated(&mut self, animated: bool) {
+        self.animated = animated;
+        if !animated {
+            self.animation = None;
+            self.invalidate();
+        }
+    }
+
+    /// Enables or disables animations when showing or hiding the view.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn animated(self, animated: bool) -> Self {
+        self.with(|s| s.set_animated(animated))
+    }
+
+    /// Sets the duration of the show and hide animations.
+    ///
+    /// Defaults to 150ms.
+    pub fn set_animation_duration(&mut self, duration: Duration) {
+        self.animation_duration = duration;
+    }
+
+    /// Sets the duration of the show and hide animations.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn animation_duration(self, duration: Duration) -> Self {
+        self.with(|s| s.set_animation_duration(duration))
+    }
+
+    /// Returns `true` if a show or hide animation is running.
+    pub fn is_animating(&self) -> bool {
+        matches!(
+            self.animation,
+            Some(animation) if !animation.is_done(self.animation_duration)
+        )
+    }
+
     /// Sets the visibility for this view to `false`.
     pub fn hide(&mut self) {
         self.set_visible(false);
@@ -117,12 +228,99 @@ impl<V: View> ViewWrapper for HideableView<V> {
         Ok(self.view)
     }
 
+    fn wrap_draw(&self, printer: &Printer) {
+        if self.animation.is_some() {
+            // The view thinks it has its full height, but is cropped.
+            let size = Vec2::new(printer.size.x, self.full_height);
+            self.view.draw(&printer.inner_size(size));
+        } else {
+            self.with_view(|v| v.draw(printer));
+        }
+    }
+
+    fn wrap_required_size(&mut self, req: Vec2) -> Vec2 {
+        match self.animation {
+            Some(animation) => {
+                let size = self.view.required_size(req);
+                self.full_height = size.y;
+
+                let fraction =
+                    animation.visible_fraction(self.animation_duration);
+                let height = (size.y as f32 * fraction).round() as usize;
+                Vec2::new(size.x, height)
+            }
+            None => self
+                .with_view_mut(|v| v.required_size(req))
+                .unwrap_or_else(Vec2::zero),
+        }
+    }
+
     fn wrap_layout(&mut self, size: Vec2) {
         self.invalidated = false;
+
+        if let Some(animation) = self.animation {
+            if animation.is_done(self.animation_duration) {
+                self.animation = None;
+            } else {
+                let height = std::cmp::max(size.y, self.full_height);
+                self.view.layout(Vec2::new(size.x, height));
+
+                // Keep drawing frames until the animation is done.
+                crate::request_redraw();
+                return;
+            }
+        }
+
         self.with_view_mut(|v| v.layout(size));
     }
 
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        let refresh = event == Event::Refresh;
+        match self.with_view_mut(|v| v.on_event(event)) {
+            Some(EventResult::Ignored) | None
+                if refresh && self.is_animating() =>
+            {
+                // This frame moves the animation forward.
+                EventResult::Consumed(None)
+            }
+            result => result.unwrap_or(EventResult::Ignored),
+        }
+    }
+
     fn wrap_needs_relayout(&self) -> bool {
-        self.invalidated || (self.visible && self.view.needs_relayout())
+        self.invalidated
+            || self.animation.is_some()
+            || (self.visible && self.view.needs_relayout())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::Resizable;
+    use crate::views::DummyView;
+
+    #[test]
+    fn animated_hide() {
+        let mut view = HideableView::new(DummyView.fixed_size((4, 10)))
+            .animated(true)
+            .animation_duration(Duration::from_secs(3600));
+        let req = Vec2::new(20, 20);
+
+        view.hide();
+        assert!(view.is_animating());
+        assert!(view.needs_relayout());
+        // The animation just started: still (almost) fully visible.
+        assert_eq!(view.required_size(req), Vec2::new(4, 10));
+        assert!(view.on_event(Event::Refresh).is_consumed());
+
+        // Finish the animation.
+        view.set_animation_duration(Duration::from_secs(0));
+        assert!(!view.is_animating());
+        assert_eq!(view.required_size(req), Vec2::new(4, 0));
+        view.layout(Vec2::new(4, 0));
+        assert!(!view.needs_relayout());
+        assert_eq!(view.required_size(req), Vec2::zero());
+        assert!(!view.on_event(Event::Refresh).is_consumed());
     }
 }
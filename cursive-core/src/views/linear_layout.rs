@@ -4,7 +4,8 @@ use crate::{
     event::{AnyCb, Event, EventResult, Key},
     rect::Rect,
     view::{
-        CannotFocus, IntoBoxedView, Selector, SizeCache, View, ViewNotFound,
+        CannotFocus, FocusWrap, IntoBoxedView, Selector, SizeCache, View,
+        ViewNotFound,
     },
     Printer, Vec2, With, XY,
 };
@@ -30,7 +31,20 @@ pub struct LinearLayout {
     orientation: direction::Orientation,
     focus: usize,
 
+    // What to do when Tab is pressed on the last child.
+    focus_wrap: FocusWrap,
+
+    // Custom order of the children for Tab navigation.
+    focus_order: Option<Vec<usize>>,
+
     cache: Option<XY<SizeCache>>,
+
+    // If `true`, children outside the viewport are not laid out again nor
+    // drawn.
+    virtualized: bool,
+
+    // Visible part of this view, as given by a scrolling parent.
+    viewport: Option<Rect>,
 }
 
 struct Child {
@@ -143,10 +157,40 @@ impl LinearLayout {
             children: Vec::new(),
             orientation,
             focus: 0,
+            focus_wrap: FocusWrap::default(),
+            focus_order: None,
             cache: None,
+            virtualized: false,
+            viewport: None,
         }
     }
 
+    /// Enables or disables virtualized layout.
+    ///
+    /// In virtualized mode, when this layout is wrapped in a `ScrollView`,
+    /// children outside the visible area are only laid out again if their
+    /// size changed or if they [need a relayout], and are not drawn at all.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [need a relayout]: crate::View::needs_relayout
+    pub fn set_virtualized(&mut self, virtualized: bool) {
+        self.virtualized = virtualized;
+    }
+
+    /// Enables or disables virtualized layout.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn virtualized(self, virtualized: bool) -> Self {
+        self.with(|s| s.set_virtualized(virtualized))
+    }
+
+    /// Returns `true` if virtualized layout is enabled.
+    pub fn is_virtualized(&self) -> bool {
+        self.virtualized
+    }
+
     /// Sets the weight of the given child. This weight is currently unused by the layout process.
     ///
     /// # Panics
@@ -245,6 +289,59 @@ impl LinearLayout {
             .ok_or(ViewNotFound)
     }
 
+    /// Sets what happens when Tab is pressed on the last focusable child
+    /// (or Shift+Tab on the first one).
+    ///
+    /// Defaults to `FocusWrap::Propagate`.
+    pub fn set_focus_wrap(&mut self, focus_wrap: FocusWrap) {
+        self.focus_wrap = focus_wrap;
+    }
+
+    /// Sets what happens when Tab is pressed on the last focusable child.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn focus_wrap(self, focus_wrap: FocusWrap) -> Self {
+        self.with(|s| s.set_focus_wrap(focus_wrap))
+    }
+
+    /// Sets a custom order for Tab navigation between the children.
+    ///
+    /// `order` lists child indices, in the order Tab should visit them.
+    /// Children missing from the list are skipped by Tab (but can still be
+    /// reached with arrow keys or the mouse). Invalid indices are ignored.
+    ///
+    /// Note that indices are not updated when children are added or
+    /// removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::views::{Button, LinearLayout};
+    /// // Tab goes from "Ok" to "Cancel", then to "Help".
+    /// let buttons = LinearLayout::horizontal()
+    ///     .child(Button::new("Help", |_| ()))
+    ///     .child(Button::new("Cancel", |_| ()))
+    ///     .child(Button::new("Ok", |_| ()))
+    ///     .focus_order(vec![2, 1, 0]);
+    /// ```
+    pub fn set_focus_order(&mut self, order: Vec<usize>) {
+        self.focus_order = Some(order);
+    }
+
+    /// Sets a custom order for Tab navigation between the children.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn focus_order(self, order: Vec<usize>) -> Self {
+        self.with(|s| s.set_focus_order(order))
+    }
+
+    /// Restores the default Tab navigation order.
+    pub fn clear_focus_order(&mut self) {
+        self.focus_order = None;
+    }
+
     fn set_focus_unchecked(&mut self, index: usize) -> EventResult {
         if index != self.focus {
             let result =
@@ -398,6 +495,48 @@ impl LinearLayout {
             })
     }
 
+    // Move the focus after a Tab (or Shift+Tab) event.
+    //
+    // `rel` is the side the focus comes from: `Front` for Tab.
+    fn move_focus_tab(&mut self, rel: direction::Relative) -> EventResult {
+        let len = self.children.len();
+        let mut order: Vec<usize> = match self.focus_order {
+            Some(ref order) => {
+                order.iter().copied().filter(|&i| i < len).collect()
+            }
+            None => (0..len).collect(),
+        };
+        if rel == direction::Relative::Back {
+            order.reverse();
+        }
+
+        // Candidates come after the current focus, in order.
+        let split = order
+            .iter()
+            .position(|&i| i == self.focus)
+            .map_or(0, |pos| pos + 1);
+        let (before, after) = order.split_at(split);
+        let wrapped = match self.focus_wrap {
+            FocusWrap::Wrap => before,
+            _ => &[],
+        };
+
+        let source = direction::Direction::Rel(rel);
+        for &i in after.iter().chain(wrapped) {
+            if i == self.focus {
+                continue;
+            }
+            if let Ok(res) = self.children[i].view.take_focus(source) {
+                return res.and(self.set_focus_unchecked(i));
+            }
+        }
+
+        match self.focus_wrap {
+            FocusWrap::Stop => EventResult::Consumed(None),
+            _ => EventResult::Ignored,
+        }
+    }
+
     // Move the focus to the selected view if needed.
     //
     // Does nothing if the event is not a `MouseEvent`.
@@ -460,6 +599,10 @@ fn try_focus(
 
 impl View for LinearLayout {
     fn draw(&self, printer: &Printer) {
+        // Only the visible children are drawn in virtualized mode.
+        let start = *printer.content_offset.get(self.orientation);
+        let end = start + *printer.output_size.get(self.orientation);
+
         // Use pre-computed sizes
         // debug!("Pre loop!");
         for (i, item) in ChildIterator::new(
@@ -469,6 +612,12 @@ impl View for LinearLayout {
         )
         .enumerate()
         {
+            if self.virtualized
+                && (item.offset >= end || item.offset + item.length <= start)
+            {
+                continue;
+            }
+
             // debug!("Printer size: {:?}", printer.size);
             // debug!("Child size: {:?}", item.child.required_size);
             // debug!("Offset: {:?}", item.offset);
@@ -480,6 +629,18 @@ impl View for LinearLayout {
         }
     }
 
+    fn debug_children(&self, f: &mut dyn FnMut(&dyn View, Option<Rect>)) {
+        for item in ChildIterator::new(
+            self.children.iter(),
+            self.orientation,
+            usize::MAX,
+        ) {
+            let offset = self.orientation.make_vec(item.offset, 0);
+            let area = Rect::from_size(offset, item.child.last_size);
+            f(&*item.child.view, Some(area));
+        }
+    }
+
     fn needs_relayout(&self) -> bool {
         if self.cache.is_none() {
             return true;
@@ -497,16 +658,38 @@ impl View for LinearLayout {
         // We'll use this guy a few times, but it's a mouthful...
         let o = self.orientation;
 
-        for item in
+        // The viewport is only valid for the layout following it.
+        let viewport = self.viewport.take().filter(|_| self.virtualized);
+        let focus = self.focus;
+
+        for (i, item) in
             ChildIterator::new(self.children.iter_mut(), o, *size.get(o))
+                .enumerate()
         {
             // Every item has the same size orthogonal to the layout
             let size = size.with_axis(o, item.length);
 
+            // Off-screen children already laid out with this size can wait.
+            let visible = !matches!(viewport, Some(viewport) if {
+                let (start, end) = viewport.side(o);
+                item.offset > end || item.offset + item.length <= start
+            });
+            if !visible
+                && i != focus
+                && item.child.last_size == size
+                && !item.child.view.needs_relayout()
+            {
+                continue;
+            }
+
             item.child.layout(size);
         }
     }
 
+    fn set_viewport(&mut self, viewport: Rect) {
+        self.viewport = Some(viewport);
+    }
+
     fn required_size(&mut self, req: Vec2) -> Vec2 {
         // Did anything change since last time?
         if let Some(size) = self.get_cache(req) {
@@ -681,13 +864,11 @@ impl View for LinearLayout {
         };
         res.and(match result {
             EventResult::Ignored => match event {
-                Event::Shift(Key::Tab) if self.focus > 0 => {
-                    self.move_focus(direction::Direction::back())
+                Event::Shift(Key::Tab) => {
+                    self.move_focus_tab(direction::Relative::Back)
                 }
-                Event::Key(Key::Tab)
-                    if self.focus + 1 < self.children.len() =>
-                {
-                    self.move_focus(direction::Direction::front())
+                Event::Key(Key::Tab) => {
+                    self.move_focus_tab(direction::Relative::Front)
                 }
                 Event::Key(Key::Left)
                     if self.orientation
@@ -772,3 +953,129 @@ impl View for LinearLayout {
         rect + offset
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::{Button, TextView};
+
+    fn buttons() -> LinearLayout {
+        LinearLayout::horizontal()
+            .child(Button::new("A", |_| ()))
+            .child(Button::new("B", |_| ()))
+            .child(Button::new("C", |_| ()))
+    }
+
+    fn tab(layout: &mut LinearLayout) -> EventResult {
+        layout.on_event(Event::Key(Key::Tab))
+    }
+
+    fn shift_tab(layout: &mut LinearLayout) -> EventResult {
+        layout.on_event(Event::Shift(Key::Tab))
+    }
+
+    #[test]
+    fn focus_wrap_propagate() {
+        let mut layout = buttons();
+        assert_eq!(layout.get_focus_index(), 0);
+
+        assert!(tab(&mut layout).is_consumed());
+        assert!(tab(&mut layout).is_consumed());
+        assert_eq!(layout.get_focus_index(), 2);
+
+        // The parent gets to move the focus.
+        assert!(!tab(&mut layout).is_consumed());
+        assert_eq!(layout.get_focus_index(), 2);
+
+        layout.set_focus_index(0).unwrap();
+        assert!(!shift_tab(&mut layout).is_consumed());
+        assert_eq!(layout.get_focus_index(), 0);
+    }
+
+    #[test]
+    fn focus_wrap_wrap() {
+        let mut layout = buttons().focus_wrap(FocusWrap::Wrap);
+        layout.set_focus_index(2).unwrap();
+
+        assert!(tab(&mut layout).is_consumed());
+        assert_eq!(layout.get_focus_index(), 0);
+
+        assert!(shift_tab(&mut layout).is_consumed());
+        assert_eq!(layout.get_focus_index(), 2);
+    }
+
+    #[test]
+    fn focus_wrap_stop() {
+        let mut layout = buttons().focus_wrap(FocusWrap::Stop);
+        layout.set_focus_index(2).unwrap();
+
+        assert!(tab(&mut layout).is_consumed());
+        assert_eq!(layout.get_focus_index(), 2);
+
+        layout.set_focus_index(0).unwrap();
+        assert!(shift_tab(&mut layout).is_consumed());
+        assert_eq!(layout.get_focus_index(), 0);
+    }
+
+    #[test]
+    fn focus_order_reversed() {
+        let mut layout = buttons().focus_order(vec![2, 1, 0]);
+        layout.set_focus_index(2).unwrap();
+
+        assert!(tab(&mut layout).is_consumed());
+        assert_eq!(layout.get_focus_index(), 1);
+        assert!(tab(&mut layout).is_consumed());
+        assert_eq!(layout.get_focus_index(), 0);
+        assert!(!tab(&mut layout).is_consumed());
+
+        assert!(shift_tab(&mut layout).is_consumed());
+        assert_eq!(layout.get_focus_index(), 1);
+
+        layout.clear_focus_order();
+        assert!(tab(&mut layout).is_consumed());
+        assert_eq!(layout.get_focus_index(), 2);
+    }
+
+    #[test]
+    fn focus_order_invalid_indices() {
+        let mut layout = buttons()
+            .focus_order(vec![5, 0, 7, 2])
+            .focus_wrap(FocusWrap::Wrap);
+        assert_eq!(layout.get_focus_index(), 0);
+
+        // Out-of-range indices are skipped, and so is the missing child.
+        assert!(tab(&mut layout).is_consumed());
+        assert_eq!(layout.get_focus_index(), 2);
+        assert!(tab(&mut layout).is_consumed());
+        assert_eq!(layout.get_focus_index(), 0);
+
+        assert!(shift_tab(&mut layout).is_consumed());
+        assert_eq!(layout.get_focus_index(), 2);
+    }
+
+    #[test]
+    fn focus_order_missing_focus() {
+        let mut layout = buttons().focus_order(vec![0, 2]);
+        layout.set_focus_index(1).unwrap();
+
+        // Tab starts over from the beginning of the order.
+        assert!(tab(&mut layout).is_consumed());
+        assert_eq!(layout.get_focus_index(), 0);
+
+        layout.set_focus_index(1).unwrap();
+        assert!(shift_tab(&mut layout).is_consumed());
+        assert_eq!(layout.get_focus_index(), 2);
+    }
+
+    #[test]
+    fn focus_order_skips_unfocusable() {
+        let mut layout = LinearLayout::vertical()
+            .child(Button::new("A", |_| ()))
+            .child(TextView::new("Not focusable"))
+            .child(Button::new("B", |_| ()))
+            .focus_order(vec![0, 1, 2]);
+
+        assert!(tab(&mut layout).is_consumed());
+        assert_eq!(layout.get_focus_index(), 2);
+    }
+}
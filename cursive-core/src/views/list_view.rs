@@ -1,13 +1,16 @@
+use crate::utils::width::TextWidth;
 use crate::{
     direction,
     event::{AnyCb, Callback, Event, EventResult, Key},
     rect::Rect,
-    view::{CannotFocus, IntoBoxedView, Selector, View, ViewNotFound},
-    Cursive, Printer, Vec2, With,
+    theme::{ColorStyle, PaletteColor},
+    view::{
+        CannotFocus, IntoBoxedView, Selector, SizeCache, View, ViewNotFound,
+    },
+    Cursive, Printer, Vec2, With, XY,
 };
 use log::debug;
 use std::rc::Rc;
-use unicode_width::UnicodeWidthStr;
 
 /// Represents a child from a [`ListView`].
 pub enum ListChild {
@@ -33,16 +36,88 @@ impl ListChild {
     }
 }
 
+/// Status of a row in a [`ListView`].
+///
+/// A non-normal status is shown as a colored marker in front of the label.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub enum RowStatus {
+    /// Nothing to report.
+    #[default]
+    Normal,
+    /// The row content is suspicious, but acceptable.
+    Warning(String),
+    /// The row content is invalid.
+    Error(String),
+}
+
+impl RowStatus {
+    /// Returns the message attached to this status, if any.
+    pub fn message(&self) -> Option<&str> {
+        match *self {
+            RowStatus::Normal => None,
+            RowStatus::Warning(ref msg) | RowStatus::Error(ref msg) => {
+                Some(msg)
+            }
+        }
+    }
+
+    fn marker(&self) -> Option<(&'static str, ColorStyle)> {
+        match *self {
+            RowStatus::Normal => None,
+            RowStatus::Warning(_) => {
+                Some(("!", ColorStyle::front(PaletteColor::Highlight)))
+            }
+            RowStatus::Error(_) => Some(("✗", ColorStyle::error())),
+        }
+    }
+}
+
+/// When to show the help text attached to rows in a [`ListView`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HelpVisibility {
+    /// Only show the help text of the focused row.
+    Focused,
+    /// Show the help text of every row.
+    Always,
+}
+
+// Callback called with the label of the newly selected row.
+type OnSelect = dyn Fn(&mut Cursive, &String);
+
+// Extra information attached to each child.
+#[derive(Default)]
+struct RowInfo {
+    help: Option<String>,
+    status: RowStatus,
+    // Was the help line given some space during the last layout?
+    help_shown: bool,
+}
+
 /// Displays a list of elements.
 pub struct ListView {
     children: Vec<ListChild>,
-    // Height for each child.
+    // Height for each child, including its help line if shown.
     // This should have the same size as the `children` list.
     children_heights: Vec<usize>,
+    // Help text and status for each child.
+    // This should have the same size as the `children` list.
+    rows_info: Vec<RowInfo>,
+    help_visibility: HelpVisibility,
     // Which child is focused? Should index into the `children` list.
     focus: usize,
     // This callback is called when the selection is changed.
-    on_select: Option<Rc<dyn Fn(&mut Cursive, &String)>>,
+    on_select: Option<Rc<OnSelect>>,
+
+    // If `true`, only the children intersecting the viewport are laid out
+    // and drawn.
+    virtualized: bool,
+    // Cached required size for each child view.
+    // This should have the same size as the `children` list.
+    children_cache: Vec<Option<XY<SizeCache>>>,
+    // Visible part of this view, as given by a scrolling parent.
+    viewport: Option<Rect>,
+    // Size given to the last call to `layout`.
+    last_size: Vec2,
 }
 
 // Implement `Default` around `ListView::new`
@@ -54,11 +129,68 @@ impl ListView {
         ListView {
             children: Vec::new(),
             children_heights: Vec::new(),
+            rows_info: Vec::new(),
+            help_visibility: HelpVisibility::Focused,
             focus: 0,
             on_select: None,
+            virtualized: false,
+            children_cache: Vec::new(),
+            viewport: None,
+            last_size: Vec2::zero(),
         }
     }
 
+    /// Enables or disables virtualized layout.
+    ///
+    /// In virtualized mode, the required size of each child is cached until
+    /// the child reports that it [needs a relayout], and when this view is
+    /// wrapped in a `ScrollView`, only the children intersecting the visible
+    /// area (and the focused one) are laid out and drawn. This keeps lists
+    /// with thousands of rows responsive.
+    ///
+    /// Children that always report needing a relayout (the default for
+    /// views) are still measured on every layout.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [needs a relayout]: crate::View::needs_relayout
+    pub fn set_virtualized(&mut self, virtualized: bool) {
+        self.virtualized = virtualized;
+        self.children_cache
+            .iter_mut()
+            .for_each(|cache| *cache = None);
+    }
+
+    /// Enables or disables virtualized layout.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::traits::Scrollable;
+    /// use cursive_core::views::{ListView, TextView};
+    /// use cursive_core::With;
+    ///
+    /// let list = ListView::new()
+    ///     .with(|list| {
+    ///         for i in 0..5_000 {
+    ///             list.add_child(&format!("Row {}", i), TextView::new("..."));
+    ///         }
+    ///     })
+    ///     .virtualized(true)
+    ///     .scrollable();
+    /// ```
+    #[must_use]
+    pub fn virtualized(self, virtualized: bool) -> Self {
+        self.with(|s| s.set_virtualized(virtualized))
+    }
+
+    /// Returns `true` if virtualized layout is enabled.
+    pub fn is_virtualized(&self) -> bool {
+        self.virtualized
+    }
+
     /// Returns the number of children, including delimiters.
     pub fn len(&self) -> usize {
         self.children.len()
@@ -102,12 +234,16 @@ impl ListView {
         // view.take_focus(direction::Direction::none());
         self.children.push(ListChild::Row(label.to_string(), view));
         self.children_heights.push(0);
+        self.rows_info.push(RowInfo::default());
+        self.children_cache.push(None);
     }
 
     /// Removes all children from this view.
     pub fn clear(&mut self) {
         self.children.clear();
         self.children_heights.clear();
+        self.rows_info.clear();
+        self.children_cache.clear();
     }
 
     /// Adds a view to the end of the list.
@@ -126,6 +262,8 @@ impl ListView {
     pub fn add_delimiter(&mut self) {
         self.children.push(ListChild::Delimiter);
         self.children_heights.push(0);
+        self.rows_info.push(RowInfo::default());
+        self.children_cache.push(None);
     }
 
     /// Adds a delimiter to the end of the list.
@@ -138,12 +276,223 @@ impl ListView {
 
     /// Removes a child from the view.
     ///
+    /// If this child was focused, the focus moves to the next row that
+    /// accepts it, or to the previous one.
+    ///
     /// # Panics
     ///
     /// If `index >= self.len()`.
     pub fn remove_child(&mut self, index: usize) -> ListChild {
+        self.remove_at(index)
+    }
+
+    /// Removes the first row with the given label.
+    ///
+    /// Returns the view from this row, or `None` if no row has this label.
+    ///
+    /// If this row was focused, the focus moves to the next row that accepts
+    /// it, or to the previous one.
+    pub fn remove_child_by_label(
+        &mut self,
+        label: &str,
+    ) -> Option<Box<dyn View>> {
+        let i = self
+            .children
+            .iter()
+            .position(|child| is_row(child, label))?;
+        Some(self.remove_child_at(i).1)
+    }
+
+    /// Removes the row at the given position.
+    ///
+    /// Returns the label and the view from this row.
+    ///
+    /// If this row was focused, the focus moves to the next row that accepts
+    /// it, or to the previous one.
+    ///
+    /// # Panics
+    ///
+    /// If `index >= self.len()`, or if the child at `index` is a delimiter.
+    pub fn remove_child_at(
+        &mut self,
+        index: usize,
+    ) -> (String, Box<dyn View>) {
+        assert!(
+            !matches!(self.children[index], ListChild::Delimiter),
+            "ListView::remove_child_at called on a delimiter"
+        );
+
+        match self.remove_at(index) {
+            ListChild::Row(label, view) => (label, view),
+            ListChild::Delimiter => unreachable!(),
+        }
+    }
+
+    /// Removes the delimiter at the given position.
+    ///
+    /// # Panics
+    ///
+    /// If `index >= self.len()`, or if the child at `index` is not a
+    /// delimiter.
+    pub fn remove_delimiter_at(&mut self, index: usize) {
+        assert!(
+            matches!(self.children[index], ListChild::Delimiter),
+            "ListView::remove_delimiter_at called on a row"
+        );
+
+        self.remove_at(index);
+    }
+
+    // Removes the child at `index`, keeping the focus on a valid row.
+    fn remove_at(&mut self, index: usize) -> ListChild {
         self.children_heights.remove(index);
-        self.children.remove(index)
+        self.rows_info.remove(index);
+        self.children_cache.remove(index);
+        let child = self.children.remove(index);
+
+        if self.focus > index {
+            self.focus -= 1;
+        } else if self.focus == index {
+            self.refocus_near(index);
+        }
+
+        child
+    }
+
+    // Focuses the first row accepting focus after `index`, or before it.
+    fn refocus_near(&mut self, index: usize) {
+        let source = direction::Direction::none();
+        let (before, after) = self.children.split_at_mut(index);
+        let found = after
+            .iter_mut()
+            .enumerate()
+            .map(|(i, child)| (index + i, child))
+            .find_map(|p| try_focus(p, source))
+            .or_else(|| {
+                before
+                    .iter_mut()
+                    .enumerate()
+                    .rev()
+                    .find_map(|p| try_focus(p, source))
+            });
+
+        self.focus = match found {
+            Some((i, _)) => i,
+            None => index.min(self.children.len().saturating_sub(1)),
+        };
+    }
+
+    /// Sets a help text to show under the row with the given label.
+    ///
+    /// The text is printed with the secondary color, on its own line.
+    ///
+    /// Does nothing if no row has this label.
+    pub fn set_row_help<S: Into<String>>(&mut self, label: &str, help: S) {
+        if let Some(info) = self.row_info_mut(label) {
+            info.help = Some(help.into());
+        }
+    }
+
+    /// Sets a help text to show under the row with the given label.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn row_help<S: Into<String>>(self, label: &str, help: S) -> Self {
+        self.with(|s| s.set_row_help(label, help))
+    }
+
+    /// Removes the help text from the row with the given label.
+    pub fn clear_row_help(&mut self, label: &str) {
+        if let Some(info) = self.row_info_mut(label) {
+            info.help = None;
+        }
+    }
+
+    /// Sets when help texts should be shown.
+    ///
+    /// Defaults to `HelpVisibility::Focused`.
+    pub fn set_help_visibility(&mut self, visibility: HelpVisibility) {
+        self.help_visibility = visibility;
+    }
+
+    /// Sets when help texts should be shown.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn help_visibility(self, visibility: HelpVisibility) -> Self {
+        self.with(|s| s.set_help_visibility(visibility))
+    }
+
+    /// Sets the status of the row with the given label.
+    ///
+    /// Non-normal statuses are shown as a colored marker before the label.
+    ///
+    /// Does nothing if no row has this label.
+    pub fn set_row_status(&mut self, label: &str, status: RowStatus) {
+        if let Some(info) = self.row_info_mut(label) {
+            info.status = status;
+        }
+    }
+
+    /// Returns the status of the row with the given label.
+    ///
+    /// Returns `None` if no row has this label.
+    pub fn row_status(&self, label: &str) -> Option<&RowStatus> {
+        self.children
+            .iter()
+            .position(|child| is_row(child, label))
+            .map(|i| &self.rows_info[i].status)
+    }
+
+    /// Returns the status message of the focused row, if any.
+    ///
+    /// This can be used to feed a status bar.
+    pub fn focused_status_message(&self) -> Option<&str> {
+        self.rows_info
+            .get(self.focus)
+            .and_then(|info| info.status.message())
+    }
+
+    /// Resets the status of every row to `RowStatus::Normal`.
+    pub fn clear_row_statuses(&mut self) {
+        for info in &mut self.rows_info {
+            info.status = RowStatus::Normal;
+        }
+    }
+
+    /// Moves the focus to the row with the given label.
+    ///
+    /// Returns `Err(ViewNotFound)` if no row has this label, or if its view
+    /// does not accept focus.
+    pub fn focus_child(
+        &mut self,
+        label: &str,
+    ) -> Result<EventResult, ViewNotFound> {
+        let i = self
+            .children
+            .iter()
+            .position(|child| is_row(child, label))
+            .ok_or(ViewNotFound)?;
+
+        let res = self.children[i]
+            .view()
+            .and_then(|view| {
+                view.take_focus(direction::Direction::none()).ok()
+            })
+            .ok_or(ViewNotFound)?;
+
+        Ok(self
+            .set_focus_unchecked(i)
+            .and(res)
+            .and(self.on_select_result()))
+    }
+
+    fn row_info_mut(&mut self, label: &str) -> Option<&mut RowInfo> {
+        let i = self
+            .children
+            .iter()
+            .position(|child| is_row(child, label))?;
+        self.rows_info.get_mut(i)
     }
 
     /// Sets a callback to be used when an item is selected.
@@ -206,7 +555,12 @@ impl ListView {
     fn set_focus_unchecked(&mut self, index: usize) -> EventResult {
         if index != self.focus {
             let res = self.unfocus_child();
-            self.focus = index;
+            let previous = std::mem::replace(&mut self.focus, index);
+            if self.virtualized {
+                // The new focus may have been skipped by the last layout.
+                // Lay it out now, so we can scroll to it.
+                self.relayout_children(&[previous, index]);
+            }
             res
         } else {
             EventResult::Consumed(None)
@@ -235,22 +589,200 @@ impl ListView {
         };
         self.set_focus_unchecked(i);
 
-        res.and(EventResult::Consumed(self.on_select.clone().map(|cb| {
+        res.and(self.on_select_result())
+    }
+
+    // Moves the focus to the first row of the next or previous section.
+    //
+    // Sections are separated by delimiters. Without delimiters, this moves
+    // by a fixed amount of rows instead.
+    fn move_focus_section(&mut self, rel: direction::Relative) -> EventResult {
+        let delimiters: Vec<usize> = self
+            .children
+            .iter()
+            .enumerate()
+            .filter(|&(_, child)| matches!(child, ListChild::Delimiter))
+            .map(|(i, _)| i)
+            .collect();
+
+        if delimiters.is_empty() {
+            return match rel {
+                direction::Relative::Front => {
+                    self.move_focus(10, direction::Direction::up())
+                }
+                direction::Relative::Back => {
+                    self.move_focus(10, direction::Direction::down())
+                }
+            };
+        }
+
+        // Each section is a range of children between delimiters.
+        let len = self.children.len();
+        let starts =
+            std::iter::once(0).chain(delimiters.iter().map(|&d| d + 1));
+        let ends = delimiters.iter().copied().chain(std::iter::once(len));
+        let sections: Vec<(usize, usize)> = starts.zip(ends).collect();
+
+        let focus = self.focus;
+        let candidates: Vec<(usize, usize)> = match rel {
+            direction::Relative::Front => sections
+                .into_iter()
+                .filter(|&(start, _)| start > focus)
+                .collect(),
+            direction::Relative::Back => sections
+                .into_iter()
+                .filter(|&(start, _)| start < focus)
+                .map(|(start, end)| (start, end.min(focus)))
+                .rev()
+                .collect(),
+        };
+
+        let source = direction::Direction::up();
+        let found = candidates.into_iter().find_map(|(start, end)| {
+            self.children[start..end]
+                .iter_mut()
+                .enumerate()
+                .find_map(|(i, child)| try_focus((start + i, child), source))
+        });
+
+        match found {
+            Some((i, res)) => self
+                .set_focus_unchecked(i)
+                .and(res)
+                .and(self.on_select_result()),
+            None => EventResult::Ignored,
+        }
+    }
+
+    fn on_select_result(&self) -> EventResult {
+        EventResult::Consumed(self.on_select.clone().map(|cb| {
             let i = self.focus();
             let focused_string = String::from(self.children[i].label());
             Callback::from_fn(move |s| cb(s, &focused_string))
-        })))
+        }))
     }
 
     fn labels_width(&self) -> usize {
         self.children
             .iter()
             .map(ListChild::label)
-            .map(UnicodeWidthStr::width)
+            .map(TextWidth::width)
             .max()
             .unwrap_or(0)
     }
 
+    // Width of the status marker column, if any row needs it.
+    fn gutter_width(&self) -> usize {
+        if self
+            .rows_info
+            .iter()
+            .any(|info| info.status != RowStatus::Normal)
+        {
+            2
+        } else {
+            0
+        }
+    }
+
+    // Horizontal offset of the views column.
+    fn views_offset(&self) -> usize {
+        self.gutter_width() + self.labels_width() + 1
+    }
+
+    // Returns the cached required size of the given child, if still valid.
+    fn cached_size(&self, i: usize, req: Vec2) -> Option<Vec2> {
+        let view = match self.children[i] {
+            ListChild::Row(_, ref view) => view,
+            ListChild::Delimiter => return Some(Vec2::new(0, 1)),
+        };
+
+        let cache = self.children_cache[i]?;
+        if view.needs_relayout()
+            || !cache.zip_map(req, SizeCache::accept).both()
+        {
+            return None;
+        }
+
+        Some(cache.map(|cache| cache.value))
+    }
+
+    // Computes the required size of the given child, and caches it.
+    fn measure_child(&mut self, i: usize, req: Vec2) -> Vec2 {
+        let size = match self.children[i] {
+            ListChild::Row(_, ref mut view) => view.required_size(req),
+            ListChild::Delimiter => Vec2::new(0, 1),
+        };
+        self.children_cache[i] = Some(SizeCache::build(size, req));
+        size
+    }
+
+    // Returns the required size of the given child.
+    //
+    // Only uses the cache in virtualized mode.
+    // The second value is `true` if the size was not cached.
+    fn child_size(&mut self, i: usize, req: Vec2) -> (Vec2, bool) {
+        match self.cached_size(i, req) {
+            Some(size) if self.virtualized => (size, false),
+            _ => (self.measure_child(i, req), true),
+        }
+    }
+
+    // Is the given range of rows visible in the last viewport?
+    fn is_visible(&self, y: usize, height: usize) -> bool {
+        match self.viewport {
+            Some(viewport) if self.virtualized => {
+                y <= viewport.bottom() && y + height > viewport.top()
+            }
+            _ => true,
+        }
+    }
+
+    // Measures the given child, and lays it out if needed.
+    //
+    // `y` is the position of the child, and `available` the width left for
+    // the views column. Returns the height of the child, including its help
+    // line if shown.
+    fn layout_child(
+        &mut self,
+        i: usize,
+        y: usize,
+        available: usize,
+        force: bool,
+    ) -> usize {
+        let help_shown = self.shows_help(i);
+        self.rows_info[i].help_shown = help_shown;
+
+        let (size, fresh) = self.child_size(i, self.last_size);
+        let height = size.y + usize::from(help_shown);
+
+        if force || fresh || self.is_visible(y, height) {
+            if let Some(child) = self.children[i].view() {
+                child.layout(Vec2::new(available, size.y));
+            }
+        }
+
+        self.children_heights[i] = height;
+        height
+    }
+
+    // Lays out the given children again, using the size from the last layout.
+    fn relayout_children(&mut self, indices: &[usize]) {
+        let available = self.last_size.x.saturating_sub(self.views_offset());
+        for &i in indices {
+            if i < self.children.len() {
+                let y = self.children_heights[..i].iter().sum();
+                self.layout_child(i, y, available, true);
+            }
+        }
+    }
+
+    // Should the help line for the given child be shown?
+    fn shows_help(&self, i: usize) -> bool {
+        self.rows_info[i].help.is_some()
+            && (self.help_visibility == HelpVisibility::Always
+                || i == self.focus)
+    }
+
     fn check_focus_grab(&mut self, event: &Event) -> Option<EventResult> {
         if let Event::Mouse {
             offset,
@@ -299,6 +831,10 @@ impl ListView {
     }
 }
 
+fn is_row(child: &ListChild, label: &str) -> bool {
+    matches!(*child, ListChild::Row(ref l, _) if l == label)
+}
+
 fn try_focus(
     (i, child): (usize, &mut ListChild),
     source: direction::Direction,
@@ -318,24 +854,57 @@ impl View for ListView {
             return;
         }
 
-        let offset = self.labels_width() + 1;
+        let gutter = self.gutter_width();
+        let offset = self.views_offset();
         let mut y = 0;
 
+        // Only the visible rows are drawn in virtualized mode.
+        let start = printer.content_offset.y;
+        let end = start + printer.output_size.y;
+
         debug!("Offset: {}", offset);
         for (i, (child, &height)) in
             self.children.iter().zip(&self.children_heights).enumerate()
         {
+            if self.virtualized {
+                if y >= end {
+                    break;
+                }
+                if y + height <= start {
+                    y += height;
+                    continue;
+                }
+            }
+
             match child {
                 ListChild::Row(ref label, ref view) => {
-                    printer.print((0, y), label);
+                    let info = &self.rows_info[i];
+                    if let Some((marker, color)) = info.status.marker() {
+                        printer.with_color(color, |printer| {
+                            printer.print((0, y), marker);
+                        });
+                    }
+                    printer.print((gutter, y), label);
+
+                    let help_height = usize::from(info.help_shown);
+                    let view_height = height.saturating_sub(help_height);
                     view.draw(
                         &printer
                             .offset((offset, y))
-                            .cropped((printer.size.x, height))
+                            .cropped((printer.size.x, view_height))
                             .focused(i == self.focus),
                     );
+
+                    if let (true, Some(help)) = (info.help_shown, &info.help) {
+                        printer.with_color(
+                            ColorStyle::secondary(),
+                            |printer| {
+                                printer.print((offset, y + view_height), help);
+                            },
+                        );
+                    }
                 }
-                ListChild::Delimiter => y += 1, // TODO: draw delimiters?
+                ListChild::Delimiter => (), // TODO: draw delimiters?
             }
             y += height;
         }
@@ -347,18 +916,27 @@ impl View for ListView {
             .children
             .iter()
             .map(ListChild::label)
-            .map(UnicodeWidthStr::width)
+            .map(TextWidth::width)
             .max()
             .unwrap_or(0);
 
-        let view_size = direction::Orientation::Vertical.stack(
-            self.children.iter_mut().map(|c| match c {
-                ListChild::Delimiter => Vec2::new(0, 1),
-                ListChild::Row(_, ref mut view) => view.required_size(req),
-            }),
-        );
+        let gutter = self.gutter_width();
 
-        view_size + (1 + label_width, 0)
+        let sizes: Vec<Vec2> = (0..self.children.len())
+            .map(|i| {
+                let (size, _) = self.child_size(i, req);
+                match self.rows_info[i].help {
+                    Some(ref help) if self.shows_help(i) => {
+                        Vec2::new(size.x.max(help.width()), size.y + 1)
+                    }
+                    _ => size,
+                }
+            })
+            .collect();
+        let view_size =
+            direction::Orientation::Vertical.stack(sizes.into_iter());
+
+        view_size + (1 + label_width + gutter, 0)
     }
 
     fn layout(&mut self, size: Vec2) {
@@ -367,27 +945,36 @@ impl View for ListView {
             .children
             .iter()
             .map(ListChild::label)
-            .map(UnicodeWidthStr::width)
+            .map(TextWidth::width)
             .max()
             .unwrap_or(0);
 
         let spacing = 1;
 
-        let available = size.x.saturating_sub(label_width + spacing);
+        let available = size
+            .x
+            .saturating_sub(self.gutter_width() + label_width + spacing);
 
         debug!("Available: {}", available);
 
         self.children_heights.resize(self.children.len(), 0);
-        for (child, height) in self
-            .children
-            .iter_mut()
-            .zip(&mut self.children_heights)
-            .filter_map(|(v, h)| v.view().map(|v| (v, h)))
-        {
-            // TODO: Find the child height?
-            *height = child.required_size(size).y;
-            child.layout(Vec2::new(available, *height));
+        self.rows_info
+            .resize_with(self.children.len(), Default::default);
+        self.last_size = size;
+
+        let mut y = 0;
+        for i in 0..self.children.len() {
+            // The focused child always gets a layout: it receives events.
+            let focused = i == self.focus;
+            y += self.layout_child(i, y, available, focused);
         }
+
+        // The viewport is only valid for the layout following it.
+        self.viewport = None;
+    }
+
+    fn set_viewport(&mut self, viewport: Rect) {
+        self.viewport = Some(viewport);
     }
 
     fn on_event(&mut self, event: Event) -> EventResult {
@@ -400,10 +987,10 @@ impl View for ListView {
             .unwrap_or(EventResult::Ignored);
 
         // Send the event to the focused child.
-        let labels_width = self.labels_width();
+        let views_offset = self.views_offset();
         if let ListChild::Row(_, ref mut view) = self.children[self.focus] {
             let y = self.children_heights[..self.focus].iter().sum();
-            let offset = (labels_width + 1, y);
+            let offset = (views_offset, y);
             let result = view.on_event(event.relativized(offset));
             if result.is_consumed() {
                 return res.and(result);
@@ -419,10 +1006,10 @@ impl View for ListView {
                 self.move_focus(1, direction::Direction::up())
             }
             Event::Key(Key::PageUp) => {
-                self.move_focus(10, direction::Direction::down())
+                self.move_focus_section(direction::Relative::Back)
             }
             Event::Key(Key::PageDown) => {
-                self.move_focus(10, direction::Direction::up())
+                self.move_focus_section(direction::Relative::Front)
             }
             Event::Key(Key::Home) | Event::Ctrl(Key::Home) => self
                 .move_focus(usize::max_value(), direction::Direction::back()),
@@ -483,19 +1070,27 @@ impl View for ListView {
         }
     }
 
+    fn debug_children(&self, f: &mut dyn FnMut(&dyn View, Option<Rect>)) {
+        for child in &self.children {
+            if let ListChild::Row(_, ref view) = *child {
+                f(&**view, None);
+            }
+        }
+    }
+
     fn important_area(&self, size: Vec2) -> Rect {
         if self.children.is_empty() {
             return Rect::from_size(Vec2::zero(), size);
         }
 
-        let labels_width = self.labels_width();
+        let views_offset = self.views_offset();
 
         // This is the size of the focused view
         let area = match self.children[self.focus] {
             ListChild::Row(_, ref view) => {
                 let available =
-                    Vec2::new(size.x.saturating_sub(labels_width + 1), 1);
-                view.important_area(available) + (labels_width, 0)
+                    Vec2::new(size.x.saturating_sub(views_offset), 1);
+                view.important_area(available) + (views_offset - 1, 0)
             }
             ListChild::Delimiter => Rect::from_size((0, 0), (size.x, 1)),
         };
@@ -508,3 +1103,205 @@ impl View for ListView {
         area + (0, y_offset)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::Scrollable;
+    use crate::views::{EditView, ScrollView};
+    use std::cell::Cell;
+
+    // Focusable view counting how many times it was laid out.
+    struct Counter(Rc<Cell<usize>>);
+
+    impl View for Counter {
+        fn draw(&self, _: &Printer) {}
+
+        fn layout(&mut self, _: Vec2) {
+            self.0.set(self.0.get() + 1);
+        }
+
+        fn needs_relayout(&self) -> bool {
+            false
+        }
+
+        fn take_focus(
+            &mut self,
+            _: direction::Direction,
+        ) -> Result<EventResult, CannotFocus> {
+            Ok(EventResult::Consumed(None))
+        }
+    }
+
+    fn sections() -> ListView {
+        ListView::new()
+            .child("a", EditView::new())
+            .child("b", EditView::new())
+            .delimiter()
+            .child("c", EditView::new())
+            .child("d", EditView::new())
+    }
+
+    #[test]
+    fn section_navigation() {
+        let mut list = sections();
+        list.layout(Vec2::new(20, 10));
+        assert!(list.take_focus(direction::Direction::none()).is_ok());
+        assert_eq!(list.focus(), 0);
+
+        list.on_event(Event::Key(Key::PageDown));
+        assert_eq!(list.focus(), 3);
+
+        list.on_event(Event::Key(Key::Down));
+        assert_eq!(list.focus(), 4);
+
+        list.on_event(Event::Key(Key::PageUp));
+        assert_eq!(list.focus(), 3);
+
+        list.on_event(Event::Key(Key::PageUp));
+        assert_eq!(list.focus(), 0);
+
+        assert!(list.focus_child("d").is_ok());
+        assert_eq!(list.focus(), 4);
+        assert!(list.focus_child("missing").is_err());
+    }
+
+    #[test]
+    fn help_and_status() {
+        let mut list = sections().row_help("c", "Some help");
+        list.set_row_status("d", RowStatus::Error("Invalid".into()));
+
+        // Help is only shown for the focused row by default.
+        list.layout(Vec2::new(20, 10));
+        assert_eq!(list.required_size(Vec2::new(20, 10)).y, 5);
+
+        list.focus_child("c").unwrap();
+        assert_eq!(list.required_size(Vec2::new(20, 10)).y, 6);
+        assert_eq!(list.focused_status_message(), None);
+
+        list.focus_child("d").unwrap();
+        assert_eq!(list.focused_status_message(), Some("Invalid"));
+
+        // Dynamically added rows keep their own info.
+        list.add_child("e", EditView::new());
+        assert_eq!(list.row_status("e"), Some(&RowStatus::Normal));
+        list.remove_child_at(0);
+        assert_eq!(
+            list.row_status("d"),
+            Some(&RowStatus::Error("Invalid".into()))
+        );
+    }
+
+    #[test]
+    fn removal() {
+        let mut list = sections();
+        list.layout(Vec2::new(20, 10));
+        list.focus_child("c").unwrap();
+
+        // Removing a row before the focus keeps the same row focused.
+        assert!(list.remove_child_by_label("a").is_some());
+        assert!(list.remove_child_by_label("a").is_none());
+        assert_eq!(list.focus(), 2);
+
+        list.remove_delimiter_at(1);
+        assert_eq!(list.focus(), 1);
+
+        // Removing the focused row moves the focus to the next one.
+        let (label, _) = list.remove_child_at(1);
+        assert_eq!(label, "c");
+        assert_eq!(list.focus(), 1);
+        assert_eq!(list.get_row(1).label(), "d");
+
+        // Or to the previous one, if it was the last.
+        list.remove_child_by_label("d");
+        assert_eq!(list.focus(), 0);
+        assert!(matches!(
+            list.remove_child(0),
+            ListChild::Row(ref label, _) if label == "b"
+        ));
+        assert!(list.is_empty());
+        assert_eq!(list.focus(), 0);
+    }
+
+    #[test]
+    fn virtualized() {
+        let layouts = Rc::new(Cell::new(0));
+        let mut view: ScrollView<ListView> = ListView::new()
+            .with(|list| {
+                for i in 0..100 {
+                    list.add_child(&i.to_string(), Counter(layouts.clone()));
+                }
+            })
+            .virtualized(true)
+            .scrollable();
+
+        // Only the visible children are laid out.
+        view.layout(Vec2::new(20, 10));
+        assert_eq!(layouts.get(), 10);
+
+        layouts.set(0);
+        view.layout(Vec2::new(20, 10));
+        assert_eq!(layouts.get(), 10);
+        assert_eq!(
+            view.get_inner_mut().required_size(Vec2::new(18, 10)).y,
+            100
+        );
+
+        // Focusing an off-screen child lays it out right away.
+        layouts.set(0);
+        view.get_inner_mut().focus_child("50").unwrap();
+        assert_eq!(layouts.get(), 2);
+        assert_eq!(
+            view.get_inner().important_area(Vec2::new(19, 100)).top(),
+            50
+        );
+
+        // After scrolling there, the new visible children are laid out.
+        view.scroll_to_important_area();
+        layouts.set(0);
+        view.layout(Vec2::new(20, 10));
+        assert_eq!(layouts.get(), 10);
+        assert_eq!(view.content_viewport().bottom(), 50);
+
+        // Outside of the scroll view, every child is laid out again.
+        let mut list = view.into_inner();
+        layouts.set(0);
+        list.layout(Vec2::new(20, 5));
+        assert_eq!(layouts.get(), 100);
+    }
+
+    #[test]
+    fn virtualized_in_panel() {
+        let first = Rc::new(Cell::new(0));
+        let last = Rc::new(Cell::new(0));
+        let others = Rc::new(Cell::new(0));
+        let mut view = crate::views::Panel::new(
+            ListView::new()
+                .with(|list| {
+                    for i in 0..100 {
+                        let layouts = match i {
+                            49 => &first,
+                            59 => &last,
+                            _ => &others,
+                        };
+                        list.add_child(
+                            &i.to_string(),
+                            Counter(layouts.clone()),
+                        );
+                    }
+                })
+                .virtualized(true),
+        )
+        .scrollable();
+
+        view.layout(Vec2::new(20, 10));
+        view.set_offset((0, 50));
+        first.set(0);
+        last.set(0);
+        view.layout(Vec2::new(20, 10));
+
+        // Rows 50 to 59 of the panel show rows 49 to 58 of the list.
+        assert_eq!(first.get(), 1);
+        assert_eq!(last.get(), 0);
+    }
+}
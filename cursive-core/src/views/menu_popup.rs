@@ -1,9 +1,11 @@
+use crate::utils::width::{char_width, TextWidth};
 use crate::{
     align::Align,
     event::{Callback, Event, EventResult, Key, MouseButton, MouseEvent},
-    menu,
+    help, menu,
     rect::Rect,
-    theme::ColorStyle,
+    theme::{ColorStyle, Effect},
+    utils::markup::StyledString,
     view::scroll,
     view::{Position, View},
     views::OnEventView,
@@ -11,7 +13,6 @@ use crate::{
 };
 use std::cmp::min;
 use std::rc::Rc;
-use unicode_width::UnicodeWidthStr;
 
 /// Popup that shows a list of items.
 ///
@@ -21,8 +22,14 @@ use unicode_width::UnicodeWidthStr;
 /// [1]: crate::views::SelectView::popup()
 /// [2]: crate::Cursive::menubar()
 pub struct MenuPopup {
+    // Items currently shown: either `unfiltered`, or the items matching
+    // `filter`.
     menu: Rc<menu::Tree>,
+    unfiltered: Rc<menu::Tree>,
+    filterable: bool,
+    filter: String,
     focus: usize,
+    max_height: Option<usize>,
     scroll_core: scroll::Core,
     align: Align,
     on_dismiss: Option<Callback>,
@@ -38,8 +45,12 @@ impl MenuPopup {
     /// Creates a new `MenuPopup` using the given menu tree.
     pub fn new(menu: Rc<menu::Tree>) -> Self {
         MenuPopup {
+            unfiltered: Rc::clone(&menu),
             menu,
+            filterable: false,
+            filter: String::new(),
             focus: 0,
+            max_height: None,
             scroll_core: scroll::Core::new(),
             align: Align::top_left(),
             on_dismiss: None,
@@ -61,14 +72,152 @@ impl MenuPopup {
     }
 
     /// Returns the position of the currently focused child.
+    ///
+    /// While a filter is active, this is the position among matching items.
     pub fn get_focus(&self) -> usize {
         self.focus
     }
 
+    /// Lets the user filter items by typing.
+    ///
+    /// When enabled, typed characters are matched (ignoring case) against
+    /// item labels: only items containing these characters, in order, are
+    /// shown, with the matching characters highlighted. Delimiters are
+    /// hidden while filtering, and disabled items still cannot be selected.
+    ///
+    /// `<Backspace>` removes the last character, and `<Esc>` clears the
+    /// filter (and dismisses the popup when there is no filter).
+    ///
+    /// Subtrees opened from this popup are filterable as well.
+    ///
+    /// Disabled by default.
+    pub fn set_filterable(&mut self, filterable: bool) {
+        self.filterable = filterable;
+        if !filterable && !self.filter.is_empty() {
+            self.set_filter(String::new());
+        }
+    }
+
+    /// Lets the user filter items by typing.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::menu::Tree;
+    /// use cursive_core::views::MenuPopup;
+    /// use std::rc::Rc;
+    ///
+    /// let tree = Tree::new().leaf("Open", |_| ()).leaf("Save", |_| ());
+    /// let popup = MenuPopup::new(Rc::new(tree)).filterable(true);
+    /// assert!(popup.is_filterable());
+    /// ```
+    #[must_use]
+    pub fn filterable(self, filterable: bool) -> Self {
+        self.with(|s| s.set_filterable(filterable))
+    }
+
+    /// Returns `true` if the user can filter items by typing.
+    pub fn is_filterable(&self) -> bool {
+        self.filterable
+    }
+
+    /// Sets the maximum number of visible items.
+    ///
+    /// When the menu has more items, it scrolls to keep the selection
+    /// visible. In any case, the popup never gets taller than the screen.
+    ///
+    /// Subtrees opened from this popup use the same limit.
+    pub fn set_max_height(&mut self, max_height: Option<usize>) {
+        self.max_height = max_height;
+    }
+
+    /// Sets the maximum number of visible items.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::menu::Tree;
+    /// use cursive_core::views::MenuPopup;
+    /// use std::rc::Rc;
+    ///
+    /// let tree = (0..100).fold(Tree::new(), |tree, i| {
+    ///     tree.leaf(format!("Item {}", i), |_| ())
+    /// });
+    /// let popup = MenuPopup::new(Rc::new(tree)).max_height(10);
+    /// ```
+    #[must_use]
+    pub fn max_height(self, max_height: usize) -> Self {
+        self.with(|s| s.set_max_height(Some(max_height)))
+    }
+
+    /// Returns the maximum number of visible items, if any.
+    pub fn get_max_height(&self) -> Option<usize> {
+        self.max_height
+    }
+
+    /// Returns the current filter.
+    ///
+    /// Empty when no filter is active.
+    pub fn get_filter(&self) -> &str {
+        &self.filter
+    }
+
+    // Changes the filter, and updates the visible items.
+    fn set_filter(&mut self, filter: String) {
+        self.filter = filter;
+        if self.filter.is_empty() {
+            self.menu = Rc::clone(&self.unfiltered);
+        } else {
+            let children = self
+                .unfiltered
+                .children
+                .iter()
+                .filter(|item| {
+                    !item.is_delimiter()
+                        && fuzzy_match(item.label(), &self.filter).is_some()
+                })
+                .cloned()
+                .collect();
+            self.menu = Rc::new(menu::Tree { children });
+        }
+
+        self.focus = self
+            .menu
+            .children
+            .iter()
+            .position(menu::Item::is_enabled)
+            .unwrap_or(0);
+    }
+
+    // Handles typing when the popup is filterable.
+    fn filter_on_event(&mut self, event: &Event) -> Option<EventResult> {
+        let mut filter = self.filter.clone();
+        match *event {
+            Event::Char(c) => filter.push(c),
+            Event::Key(Key::Backspace) if !filter.is_empty() => {
+                filter.pop();
+            }
+            Event::Key(Key::Esc) if !filter.is_empty() => filter.clear(),
+            _ => return None,
+        }
+        self.set_filter(filter);
+        Some(EventResult::Consumed(None))
+    }
+
     fn item_width(item: &menu::Item) -> usize {
         match *item {
             menu::Item::Delimiter => 1,
-            menu::Item::Leaf { ref label, .. } => label.width(),
+            menu::Item::Leaf { ref label, .. } => match item.get_shortcut() {
+                // 2 spaces between the label and the shortcut.
+                Some(event) => {
+                    label.width() + 2 + shortcut_hint(event).width()
+                }
+                None => label.width(),
+            },
             menu::Item::Subtree { ref label, .. } => label.width() + 3,
         }
     }
@@ -134,7 +283,7 @@ impl MenuPopup {
                 break;
             }
 
-            if self.menu.children[self.focus].is_enabled() {
+            if is_focusable(&self.menu.children[self.focus]) {
                 n -= 1;
             }
         }
@@ -151,7 +300,7 @@ impl MenuPopup {
                 break;
             }
 
-            if self.menu.children[self.focus].is_enabled() {
+            if is_focusable(&self.menu.children[self.focus]) {
                 n -= 1;
             }
         }
@@ -165,6 +314,7 @@ impl MenuPopup {
                 EventResult::with_cb(move |s| {
                     // Remove ourselves from the face of the earth
                     s.pop_layer();
+                    s.menu_help_changed(None);
                     // If we had prior orders, do it now.
                     if let Some(ref action_cb) = action_cb {
                         action_cb.clone()(s);
@@ -173,7 +323,11 @@ impl MenuPopup {
                     cb.clone()(s);
                 })
             }
-            menu::Item::Subtree { ref tree, .. } => self.make_subtree_cb(tree),
+            menu::Item::Subtree {
+                ref tree,
+                ref extras,
+                ..
+            } => self.make_subtree_cb(tree, &extras.on_open),
             _ => unreachable!("Delimiters cannot be submitted."),
         }
     }
@@ -185,40 +339,67 @@ impl MenuPopup {
                 cb.clone()(s);
             }
             s.pop_layer();
+            s.menu_help_changed(None);
         })
     }
 
-    fn make_subtree_cb(&self, tree: &Rc<menu::Tree>) -> EventResult {
+    // Help text of the focused item.
+    fn focused_help(&self) -> Option<&str> {
+        self.menu
+            .children
+            .get(self.focus)
+            .and_then(menu::Item::help)
+    }
+
+    fn make_subtree_cb(
+        &self,
+        tree: &Rc<menu::Tree>,
+        on_open: &Option<Callback>,
+    ) -> EventResult {
         let tree = Rc::clone(tree);
+        let on_open = on_open.clone();
         let max_width = 4 + self
-            .menu
+            .unfiltered
             .children
             .iter()
             .map(MenuPopup::item_width)
             .max()
             .unwrap_or(1);
-        let offset = Vec2::new(max_width, self.focus);
+        // Open the subtree next to the focused item, where it is visible.
+        let top = self.scroll_core.content_viewport().top();
+        let offset = Vec2::new(max_width, self.focus.saturating_sub(top));
         let action_cb = self.on_action.clone();
+        let filterable = self.filterable;
+        let max_height = self.max_height;
 
         EventResult::with_cb(move |s| {
             let action_cb = action_cb.clone();
             s.screen_mut().add_layer_at(
                 Position::parent(offset),
-                OnEventView::new(MenuPopup::new(Rc::clone(&tree)).on_action(
-                    move |s| {
-                        // This will happen when the subtree popup
-                        // activates something;
-                        // First, remove ourselve.
-                        s.pop_layer();
-                        if let Some(ref action_cb) = action_cb {
-                            action_cb.clone()(s);
-                        }
-                    },
-                ))
+                OnEventView::new(
+                    MenuPopup::new(Rc::clone(&tree))
+                        .filterable(filterable)
+                        .with(|popup| popup.set_max_height(max_height))
+                        .on_action(move |s| {
+                            // This will happen when the subtree popup
+                            // activates something;
+                            // First, remove ourselve.
+                            s.pop_layer();
+                            if let Some(ref action_cb) = action_cb {
+                                action_cb.clone()(s);
+                            }
+                        }),
+                )
                 .on_event(Key::Left, |s| {
                     s.pop_layer();
                 }),
             );
+            s.menu_help_changed(
+                tree.children.first().and_then(menu::Item::help),
+            );
+            if let Some(ref on_open) = on_open {
+                on_open(s);
+            }
         })
     }
 
@@ -226,11 +407,27 @@ impl MenuPopup {
     ///
     /// Here the event has already been relativized. This means `y=0` points to the first item.
     fn inner_on_event(&mut self, event: Event) -> EventResult {
+        if self.filterable {
+            if let Some(result) = self.filter_on_event(&event) {
+                return result;
+            }
+        }
+
+        // The filter may hide every selectable item.
+        if !self.menu.children.iter().any(menu::Item::is_enabled) {
+            return match event {
+                Event::Key(Key::Esc) => self.dismiss(),
+                _ => EventResult::Ignored,
+            };
+        }
+
         match event {
             Event::Key(Key::Up) => self.scroll_up(1, true),
-            Event::Key(Key::PageUp) => self.scroll_up(5, false),
+            Event::Key(Key::PageUp) => self.scroll_up(self.page_size(), false),
             Event::Key(Key::Down) => self.scroll_down(1, true),
-            Event::Key(Key::PageDown) => self.scroll_down(5, false),
+            Event::Key(Key::PageDown) => {
+                self.scroll_down(self.page_size(), false)
+            }
 
             Event::Key(Key::Home) => self.focus = 0,
             Event::Key(Key::End) => {
@@ -238,12 +435,15 @@ impl MenuPopup {
             }
 
             Event::Key(Key::Right)
-                if self.menu.children[self.focus].is_subtree() =>
+                if self.menu.children[self.focus].is_subtree()
+                    && self.menu.children[self.focus].is_enabled() =>
             {
                 return match self.menu.children[self.focus] {
-                    menu::Item::Subtree { ref tree, .. } => {
-                        self.make_subtree_cb(tree)
-                    }
+                    menu::Item::Subtree {
+                        ref tree,
+                        ref extras,
+                        ..
+                    } => self.make_subtree_cb(tree, &extras.on_open),
                     _ => unreachable!("Child is a subtree"),
                 };
             }
@@ -262,7 +462,7 @@ impl MenuPopup {
                     // Now `position` is relative to the top-left of the content.
                     let focus = position.y;
                     if focus < self.menu.len()
-                        && self.menu.children[focus].is_enabled()
+                        && is_focusable(&self.menu.children[focus])
                     {
                         self.focus = focus;
                     }
@@ -290,10 +490,16 @@ impl MenuPopup {
         EventResult::Consumed(None)
     }
 
+    // Number of items visible at once.
+    fn page_size(&self) -> usize {
+        usize::max(self.scroll_core.content_viewport().height(), 1)
+    }
+
     /// Compute the required size for the content.
     fn inner_required_size(&mut self, _req: Vec2) -> Vec2 {
+        // Keep the same width while filtering.
         let w = 2 + self
-            .menu
+            .unfiltered
             .children
             .iter()
             .map(Self::item_width)
@@ -305,6 +511,43 @@ impl MenuPopup {
         Vec2::new(w, h)
     }
 
+    // Prints an item label, highlighting the characters matching the filter.
+    //
+    // The label is truncated to `max_width`.
+    fn print_label(&self, printer: &Printer, label: &str, max_width: usize) {
+        let shown = StyledString::plain(label).truncated(max_width, "…");
+        let matches = match fuzzy_match(label, &self.filter) {
+            Some(matches) if !self.filter.is_empty() => matches,
+            _ => {
+                printer.print_styled((1, 0), (&shown).into());
+                return;
+            }
+        };
+
+        // The ellipsis, if any, is the last span.
+        let source = shown.source();
+        let (label, ellipsis) = match *shown.spans_raw() {
+            [ref kept, _] => {
+                source.split_at(kept.content.resolve(source).len())
+            }
+            _ => (source, ""),
+        };
+
+        let mut x = 1;
+        for (i, c) in label.chars().enumerate() {
+            let text = c.to_string();
+            if matches.contains(&i) {
+                printer.with_effect(Effect::Underline, |printer| {
+                    printer.print((x, 0), &text);
+                });
+            } else {
+                printer.print((x, 0), &text);
+            }
+            x += char_width(c);
+        }
+        printer.print((x, 0), ellipsis);
+    }
+
     fn inner_important_area(&self, size: Vec2) -> Rect {
         if self.menu.is_empty() {
             return Rect::from_size(Vec2::zero(), size);
@@ -333,6 +576,23 @@ impl View for MenuPopup {
             |_s, _x| false,
         );
 
+        if !self.filter.is_empty() {
+            printer.print((1, 0), &format!(" {} ", self.filter));
+        }
+
+        // Explain why the focused item is disabled, on the bottom border.
+        if let Some(reason) = self
+            .menu
+            .children
+            .get(self.focus)
+            .and_then(menu::Item::disabled_reason)
+        {
+            let y = printer.size.y.saturating_sub(1);
+            printer.with_color(ColorStyle::secondary(), |printer| {
+                printer.print((1, y), &format!(" {} ", reason));
+            });
+        }
+
         // We're giving it a reduced size because of borders.
         let printer = printer.shrinked_centered((2, 2));
 
@@ -340,7 +600,9 @@ impl View for MenuPopup {
             let item = &s.menu.children[i];
             let enabled =
                 printer.enabled && (item.is_enabled() || item.is_delimiter());
-            let color = if !enabled {
+            let color = if !enabled && i == s.focus {
+                ColorStyle::highlight_inactive()
+            } else if !enabled {
                 ColorStyle::secondary()
             } else if i == s.focus {
                 ColorStyle::highlight()
@@ -358,7 +620,8 @@ impl View for MenuPopup {
                             return;
                         }
                         printer.print_hline((0, 0), printer.size.x, " ");
-                        printer.print((1, 0), label);
+                        let max_width = printer.size.x.saturating_sub(5);
+                        s.print_label(printer, label, max_width);
                         let x = printer.size.x.saturating_sub(3);
                         printer.print((x, 0), ">>");
                     }
@@ -367,7 +630,20 @@ impl View for MenuPopup {
                             return;
                         }
                         printer.print_hline((0, 0), printer.size.x, " ");
-                        printer.print((1, 0), label);
+                        // Keep the shortcut hint visible.
+                        let hint = item.get_shortcut().map(shortcut_hint);
+                        let max_width = match hint {
+                            Some(ref hint) => hint.width() + 4,
+                            None => 2,
+                        };
+                        let max_width =
+                            printer.size.x.saturating_sub(max_width);
+                        s.print_label(printer, label, max_width);
+                        if let Some(hint) = hint {
+                            let x =
+                                printer.size.x.saturating_sub(hint.width());
+                            printer.print((x, 0), &hint);
+                        }
                     }
                 }
             });
@@ -378,6 +654,10 @@ impl View for MenuPopup {
         // We can't really shrink our items here, so it's not flexible.
 
         // 2 is the padding
+        let mut req = req;
+        if let Some(max_height) = self.max_height {
+            req.y = min(req.y, max_height + 2);
+        }
 
         scroll::required_size(
             self,
@@ -388,7 +668,9 @@ impl View for MenuPopup {
     }
 
     fn on_event(&mut self, event: Event) -> EventResult {
-        match scroll::on_event(
+        let help = self.focused_help().map(String::from);
+
+        let result = match scroll::on_event(
             self,
             event.relativized((1, 1)),
             Self::inner_on_event,
@@ -416,6 +698,7 @@ impl View for MenuPopup {
                                 cb.clone()(s);
                             }
                             s.pop_layer();
+                            s.menu_help_changed(None);
                         });
                     }
                 }
@@ -423,6 +706,12 @@ impl View for MenuPopup {
                 EventResult::Ignored
             }
             other => other,
+        };
+
+        if self.focused_help() == help.as_deref() {
+            result
+        } else {
+            result.and(help_changed(self.focused_help()))
         }
     }
 
@@ -445,3 +734,144 @@ impl View for MenuPopup {
         .with(|area| area.offset((1, 1)))
     }
 }
+
+// Tells the application that the highlighted menu item changed.
+pub(crate) fn help_changed(help: Option<&str>) -> EventResult {
+    let help = help.map(String::from);
+    EventResult::with_cb(move |s| s.menu_help_changed(help.as_deref()))
+}
+
+// Returns `true` if the focus can land on this item.
+//
+// Disabled items can be focused if they explain why they are disabled.
+fn is_focusable(item: &menu::Item) -> bool {
+    item.is_enabled() || item.disabled_reason().is_some()
+}
+
+// Text shown for a leaf shortcut, right-aligned.
+fn shortcut_hint(event: &Event) -> String {
+    help::key_name(event).unwrap_or_default()
+}
+
+// Returns the (char) positions in `label` matching the characters of
+// `query`, in order and ignoring case.
+//
+// Returns `None` if `label` does not contain all characters of `query`.
+pub(crate) fn fuzzy_match(label: &str, query: &str) -> Option<Vec<usize>> {
+    let mut positions = Vec::new();
+    let mut chars = label.chars().enumerate();
+
+    for q in query.chars().flat_map(char::to_lowercase) {
+        let (i, _) = chars
+            .by_ref()
+            .find(|&(_, c)| c.to_lowercase().any(|c| c == q))?;
+        positions.push(i);
+    }
+
+    Some(positions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn max_height_scrolls() {
+        let tree = (0..30).fold(menu::Tree::new(), |tree, i| {
+            tree.leaf(format!("Item {}", i), |_| ())
+        });
+        let mut popup = MenuPopup::new(Rc::new(tree)).max_height(5);
+
+        let size = popup.required_size(Vec2::new(80, 40));
+        assert_eq!(size.y, 7);
+        popup.layout(size);
+
+        popup.on_event(Event::Key(Key::PageDown));
+        assert_eq!(popup.get_focus(), 5);
+        for _ in 0..3 {
+            popup.on_event(Event::Key(Key::Down));
+        }
+        popup.layout(size);
+        let viewport = popup.scroll_core.content_viewport();
+        assert!(viewport.top() <= 8 && 8 <= viewport.bottom());
+    }
+
+    #[test]
+    fn focus_disabled_with_reason() {
+        let tree = menu::Tree::new()
+            .leaf("Open", |_| ())
+            .item(menu::Item::leaf("Close", |_| ()).disabled())
+            .item(
+                menu::Item::leaf("Save", |_| ())
+                    .disabled_with_reason("No open file"),
+            );
+        let mut popup = MenuPopup::new(Rc::new(tree));
+
+        popup.on_event(Event::Key(Key::Down));
+        assert_eq!(popup.get_focus(), 2);
+        assert!(!popup.on_event(Event::Key(Key::Enter)).has_callback());
+    }
+
+    #[test]
+    fn help_changes_with_focus() {
+        let tree = menu::Tree::new()
+            .item(menu::Item::leaf("Open", |_| ()).with_help("Open a file"))
+            .item(menu::Item::leaf("Reopen", |_| ()).with_help("Open a file"))
+            .leaf("Close", |_| ());
+        let mut popup = MenuPopup::new(Rc::new(tree));
+
+        // Same help text: nothing to report.
+        assert!(!popup.on_event(Event::Key(Key::Down)).has_callback());
+        assert!(popup.on_event(Event::Key(Key::Down)).has_callback());
+    }
+
+    #[test]
+    fn fuzzy_match_in_order() {
+        assert_eq!(fuzzy_match("Save As", "sa"), Some(vec![0, 1]));
+        assert_eq!(fuzzy_match("Save As", "vas"), Some(vec![2, 5, 6]));
+        assert_eq!(fuzzy_match("Save As", "sz"), None);
+        assert_eq!(fuzzy_match("Open", ""), Some(vec![]));
+    }
+
+    #[test]
+    fn filter_hides_delimiters() {
+        let tree = menu::Tree::new()
+            .leaf("Open", |_| ())
+            .delimiter()
+            .item(menu::Item::leaf("Save", |_| ()).disabled())
+            .leaf("Save As", |_| ());
+        let mut popup = MenuPopup::new(Rc::new(tree)).filterable(true);
+
+        popup.inner_on_event(Event::Char('s'));
+        let labels: Vec<_> =
+            popup.menu.children.iter().map(menu::Item::label).collect();
+        assert_eq!(labels, vec!["Save", "Save As"]);
+        // The disabled item is skipped.
+        assert_eq!(popup.get_focus(), 1);
+
+        popup.inner_on_event(Event::Key(Key::Esc));
+        assert_eq!(popup.get_filter(), "");
+        assert_eq!(popup.menu.len(), 4);
+    }
+
+    #[test]
+    fn subtree_on_open() {
+        let tree = menu::Tree::new().leaf("Open", |_| ()).item(
+            menu::Item::subtree("Recent", menu::Tree::new().leaf("a", |_| ()))
+                .on_open(|s| *s.user_data::<usize>().unwrap() += 1),
+        );
+        let mut popup = MenuPopup::new(Rc::new(tree));
+        let mut siv = crate::Cursive::new();
+        siv.set_user_data(0usize);
+
+        // Selecting the subtree does not open it.
+        popup.on_event(Event::Key(Key::Down)).process(&mut siv);
+        assert_eq!(siv.user_data::<usize>(), Some(&mut 0));
+
+        popup.on_event(Event::Key(Key::Right)).process(&mut siv);
+        assert_eq!(siv.user_data::<usize>(), Some(&mut 1));
+        popup.on_event(Event::Key(Key::Enter)).process(&mut siv);
+        assert_eq!(siv.user_data::<usize>(), Some(&mut 2));
+        assert_eq!(siv.screen().len(), 2);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::utils::width::TextWidth;
 use crate::{
     direction,
     event::*,
@@ -5,11 +6,10 @@ use crate::{
     rect::Rect,
     theme::ColorStyle,
     view::{CannotFocus, Position, View},
-    views::{MenuPopup, OnEventView},
-    Cursive, Printer, Vec2,
+    views::{command_palette, menu_popup, Command, MenuPopup, OnEventView},
+    Cursive, Printer, Vec2, With,
 };
 use std::rc::Rc;
-use unicode_width::UnicodeWidthStr;
 
 /// Current state of the menubar
 #[derive(PartialEq, Debug)]
@@ -40,6 +40,9 @@ pub struct Menubar {
     pub autohide: bool,
     focus: usize,
 
+    // Maximum number of visible items in the popups.
+    popup_max_height: Option<usize>,
+
     // TODO: make Menubar impl View and take out the State management
     state: State,
 }
@@ -54,9 +57,17 @@ impl Menubar {
             autohide: true,
             state: State::Inactive,
             focus: 0,
+            popup_max_height: None,
         }
     }
 
+    /// Sets the maximum number of visible items in the menu popups.
+    ///
+    /// Longer menus scroll. See [`MenuPopup::set_max_height`].
+    pub fn set_popup_max_height(&mut self, max_height: Option<usize>) {
+        self.popup_max_height = max_height;
+    }
+
     /// Hides the menubar.
     fn hide(&mut self) {
         self.state = State::Inactive;
@@ -191,6 +202,18 @@ impl Menubar {
         self.root.remove(i);
     }
 
+    // Returns the enabled leaves, as commands for the command palette.
+    pub(crate) fn commands(&self) -> Vec<Command> {
+        command_palette::menu_commands(&self.root)
+    }
+
+    /// Returns the callback of the enabled leaf with this shortcut, if any.
+    ///
+    /// See [`menu::Item::set_shortcut`].
+    pub fn find_shortcut(&self, event: &Event) -> Option<Callback> {
+        self.root.find_shortcut(event)
+    }
+
     fn child_at(&self, x: usize) -> Option<usize> {
         if x == 0 {
             return None;
@@ -212,12 +235,19 @@ impl Menubar {
             menu::Item::Leaf { ref cb, .. } if !open_only => {
                 // Go inactive after an action.
                 self.state = State::Inactive;
-                EventResult::Consumed(Some(cb.clone()))
+                menu_popup::help_changed(None)
+                    .and(EventResult::Consumed(Some(cb.clone())))
             }
-            menu::Item::Subtree { ref tree, .. } => {
+            menu::Item::Subtree {
+                ref tree,
+                ref extras,
+                ..
+            } => {
                 // First, we need a new Rc to send the callback,
                 // since we don't know when it will be called.
                 let menu = Rc::clone(tree);
+                let on_open = extras.on_open.clone();
+                let max_height = self.popup_max_height;
 
                 self.state = State::Submenu;
                 let offset = Vec2::new(
@@ -230,7 +260,10 @@ impl Menubar {
                 // Since the closure will be called multiple times,
                 // we also need a new Rc on every call.
                 EventResult::with_cb(move |s| {
-                    show_child(s, offset, Rc::clone(&menu))
+                    show_child(s, offset, Rc::clone(&menu), max_height);
+                    if let Some(ref on_open) = on_open {
+                        on_open(s);
+                    }
                 })
             }
             _ => EventResult::Ignored,
@@ -238,17 +271,24 @@ impl Menubar {
     }
 }
 
-fn show_child(s: &mut Cursive, offset: Vec2, menu: Rc<menu::Tree>) {
+fn show_child(
+    s: &mut Cursive,
+    offset: Vec2,
+    menu: Rc<menu::Tree>,
+    max_height: Option<usize>,
+) {
     // Adds a new layer located near the item title with the menu popup.
     // Also adds two key callbacks on this new view, to handle `left` and
     // `right` key presses.
     // (If the view itself listens for a `left` or `right` press, it will
     // consume it before our OnEventView. This means sub-menus can properly
     // be entered.)
+    s.menu_help_changed(menu.children.first().and_then(menu::Item::help));
     s.screen_mut().add_layer_at(
         Position::absolute(offset),
         OnEventView::new(
             MenuPopup::new(menu)
+                .with(|popup| popup.set_max_height(max_height))
                 .on_dismiss(Cursive::select_menubar)
                 .on_action(|s| s.menubar().state = State::Inactive),
         )
@@ -315,10 +355,12 @@ impl View for Menubar {
     }
 
     fn on_event(&mut self, event: Event) -> EventResult {
+        let focus = self.focus;
         match event {
             Event::Key(Key::Esc) => {
                 self.hide();
-                return EventResult::with_cb(Cursive::clear);
+                return menu_popup::help_changed(None)
+                    .and(EventResult::with_cb(Cursive::clear));
             }
             Event::Key(Key::Left) => loop {
                 // TODO: fix endless loop if nothing is enabled?
@@ -390,7 +432,12 @@ impl View for Menubar {
             }
             _ => return EventResult::Ignored,
         }
-        EventResult::Consumed(None)
+
+        if self.focus == focus {
+            EventResult::Consumed(None)
+        } else {
+            menu_popup::help_changed(self.root.children[self.focus].help())
+        }
     }
 
     fn take_focus(
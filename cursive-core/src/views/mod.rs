@@ -63,18 +63,22 @@ macro_rules! impl_enabled {
 
 mod arbitrary_select_view;
 mod arbitrary_select_view_v2;
+mod aspect_ratio_view;
 mod boxed_view;
 mod button;
 mod canvas;
 mod checkbox;
 mod circular_focus;
+mod command_palette;
 mod debug_view;
+mod diff_view;
 mod dialog;
 mod dummy;
 mod edit_view;
 mod enableable_view;
 mod fixed_layout;
 mod focus_tracker;
+mod form;
 mod hideable_view;
 mod last_size_view;
 mod layer;
@@ -83,6 +87,7 @@ mod list_view;
 mod menu_popup;
 mod menubar;
 mod named_view;
+mod offset_view;
 mod on_event_view;
 mod on_layout_view;
 mod padded_view;
@@ -90,39 +95,50 @@ mod panel;
 mod progress_bar;
 mod radio;
 mod resized_view;
+mod reader_view;
+mod ring_buffer_view;
 mod screens_view;
 mod scroll_view;
 mod select_view;
 mod shadow_view;
 mod slider_view;
 mod stack_view;
+mod statusbar;
+mod table_view;
 mod text_area;
 mod text_view;
 mod themed_view;
+mod tiled_layout;
 mod tracked_view;
+mod tree_view;
 
 pub use self::{
     arbitrary_select_view::{ArbitrarySelectView},
+    aspect_ratio_view::AspectRatioView,
     boxed_view::BoxedView,
     button::Button,
-    canvas::Canvas,
+    canvas::{Canvas, CanvasBuilder},
     checkbox::Checkbox,
     circular_focus::CircularFocus,
+    command_palette::{Command, CommandPalette},
     debug_view::DebugView,
+    diff_view::{DiffLayout, DiffView},
     dialog::{Dialog, DialogFocus},
     dummy::DummyView,
-    edit_view::EditView,
+    edit_view::{EditView, WordNavigationKeys},
     enableable_view::EnableableView,
-    fixed_layout::FixedLayout,
+    fixed_layout::{FixedLayout, Placement},
     focus_tracker::FocusTracker,
+    form::{Form, FormData, FormField, FormValue},
     hideable_view::HideableView,
     last_size_view::LastSizeView,
     layer::Layer,
     linear_layout::LinearLayout,
-    list_view::{ListChild, ListView},
+    list_view::{HelpVisibility, ListChild, ListView, RowStatus},
     menu_popup::MenuPopup,
     menubar::Menubar,
     named_view::{NamedView, ViewRef},
+    offset_view::OffsetView,
     on_event_view::OnEventView,
     on_layout_view::OnLayoutView,
     padded_view::PaddedView,
@@ -130,14 +146,20 @@ pub use self::{
     progress_bar::ProgressBar,
     radio::{RadioButton, RadioGroup},
     resized_view::ResizedView,
+    reader_view::ReaderView,
+    ring_buffer_view::{RingBufferSink, RingBufferView},
     screens_view::ScreensView,
-    scroll_view::ScrollView,
+    scroll_view::{ScrollPosition, ScrollView},
     select_view::SelectView,
     shadow_view::ShadowView,
     slider_view::SliderView,
     stack_view::{LayerPosition, StackView},
-    text_area::TextArea,
+    statusbar::Statusbar,
+    table_view::{ColumnWidth, TableView, TableViewItem},
+    text_area::{TextArea, WrapMode},
     text_view::{TextContent, TextContentRef, TextView},
     themed_view::ThemedView,
+    tiled_layout::{Layout, SlotId, TiledLayout},
     tracked_view::TrackedView,
+    tree_view::{RowId, TreeItem, TreeView},
 };
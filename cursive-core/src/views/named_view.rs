@@ -88,6 +88,10 @@ impl<T: View + 'static> ViewWrapper for NamedView<T> {
         }
     }
 
+    fn wrap_debug_name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
     fn wrap_call_on_any<'a>(
         &mut self,
         selector: &Selector<'_>,
@@ -0,0 +1,133 @@
+use crate::event::{Event, EventResult};
+use crate::rect::Rect;
+use crate::view::{Position, View, ViewWrapper};
+use crate::Printer;
+use crate::Vec2;
+use crate::With;
+
+/// Wrapper view placing its child at a given offset.
+///
+/// This view takes all the space it is given, and draws its child at its
+/// natural size, positioned according to a [`Position`]. The rest of the
+/// space is left untouched, so it is best used as a transparent layer.
+///
+/// `Offset::Parent` offsets are relative to the top-left corner of this view.
+///
+/// To place a layer in a [`StackView`](crate::views::StackView), use
+/// [`StackView::add_layer_at`](crate::views::StackView::add_layer_at)
+/// instead.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive_core::align::Align;
+/// # use cursive_core::view::Position;
+/// # use cursive_core::views::{OffsetView, TextView};
+/// let view = OffsetView::new(
+///     Position::aligned(Align::bot_right()),
+///     TextView::new("Saved."),
+/// );
+/// ```
+pub struct OffsetView<V> {
+    view: V,
+    offset: Position,
+
+    // Position of the child, computed during layout.
+    child_offset: Vec2,
+    child_size: Vec2,
+}
+
+impl<V> OffsetView<V> {
+    /// Wraps `view` in a new `OffsetView`.
+    pub fn new(offset: Position, view: V) -> Self {
+        OffsetView {
+            view,
+            offset,
+            child_offset: Vec2::zero(),
+            child_size: Vec2::zero(),
+        }
+    }
+
+    /// Wraps `view` in a new `OffsetView`, centered.
+    pub fn centered(view: V) -> Self {
+        Self::new(Position::center(), view)
+    }
+
+    /// Sets the offset of the child.
+    pub fn set_offset(&mut self, offset: Position) {
+        self.offset = offset;
+    }
+
+    /// Sets the offset of the child.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn offset(self, offset: Position) -> Self {
+        self.with(|s| s.set_offset(offset))
+    }
+
+    /// Returns the offset of the child.
+    pub fn get_offset(&self) -> Position {
+        self.offset
+    }
+
+    inner_getters!(self.view: V);
+}
+
+impl<V: View> ViewWrapper for OffsetView<V> {
+    wrap_impl!(self.view: V);
+
+    fn wrap_required_size(&mut self, req: Vec2) -> Vec2 {
+        // Ask for everything: we need the full area to compute the offset.
+        let _ = self.view.required_size(req);
+        req
+    }
+
+    fn wrap_layout(&mut self, size: Vec2) {
+        self.child_size = Vec2::min(self.view.required_size(size), size);
+        self.child_offset =
+            self.offset
+                .compute_offset(self.child_size, size, Vec2::zero());
+        self.view.layout(self.child_size);
+    }
+
+    fn wrap_draw(&self, printer: &Printer) {
+        let printer =
+            &printer.offset(self.child_offset).cropped(self.child_size);
+        self.view.draw(printer);
+    }
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        self.view.on_event(event.relativized(self.child_offset))
+    }
+
+    fn wrap_important_area(&self, _: Vec2) -> Rect {
+        self.view.important_area(self.child_size) + self.child_offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::align::Align;
+    use crate::view::Resizable;
+    use crate::views::DummyView;
+
+    #[test]
+    fn layout_child() {
+        let mut view = OffsetView::new(
+            Position::aligned(Align::top_right()),
+            DummyView.fixed_size((5, 3)),
+        );
+
+        assert_eq!(view.required_size(Vec2::new(20, 10)), Vec2::new(20, 10));
+
+        view.layout(Vec2::new(20, 10));
+        assert_eq!(view.child_offset, Vec2::new(15, 0));
+        assert_eq!(view.child_size, Vec2::new(5, 3));
+
+        view.set_offset(Position::percent(50, 100));
+        view.layout(Vec2::new(20, 10));
+        assert_eq!(view.child_offset, Vec2::new(8, 7));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::event::{Callback, Event, EventResult, EventTrigger};
+use crate::help::{self, Binding};
 use crate::view::{View, ViewWrapper};
 use crate::Cursive;
 use crate::With;
@@ -41,6 +42,10 @@ use std::rc::Rc;
 pub struct OnEventView<T> {
     view: T,
     callbacks: Vec<(EventTrigger, Action<T>)>,
+
+    // Descriptions of the documented callbacks, for the help screen.
+    help: Vec<(Event, String)>,
+    help_scope: String,
 }
 
 new_default!(OnEventView<T: Default>);
@@ -73,6 +78,8 @@ impl<T> OnEventView<T> {
         OnEventView {
             view,
             callbacks: Vec::new(),
+            help: Vec::new(),
+            help_scope: help::DEFAULT_SCOPE.to_string(),
         }
     }
 
@@ -82,6 +89,7 @@ impl<T> OnEventView<T> {
         E: Into<Event>,
     {
         let event = event.into();
+        self.help.retain(|(e, _)| *e != event);
         self.callbacks
             .retain(move |&(ref trigger, _)| !trigger.has_tag(&event));
     }
@@ -261,9 +269,77 @@ impl<T> OnEventView<T> {
         ));
     }
 
+    /// Registers a callback when the given event is ignored by the child,
+    /// with a description for the help screen.
+    ///
+    /// The description is listed by
+    /// [`Cursive::show_help`](crate::Cursive::show_help), under this view's
+    /// help scope (see [`OnEventView::set_help_scope`]).
+    pub fn set_on_event_help<F, E, S>(
+        &mut self,
+        event: E,
+        description: S,
+        cb: F,
+    ) where
+        E: Into<Event>,
+        S: Into<String>,
+        F: Fn(&mut Cursive) + 'static,
+    {
+        let event = event.into();
+        self.help.push((event.clone(), description.into()));
+        self.set_on_event(event, cb);
+    }
+
+    /// Registers a callback when the given event is ignored by the child,
+    /// with a description for the help screen.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::views::{OnEventView, TextView};
+    /// # use cursive_core::event::Key;
+    /// let view = OnEventView::new(TextView::new("Some text"))
+    ///     .on_event_help(Key::Del, "Delete the text", |_| ())
+    ///     .help_scope("Editor");
+    /// ```
+    #[must_use]
+    pub fn on_event_help<F, E, S>(
+        self,
+        event: E,
+        description: S,
+        cb: F,
+    ) -> Self
+    where
+        E: Into<Event>,
+        S: Into<String>,
+        F: Fn(&mut Cursive) + 'static,
+    {
+        self.with(|s| s.set_on_event_help(event, description, cb))
+    }
+
+    /// Sets the group under which this view's bindings are listed in the help
+    /// screen.
+    ///
+    /// Defaults to [`help::DEFAULT_SCOPE`].
+    pub fn set_help_scope<S: Into<String>>(&mut self, scope: S) {
+        self.help_scope = scope.into();
+    }
+
+    /// Sets the group under which this view's bindings are listed in the help
+    /// screen.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn help_scope<S: Into<String>>(self, scope: S) -> Self {
+        self.with(|s| s.set_help_scope(scope))
+    }
+
     /// Remove any callbacks defined for this view.
     pub fn clear_callbacks(&mut self) {
         self.callbacks.clear();
+        self.help.clear();
     }
 
     inner_getters!(self.view: T);
@@ -272,6 +348,17 @@ impl<T> OnEventView<T> {
 impl<T: View> ViewWrapper for OnEventView<T> {
     wrap_impl!(self.view: T);
 
+    fn wrap_key_help(&self, f: &mut dyn FnMut(&Binding)) {
+        for (event, description) in &self.help {
+            f(&Binding {
+                event: event.clone(),
+                description: description.clone(),
+                scope: self.help_scope.clone(),
+            });
+        }
+        self.view.key_help(f);
+    }
+
     fn wrap_on_event(&mut self, event: Event) -> EventResult {
         // Until we have better closure capture, define captured members separately.
         let callbacks = &self.callbacks;
@@ -63,6 +63,15 @@ impl<V: View> ViewWrapper for PaddedView<V> {
         self.view.layout(size.saturating_sub(margins));
     }
 
+    fn wrap_set_viewport(&mut self, viewport: Rect) {
+        // The viewport is given in our coordinates.
+        let padding = self.margins.top_left();
+        self.view.set_viewport(Rect::from_corners(
+            viewport.top_left().saturating_sub(padding),
+            viewport.bottom_right().saturating_sub(padding),
+        ));
+    }
+
     fn wrap_on_event(&mut self, event: Event) -> EventResult {
         let padding = self.margins.top_left();
         self.view.on_event(event.relativized(padding))
@@ -2,11 +2,11 @@ use crate::align::*;
 use crate::event::{Event, EventResult};
 use crate::rect::Rect;
 use crate::theme::ColorStyle;
+use crate::utils::width::TextWidth;
 use crate::view::{View, ViewWrapper};
 use crate::Printer;
 use crate::Vec2;
 use crate::With;
-use unicode_width::UnicodeWidthStr;
 
 /// Draws a border around a wrapped view.
 #[derive(Debug)]
@@ -130,6 +130,14 @@ impl<V: View> ViewWrapper for Panel<V> {
         self.view.layout(size.saturating_sub((2, 2)));
     }
 
+    fn wrap_set_viewport(&mut self, viewport: Rect) {
+        // The viewport is given in our coordinates.
+        self.view.set_viewport(Rect::from_corners(
+            viewport.top_left().saturating_sub((1, 1)),
+            viewport.bottom_right().saturating_sub((1, 1)),
+        ));
+    }
+
     fn wrap_important_area(&self, size: Vec2) -> Rect {
         let inner_size = size.saturating_sub((2, 2));
         self.view.important_area(inner_size) + (1, 1)
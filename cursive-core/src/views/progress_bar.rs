@@ -1,6 +1,6 @@
 use crate::align::HAlign;
 use crate::theme::{ColorStyle, ColorType, Effect};
-use crate::utils::Counter;
+use crate::utils::{Counter, FloatCounter};
 use crate::view::View;
 use crate::{Printer, With};
 use std::cmp;
@@ -37,6 +37,8 @@ pub struct ProgressBar {
     min: usize,
     max: usize,
     value: Counter,
+    // If set, followed instead of `value`.
+    float_value: Option<FloatCounter>,
     color: ColorType,
     // TODO: use a Promise instead?
     label_maker: Box<dyn Fn(usize, (usize, usize)) -> String>,
@@ -88,6 +90,7 @@ impl ProgressBar {
             min: 0,
             max: 100,
             value: Counter::new(0),
+            float_value: None,
             color: ColorStyle::highlight().back,
             label_maker: Box::new(make_percentage),
         }
@@ -239,6 +242,37 @@ impl ProgressBar {
     /// by directly modifying the value pointed to by `value`.
     pub fn set_counter(&mut self, value: Counter) {
         self.value = value;
+        self.float_value = None;
+    }
+
+    /// Follows a floating-point counter instead of a `Counter`.
+    ///
+    /// The counter holds the progress as a fraction, from `0.0` for `min`
+    /// to `1.0` for `max`. The label is given the nearest whole value in
+    /// the range.
+    pub fn set_float_counter(&mut self, value: FloatCounter) {
+        self.float_value = Some(value);
+    }
+
+    /// Follows a floating-point counter instead of a `Counter`.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::utils::FloatCounter;
+    /// use cursive_core::views::ProgressBar;
+    ///
+    /// let progress = FloatCounter::new(0.0);
+    /// let bar = ProgressBar::new().with_float_value(progress.clone());
+    ///
+    /// // Shows "43 %"
+    /// progress.set(0.425);
+    /// ```
+    #[must_use]
+    pub fn with_float_value(self, value: FloatCounter) -> Self {
+        self.with(|s| s.set_float_counter(value))
     }
 
     /// Sets the color style.
@@ -263,6 +297,28 @@ impl ProgressBar {
     }
 }
 
+impl ProgressBar {
+    // Returns the value for the label, and the length of the bar, for the
+    // given fraction of the range.
+    fn float_progress(
+        &self,
+        fraction: f64,
+        available: usize,
+    ) -> (usize, (usize, usize)) {
+        let fraction = if fraction.is_nan() {
+            0.0
+        } else {
+            fraction.clamp(0.0, 1.0)
+        };
+
+        let range = (self.max - self.min) as f64;
+        let value = self.min + (fraction * range).round() as usize;
+
+        let eighths = (fraction * available as f64 * 8.0) as usize;
+        (value, (eighths / 8, eighths % 8))
+    }
+}
+
 fn sub_block(extra: usize) -> &'static str {
     match extra {
         0 => " ",
@@ -282,15 +338,23 @@ impl View for ProgressBar {
         // Now, the bar itself...
         let available = printer.size.x;
 
-        let value = self.value.get();
-
-        // If we're under the minimum, don't draw anything.
-        // If we're over the maximum, we'll try to draw more, but the printer
-        // will crop us anyway, so it's not a big deal.
-        let (length, extra) = if value < self.min {
-            (0, 0)
-        } else {
-            ratio(value - self.min, self.max - self.min, available)
+        let (value, (length, extra)) = match self.float_value {
+            Some(ref float_value) => {
+                self.float_progress(float_value.get(), available)
+            }
+            None => {
+                let value = self.value.get();
+
+                // If we're under the minimum, don't draw anything.
+                // If we're over the maximum, we'll try to draw more, but the printer
+                // will crop us anyway, so it's not a big deal.
+                let length = if value < self.min {
+                    (0, 0)
+                } else {
+                    ratio(value - self.min, self.max - self.min, available)
+                };
+                (value, length)
+            }
         };
 
         let label = (self.label_maker)(value, (self.min, self.max));
@@ -26,6 +26,26 @@ impl<T> SharedState<T> {
 /// A `RadioGroup` is used to create and manage [`RadioButton`]s.
 ///
 /// A `RadioGroup` can be cloned; it will keep pointing to the same group.
+///
+/// Buttons are shown as `( )` or `(X)`, and selected with `<Space>`,
+/// `<Enter>` or a click. The first button is selected by default.
+///
+/// # Examples
+///
+/// ```
+/// use cursive_core::views::{LinearLayout, RadioGroup};
+///
+/// let mut group = RadioGroup::new().on_change(|_, size: &u32| {
+///     println!("Size: {}", size);
+/// });
+///
+/// let layout = LinearLayout::vertical()
+///     .child(group.button(1, "Small"))
+///     .child(group.button(2, "Medium"))
+///     .child(group.button(3, "Large"));
+///
+/// assert_eq!(*group.selection(), 1);
+/// ```
 pub struct RadioGroup<T> {
     // Given to every child button
     state: Rc<RefCell<SharedState<T>>>,
@@ -78,6 +98,10 @@ impl<T: 'static> RadioGroup<T> {
     }
 
     /// Returns the value associated with the selected button.
+    ///
+    /// # Panics
+    ///
+    /// If no button was added to this group.
     pub fn selection(&self) -> Rc<T> {
         self.state.borrow().selection()
     }
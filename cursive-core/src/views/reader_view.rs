@@ -0,0 +1,137 @@
+use crate::event::{Event, EventResult};
+use crate::rect::Rect;
+use crate::view::{View, ViewWrapper};
+use crate::Printer;
+use crate::Vec2;
+use crate::With;
+use std::cmp::{max, min};
+
+/// Centers its child horizontally, with a maximum width.
+///
+/// This view takes the entire width available. The child is given at most
+/// `max_width` columns, and the remaining space is split between the left
+/// and right margins. When less than `max_width` columns are available, the
+/// child gets the full width.
+///
+/// This makes long text easier to read on wide terminals.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive_core::views::{ReaderView, TextView};
+/// # use cursive_core::view::Scrollable;
+/// let view = ReaderView::new(TextView::new("Some long text...")).scrollable();
+/// ```
+pub struct ReaderView<V> {
+    view: V,
+    max_width: usize,
+
+    // Left margin, computed during layout.
+    offset: usize,
+    child_width: usize,
+}
+
+impl<V> ReaderView<V> {
+    /// Default maximum width for the child.
+    pub const DEFAULT_MAX_WIDTH: usize = 80;
+
+    /// Wraps `view` in a new `ReaderView`.
+    ///
+    /// The maximum width defaults to 80 columns.
+    pub fn new(view: V) -> Self {
+        ReaderView {
+            view,
+            max_width: Self::DEFAULT_MAX_WIDTH,
+            offset: 0,
+            child_width: 0,
+        }
+    }
+
+    /// Sets the maximum width given to the child.
+    pub fn set_max_width(&mut self, max_width: usize) {
+        self.max_width = max_width;
+    }
+
+    /// Sets the maximum width given to the child.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn max_width(self, max_width: usize) -> Self {
+        self.with(|s| s.set_max_width(max_width))
+    }
+
+    /// Returns the maximum width given to the child.
+    pub fn get_max_width(&self) -> usize {
+        self.max_width
+    }
+
+    inner_getters!(self.view: V);
+}
+
+impl<V: View> ViewWrapper for ReaderView<V> {
+    wrap_impl!(self.view: V);
+
+    fn wrap_required_size(&mut self, req: Vec2) -> Vec2 {
+        let width = min(req.x, self.max_width);
+        let child_size = self.view.required_size(Vec2::new(width, req.y));
+
+        // Take all the width we can, the extra space goes to the margins.
+        Vec2::new(max(req.x, child_size.x), child_size.y)
+    }
+
+    fn wrap_layout(&mut self, size: Vec2) {
+        self.child_width = min(size.x, self.max_width);
+        self.offset = (size.x - self.child_width) / 2;
+        self.view.layout(Vec2::new(self.child_width, size.y));
+    }
+
+    fn wrap_draw(&self, printer: &Printer) {
+        let printer = &printer
+            .offset((self.offset, 0))
+            .cropped((self.child_width, printer.size.y));
+        self.view.draw(printer);
+    }
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        self.view.on_event(event.relativized((self.offset, 0)))
+    }
+
+    fn wrap_important_area(&self, size: Vec2) -> Rect {
+        let child_size = Vec2::new(self.child_width, size.y);
+        self.view.important_area(child_size) + (self.offset, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::Resizable;
+    use crate::views::{DummyView, TextView};
+
+    #[test]
+    fn centered_child() {
+        let mut view =
+            ReaderView::new(TextView::new("a b c d e f")).max_width(4);
+
+        // The child wraps at the maximum width, the view takes everything.
+        assert_eq!(view.required_size(Vec2::new(20, 10)), Vec2::new(20, 3));
+        view.layout(Vec2::new(20, 3));
+        assert_eq!((view.offset, view.child_width), (8, 4));
+        assert_eq!(
+            view.important_area(Vec2::new(20, 3)),
+            Rect::from_size((8, 0), (4, 3))
+        );
+
+        // Narrower than the maximum: the child gets the full width.
+        assert_eq!(view.required_size(Vec2::new(2, 10)), Vec2::new(2, 6));
+        view.layout(Vec2::new(2, 6));
+        assert_eq!((view.offset, view.child_width), (0, 2));
+    }
+
+    #[test]
+    fn wide_child() {
+        // A child wider than the maximum still gets its width requested.
+        let mut view = ReaderView::new(DummyView.fixed_width(10)).max_width(4);
+        assert_eq!(view.required_size(Vec2::new(6, 1)).x, 10);
+    }
+}
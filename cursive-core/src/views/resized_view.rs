@@ -11,6 +11,7 @@ use crate::XY;
 /// * Use **all** available size
 /// * Use **at most** a given size
 /// * Use **at least** a given size
+/// * Stay **between** a minimum and a maximum size
 /// * Let the wrapped view decide.
 ///
 /// # Examples
@@ -186,6 +187,102 @@ impl<T> ResizedView<T> {
         )
     }
 
+    /// Wraps `view` in a `ResizedView` which will stay between `min` and `max`.
+    ///
+    /// Each axis is constrained independently. The wrapped view's preferred
+    /// size is used when it falls within the bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::view::SizeConstraint;
+    /// use cursive_core::views::{ResizedView, TextView};
+    ///
+    /// // Between 20 and 60 columns wide, between 1 and 10 rows high.
+    /// let mut view = ResizedView::with_clamped_size(
+    ///     (20, 1),
+    ///     (60, 10),
+    ///     TextView::new("Hello!"),
+    /// );
+    ///
+    /// // Fill the available height instead.
+    /// view.set_height(SizeConstraint::Full);
+    /// ```
+    pub fn with_clamped_size<S, U>(min: S, max: U, view: T) -> Self
+    where
+        S: Into<Vec2>,
+        U: Into<Vec2>,
+    {
+        let min = min.into();
+        let max = max.into();
+
+        ResizedView::new(
+            SizeConstraint::Between(min.x, max.x),
+            SizeConstraint::Between(min.y, max.y),
+            view,
+        )
+    }
+
+    /// Wraps `view` in a `ResizedView` with a width between `min` and `max`.
+    pub fn with_clamped_width(min: usize, max: usize, view: T) -> Self {
+        ResizedView::new(
+            SizeConstraint::Between(min, max),
+            SizeConstraint::Free,
+            view,
+        )
+    }
+
+    /// Wraps `view` in a `ResizedView` with a height between `min` and `max`.
+    pub fn with_clamped_height(min: usize, max: usize, view: T) -> Self {
+        ResizedView::new(
+            SizeConstraint::Free,
+            SizeConstraint::Between(min, max),
+            view,
+        )
+    }
+
+    /// Wraps `view` in a `ResizedView` taking the given fractions of the
+    /// available space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::{ResizedView, TextView};
+    ///
+    /// // Half the width, and a third of the height of the parent.
+    /// let view = ResizedView::with_ratio_size(
+    ///     (0.5, 1.0 / 3.0),
+    ///     TextView::new("Hello!"),
+    /// );
+    /// ```
+    pub fn with_ratio_size((width, height): (f32, f32), view: T) -> Self {
+        ResizedView::new(
+            SizeConstraint::Ratio(width),
+            SizeConstraint::Ratio(height),
+            view,
+        )
+    }
+
+    /// Wraps `view` in a `ResizedView` taking the given fraction of the
+    /// available width.
+    pub fn with_ratio_width(width: f32, view: T) -> Self {
+        ResizedView::new(
+            SizeConstraint::Ratio(width),
+            SizeConstraint::Free,
+            view,
+        )
+    }
+
+    /// Wraps `view` in a `ResizedView` taking the given fraction of the
+    /// available height.
+    pub fn with_ratio_height(height: f32, view: T) -> Self {
+        ResizedView::new(
+            SizeConstraint::Free,
+            SizeConstraint::Ratio(height),
+            view,
+        )
+    }
+
     /// Should be called anytime something changes.
     fn invalidate(&mut self) {
         self.invalidated = true;
@@ -230,7 +327,7 @@ impl<T: View> ViewWrapper for ResizedView<T> {
 #[cfg(test)]
 mod tests {
 
-    use crate::view::{Resizable, View};
+    use crate::view::{Resizable, SizeConstraint, View};
     use crate::views::DummyView;
     use crate::Vec2;
 
@@ -284,6 +381,52 @@ mod tests {
         assert_eq!(Vec2::new(5, 5), max_s.required_size(Vec2::new(10, 10)));
     }
 
+    #[test]
+    fn clamped_size() {
+        // The inner view always wants 10x1.
+        let mut clamped_w = DummyView.fixed_size((10, 1)).clamped_width(5, 20);
+
+        assert_eq!(
+            Vec2::new(10, 1),
+            clamped_w.required_size(Vec2::new(30, 5))
+        );
+
+        let mut clamped_s =
+            DummyView.fixed_size((10, 1)).clamped_size((12, 2), (20, 3));
+
+        assert_eq!(
+            Vec2::new(12, 2),
+            clamped_s.required_size(Vec2::new(30, 5))
+        );
+
+        let mut clamped_s =
+            DummyView.fixed_size((10, 1)).clamped_size((2, 2), (4, 4));
+
+        assert_eq!(Vec2::new(4, 2), clamped_s.required_size(Vec2::new(30, 5)));
+
+        // Combined with a full-screen view, stays within the bounds.
+        let mut full = DummyView.full_screen().clamped_height(2, 5);
+
+        assert_eq!(Vec2::new(10, 5), full.required_size(Vec2::new(10, 10)));
+        assert_eq!(Vec2::new(10, 3), full.required_size(Vec2::new(10, 3)));
+        assert_eq!(Vec2::new(10, 2), full.required_size(Vec2::new(10, 1)));
+    }
+
+    #[test]
+    fn ratio_size() {
+        let mut half = DummyView.fixed_size((10, 1)).ratio_width(0.5);
+
+        assert_eq!(Vec2::new(15, 1), half.required_size(Vec2::new(30, 5)));
+        assert_eq!(Vec2::new(3, 1), half.required_size(Vec2::new(5, 5)));
+
+        // Out-of-range ratios are clamped.
+        let mut over = DummyView.ratio_size((2.0, -1.0));
+        assert_eq!(Vec2::new(30, 0), over.required_size(Vec2::new(30, 5)));
+
+        assert_eq!(SizeConstraint::Ratio(0.5), SizeConstraint::Ratio(0.5));
+        assert_ne!(SizeConstraint::Ratio(0.5), SizeConstraint::Fixed(0));
+    }
+
     #[test]
     fn full_screen() {
         let mut full = DummyView.full_screen();
@@ -0,0 +1,266 @@
+use crate::utils::markup::StyledString;
+use crate::view::View;
+use crate::Printer;
+use crate::Vec2;
+
+use std::cmp::min;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+// Content shared between the view and its sinks.
+struct RingBuffer {
+    lines: VecDeque<StyledString>,
+    capacity: usize,
+    // Incremented every time the content changes.
+    generation: usize,
+}
+
+impl RingBuffer {
+    fn push(&mut self, line: StyledString) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        while self.lines.len() >= self.capacity {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(line);
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        let excess = self.lines.len().saturating_sub(capacity);
+        self.lines.drain(..excess);
+        self.generation = self.generation.wrapping_add(1);
+    }
+}
+
+/// Handle to push lines into a [`RingBufferView`].
+///
+/// This can be cloned and sent to other threads.
+///
+/// Modifying the buffer requests a redraw (see
+/// [`request_redraw`](crate::request_redraw)), so new lines become visible
+/// at the next frame, even when pushed from a background thread.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive_core::views::RingBufferView;
+/// let view = RingBufferView::new(2);
+/// let sink = view.sink();
+///
+/// std::thread::spawn(move || {
+///     for i in 0..10 {
+///         sink.push(format!("Line {}", i));
+///     }
+/// })
+/// .join()
+/// .unwrap();
+///
+/// // Only the last 2 lines are kept.
+/// assert_eq!(view.sink().len(), 2);
+/// ```
+#[derive(Clone)]
+pub struct RingBufferSink {
+    buffer: Arc<Mutex<RingBuffer>>,
+}
+
+impl RingBufferSink {
+    /// Appends a line at the end of the buffer.
+    ///
+    /// If the buffer is full, the oldest line is dropped.
+    ///
+    /// The line is printed on a single row: it should not contain newlines.
+    pub fn push<S: Into<StyledString>>(&self, line: S) {
+        self.buffer.lock().unwrap().push(line.into());
+        crate::request_redraw();
+    }
+
+    /// Removes all lines from the buffer.
+    pub fn clear(&self) {
+        let mut buffer = self.buffer.lock().unwrap();
+        buffer.lines.clear();
+        buffer.generation = buffer.generation.wrapping_add(1);
+        crate::request_redraw();
+    }
+
+    /// Returns the number of lines currently in the buffer.
+    pub fn len(&self) -> usize {
+        self.buffer.lock().unwrap().lines.len()
+    }
+
+    /// Returns `true` if the buffer contains no line.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the maximum number of lines kept in the buffer.
+    pub fn capacity(&self) -> usize {
+        self.buffer.lock().unwrap().capacity
+    }
+
+    /// Changes the maximum number of lines kept in the buffer.
+    ///
+    /// If needed, the oldest lines are dropped immediately.
+    pub fn set_capacity(&self, capacity: usize) {
+        self.buffer.lock().unwrap().set_capacity(capacity);
+        crate::request_redraw();
+    }
+}
+
+/// View showing the last lines pushed into a fixed-size buffer.
+///
+/// Memory usage is bounded: once `capacity` lines are stored, adding a new
+/// line drops the oldest one. This makes it suitable for logs or other
+/// high-frequency output.
+///
+/// Lines are added through a [`RingBufferSink`], which can be used from
+/// other threads.
+///
+/// When the view is too short to show every line, the most recent ones are
+/// shown. Inside a [`ScrollView`], only the visible lines are printed.
+///
+/// [`ScrollView`]: crate::views::ScrollView
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive_core::views::RingBufferView;
+/// let view = RingBufferView::new(1000);
+/// let sink = view.sink();
+///
+/// sink.push("Starting...");
+/// ```
+pub struct RingBufferView {
+    buffer: Arc<Mutex<RingBuffer>>,
+    // Generation of the content during the last layout.
+    last_generation: Option<usize>,
+}
+
+impl RingBufferView {
+    /// Creates a new view keeping at most `capacity` lines.
+    pub fn new(capacity: usize) -> Self {
+        RingBufferView {
+            buffer: Arc::new(Mutex::new(RingBuffer {
+                lines: VecDeque::with_capacity(capacity),
+                capacity,
+                generation: 0,
+            })),
+            last_generation: None,
+        }
+    }
+
+    /// Creates a new view sharing the buffer of the given sink.
+    pub fn with_sink(sink: RingBufferSink) -> Self {
+        RingBufferView {
+            buffer: sink.buffer,
+            last_generation: None,
+        }
+    }
+
+    /// Returns a new handle to push lines into this view.
+    pub fn sink(&self) -> RingBufferSink {
+        RingBufferSink {
+            buffer: Arc::clone(&self.buffer),
+        }
+    }
+
+    /// Appends a line at the end of the buffer.
+    ///
+    /// If the buffer is full, the oldest line is dropped.
+    pub fn push<S: Into<StyledString>>(&mut self, line: S) {
+        self.buffer.lock().unwrap().push(line.into());
+        crate::request_redraw();
+    }
+
+    /// Removes all lines from the buffer.
+    pub fn clear(&mut self) {
+        self.sink().clear();
+    }
+}
+
+impl View for RingBufferView {
+    fn draw(&self, printer: &Printer) {
+        let buffer = self.buffer.lock().unwrap();
+        let lines = &buffer.lines;
+
+        // Only print the last lines, so skip what doesn't fit.
+        let skipped = lines.len().saturating_sub(printer.size.y);
+
+        // And only print what's actually visible.
+        let start = skipped + printer.content_offset.y;
+        let end = min(lines.len(), start + printer.output_size.y);
+
+        for (i, line) in lines.iter().enumerate().take(end).skip(start) {
+            printer.print_styled((0, i - skipped), line.into());
+        }
+    }
+
+    fn required_size(&mut self, _constraint: Vec2) -> Vec2 {
+        let buffer = self.buffer.lock().unwrap();
+
+        // The longest line sets the width
+        let w = buffer
+            .lines
+            .iter()
+            .map(StyledString::width)
+            .max()
+            .unwrap_or(1);
+        let h = buffer.lines.len();
+
+        Vec2::new(w, h)
+    }
+
+    fn layout(&mut self, _size: Vec2) {
+        self.last_generation = Some(self.buffer.lock().unwrap().generation);
+    }
+
+    fn needs_relayout(&self) -> bool {
+        // Only re-compute the size if new lines were pushed.
+        self.last_generation != Some(self.buffer.lock().unwrap().generation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contents(view: &RingBufferView) -> Vec<String> {
+        let buffer = view.buffer.lock().unwrap();
+        buffer
+            .lines
+            .iter()
+            .map(|line| line.source().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn drops_oldest_lines() {
+        let mut view = RingBufferView::new(3);
+        let sink = view.sink();
+
+        for i in 0..5 {
+            sink.push(format!("{}", i));
+        }
+        assert_eq!(contents(&view), vec!["2", "3", "4"]);
+
+        view.push("5");
+        assert_eq!(contents(&view), vec!["3", "4", "5"]);
+
+        sink.set_capacity(1);
+        assert_eq!(contents(&view), vec!["5"]);
+    }
+
+    #[test]
+    fn relayout_on_push() {
+        let mut view = RingBufferView::new(3);
+        view.layout(Vec2::new(10, 10));
+        assert!(!view.needs_relayout());
+
+        view.sink().push("line");
+        assert!(view.needs_relayout());
+        assert_eq!(view.required_size(Vec2::new(10, 10)), Vec2::new(4, 1));
+    }
+}
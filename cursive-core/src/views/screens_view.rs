@@ -3,14 +3,17 @@ use crate::{
     view::{Selector, View, ViewNotFound},
     views::BoxedView,
 };
+use std::collections::HashMap;
 
 /// Identifies a screen in the cursive root.
 pub type ScreenId = usize;
 
 /// A view that can switch between different screens.
 pub struct ScreensView<V = BoxedView> {
-    screens: Vec<V>,
+    // Removed screens leave a hole, so IDs stay valid.
+    screens: Vec<Option<V>>,
     active_screen: ScreenId,
+    names: HashMap<String, ScreenId>,
 }
 
 new_default!(ScreensView<V>);
@@ -21,14 +24,16 @@ impl<V> ScreensView<V> {
         ScreensView {
             screens: Vec::new(),
             active_screen: 0,
+            names: HashMap::new(),
         }
     }
 
     /// Creates a new `ScreensView` with a single screen.
     pub fn single_screen(v: V) -> Self {
         ScreensView {
-            screens: vec![v],
+            screens: vec![Some(v)],
             active_screen: 0,
+            names: HashMap::new(),
         }
     }
 
@@ -36,13 +41,13 @@ impl<V> ScreensView<V> {
     ///
     /// Returns `None` if there is no active screen.
     pub fn screen(&self) -> Option<&V> {
-        self.screens.get(self.active_screen)
+        self.screens.get(self.active_screen)?.as_ref()
     }
 
     /// Returns a mutable reference to the currently active screen.
     pub fn screen_mut(&mut self) -> Option<&mut V> {
         let id = self.active_screen;
-        self.screens.get_mut(id)
+        self.screens.get_mut(id)?.as_mut()
     }
 
     /// Returns the id of the currently active screen.
@@ -51,12 +56,34 @@ impl<V> ScreensView<V> {
     }
 
     /// Adds a new screen, and returns its ID.
+    ///
+    /// IDs are never reused, even after a screen is removed.
     pub fn add_screen(&mut self, v: V) -> ScreenId {
         let res = self.screens.len();
-        self.screens.push(v);
+        self.screens.push(Some(v));
         res
     }
 
+    /// Removes a screen, and returns it.
+    ///
+    /// The IDs and names of other screens are unchanged.
+    ///
+    /// Returns `None` if no such screen exist, or if it is the active
+    /// screen. Switch to another screen first to remove it.
+    pub fn remove_screen(&mut self, screen_id: ScreenId) -> Option<V> {
+        if screen_id == self.active_screen {
+            return None;
+        }
+        let screen = self.screens.get_mut(screen_id)?.take()?;
+        self.names.retain(|_, &mut id| id != screen_id);
+        Some(screen)
+    }
+
+    /// Returns `true` if a screen with the given ID exists.
+    pub fn has_screen(&self, screen_id: ScreenId) -> bool {
+        matches!(self.screens.get(screen_id), Some(Some(_)))
+    }
+
     /// Convenient method to create a new screen, and set it as active.
     pub fn add_active_screen(&mut self, v: V) -> ScreenId {
         let res = self.add_screen(v);
@@ -64,15 +91,62 @@ impl<V> ScreensView<V> {
         res
     }
 
+    /// Adds a new screen with the given name, and returns its ID.
+    ///
+    /// If another screen already had this name, it loses it.
+    pub fn add_named_screen<S: Into<String>>(
+        &mut self,
+        name: S,
+        v: V,
+    ) -> ScreenId {
+        let res = self.add_screen(v);
+        self.set_screen_name(res, name);
+        res
+    }
+
+    /// Gives a name to an existing screen.
+    ///
+    /// If another screen already had this name, it loses it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no such screen exist.
+    pub fn set_screen_name<S: Into<String>>(
+        &mut self,
+        screen_id: ScreenId,
+        name: S,
+    ) {
+        assert!(
+            self.has_screen(screen_id),
+            "Tried to name an invalid screen ID: {}",
+            screen_id
+        );
+        self.names.retain(|_, &mut id| id != screen_id);
+        self.names.insert(name.into(), screen_id);
+    }
+
+    /// Returns the ID of the screen with the given name, if any.
+    pub fn find_screen(&self, name: &str) -> Option<ScreenId> {
+        self.names.get(name).copied()
+    }
+
+    /// Returns the name of the given screen, if it has one.
+    pub fn screen_name(&self, screen_id: ScreenId) -> Option<&str> {
+        self.names
+            .iter()
+            .find(|&(_, &id)| id == screen_id)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Returns the name of the active screen, if it has one.
+    pub fn active_screen_name(&self) -> Option<&str> {
+        self.screen_name(self.active_screen)
+    }
+
     /// Sets the active screen. Panics if no such screen exist.
     pub fn set_active_screen(&mut self, screen_id: ScreenId) {
-        if screen_id >= self.screens.len() {
-            panic!(
-                "Tried to set an invalid screen ID: {}, but only {} \
-                 screens present.",
-                screen_id,
-                self.screens.len()
-            );
+        if !self.has_screen(screen_id) {
+            panic!("Tried to set an invalid screen ID: {}", screen_id);
         }
         self.active_screen = screen_id;
     }
@@ -125,7 +199,7 @@ where
         selector: &Selector<'_>,
         callback: AnyCb<'a>,
     ) {
-        for screen in &mut self.screens {
+        for screen in self.screens.iter_mut().flatten() {
             screen.call_on_any(selector, callback);
         }
     }
@@ -135,6 +209,10 @@ where
         selector: &Selector<'_>,
     ) -> Result<EventResult, ViewNotFound> {
         for (i, child) in self.screens.iter_mut().enumerate() {
+            let child = match child {
+                Some(child) => child,
+                None => continue,
+            };
             if let Ok(res) = child.focus_view(selector) {
                 self.active_screen = i;
                 return Ok(res);
@@ -144,3 +222,47 @@ where
         Err(ViewNotFound)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn named_screens() {
+        let mut screens = ScreensView::single_screen("main");
+        assert_eq!(screens.active_screen_name(), None);
+
+        let editor = screens.add_named_screen("editor", "editor");
+        assert_eq!(screens.find_screen("editor"), Some(editor));
+        assert_eq!(screens.screen_name(editor), Some("editor"));
+
+        // A name belongs to a single screen, and a screen has a single name.
+        screens.set_screen_name(0, "editor");
+        assert_eq!(screens.find_screen("editor"), Some(0));
+        assert_eq!(screens.screen_name(editor), None);
+        screens.set_screen_name(0, "home");
+        assert_eq!(screens.find_screen("editor"), None);
+        assert_eq!(screens.active_screen_name(), Some("home"));
+    }
+
+    #[test]
+    fn remove_keeps_ids() {
+        let mut screens = ScreensView::single_screen("main");
+        let settings = screens.add_named_screen("settings", "settings");
+        let help = screens.add_named_screen("help", "help");
+
+        // The active screen cannot be removed.
+        assert_eq!(screens.remove_screen(0), None);
+
+        assert_eq!(screens.remove_screen(settings), Some("settings"));
+        assert_eq!(screens.remove_screen(settings), None);
+        assert_eq!(screens.find_screen("settings"), None);
+        assert!(!screens.has_screen(settings));
+
+        assert_eq!(screens.find_screen("help"), Some(help));
+        assert_eq!(screens.add_screen("new"), help + 1);
+
+        screens.set_active_screen(help);
+        assert_eq!(screens.screen(), Some(&"help"));
+    }
+}
@@ -1,12 +1,14 @@
 use crate::{
-    direction::Direction,
-    event::{AnyCb, Event, EventResult},
+    direction::{Absolute, Direction},
+    event::{AnyCb, Callback, Event, EventResult, Key},
     view::{
-        scroll, CannotFocus, ScrollStrategy, Selector, View, ViewNotFound,
+        scroll, CannotFocus, ScrollStrategy, SearchMatch, Searchable,
+        Selector, View, ViewNotFound,
     },
     Cursive, Printer, Rect, Vec2, With,
 };
 
+use std::cell::Cell;
 use std::rc::Rc;
 
 /// Wraps a view in a scrollable area.
@@ -17,6 +19,85 @@ pub struct ScrollView<V> {
     core: scroll::Core,
 
     on_scroll: Rc<dyn Fn(&mut Self, Rect) -> EventResult>,
+
+    on_near_edge: Vec<EdgeCallback>,
+
+    linked_y: Option<LinkedPosition>,
+}
+
+/// Vertical scroll position shared between several `ScrollView`s.
+///
+/// Scrolling one of the linked views scrolls the others to the same row.
+/// Views with shorter content stop at their own bottom, and start following
+/// again when the position goes back in their range.
+///
+/// Cloning this gives another handle to the same position.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{ScrollPosition, ScrollView, TextView};
+///
+/// let position = ScrollPosition::new();
+/// let left = ScrollView::new(TextView::new("old"))
+///     .scroll_position(position.clone());
+/// let right =
+///     ScrollView::new(TextView::new("new")).scroll_position(position);
+/// ```
+#[derive(Clone, Default)]
+pub struct ScrollPosition {
+    // Row and number of changes, so views know when to follow.
+    state: Rc<Cell<(usize, usize)>>,
+}
+
+impl ScrollPosition {
+    /// Creates a new position, at the top.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the current row.
+    pub fn row(&self) -> usize {
+        self.state.get().0
+    }
+
+    /// Scrolls every linked view to the given row.
+    ///
+    /// Views are updated on their next layout phase.
+    pub fn set_row(&self, row: usize) {
+        let (_, version) = self.state.get();
+        self.state.set((row, version.wrapping_add(1)));
+    }
+
+    fn version(&self) -> usize {
+        self.state.get().1
+    }
+}
+
+/// Link between a `ScrollView` and a `ScrollPosition`.
+struct LinkedPosition {
+    position: ScrollPosition,
+
+    // Version of the position last applied to the view.
+    version: Option<usize>,
+
+    // Row of the view when it last synced with the position.
+    row: usize,
+}
+
+/// Callback fired when the viewport comes close to an edge of the content.
+struct EdgeCallback {
+    edge: Absolute,
+    threshold: usize,
+
+    // `false` while the viewport is still near the edge after firing.
+    armed: bool,
+
+    // `true` when the edge was reached during layout, and the callback
+    // still needs to run.
+    due: bool,
+
+    callback: Callback,
 }
 
 new_default!(ScrollView<V: Default>);
@@ -30,6 +111,8 @@ impl<V> ScrollView<V> {
             inner,
             core: scroll::Core::new(),
             on_scroll: Rc::new(|_, _| EventResult::Ignored),
+            on_near_edge: Vec::new(),
+            linked_y: None,
         }
     }
 
@@ -165,6 +248,36 @@ impl<V> ScrollView<V> {
         })
     }
 
+    /// Sets the modifier for arrow keys that scroll horizontally.
+    ///
+    /// `<Left>` and `<Right>` scroll horizontally when the content ignores
+    /// them. With this modifier, they scroll even if the content would use
+    /// them. Only applies when horizontal scrolling is enabled.
+    ///
+    /// Defaults to `Event::Shift`.
+    pub fn set_horizontal_scroll_key(&mut self, modifier: fn(Key) -> Event) {
+        self.core.set_horizontal_scroll_key(modifier);
+    }
+
+    /// Sets the modifier for arrow keys that scroll horizontally.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::event::Event;
+    /// use cursive_core::views::{ScrollView, TextView};
+    ///
+    /// let view = ScrollView::new(TextView::new("A very long line"))
+    ///     .scroll_x(true)
+    ///     .horizontal_scroll_key(Event::Ctrl);
+    /// ```
+    #[must_use]
+    pub fn horizontal_scroll_key(self, modifier: fn(Key) -> Event) -> Self {
+        self.with(|s| s.set_horizontal_scroll_key(modifier))
+    }
+
     /// Programmatically scroll to the top of the view.
     pub fn scroll_to_top(&mut self) -> EventResult {
         self.core.scroll_to_top();
@@ -309,16 +422,226 @@ impl<V> ScrollView<V> {
         self.with(|s| s.set_on_scroll(on_scroll))
     }
 
+    /// Sets a callback to be run when the viewport comes near an edge.
+    ///
+    /// The callback runs when the viewport gets within `threshold` cells of
+    /// the given `edge`. It only fires once per approach: the viewport needs
+    /// to move away from the edge before the callback can fire again.
+    ///
+    /// This replaces any callback previously set for the same edge.
+    ///
+    /// The viewport is checked after scrolling, and after each layout: this
+    /// catches content growing or shrinking, and prepend compensation. In
+    /// the latter case, the callback runs with the next event reaching this
+    /// view, including `Event::Refresh`.
+    ///
+    /// This can be used to load content on demand, for example loading older
+    /// messages when scrolling near the top of a chat history. See also
+    /// [`ScrollView::compensate_prepend`] to keep the viewport stable after
+    /// inserting content at the top.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::direction::Absolute;
+    /// use cursive_core::views::{ScrollView, TextView};
+    ///
+    /// let mut scroll_view = ScrollView::new(TextView::new("..."));
+    /// scroll_view.set_on_scroll_near_edge(Absolute::Up, 3, |s| {
+    ///     // Load more content here.
+    /// });
+    /// ```
+    pub fn set_on_scroll_near_edge<F>(
+        &mut self,
+        edge: Absolute,
+        threshold: usize,
+        callback: F,
+    ) where
+        F: FnMut(&mut Cursive) + 'static,
+    {
+        self.on_near_edge.retain(|e| e.edge != edge);
+        self.on_near_edge.push(EdgeCallback {
+            edge,
+            threshold,
+            armed: self.core.distance_to_edge(edge) > threshold,
+            due: false,
+            callback: Callback::from_fn_mut(callback),
+        });
+    }
+
+    /// Sets a callback to be run when the viewport comes near an edge.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn on_scroll_near_edge<F>(
+        self,
+        edge: Absolute,
+        threshold: usize,
+        callback: F,
+    ) -> Self
+    where
+        F: FnMut(&mut Cursive) + 'static,
+    {
+        self.with(|s| s.set_on_scroll_near_edge(edge, threshold, callback))
+    }
+
+    /// Removes any callback set for the given edge.
+    pub fn clear_on_scroll_near_edge(&mut self, edge: Absolute) {
+        self.on_near_edge.retain(|e| e.edge != edge);
+    }
+
+    /// Keeps the viewport in place after `rows` were inserted at the top.
+    ///
+    /// The scroll offset will be shifted down by `rows` on the next layout
+    /// phase, once the new content size is known. Without this, prepending
+    /// content would make the visible content jump.
+    pub fn compensate_prepend(&mut self, rows: usize) {
+        self.core.shift_offset_on_layout((0, rows));
+    }
+
+    /// Links the vertical scrolling of this view to the given position.
+    ///
+    /// Every view linked to the same position scrolls together. This view
+    /// follows the position on its next layout phase.
+    pub fn set_scroll_position(&mut self, position: ScrollPosition) {
+        self.linked_y = Some(LinkedPosition {
+            position,
+            version: None,
+            row: self.content_viewport().top(),
+        });
+    }
+
+    /// Links the vertical scrolling of this view to the given position.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn scroll_position(self, position: ScrollPosition) -> Self {
+        self.with(|s| s.set_scroll_position(position))
+    }
+
+    /// Returns the position this view is linked to, if any.
+    pub fn get_scroll_position(&self) -> Option<ScrollPosition> {
+        self.linked_y.as_ref().map(|link| link.position.clone())
+    }
+
+    /// Links the vertical scrolling of this view and `other`.
+    ///
+    /// If `other` is already linked, this view joins its position.
+    /// Otherwise, both are linked to a new position, starting at the current
+    /// row of this view.
+    pub fn link_vertical<W>(&mut self, other: &mut ScrollView<W>) {
+        let position = match other.get_scroll_position() {
+            Some(position) => position,
+            None => {
+                let position = ScrollPosition::new();
+                position.set_row(self.content_viewport().top());
+                other.set_scroll_position(position.clone());
+                position
+            }
+        };
+        self.set_scroll_position(position);
+    }
+
+    /// Stops following the linked position, if any.
+    pub fn unlink_vertical(&mut self) {
+        self.linked_y = None;
+    }
+
+    /// Shares our row with the linked views, if we scrolled.
+    fn push_linked_offset(&mut self) {
+        let row = self.content_viewport().top();
+        if let Some(link) = self.linked_y.as_mut() {
+            if link.row != row {
+                link.position.set_row(row);
+                link.row = row;
+                link.version = Some(link.position.version());
+            }
+        }
+    }
+
+    /// Follows the linked position, if it changed.
+    ///
+    /// The offset is clamped to our own content: we do not push it back, so
+    /// views of different heights don't fight over the position.
+    fn pull_linked_offset(&mut self) {
+        let link = match self.linked_y.as_mut() {
+            Some(link) => link,
+            None => return,
+        };
+        let version = link.position.version();
+        if link.version == Some(version) {
+            return;
+        }
+
+        let x = self.core.content_viewport().left();
+        self.core.set_offset((x, link.position.row()));
+        link.version = Some(version);
+        link.row = self.core.content_viewport().top();
+    }
+
     /// Run any callback after scrolling.
     fn on_scroll_callback(&mut self) -> EventResult {
+        self.push_linked_offset();
         let viewport = self.content_viewport();
         let on_scroll = Rc::clone(&self.on_scroll);
-        (on_scroll)(self, viewport)
+        (on_scroll)(self, viewport).and(self.near_edge_callbacks())
+    }
+
+    /// Checks whether we just came close to an edge.
+    fn check_near_edges(&mut self) {
+        for edge_callback in &mut self.on_near_edge {
+            let distance = self.core.distance_to_edge(edge_callback.edge);
+            if distance > edge_callback.threshold {
+                edge_callback.armed = true;
+            } else if edge_callback.armed {
+                edge_callback.armed = false;
+                edge_callback.due = true;
+            }
+        }
+    }
+
+    /// Returns `true` if some near-edge callbacks are waiting to run.
+    fn has_due_callbacks(&self) -> bool {
+        self.on_near_edge.iter().any(|e| e.due)
+    }
+
+    /// Takes the near-edge callbacks to run now.
+    fn near_edge_callbacks(&mut self) -> EventResult {
+        self.check_near_edges();
+
+        let mut result = EventResult::Ignored;
+        for edge_callback in &mut self.on_near_edge {
+            if edge_callback.due {
+                edge_callback.due = false;
+                result = result.and(EventResult::Consumed(Some(
+                    edge_callback.callback.clone(),
+                )));
+            }
+        }
+
+        result
     }
 
     inner_getters!(self.inner: V);
 }
 
+impl<V> Searchable for ScrollView<V>
+where
+    V: View + Searchable,
+{
+    fn search(&self, query: &str) -> Vec<SearchMatch> {
+        self.inner.search(query)
+    }
+
+    /// Reveals the match in the inner view, and scrolls to its important
+    /// area.
+    fn reveal(&mut self, found: &SearchMatch) -> EventResult {
+        self.inner
+            .reveal(found)
+            .and(self.scroll_to_important_area())
+    }
+}
+
 impl<V> View for ScrollView<V>
 where
     V: View,
@@ -328,12 +651,17 @@ where
     }
 
     fn on_event(&mut self, event: Event) -> EventResult {
+        let refresh = event == Event::Refresh;
         match scroll::on_event(
             self,
             event,
             |s, e| s.inner.on_event(e),
             |s, si| s.inner.important_area(si),
         ) {
+            // Edges reached during layout are reported at the next refresh.
+            EventResult::Ignored if refresh && self.has_due_callbacks() => {
+                self.near_edge_callbacks()
+            }
             EventResult::Ignored => EventResult::Ignored,
             // If the event was consumed, then we may have scrolled.
             other => other.and(self.on_scroll_callback()),
@@ -345,9 +673,16 @@ where
             self,
             size,
             self.inner.needs_relayout(),
-            |s, si| s.inner.layout(si),
+            |s, si| {
+                // Let the content know what will actually be visible.
+                s.core.update_offset();
+                s.inner.set_viewport(s.core.content_viewport());
+                s.inner.layout(si)
+            },
             |s, c| s.inner.required_size(c),
         );
+        self.pull_linked_offset();
+        self.check_near_edges();
     }
 
     fn needs_relayout(&self) -> bool {
@@ -407,4 +742,135 @@ where
     fn important_area(&self, size: Vec2) -> Rect {
         scroll::important_area(self, size, |s, si| s.inner.important_area(si))
     }
+
+    fn debug_children(&self, f: &mut dyn FnMut(&dyn View, Option<Rect>)) {
+        // The area is the entire scrolled content.
+        let area = Rect::from_size(Vec2::zero(), self.core.inner_size());
+        f(&self.inner, Some(area));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::TextView;
+
+    fn lines(n: usize) -> ScrollView<TextView> {
+        let content: Vec<String> = (0..n).map(|i| i.to_string()).collect();
+        ScrollView::new(TextView::new(content.join("\n")))
+    }
+
+    #[test]
+    fn linked_views_clamp_independently() {
+        let mut long = lines(10);
+        let mut short = lines(5);
+        long.link_vertical(&mut short);
+
+        let size = Vec2::new(5, 3);
+        long.layout(size);
+        short.layout(size);
+
+        long.set_offset((0, 6));
+        long.layout(size);
+        short.layout(size);
+        assert_eq!(long.content_viewport().top(), 6);
+        // The short view stops at its own bottom.
+        assert_eq!(short.content_viewport().top(), 2);
+
+        // Without moving the long view.
+        short.set_offset((0, 2));
+        long.layout(size);
+        assert_eq!(long.content_viewport().top(), 6);
+
+        short.set_offset((0, 1));
+        long.layout(size);
+        short.layout(size);
+        assert_eq!(long.content_viewport().top(), 1);
+        assert_eq!(short.content_viewport().top(), 1);
+    }
+
+    #[test]
+    fn horizontal_scroll_key() {
+        let mut view = ScrollView::new(TextView::new("0123456789").no_wrap())
+            .scroll_x(true)
+            .scroll_y(false);
+        view.layout(Vec2::new(4, 2));
+
+        assert!(view.on_event(Event::Shift(Key::Right)).is_consumed());
+        assert_eq!(view.content_viewport().left(), 1);
+
+        view.set_horizontal_scroll_key(Event::Ctrl);
+        assert!(!view.on_event(Event::Shift(Key::Right)).is_consumed());
+        assert!(view.on_event(Event::Ctrl(Key::Right)).is_consumed());
+        assert_eq!(view.content_viewport().left(), 2);
+        assert!(view.on_event(Event::Ctrl(Key::Left)).is_consumed());
+        assert_eq!(view.content_viewport().left(), 1);
+    }
+
+    fn counter(siv: &mut Cursive) {
+        *siv.user_data::<usize>().unwrap() += 1;
+    }
+
+    #[test]
+    fn near_edge_fires_once() {
+        let mut siv = Cursive::new();
+        siv.set_user_data(0usize);
+
+        let mut view =
+            lines(20).on_scroll_near_edge(Absolute::Down, 2, counter);
+        let size = Vec2::new(5, 3);
+        view.layout(size);
+
+        for _ in 0..20 {
+            view.on_event(Event::Key(Key::Down)).process(&mut siv);
+            view.layout(size);
+        }
+        assert_eq!(view.content_viewport().bottom(), 19);
+        assert_eq!(siv.user_data::<usize>(), Some(&mut 1));
+
+        // Going away from the edge and back fires it again.
+        view.on_event(Event::Key(Key::PageUp)).process(&mut siv);
+        view.layout(size);
+        view.on_event(Event::Key(Key::PageDown)).process(&mut siv);
+        assert_eq!(siv.user_data::<usize>(), Some(&mut 2));
+    }
+
+    #[test]
+    fn near_edge_after_layout() {
+        let mut siv = Cursive::new();
+        siv.set_user_data(0usize);
+
+        let mut view = lines(20).on_scroll_near_edge(Absolute::Up, 1, counter);
+        let size = Vec2::new(5, 3);
+        view.layout(size);
+        view.set_offset((0, 10));
+        view.layout(size);
+        assert!(!view.on_event(Event::Refresh).is_consumed());
+
+        // The content shrinks: the viewport is now at the top.
+        view.get_inner_mut().set_content("0\n1\n2\n3");
+        view.layout(size);
+        assert_eq!(view.content_viewport().top(), 1);
+        view.on_event(Event::Refresh).process(&mut siv);
+        assert_eq!(siv.user_data::<usize>(), Some(&mut 1));
+
+        // Only once.
+        view.layout(size);
+        assert!(!view.on_event(Event::Refresh).is_consumed());
+    }
+
+    #[test]
+    fn compensate_prepend_keeps_offset() {
+        let mut view = lines(10);
+        let size = Vec2::new(5, 3);
+        view.layout(size);
+        view.set_offset((0, 4));
+        view.layout(size);
+
+        let content: Vec<String> = (0..13).map(|i| i.to_string()).collect();
+        view.get_inner_mut().set_content(content.join("\n"));
+        view.compensate_prepend(3);
+        view.layout(size);
+        assert_eq!(view.content_viewport().top(), 7);
+    }
 }
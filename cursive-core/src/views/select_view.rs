@@ -1,20 +1,25 @@
 use crate::{
-    align::{Align, HAlign, VAlign},
+    align::{Align, HAlign, TextDirection, VAlign},
     direction,
     event::{Callback, Event, EventResult, Key, MouseButton, MouseEvent},
     menu,
     rect::Rect,
     theme::ColorStyle,
-    utils::markup::StyledString,
-    view::{CannotFocus, Position, View},
+    utils::{bidi, markup::StyledString},
+    view::{CannotFocus, Position, SearchMatch, Searchable, View},
     views::{LayerPosition, MenuPopup},
     Cursive, Printer, Vec2, With,
 };
 use std::borrow::Borrow;
 use std::cell::Cell;
-use std::cmp::{min, Ordering};
+use std::cmp::{max, min, Ordering};
 use std::rc::Rc;
 
+type ItemDraw<T> = dyn Fn(&Printer, &T, bool);
+
+// Callback called with an item, on submit or selection.
+type ItemCallback<T> = dyn Fn(&mut Cursive, &T);
+
 /// View to select an item among a list.
 ///
 /// It contains a list of values of type T, with associated labels.
@@ -54,18 +59,34 @@ pub struct SelectView<T = String> {
 
     // This is a custom callback to include a &T.
     // It will be called whenever "Enter" is pressed or when an item is clicked.
-    on_submit: Option<Rc<dyn Fn(&mut Cursive, &T)>>,
+    on_submit: Option<Rc<ItemCallback<T>>>,
+
+    // Called on the secondary action: a right-click on an item, or
+    // `secondary_key`.
+    on_secondary_submit: Option<Rc<ItemCallback<T>>>,
+
+    // Event triggering the secondary action.
+    secondary_key: Event,
 
     // This callback is called when the selection is changed.
     // TODO: add the previous selection? Indices?
-    on_select: Option<Rc<dyn Fn(&mut Cursive, &T)>>,
+    on_select: Option<Rc<ItemCallback<T>>>,
 
     // If `true`, when a character is pressed, jump to the next item starting
     // with this character.
     autojump: bool,
 
+    // If `true`, typed characters build a search query.
+    searchable: bool,
+
+    // Current search query, when searchable.
+    query: String,
+
     align: Align,
 
+    // Direction of the labels.
+    direction: TextDirection,
+
     // `true` if we show a one-line view, with popup on selection.
     popup: bool,
 
@@ -73,6 +94,20 @@ pub struct SelectView<T = String> {
     // We "cache" it during the draw, so we need interior mutability.
     last_offset: Cell<Vec2>,
     last_size: Vec2,
+
+    // If `true`, only visible items are drawn.
+    virtualized: bool,
+
+    // Widths of the widest icon, label and suffix.
+    // Only cached in virtualized mode, `None` when it needs to be computed
+    // again.
+    columns: Option<Columns>,
+
+    // If set, replaces the default rendering of the items.
+    item_draw: Option<Rc<ItemDraw<T>>>,
+
+    // Number of rows taken by each item.
+    item_height: usize,
 }
 
 impl<T: 'static> Default for SelectView<T> {
@@ -92,14 +127,133 @@ impl<T: 'static> SelectView<T> {
             focus: Rc::new(Cell::new(0)),
             on_select: None,
             on_submit: None,
+            on_secondary_submit: None,
+            secondary_key: Event::Ctrl(Key::Enter),
             align: Align::top_left(),
+            direction: TextDirection::Ltr,
             popup: false,
             autojump: false,
+            searchable: false,
+            query: String::new(),
             last_offset: Cell::new(Vec2::zero()),
             last_size: Vec2::zero(),
+            virtualized: false,
+            columns: None,
+            item_draw: None,
+            item_height: 1,
         }
     }
 
+    /// Enables or disables virtualized rendering.
+    ///
+    /// In virtualized mode, only the items currently visible are drawn, and
+    /// the width of the widest item is cached instead of being computed
+    /// again on every layout. This keeps lists with a very large number of
+    /// items responsive, especially when wrapped in a `ScrollView`.
+    ///
+    /// Every item takes the same number of rows (see
+    /// [`set_item_height`](#method.set_item_height)), so no height
+    /// estimation is required.
+    ///
+    /// Defaults to `false`.
+    pub fn set_virtualized(&mut self, virtualized: bool) {
+        self.virtualized = virtualized;
+        self.columns = None;
+    }
+
+    /// Enables or disables virtualized rendering.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::traits::Scrollable;
+    /// use cursive_core::views::SelectView;
+    ///
+    /// let select_view = SelectView::new()
+    ///     .with_all((0..100_000).map(|i| (format!("Item {}", i), i)))
+    ///     .virtualized(true)
+    ///     .scrollable();
+    /// ```
+    #[must_use]
+    pub fn virtualized(self, virtualized: bool) -> Self {
+        self.with(|s| s.set_virtualized(virtualized))
+    }
+
+    /// Returns `true` if virtualized rendering is enabled.
+    pub fn is_virtualized(&self) -> bool {
+        self.virtualized
+    }
+
+    /// Sets a function to draw each item, replacing the default rendering.
+    ///
+    /// The function is given a printer for the item area (the full width of
+    /// the view, and `item_height` rows), the item value, and whether the
+    /// item is currently selected. The selection highlight is already
+    /// applied to the printer.
+    ///
+    /// Labels are still used to compute the width of the view, for
+    /// auto-jump and in popup mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::views::SelectView;
+    /// let select = SelectView::new()
+    ///     .item("Alice", 3)
+    ///     .item("Bob", 12)
+    ///     .item_height(2)
+    ///     .item_draw(|printer, unread, _selected| {
+    ///         printer.print((0, 0), "Inbox");
+    ///         printer.print((2, 1), &format!("{} unread", unread));
+    ///     });
+    /// ```
+    pub fn set_item_draw<F>(&mut self, draw: F)
+    where
+        F: Fn(&Printer, &T, bool) + 'static,
+    {
+        self.item_draw = Some(Rc::new(draw));
+    }
+
+    /// Sets a function to draw each item, replacing the default rendering.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn item_draw<F>(self, draw: F) -> Self
+    where
+        F: Fn(&Printer, &T, bool) + 'static,
+    {
+        self.with(|s| s.set_item_draw(draw))
+    }
+
+    /// Restores the default rendering of the items.
+    pub fn clear_item_draw(&mut self) {
+        self.item_draw = None;
+    }
+
+    /// Sets the number of rows taken by each item.
+    ///
+    /// With the default rendering, the label is printed on the first row.
+    ///
+    /// Defaults to 1. A height of 0 is treated as 1.
+    pub fn set_item_height(&mut self, height: usize) {
+        self.item_height = max(height, 1);
+    }
+
+    /// Sets the number of rows taken by each item.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn item_height(self, height: usize) -> Self {
+        self.with(|s| s.set_item_height(height))
+    }
+
+    /// Returns the number of rows taken by each item.
+    pub fn get_item_height(&self) -> usize {
+        self.item_height
+    }
+
     /// Sets the "auto-jump" property for this view.
     ///
     /// If enabled, when a key is pressed, the selection will jump to the next
@@ -119,6 +273,34 @@ impl<T: 'static> SelectView<T> {
         self.with(|s| s.set_autojump(true))
     }
 
+    /// Enables or disables type-to-search.
+    ///
+    /// If enabled, typed characters build a search query, and the selection
+    /// jumps to the first item whose label contains it, ignoring case.
+    /// Backspace removes the last character of the query, and any other key
+    /// starts a new search.
+    ///
+    /// This takes precedence over auto-jump.
+    pub fn set_searchable(&mut self, searchable: bool) {
+        self.searchable = searchable;
+        self.query.clear();
+    }
+
+    /// Enables type-to-search.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn searchable(self) -> Self {
+        self.with(|s| s.set_searchable(true))
+    }
+
+    /// Returns the current search query.
+    ///
+    /// It is always empty unless the view is searchable.
+    pub fn get_search_query(&self) -> &str {
+        &self.query
+    }
+
     /// Turns `self` into a popup select view.
     ///
     /// Chainable variant.
@@ -230,6 +412,69 @@ impl<T: 'static> SelectView<T> {
         self.with(|s| s.set_on_submit(cb))
     }
 
+    /// Sets a callback for the secondary action on an item.
+    ///
+    /// This happens when the user presses the secondary key (`Ctrl+Enter` by
+    /// default, see [`set_secondary_key`](Self::set_secondary_key)), or
+    /// right-clicks an item. It can be used to open or preview an item,
+    /// while `<Enter>` selects it.
+    ///
+    /// The item currently selected will be given to the callback.
+    ///
+    /// Here, `V` can be `T` itself, or a type that can be borrowed from `T`.
+    pub fn set_on_secondary_submit<F, R, V: ?Sized>(&mut self, cb: F)
+    where
+        F: 'static + Fn(&mut Cursive, &V) -> R,
+        T: Borrow<V>,
+    {
+        self.on_secondary_submit = Some(Rc::new(move |s, t| {
+            cb(s, t.borrow());
+        }));
+    }
+
+    /// Sets a callback for the secondary action on an item.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::{Dialog, SelectView};
+    ///
+    /// let select_view = SelectView::new()
+    ///     .item("cursive", "https://github.com/gyscos/cursive")
+    ///     .on_submit(|s, url: &str| s.add_layer(Dialog::info(url)))
+    ///     .on_secondary_submit(|s, url: &str| {
+    ///         s.add_layer(Dialog::info(format!("Opening {}", url)))
+    ///     });
+    /// ```
+    #[must_use]
+    pub fn on_secondary_submit<F, V: ?Sized>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, &V) + 'static,
+        T: Borrow<V>,
+    {
+        self.with(|s| s.set_on_secondary_submit(cb))
+    }
+
+    /// Sets the event triggering the secondary action.
+    ///
+    /// Some terminals cannot tell `Ctrl+Enter` from `Enter`, so another key
+    /// may be needed.
+    ///
+    /// Defaults to `Event::Ctrl(Key::Enter)`.
+    pub fn set_secondary_key<E: Into<Event>>(&mut self, event: E) {
+        self.secondary_key = event.into();
+    }
+
+    /// Sets the event triggering the secondary action.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn secondary_key<E: Into<Event>>(self, event: E) -> Self {
+        self.with(|s| s.set_secondary_key(event))
+    }
+
     /// Sets the alignment for this view.
     ///
     /// # Examples
@@ -266,6 +511,29 @@ impl<T: 'static> SelectView<T> {
         self
     }
 
+    /// Sets the direction of the labels.
+    ///
+    /// Right-to-left words in labels are always shown in visual order. For
+    /// right-to-left labels (with `TextDirection::Rtl`, or with
+    /// `TextDirection::Auto` for labels starting with a right-to-left
+    /// word), the horizontal alignment is also mirrored.
+    pub fn set_text_direction(&mut self, direction: TextDirection) {
+        self.direction = direction;
+    }
+
+    /// Sets the direction of the labels.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn text_direction(self, direction: TextDirection) -> Self {
+        self.with(|s| s.set_text_direction(direction))
+    }
+
+    /// Returns the direction of the labels.
+    pub fn get_text_direction(&self) -> TextDirection {
+        self.direction
+    }
+
     /// Returns the value of the currently selected item.
     ///
     /// Returns `None` if the list is empty.
@@ -279,9 +547,27 @@ impl<T: 'static> SelectView<T> {
     }
 
     /// Removes all items from this view.
+    ///
+    /// The selection is reset: `selection()` returns `None` until items are
+    /// added again.
+    ///
+    /// Callbacks (`on_select`, `on_submit` and `on_secondary_submit`) are
+    /// kept, so the view can be refilled without registering them again.
+    /// Use [`clear_callbacks`](SelectView::clear_callbacks) to remove them.
     pub fn clear(&mut self) {
         self.items.clear();
         self.focus.set(0);
+        self.columns = None;
+    }
+
+    /// Removes the `on_select`, `on_submit` and `on_secondary_submit`
+    /// callbacks.
+    ///
+    /// Items are left untouched.
+    pub fn clear_callbacks(&mut self) {
+        self.on_select = None;
+        self.on_submit = None;
+        self.on_secondary_submit = None;
     }
 
     /// Adds a item to the list, with given label and value.
@@ -297,7 +583,64 @@ impl<T: 'static> SelectView<T> {
     /// select_view.add_item("Item 2", 2);
     /// ```
     pub fn add_item<S: Into<StyledString>>(&mut self, label: S, value: T) {
-        self.items.push(Item::new(label.into(), value));
+        let item = Item::new(label.into(), value);
+        self.grow_columns(Columns::of(&item));
+        self.items.push(item);
+    }
+
+    /// Adds an item with an icon and a suffix.
+    ///
+    /// The icon is shown before the label, and the suffix is right-aligned
+    /// at the end of the row. Icons and suffixes each get their own column,
+    /// as wide as the widest one, so they stay aligned across rows.
+    ///
+    /// Decorations are not shown in popup mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::SelectView;
+    ///
+    /// let mut select_view = SelectView::new();
+    ///
+    /// select_view.add_item_decorated("📁", "src", "", "src/");
+    /// select_view.add_item_decorated("📄", "Cargo.toml", "1.2 KB", "Cargo.toml");
+    /// ```
+    pub fn add_item_decorated<I, S, U>(
+        &mut self,
+        icon: I,
+        label: S,
+        suffix: U,
+        value: T,
+    ) where
+        I: Into<StyledString>,
+        S: Into<StyledString>,
+        U: Into<StyledString>,
+    {
+        let mut item = Item::new(label.into(), value);
+        item.icon = icon.into();
+        item.suffix = suffix.into();
+        self.grow_columns(Columns::of(&item));
+        self.items.push(item);
+    }
+
+    /// Adds an item with an icon and a suffix.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn item_decorated<I, S, U>(
+        self,
+        icon: I,
+        label: S,
+        suffix: U,
+        value: T,
+    ) -> Self
+    where
+        I: Into<StyledString>,
+        S: Into<StyledString>,
+        U: Into<StyledString>,
+    {
+        self.with(|s| s.add_item_decorated(icon, label, suffix, value))
     }
 
     /// Gets an item at given idx or None.
@@ -320,6 +663,8 @@ impl<T: 'static> SelectView<T> {
         if i >= self.items.len() {
             None
         } else {
+            // The label may change.
+            self.columns = None;
             let item = &mut self.items[i];
             if let Some(t) = Rc::get_mut(&mut item.value) {
                 let label = &mut item.label;
@@ -344,6 +689,7 @@ impl<T: 'static> SelectView<T> {
     where
         T: Clone,
     {
+        self.columns = None;
         self.items
             .iter_mut()
             .map(|item| (&mut item.label, Rc::make_mut(&mut item.value)))
@@ -358,6 +704,7 @@ impl<T: 'static> SelectView<T> {
     pub fn try_iter_mut(
         &mut self,
     ) -> impl Iterator<Item = (&mut StyledString, Option<&mut T>)> {
+        self.columns = None;
         self.items
             .iter_mut()
             .map(|item| (&mut item.label, Rc::get_mut(&mut item.value)))
@@ -379,6 +726,7 @@ impl<T: 'static> SelectView<T> {
     /// You should run this callback with a `&mut Cursive`.
     pub fn remove_item(&mut self, id: usize) -> Callback {
         self.items.remove(id);
+        self.columns = None;
         let focus = self.focus();
         (focus >= id && focus > 0)
             .then(|| {
@@ -395,7 +743,9 @@ impl<T: 'static> SelectView<T> {
     where
         S: Into<StyledString>,
     {
-        self.items.insert(index, Item::new(label.into(), value));
+        let item = Item::new(label.into(), value);
+        self.grow_columns(Columns::of(&item));
+        self.items.insert(index, item);
         let focus = self.focus();
         if focus >= index {
             self.focus.set(focus + 1);
@@ -452,14 +802,87 @@ impl<T: 'static> SelectView<T> {
         self.with(|s| s.add_all(iter))
     }
 
-    fn draw_item(&self, printer: &Printer, i: usize) {
-        let l = self.items[i].label.width();
-        let x = self.align.h.get_offset(l, printer.size.x);
-        printer.print_hline((0, 0), x, " ");
-        printer.print_styled((x, 0), (&self.items[i].label).into());
-        if l < printer.size.x {
-            assert!((l + x) <= printer.size.x);
-            printer.print_hline((x + l, 0), printer.size.x - (l + x), " ");
+    /// Updates the cached column widths after adding an item.
+    fn grow_columns(&mut self, item: Columns) {
+        if let Some(ref mut columns) = self.columns {
+            *columns = columns.max(item);
+        }
+    }
+
+    /// Returns the widths of the widest icon, label and suffix.
+    fn columns(&self) -> Columns {
+        self.columns.unwrap_or_else(|| {
+            self.items
+                .iter()
+                .map(Columns::of)
+                .fold(Columns::default(), Columns::max)
+        })
+    }
+
+    /// Returns the width of the widest item.
+    fn widest_item_width(&mut self) -> usize {
+        let columns = self.columns();
+        if self.virtualized {
+            self.columns = Some(columns);
+        }
+
+        if self.popup {
+            max(columns.label, 1)
+        } else {
+            max(columns.total(), 1)
+        }
+    }
+
+    fn draw_item(&self, printer: &Printer, i: usize, columns: Columns) {
+        // Prepare the background of the extra rows.
+        for y in 1..self.item_height {
+            printer.print_hline((0, y), printer.size.x, " ");
+        }
+
+        printer.print_hline((0, 0), printer.size.x, " ");
+
+        if let Some(ref item_draw) = self.item_draw {
+            item_draw(printer, &self.items[i].value, i == self.focus());
+            return;
+        }
+
+        let item = &self.items[i];
+
+        // The icon goes first, followed by a space.
+        let start = if columns.icon > 0 {
+            printer.print_styled((0, 0), (&item.icon).into());
+            columns.icon + 1
+        } else {
+            0
+        };
+
+        // The suffix is right-aligned, after a space.
+        let end = if columns.suffix > 0 {
+            let x = printer.size.x.saturating_sub(item.suffix.width());
+            printer.print_styled((x, 0), (&item.suffix).into());
+            printer.size.x.saturating_sub(columns.suffix + 1)
+        } else {
+            printer.size.x
+        };
+
+        let printer = &printer
+            .offset((start, 0))
+            .cropped((end.saturating_sub(start), 1));
+        let label = &item.label;
+        let l = label.width();
+        let direction = self.direction.resolve(label.source());
+        let x = direction.align(self.align.h).get_offset(l, printer.size.x);
+        self.print_label(printer, x, label);
+    }
+
+    // Prints a label, reordering right-to-left text.
+    fn print_label(&self, printer: &Printer, x: usize, label: &StyledString) {
+        let direction = self.direction.resolve(label.source());
+        if bidi::needs_reorder(label.source(), direction) {
+            let spans = label.spans().map(|span| (span.content, *span.attr));
+            bidi::print_styled(printer, Vec2::new(x, 0), spans, direction);
+        } else {
+            printer.print_styled((x, 0), label.into());
         }
     }
 
@@ -632,6 +1055,15 @@ impl<T: 'static> SelectView<T> {
 
     fn submit(&mut self) -> EventResult {
         let cb = self.on_submit.clone().unwrap();
+        self.submit_with(cb)
+    }
+
+    fn secondary_submit(&mut self) -> EventResult {
+        let cb = self.on_secondary_submit.clone().unwrap();
+        self.submit_with(cb)
+    }
+
+    fn submit_with(&self, cb: Rc<ItemCallback<T>>) -> EventResult {
         // We return a Callback Rc<|s| cb(s, &*v)>
         EventResult::Consumed(
             self.selection()
@@ -666,7 +1098,44 @@ impl<T: 'static> SelectView<T> {
         EventResult::Consumed(Some(cb))
     }
 
+    // Adds `c` to the search query, or removes the last character if `None`,
+    // then selects the first matching item.
+    fn on_search_event(&mut self, c: Option<char>) -> EventResult {
+        let mut query = self.query.clone();
+        match c {
+            Some(c) => query.push(c),
+            None => {
+                query.pop();
+            }
+        }
+
+        let found = self.items.iter().position(|item| {
+            SearchMatch::matches(item.label.source(), &query)
+        });
+        match found {
+            Some(i) => {
+                self.query = query;
+                EventResult::Consumed(Some(self.set_selection(i)))
+            }
+            None if query.is_empty() => {
+                self.query = query;
+                EventResult::Consumed(None)
+            }
+            None => EventResult::Ignored,
+        }
+    }
+
     fn on_event_regular(&mut self, event: Event) -> EventResult {
+        if self.searchable {
+            match event {
+                Event::Char(c) => return self.on_search_event(Some(c)),
+                Event::Key(Key::Backspace) if !self.query.is_empty() => {
+                    return self.on_search_event(None);
+                }
+                _ => self.query.clear(),
+            }
+        }
+
         match event {
             Event::Key(Key::Up) if self.focus() > 0 => self.focus_up(1),
             Event::Key(Key::Down) if self.focus() + 1 < self.items.len() => {
@@ -685,11 +1154,12 @@ impl<T: 'static> SelectView<T> {
             } if position
                 .checked_sub(offset)
                 .map(|position| {
-                    position < self.last_size && position.y < self.len()
+                    position < self.last_size
+                        && position.y < self.len() * self.item_height
                 })
                 .unwrap_or(false) =>
             {
-                self.focus.set(position.y - offset.y)
+                self.focus.set((position.y - offset.y) / self.item_height)
             }
             Event::Mouse {
                 event: MouseEvent::Release(MouseButton::Left),
@@ -699,15 +1169,37 @@ impl<T: 'static> SelectView<T> {
                 && position
                     .checked_sub(offset)
                     .map(|position| {
-                        position < self.last_size && position.y == self.focus()
+                        position < self.last_size
+                            && position.y / self.item_height == self.focus()
                     })
                     .unwrap_or(false) =>
             {
                 return self.submit();
             }
+            Event::Mouse {
+                event: MouseEvent::Release(MouseButton::Right),
+                position,
+                offset,
+            } if self.on_secondary_submit.is_some()
+                && position
+                    .checked_sub(offset)
+                    .map(|position| {
+                        position < self.last_size
+                            && position.y / self.item_height == self.focus()
+                    })
+                    .unwrap_or(false) =>
+            {
+                return self.secondary_submit();
+            }
             Event::Key(Key::Enter) if self.on_submit.is_some() => {
                 return self.submit();
             }
+            ref event
+                if *event == self.secondary_key
+                    && self.on_secondary_submit.is_some() =>
+            {
+                return self.secondary_submit();
+            }
             Event::Char(c) if self.autojump => return self.on_char_event(c),
             _ => return EventResult::Ignored,
         }
@@ -887,6 +1379,30 @@ where
     }
 }
 
+impl<T: 'static> Searchable for SelectView<T> {
+    /// Searches the item labels.
+    ///
+    /// The position of each match is the index of the item.
+    fn search(&self, query: &str) -> Vec<SearchMatch> {
+        self.items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| {
+                SearchMatch::matches(item.label.source(), query)
+            })
+            .map(|(i, item)| SearchMatch::new(i, item.label.source()))
+            .collect()
+    }
+
+    /// Selects the matching item.
+    fn reveal(&mut self, found: &SearchMatch) -> EventResult {
+        if found.position >= self.len() {
+            return EventResult::Ignored;
+        }
+        EventResult::Consumed(Some(self.set_selection(found.position)))
+    }
+}
+
 impl<T: 'static> View for SelectView<T> {
     fn draw(&self, printer: &Printer) {
         self.last_offset.set(printer.offset);
@@ -917,35 +1433,55 @@ impl<T: 'static> View for SelectView<T> {
                 if let Some(label) =
                     self.items.get(self.focus()).map(|item| &item.label)
                 {
+                    // Keep the borders visible if the label is too long.
+                    let label = label.truncated(x.saturating_sub(1), "…");
+
                     // And center the text?
                     let offset =
                         HAlign::Center.get_offset(label.width(), x + 1);
 
-                    printer.print_styled((offset, 0), label.into());
+                    self.print_label(printer, offset, &label);
                 }
             });
         } else {
             // Non-popup mode: we always print the entire list.
-            let h = self.items.len();
+            let n = self.items.len();
+            let item_height = self.item_height;
+            let h = n * item_height;
             let offset = self.align.v.get_offset(h, printer.size.y);
             let printer = &printer.offset((0, offset));
 
-            for i in 0..self.len() {
-                printer.offset((0, i)).with_selection(
-                    i == self.focus(),
-                    |printer| {
+            // In virtualized mode, skip items outside of the visible area.
+            let range = if self.virtualized {
+                let start = min(printer.content_offset.y / item_height, n);
+                // Include the last item, even if only partially visible.
+                let end = printer.content_offset.y + printer.output_size.y;
+                let end = match end.checked_sub(1) {
+                    Some(last_row) => min(last_row / item_height + 1, n),
+                    None => 0,
+                };
+                start..end
+            } else {
+                0..n
+            };
+
+            let columns = self.columns();
+            for i in range {
+                printer
+                    .offset((0, i * item_height))
+                    .cropped((printer.size.x, item_height))
+                    .with_selection(i == self.focus(), |printer| {
                         if i != self.focus()
                             && !(self.enabled && printer.enabled)
                         {
                             printer.with_color(
                                 ColorStyle::secondary(),
-                                |printer| self.draw_item(printer, i),
+                                |printer| self.draw_item(printer, i, columns),
                             );
                         } else {
-                            self.draw_item(printer, i);
+                            self.draw_item(printer, i, columns);
                         }
-                    },
-                );
+                    });
             }
         }
     }
@@ -954,16 +1490,11 @@ impl<T: 'static> View for SelectView<T> {
         // Items here are not compressible.
         // So no matter what the horizontal requirements are,
         // we'll still return our longest item.
-        let w = self
-            .items
-            .iter()
-            .map(|item| item.label.width())
-            .max()
-            .unwrap_or(1);
+        let w = self.widest_item_width();
         if self.popup {
             Vec2::new(w + 2, 1)
         } else {
-            let h = self.items.len();
+            let h = self.items.len() * self.item_height;
 
             Vec2::new(w, h)
         }
@@ -1011,7 +1542,12 @@ impl<T: 'static> View for SelectView<T> {
 
     fn important_area(&self, size: Vec2) -> Rect {
         self.selected_id()
-            .map(|i| Rect::from_size((0, i), (size.x, 1)))
+            .map(|i| {
+                Rect::from_size(
+                    (0, i * self.item_height),
+                    (size.x, self.item_height),
+                )
+            })
             .unwrap_or_else(|| Rect::from_size(Vec2::zero(), size))
     }
 }
@@ -1020,12 +1556,52 @@ impl<T: 'static> View for SelectView<T> {
 struct Item<T> {
     label: StyledString,
     value: Rc<T>,
+    // Optional decorations, empty by default.
+    icon: StyledString,
+    suffix: StyledString,
 }
 
 impl<T> Item<T> {
     fn new(label: StyledString, value: T) -> Self {
         let value = Rc::new(value);
-        Item { label, value }
+        Item {
+            label,
+            value,
+            icon: StyledString::new(),
+            suffix: StyledString::new(),
+        }
+    }
+}
+
+// Widths of the columns of a row.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Columns {
+    icon: usize,
+    label: usize,
+    suffix: usize,
+}
+
+impl Columns {
+    fn of<T>(item: &Item<T>) -> Self {
+        Columns {
+            icon: item.icon.width(),
+            label: item.label.width(),
+            suffix: item.suffix.width(),
+        }
+    }
+
+    fn max(self, other: Self) -> Self {
+        Columns {
+            icon: max(self.icon, other.icon),
+            label: max(self.label, other.label),
+            suffix: max(self.suffix, other.suffix),
+        }
+    }
+
+    // Total width, including the spaces around the label.
+    fn total(self) -> usize {
+        let gap = |w: usize| if w > 0 { w + 1 } else { 0 };
+        gap(self.icon) + self.label + gap(self.suffix)
     }
 }
 
@@ -1033,6 +1609,32 @@ impl<T> Item<T> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn type_to_search() {
+        let mut view = SelectView::new().searchable();
+        view.add_all_str(vec!["Open", "Save", "Save As", "Quit"]);
+
+        assert!(view.on_event(Event::Char('a')).is_consumed());
+        assert_eq!(view.selected_id(), Some(1));
+        assert!(view.on_event(Event::Char('s')).is_consumed());
+        assert_eq!(view.selected_id(), Some(2));
+        assert_eq!(view.get_search_query(), "as");
+
+        // No match: the query is unchanged.
+        assert!(!view.on_event(Event::Char('z')).is_consumed());
+        assert_eq!(view.get_search_query(), "as");
+
+        assert!(view.on_event(Event::Key(Key::Backspace)).is_consumed());
+        assert_eq!(view.get_search_query(), "a");
+        assert_eq!(view.selected_id(), Some(1));
+
+        // Other keys start a new search.
+        view.on_event(Event::Key(Key::Down));
+        assert_eq!(view.get_search_query(), "");
+        assert!(view.on_event(Event::Char('q')).is_consumed());
+        assert_eq!(view.selected_id(), Some(3));
+    }
+
     #[test]
     fn select_view_sorting() {
         // We add items in no particular order, from going by their label.
@@ -1125,4 +1727,112 @@ mod tests {
         view.on_event(Event::Key(Key::Down));
         assert_eq!(view.selection(), Some(Rc::new(3)));
     }
+
+    #[test]
+    fn select_view_virtualized_width() {
+        let mut view = SelectView::new().virtualized(true);
+        view.add_item_str("abc");
+        assert_eq!(view.required_size(Vec2::new(10, 10)), Vec2::new(3, 1));
+
+        // The cached width grows with new items...
+        view.add_item_str("abcdef");
+        assert_eq!(view.required_size(Vec2::new(10, 10)), Vec2::new(6, 2));
+
+        // ... and shrinks back when they are removed.
+        view.remove_item(1);
+        assert_eq!(view.required_size(Vec2::new(10, 10)), Vec2::new(3, 1));
+    }
+
+    #[test]
+    fn select_view_item_height() {
+        let mut view = SelectView::new()
+            .item("a", 1)
+            .item("b", 2)
+            .item("c", 3)
+            .item_height(2);
+        assert_eq!(view.required_size(Vec2::new(10, 10)), Vec2::new(1, 6));
+        view.layout(Vec2::new(10, 6));
+
+        // Clicking on the second row of an item selects it.
+        view.on_event(Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(0, 3),
+            event: MouseEvent::Press(MouseButton::Left),
+        });
+        assert_eq!(view.selected_id(), Some(1));
+        assert_eq!(
+            view.important_area(Vec2::new(10, 6)),
+            Rect::from_size((0, 2), (10, 2))
+        );
+    }
+
+    #[test]
+    fn select_view_decorated_width() {
+        let mut view = SelectView::new()
+            .item_decorated("#", "abc", "1 KB", 1)
+            .item("abcdef", 2);
+
+        // Icon and suffix columns are reserved for every row.
+        assert_eq!(view.required_size(Vec2::new(20, 10)), Vec2::new(13, 2));
+
+        // They don't count in popup mode.
+        view.set_popup(true);
+        assert_eq!(view.required_size(Vec2::new(20, 10)), Vec2::new(8, 1));
+    }
+
+    #[test]
+    fn select_view_secondary_submit() {
+        let opened = Rc::new(Cell::new(0));
+        let mut view = SelectView::new()
+            .item("a", 1)
+            .item("b", 2)
+            .on_secondary_submit({
+                let opened = Rc::clone(&opened);
+                move |_, &v| opened.set(v)
+            });
+        view.layout(Vec2::new(10, 2));
+
+        let mut siv = Cursive::new();
+        view.on_event(Event::Ctrl(Key::Enter)).process(&mut siv);
+        assert_eq!(opened.get(), 1);
+
+        // Right-clicking an item selects and opens it.
+        for event in [MouseEvent::Press, MouseEvent::Release] {
+            view.on_event(Event::Mouse {
+                offset: Vec2::zero(),
+                position: Vec2::new(0, 1),
+                event: event(MouseButton::Right),
+            })
+            .process(&mut siv);
+        }
+        assert_eq!(opened.get(), 2);
+
+        // Enter is left to the main action.
+        view.set_secondary_key('o');
+        assert!(!view.on_event(Event::Key(Key::Enter)).is_consumed());
+        view.on_event(Event::Key(Key::Up));
+        view.on_event(Event::Char('o')).process(&mut siv);
+        assert_eq!(opened.get(), 1);
+    }
+
+    #[test]
+    fn select_view_clear_keeps_callbacks() {
+        let submitted = Rc::new(Cell::new(0));
+        let mut view = SelectView::new().item("a", 1).on_submit({
+            let submitted = Rc::clone(&submitted);
+            move |_, &v| submitted.set(v)
+        });
+
+        view.clear();
+        assert_eq!(view.selection(), None);
+
+        // Refilling the view does not require new callbacks.
+        view.add_item("b", 2);
+        let mut siv = Cursive::new();
+        view.on_event(Event::Key(Key::Enter)).process(&mut siv);
+        assert_eq!(submitted.get(), 2);
+
+        view.clear_callbacks();
+        assert!(!view.on_event(Event::Key(Key::Enter)).has_callback());
+    }
 }
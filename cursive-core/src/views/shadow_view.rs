@@ -28,12 +28,12 @@ impl<T> ShadowView<T> {
     }
 
     /// Return the total padding for this view (include both sides)
-    fn padding(&self) -> Vec2 {
+    pub(crate) fn padding(&self) -> Vec2 {
         // We always need (1, 1) for the shadow.
         self.top_left_padding() + (1, 1)
     }
 
-    fn top_left_padding(&self) -> Vec2 {
+    pub(crate) fn top_left_padding(&self) -> Vec2 {
         Vec2::new(self.left_padding as usize, self.top_padding as usize)
     }
 
@@ -72,6 +72,15 @@ impl<T: View> ViewWrapper for ShadowView<T> {
         self.view.layout(size.saturating_sub(offset));
     }
 
+    fn wrap_set_viewport(&mut self, viewport: Rect) {
+        // The viewport is given in our coordinates.
+        let padding = self.top_left_padding();
+        self.view.set_viewport(Rect::from_corners(
+            viewport.top_left().saturating_sub(padding),
+            viewport.bottom_right().saturating_sub(padding),
+        ));
+    }
+
     fn wrap_on_event(&mut self, event: Event) -> EventResult {
         let padding = self.top_left_padding();
         self.view.on_event(event.relativized(padding))
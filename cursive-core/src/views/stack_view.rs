@@ -1,6 +1,7 @@
 use crate::{
     direction::Direction,
-    event::{AnyCb, Event, EventResult},
+    event::{AnyCb, Event, EventResult, MouseButton, MouseEvent},
+    rect::Rect,
     theme::ColorStyle,
     view::{
         CannotFocus, IntoBoxedView, Offset, Position, Selector, View,
@@ -21,6 +22,16 @@ pub struct StackView {
     // Flag indicates if undrawn areas of the background are exposed
     // and therefore need redrawing.
     bg_dirty: cell::Cell<bool>,
+    // Set while the top layer is being dragged with the mouse.
+    drag: Option<LayerDrag>,
+}
+
+// A layer being dragged by its title bar.
+struct LayerDrag {
+    // Mouse position when the drag started.
+    start: Vec2,
+    // Offset of the layer when the drag started.
+    start_offset: Vec2,
 }
 
 /// Where should the view be on the screen (per dimension).
@@ -128,6 +139,24 @@ impl<T: View> ChildWrapper<T> {
             ChildWrapper::Plain(ref mut layer) => layer.get_inner_mut(),
         }
     }
+
+    /// Returns `true` if the layer is drawn on its own background.
+    ///
+    /// Transparent layers have no frame, and so no title bar.
+    fn has_frame(&self) -> bool {
+        !matches!(*self, ChildWrapper::Plain(_))
+    }
+
+    /// Returns the area of the inner view, without the shadow.
+    fn content_area(&self, size: Vec2) -> Rect {
+        match *self {
+            ChildWrapper::Shadow(ref shadow) => Rect::from_size(
+                shadow.top_left_padding(),
+                size.saturating_sub(shadow.padding()),
+            ),
+            _ => Rect::from_size(Vec2::zero(), size),
+        }
+    }
 }
 
 // TODO: use macros to make this less ugly?
@@ -215,6 +244,9 @@ struct Child {
     // So we want to call `take_focus` right after the first call to `layout`.
     // This flag remembers when we've done that.
     virgin: bool,
+
+    // Can this layer be moved by dragging its title bar?
+    draggable: bool,
 }
 
 new_default!(StackView);
@@ -226,6 +258,7 @@ impl StackView {
             layers: Vec::new(),
             last_size: Vec2::zero(),
             bg_dirty: cell::Cell::new(true),
+            drag: None,
         }
     }
 
@@ -252,7 +285,12 @@ impl StackView {
 
     /// Adds a new full-screen layer on top of the stack.
     ///
+    /// The view is given the entire size of the stack, even if it asks for
+    /// less, like with [`ResizedView::with_full_screen`].
+    ///
     /// Fullscreen layers have no shadow.
+    ///
+    /// [`ResizedView::with_full_screen`]: crate::views::ResizedView::with_full_screen
     pub fn add_fullscreen_layer<T>(&mut self, view: T)
     where
         T: IntoBoxedView,
@@ -265,6 +303,7 @@ impl StackView {
             size: Vec2::zero(),
             placement: Placement::Fullscreen,
             virgin: true,
+            draggable: false,
         });
     }
 
@@ -379,6 +418,7 @@ impl StackView {
             size: Vec2::new(0, 0),
             placement: Placement::Floating(position),
             virgin: true,
+            draggable: false,
         });
     }
 
@@ -401,6 +441,7 @@ impl StackView {
             size: Vec2::new(0, 0),
             placement: Placement::Floating(position),
             virgin: true,
+            draggable: false,
         });
     }
 
@@ -532,6 +573,92 @@ impl StackView {
         }
     }
 
+    /// Lets the user move a layer by dragging its title bar with the mouse.
+    ///
+    /// The title bar is the top row of the layer, where a `Dialog` shows its
+    /// title. Once dragged, the layer keeps its new absolute position.
+    ///
+    /// Only the top layer can be dragged. Fullscreen and transparent layers
+    /// have no title bar, and never move.
+    ///
+    /// # Panics
+    ///
+    /// If `layer` is out of bounds.
+    pub fn set_draggable(&mut self, layer: LayerPosition, draggable: bool) {
+        let i = self.get_index(layer).unwrap();
+        self.layers[i].draggable = draggable;
+    }
+
+    /// Returns `true` if the given layer can be dragged with the mouse.
+    ///
+    /// Returns `false` if `layer` is out of bounds.
+    pub fn is_draggable(&self, layer: LayerPosition) -> bool {
+        matches!(
+            self.get_index(layer).and_then(|i| self.layers.get(i)),
+            Some(child) if child.draggable
+        )
+    }
+
+    // Moves the top layer if its title bar is being dragged.
+    fn on_event_drag(&mut self, event: &Event) -> Option<EventResult> {
+        let (position, event) = match *event {
+            Event::Mouse {
+                offset,
+                position,
+                event,
+            } => (position.checked_sub(offset)?, event),
+            _ => return None,
+        };
+
+        match event {
+            MouseEvent::Press(MouseButton::Left) => {
+                let offset = self.layer_offsets().last()?;
+                let layer = self.layers.last()?;
+                let title = layer.view.content_area(layer.size);
+                let relative = position.checked_sub(offset)?;
+                // Fullscreen and transparent layers have no title bar.
+                if !layer.draggable
+                    || !layer.view.has_frame()
+                    || !matches!(layer.placement, Placement::Floating(_))
+                    || relative.y != title.top()
+                    || !(title.left()..=title.right()).contains(&relative.x)
+                {
+                    return None;
+                }
+
+                self.drag = Some(LayerDrag {
+                    start: position,
+                    start_offset: offset,
+                });
+            }
+            MouseEvent::Hold(MouseButton::Left) => {
+                let drag = self.drag.as_ref()?;
+                let last_size = self.last_size;
+                let layer = self.layers.last_mut()?;
+                if !layer.draggable {
+                    return None;
+                }
+                if let Placement::Floating(_) = layer.placement {
+                    let offset = drag
+                        .start_offset
+                        .saturating_add(
+                            position.signed() - drag.start.signed(),
+                        )
+                        .or_min(last_size.saturating_sub(layer.size));
+                    layer.placement =
+                        Placement::Floating(Position::absolute(offset));
+                    self.bg_dirty.set(true);
+                }
+            }
+            MouseEvent::Release(MouseButton::Left) => {
+                self.drag.take()?;
+            }
+            _ => return None,
+        }
+
+        Some(EventResult::Consumed(None))
+    }
+
     /// Background drawing
     ///
     /// Drawing functions are split into forground and background to
@@ -635,6 +762,9 @@ impl View for StackView {
         if event == Event::WindowResize {
             self.bg_dirty.set(true);
         }
+        if let Some(res) = self.on_event_drag(&event) {
+            return res;
+        }
         // Use the stack position iterator to get the offset of the top layer.
         // TODO: save it instead when drawing?
         match StackPositionIterator::new(
@@ -656,8 +786,12 @@ impl View for StackView {
 
         for layer in &mut self.layers {
             // Give each guy what he asks for, within the budget constraints.
-            let size = Vec2::min(size, layer.view.required_size(size));
-            layer.size = size;
+            let required = layer.view.required_size(size);
+            layer.size = match layer.placement {
+                // Fullscreen layers fill the screen, whatever they ask for.
+                Placement::Fullscreen => size,
+                Placement::Floating(_) => Vec2::min(size, required),
+            };
             layer.view.layout(layer.size);
 
             // We need to call `layout()` on the view before giving it focus
@@ -693,6 +827,14 @@ impl View for StackView {
         }
     }
 
+    fn debug_children(&self, f: &mut dyn FnMut(&dyn View, Option<Rect>)) {
+        // Skip the shadow and background wrappers.
+        for (layer, offset) in self.layers.iter().zip(self.layer_offsets()) {
+            let area = Rect::from_size(offset, layer.size);
+            f(&**layer.view.get_inner(), Some(area));
+        }
+    }
+
     fn call_on_any<'a>(
         &mut self,
         selector: &Selector<'_>,
@@ -720,7 +862,7 @@ impl View for StackView {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::views::TextView;
+    use crate::views::{Dialog, TextView};
 
     #[test]
     fn pop_add() {
@@ -740,6 +882,20 @@ mod tests {
         assert_eq!(text.get_content().source(), "1");
     }
 
+    #[test]
+    fn fullscreen_fills_the_stack() {
+        let mut stack = StackView::new()
+            .fullscreen_layer(TextView::new("1"))
+            .layer(TextView::new("2"));
+
+        stack.layout(Vec2::new(20, 10));
+        // Floating layers only take what they need, including the shadow.
+        assert_eq!(
+            stack.layer_sizes(),
+            vec![Vec2::new(20, 10), Vec2::new(3, 3)]
+        );
+    }
+
     #[test]
     fn move_layer_works() {
         let mut stack = StackView::new()
@@ -787,6 +943,86 @@ mod tests {
         assert!(stack.pop_layer().is_none());
     }
 
+    #[test]
+    fn drag_layer() {
+        let mut stack = StackView::new().layer_at(
+            Position::absolute((2, 2)),
+            Dialog::text("Hi").title("Title"),
+        );
+        stack.set_draggable(LayerPosition::FromFront(0), true);
+        stack.layout(Vec2::new(40, 20));
+
+        let mouse = |event, position: (usize, usize)| Event::Mouse {
+            offset: Vec2::zero(),
+            position: position.into(),
+            event,
+        };
+        let left = MouseButton::Left;
+
+        // The title bar is the top border of the dialog.
+        let origin = stack.layer_offset(LayerPosition::FromFront(0)).unwrap();
+        assert_eq!(origin, Vec2::new(2, 2));
+        stack.on_event(mouse(MouseEvent::Press(left), (4, 2)));
+        stack.on_event(mouse(MouseEvent::Hold(left), (10, 7)));
+        stack.on_event(mouse(MouseEvent::Release(left), (10, 7)));
+        assert_eq!(
+            stack.layer_offset(LayerPosition::FromFront(0)),
+            Some(Vec2::new(8, 7))
+        );
+
+        // Layers stay on screen.
+        stack.on_event(mouse(MouseEvent::Press(left), (10, 7)));
+        stack.on_event(mouse(MouseEvent::Hold(left), (0, 0)));
+        stack.on_event(mouse(MouseEvent::Release(left), (0, 0)));
+        assert_eq!(
+            stack.layer_offset(LayerPosition::FromFront(0)),
+            Some(Vec2::new(0, 0))
+        );
+
+        // Dragging from anywhere but the title bar does nothing.
+        stack.on_event(mouse(MouseEvent::Press(left), (2, 3)));
+        stack.on_event(mouse(MouseEvent::Hold(left), (12, 13)));
+        assert_eq!(
+            stack.layer_offset(LayerPosition::FromFront(0)),
+            Some(Vec2::new(0, 0))
+        );
+    }
+
+    #[test]
+    fn drag_without_frame() {
+        let mouse = |event, position: (usize, usize)| Event::Mouse {
+            offset: Vec2::zero(),
+            position: position.into(),
+            event,
+        };
+        let left = MouseButton::Left;
+
+        let mut stack = StackView::new();
+        stack.add_fullscreen_layer(Dialog::text("Hi").title("Title"));
+        stack.add_transparent_layer_at(
+            Position::absolute((2, 2)),
+            Dialog::text("Hi").title("Title"),
+        );
+        for i in 0..2 {
+            stack.set_draggable(LayerPosition::FromFront(i), true);
+        }
+        stack.layout(Vec2::new(40, 20));
+
+        // The transparent layer lets the event through.
+        stack.on_event(mouse(MouseEvent::Press(left), (4, 2)));
+        assert!(stack.drag.is_none());
+        stack.on_event(mouse(MouseEvent::Release(left), (4, 2)));
+
+        // So does the fullscreen layer.
+        stack.pop_layer();
+        stack.on_event(mouse(MouseEvent::Press(left), (4, 0)));
+        assert!(stack.drag.is_none());
+        assert_eq!(
+            stack.layer_offset(LayerPosition::FromFront(0)),
+            Some(Vec2::new(0, 0))
+        );
+    }
+
     #[test]
     fn get() {
         let mut stack = StackView::new()
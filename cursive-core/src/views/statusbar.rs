@@ -0,0 +1,222 @@
+use crate::{
+    align::HAlign,
+    event::{Callback, Event, EventResult, MouseButton, MouseEvent},
+    theme::ColorStyle,
+    utils::markup::StyledString,
+    view::View,
+    Cursive, Printer, Vec2, With,
+};
+
+/// Single-line bar with a left, a center and a right segment.
+///
+/// The [`Cursive`] root already includes a statusbar, shown at the bottom of
+/// the screen, that you just need to configure.
+///
+/// Segments are clipped when they don't fit: the left segment has priority,
+/// then the right one, and the center segment only uses the space left
+/// between them.
+///
+/// [`Cursive`]: crate::Cursive::statusbar
+pub struct Statusbar {
+    // Indexed by `index(HAlign)`.
+    segments: [StyledString; 3],
+    callbacks: [Option<Callback>; 3],
+
+    autohide: bool,
+    last_width: usize,
+}
+
+new_default!(Statusbar);
+
+impl Statusbar {
+    /// Creates a new, empty statusbar.
+    pub fn new() -> Self {
+        Statusbar {
+            segments: Default::default(),
+            callbacks: Default::default(),
+            autohide: true,
+            last_width: 0,
+        }
+    }
+
+    /// Sets the content of a segment.
+    pub fn set_segment<S>(&mut self, position: HAlign, content: S)
+    where
+        S: Into<StyledString>,
+    {
+        self.segments[index(position)] = content.into();
+    }
+
+    /// Sets the content of a segment.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn segment<S>(self, position: HAlign, content: S) -> Self
+    where
+        S: Into<StyledString>,
+    {
+        self.with(|s| s.set_segment(position, content))
+    }
+
+    /// Returns the content of a segment.
+    pub fn get_segment(&self, position: HAlign) -> &StyledString {
+        &self.segments[index(position)]
+    }
+
+    /// Removes the content of every segment.
+    pub fn clear(&mut self) {
+        self.segments = Default::default();
+    }
+
+    /// Sets a callback to run when a segment is clicked.
+    pub fn set_on_click<F>(&mut self, position: HAlign, cb: F)
+    where
+        F: 'static + Fn(&mut Cursive),
+    {
+        self.callbacks[index(position)] = Some(Callback::from_fn(cb));
+    }
+
+    /// Sets a callback to run when a segment is clicked.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn on_click<F>(self, position: HAlign, cb: F) -> Self
+    where
+        F: 'static + Fn(&mut Cursive),
+    {
+        self.with(|s| s.set_on_click(position, cb))
+    }
+
+    /// Hides the statusbar when all segments are empty.
+    ///
+    /// Enabled by default.
+    pub fn set_autohide(&mut self, autohide: bool) {
+        self.autohide = autohide;
+    }
+
+    /// Hides the statusbar when all segments are empty.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn autohide(self, autohide: bool) -> Self {
+        self.with(|s| s.set_autohide(autohide))
+    }
+
+    /// Returns `true` if we should be drawn.
+    pub fn visible(&self) -> bool {
+        !self.autohide || self.segments.iter().any(|s| !s.is_empty())
+    }
+
+    // Returns the `(start, width)` of each segment, for the given width.
+    fn ranges(&self, width: usize) -> [(usize, usize); 3] {
+        let widths = [
+            self.segments[0].width(),
+            self.segments[1].width(),
+            self.segments[2].width(),
+        ];
+        segment_ranges(width, widths)
+    }
+}
+
+// Returns the position of a segment in the arrays.
+fn index(position: HAlign) -> usize {
+    match position {
+        HAlign::Left => 0,
+        HAlign::Center => 1,
+        HAlign::Right => 2,
+    }
+}
+
+// Returns the `(start, width)` of the left, center and right segments.
+//
+// The left segment is shown first, then the right one in the space left, and
+// the center one only between them.
+fn segment_ranges(width: usize, widths: [usize; 3]) -> [(usize, usize); 3] {
+    let left = (0, widths[0].min(width));
+
+    let right_width = widths[2].min(width - left.1);
+    let right = (width - right_width, right_width);
+
+    // Try to center the middle segment, then shift it to fit in the gap.
+    let gap = (left.1, right.0);
+    let center_width = widths[1].min(gap.1 - gap.0);
+    let start = (width.saturating_sub(widths[1]) / 2)
+        .max(gap.0)
+        .min(gap.1 - center_width);
+    let center = (start, center_width);
+
+    [left, center, right]
+}
+
+impl View for Statusbar {
+    fn draw(&self, printer: &Printer) {
+        printer.with_color(ColorStyle::primary(), |printer| {
+            printer.print_hline((0, 0), printer.size.x, " ");
+
+            for (segment, (start, width)) in
+                self.segments.iter().zip(self.ranges(printer.size.x).iter())
+            {
+                if *width == 0 {
+                    continue;
+                }
+                printer
+                    .offset((*start, 0))
+                    .cropped((*width, 1))
+                    .print_styled((0, 0), segment.into());
+            }
+        });
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.last_width = size.x;
+    }
+
+    fn required_size(&mut self, req: Vec2) -> Vec2 {
+        Vec2::new(req.x, 1)
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        if let Event::Mouse {
+            event: MouseEvent::Release(MouseButton::Left),
+            position,
+            offset,
+        } = event
+        {
+            let position = match position.checked_sub(offset) {
+                Some(position) if position.y == 0 => position,
+                _ => return EventResult::Ignored,
+            };
+
+            let ranges = self.ranges(self.last_width);
+            for (i, &(start, width)) in ranges.iter().enumerate() {
+                if position.x >= start && position.x < start + width {
+                    if let Some(ref cb) = self.callbacks[i] {
+                        return EventResult::Consumed(Some(cb.clone()));
+                    }
+                }
+            }
+        }
+
+        EventResult::Ignored
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranges_clip_segments() {
+        // Everything fits: the center segment is centered.
+        assert_eq!(segment_ranges(20, [3, 4, 5]), [(0, 3), (8, 4), (15, 5)]);
+
+        // The center segment is pushed away from the left one.
+        assert_eq!(segment_ranges(20, [9, 4, 5]), [(0, 9), (9, 4), (15, 5)]);
+
+        // The center segment is clipped to the gap.
+        assert_eq!(segment_ranges(10, [3, 8, 4]), [(0, 3), (3, 3), (6, 4)]);
+
+        // The right segment is clipped by the left one.
+        assert_eq!(segment_ranges(10, [7, 2, 5]), [(0, 7), (7, 0), (7, 3)]);
+    }
+}
@@ -0,0 +1,998 @@
+use crate::align::HAlign;
+use crate::direction::Direction;
+use crate::event::{
+    Callback, Event, EventResult, Key, MouseButton, MouseEvent,
+};
+use crate::theme::ColorStyle;
+use crate::utils::width::TextWidth;
+use crate::view::{scroll, CannotFocus, View};
+use crate::Cursive;
+use crate::Printer;
+use crate::Rect;
+use crate::Vec2;
+use crate::With;
+
+use std::cmp::{self, Ordering};
+use std::rc::Rc;
+
+/// A row in a [`TableView`].
+///
+/// `H` identifies the columns, usually with a small `enum`.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::TableViewItem;
+/// use std::cmp::Ordering;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq)]
+/// enum FileColumn {
+///     Name,
+///     Size,
+/// }
+///
+/// struct File {
+///     name: String,
+///     size: u64,
+/// }
+///
+/// impl TableViewItem<FileColumn> for File {
+///     fn to_column(&self, column: FileColumn) -> String {
+///         match column {
+///             FileColumn::Name => self.name.clone(),
+///             FileColumn::Size => self.size.to_string(),
+///         }
+///     }
+///
+///     fn cmp(&self, other: &Self, column: FileColumn) -> Ordering {
+///         match column {
+///             FileColumn::Name => self.name.cmp(&other.name),
+///             FileColumn::Size => self.size.cmp(&other.size),
+///         }
+///     }
+/// }
+/// ```
+pub trait TableViewItem<H> {
+    /// Returns the text to show in the given column.
+    fn to_column(&self, column: H) -> String;
+
+    /// Compares `self` and `other` on the given column.
+    ///
+    /// Used to sort the rows in ascending order.
+    fn cmp(&self, other: &Self, column: H) -> Ordering
+    where
+        Self: Sized;
+}
+
+/// Width of a column in a [`TableView`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColumnWidth {
+    /// Exactly this many cells.
+    Fixed(usize),
+
+    /// As wide as the widest value, or the title.
+    Auto,
+
+    /// Shares the remaining space with other weighted columns.
+    ///
+    /// Each column gets a part proportional to its weight.
+    Weight(usize),
+}
+
+/// A table with a header row, sortable columns and row selection.
+///
+/// Each row is an item implementing [`TableViewItem`]. Columns are sorted
+/// by clicking on their title, or by pressing `<Up>` from the first row to
+/// focus the header, then `<Left>`, `<Right>` and `<Enter>`. Sorting again
+/// on the same column reverses the order.
+///
+/// Items keep the index they were added with, which is given to the
+/// callbacks, no matter how rows are sorted.
+///
+/// When there are too many rows, the table scrolls vertically, while the
+/// header stays visible.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive_core::views::TableViewItem;
+/// # use std::cmp::Ordering;
+/// # #[derive(Clone, Copy, PartialEq, Eq)]
+/// # enum FileColumn { Name, Size }
+/// # struct File { name: String, size: u64 }
+/// # impl TableViewItem<FileColumn> for File {
+/// #     fn to_column(&self, column: FileColumn) -> String {
+/// #         match column {
+/// #             FileColumn::Name => self.name.clone(),
+/// #             FileColumn::Size => self.size.to_string(),
+/// #         }
+/// #     }
+/// #     fn cmp(&self, other: &Self, column: FileColumn) -> Ordering {
+/// #         match column {
+/// #             FileColumn::Name => self.name.cmp(&other.name),
+/// #             FileColumn::Size => self.size.cmp(&other.size),
+/// #         }
+/// #     }
+/// # }
+/// use cursive_core::align::HAlign;
+/// use cursive_core::views::{ColumnWidth, Dialog, TableView};
+///
+/// let mut table = TableView::new()
+///     .column(FileColumn::Name, "Name", ColumnWidth::Weight(1))
+///     .column(FileColumn::Size, "Size", ColumnWidth::Auto)
+///     .on_submit(|s, index| {
+///         s.add_layer(Dialog::info(format!("Opening file #{}", index)));
+///     });
+/// table.set_column_align(FileColumn::Size, HAlign::Right);
+///
+/// table.set_items(vec![
+///     File { name: "Cargo.toml".into(), size: 1234 },
+///     File { name: "README.md".into(), size: 567 },
+/// ]);
+/// table.sort_by(FileColumn::Size, Ordering::Less);
+///
+/// // The selection follows the item, now on the second row.
+/// assert_eq!(table.item(), Some(0));
+/// assert_eq!(table.row(), Some(1));
+/// ```
+pub struct TableView<T, H> {
+    columns: Vec<Column<H>>,
+    items: Vec<T>,
+
+    // Index of the item shown in each row.
+    rows: Vec<usize>,
+
+    // Sort column (as index in `columns`) and order.
+    sort: Option<(usize, Ordering)>,
+
+    // Selected row.
+    focus: usize,
+
+    // `Some(column)` when the header has the focus.
+    header_focus: Option<usize>,
+
+    enabled: bool,
+
+    on_sort: Option<Rc<SortCallback<H>>>,
+    on_submit: Option<Rc<IndexCallback>>,
+    on_select: Option<Rc<IndexCallback>>,
+
+    scroll_core: scroll::Core,
+}
+
+type SortCallback<H> = dyn Fn(&mut Cursive, H, Ordering);
+type IndexCallback = dyn Fn(&mut Cursive, usize);
+
+struct Column<H> {
+    column: H,
+    title: String,
+    width: ColumnWidth,
+    align: HAlign,
+
+    // Width from the last layout.
+    last_width: usize,
+}
+
+// Separator drawn between columns.
+const SEPARATOR: &str = " │ ";
+const SEPARATOR_WIDTH: usize = 3;
+
+// The header is a row of titles, and a delimiter.
+const HEADER_HEIGHT: usize = 2;
+
+impl<H: Copy> Column<H> {
+    // Width taken by the title, with room for the sort indicator.
+    fn title_width(&self) -> usize {
+        self.title.width() + 2
+    }
+
+    // Width of the widest of the title and values.
+    fn auto_width<T: TableViewItem<H>>(&self, items: &[T]) -> usize {
+        items
+            .iter()
+            .map(|item| item.to_column(self.column).width())
+            .fold(self.title_width(), cmp::max)
+    }
+
+    // Minimum width required by this column.
+    fn required_width<T: TableViewItem<H>>(&self, items: &[T]) -> usize {
+        match self.width {
+            ColumnWidth::Fixed(width) => width,
+            ColumnWidth::Auto => self.auto_width(items),
+            ColumnWidth::Weight(_) => self.title_width(),
+        }
+    }
+}
+
+impl_scroller!(TableView<T, H>::scroll_core);
+
+impl<T, H> Default for TableView<T, H>
+where
+    T: TableViewItem<H> + 'static,
+    H: Copy + Eq + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, H> TableView<T, H>
+where
+    T: TableViewItem<H> + 'static,
+    H: Copy + Eq + 'static,
+{
+    /// Creates a new, empty `TableView`.
+    pub fn new() -> Self {
+        TableView {
+            columns: Vec::new(),
+            items: Vec::new(),
+            rows: Vec::new(),
+            sort: None,
+            focus: 0,
+            header_focus: None,
+            enabled: true,
+            on_sort: None,
+            on_submit: None,
+            on_select: None,
+            scroll_core: scroll::Core::new().scroll_x(false),
+        }
+    }
+
+    /// Adds a column at the end.
+    pub fn add_column<S: Into<String>>(
+        &mut self,
+        column: H,
+        title: S,
+        width: ColumnWidth,
+    ) {
+        self.insert_column(self.columns.len(), column, title, width);
+    }
+
+    /// Adds a column at the end.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn column<S: Into<String>>(
+        self,
+        column: H,
+        title: S,
+        width: ColumnWidth,
+    ) -> Self {
+        self.with(|s| s.add_column(column, title, width))
+    }
+
+    /// Inserts a column at the given position.
+    ///
+    /// # Panics
+    ///
+    /// If `index > self.columns_len()`.
+    pub fn insert_column<S: Into<String>>(
+        &mut self,
+        index: usize,
+        column: H,
+        title: S,
+        width: ColumnWidth,
+    ) {
+        self.columns.insert(
+            index,
+            Column {
+                column,
+                title: title.into(),
+                width,
+                align: HAlign::Left,
+                last_width: 0,
+            },
+        );
+
+        if let Some((ref mut sorted, _)) = self.sort {
+            if *sorted >= index {
+                *sorted += 1;
+            }
+        }
+        self.header_focus = None;
+    }
+
+    /// Returns the number of columns.
+    pub fn columns_len(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Sets the horizontal alignment of the values in a column.
+    ///
+    /// Defaults to `HAlign::Left`.
+    pub fn set_column_align(&mut self, column: H, align: HAlign) {
+        if let Some(i) = self.column_index(column) {
+            self.columns[i].align = align;
+        }
+    }
+
+    /// Sets the horizontal alignment of the values in a column.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn column_align(self, column: H, align: HAlign) -> Self {
+        self.with(|s| s.set_column_align(column, align))
+    }
+
+    /// Replaces all items.
+    ///
+    /// The rows are sorted again, and the selection goes back to the first
+    /// row.
+    pub fn set_items(&mut self, items: Vec<T>) {
+        self.items = items;
+        self.rows = (0..self.items.len()).collect();
+        self.sort_rows();
+        self.focus = 0;
+        self.scroll_core.scroll_to_top();
+    }
+
+    /// Replaces all items.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn items(self, items: Vec<T>) -> Self {
+        self.with(|s| s.set_items(items))
+    }
+
+    /// Adds an item, and returns its index.
+    ///
+    /// The row is placed according to the current sort order.
+    pub fn insert_item(&mut self, item: T) -> usize {
+        let selected = self.item();
+
+        let index = self.items.len();
+        self.items.push(item);
+        self.rows.push(index);
+        self.sort_rows();
+
+        if let Some(selected) = selected {
+            self.focus = self.row_of(selected);
+        }
+        index
+    }
+
+    /// Removes the item with the given index, and returns it.
+    ///
+    /// Items after it get their index decreased by one.
+    pub fn remove_item(&mut self, index: usize) -> Option<T> {
+        if index >= self.items.len() {
+            return None;
+        }
+
+        let selected = self.item().filter(|&selected| selected != index);
+
+        let item = self.items.remove(index);
+        self.rows.retain(|&i| i != index);
+        for i in &mut self.rows {
+            if *i > index {
+                *i -= 1;
+            }
+        }
+
+        self.focus = match selected {
+            Some(selected) if selected > index => self.row_of(selected - 1),
+            Some(selected) => self.row_of(selected),
+            None => cmp::min(self.focus, self.rows.len().saturating_sub(1)),
+        };
+        Some(item)
+    }
+
+    /// Removes all items.
+    pub fn clear(&mut self) {
+        self.set_items(Vec::new());
+    }
+
+    /// Returns the number of items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if there are no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the item with the given index.
+    pub fn borrow_item(&self, index: usize) -> Option<&T> {
+        self.items.get(index)
+    }
+
+    /// Returns a mutable access to the item with the given index.
+    ///
+    /// Rows are not sorted again; call [`sort`](Self::sort) if needed.
+    pub fn borrow_item_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.items.get_mut(index)
+    }
+
+    /// Returns all items, in the order they were added.
+    pub fn borrow_items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Returns the index of the selected item, if any.
+    pub fn item(&self) -> Option<usize> {
+        self.rows.get(self.focus).copied()
+    }
+
+    /// Selects the item with the given index.
+    ///
+    /// Returns a callback from the `on_select` callback.
+    pub fn set_selected_item(&mut self, index: usize) -> Callback {
+        if index < self.items.len() {
+            self.focus = self.row_of(index);
+        }
+        self.select_cb().unwrap_or_else(Callback::dummy)
+    }
+
+    /// Returns the selected row, if any.
+    ///
+    /// This is the position on screen, from the top, in the current order.
+    pub fn row(&self) -> Option<usize> {
+        (self.focus < self.rows.len()).then_some(self.focus)
+    }
+
+    /// Returns the current sort column and order, if any.
+    ///
+    /// `Ordering::Less` means ascending order.
+    pub fn order(&self) -> Option<(H, Ordering)> {
+        self.sort.map(|(i, order)| (self.columns[i].column, order))
+    }
+
+    /// Sorts rows on the given column.
+    ///
+    /// `Ordering::Less` sorts in ascending order, `Ordering::Greater` in
+    /// descending order. The selected item stays selected.
+    pub fn sort_by(&mut self, column: H, order: Ordering) {
+        if let Some(i) = self.column_index(column) {
+            let selected = self.item();
+            self.sort = Some((i, order));
+            self.sort_rows();
+            if let Some(selected) = selected {
+                self.focus = self.row_of(selected);
+            }
+        }
+    }
+
+    /// Sorts rows again, using the current order.
+    ///
+    /// Useful after modifying items.
+    pub fn sort(&mut self) {
+        if let Some((i, order)) = self.sort {
+            self.sort_by(self.columns[i].column, order);
+        }
+    }
+
+    /// Sets a callback to run when the user sorts a column.
+    ///
+    /// It is given the column and the new order.
+    pub fn set_on_sort<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, H, Ordering) + 'static,
+    {
+        self.on_sort = Some(Rc::new(cb));
+    }
+
+    /// Sets a callback to run when the user sorts a column.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn on_sort<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, H, Ordering) + 'static,
+    {
+        self.with(|s| s.set_on_sort(cb))
+    }
+
+    /// Sets a callback to run when `<Enter>` is pressed on a row, or when a
+    /// row is clicked.
+    ///
+    /// It is given the index of the item.
+    pub fn set_on_submit<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, usize) + 'static,
+    {
+        self.on_submit = Some(Rc::new(cb));
+    }
+
+    /// Sets a callback to run when `<Enter>` is pressed on a row, or when a
+    /// row is clicked.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn on_submit<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, usize) + 'static,
+    {
+        self.with(|s| s.set_on_submit(cb))
+    }
+
+    /// Sets a callback to run when the selection changes.
+    ///
+    /// It is given the index of the item.
+    pub fn set_on_select<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, usize) + 'static,
+    {
+        self.on_select = Some(Rc::new(cb));
+    }
+
+    /// Sets a callback to run when the selection changes.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn on_select<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, usize) + 'static,
+    {
+        self.with(|s| s.set_on_select(cb))
+    }
+
+    /// Enables or disables this view.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Returns `true` if this view is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn column_index(&self, column: H) -> Option<usize> {
+        self.columns.iter().position(|c| c.column == column)
+    }
+
+    // Returns the row showing the given item.
+    fn row_of(&self, index: usize) -> usize {
+        self.rows.iter().position(|&i| i == index).unwrap_or(0)
+    }
+
+    fn sort_rows(&mut self) {
+        if let Some((i, order)) = self.sort {
+            let column = self.columns[i].column;
+            let items = &self.items;
+            self.rows.sort_by(|&a, &b| {
+                let ordering = items[a].cmp(&items[b], column);
+                if order == Ordering::Greater {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+    }
+
+    // Sorts on the given column, reversing the order if it is already used.
+    fn sort_on_click(&mut self, i: usize) -> EventResult {
+        let order = match self.sort {
+            Some((sorted, Ordering::Less)) if sorted == i => Ordering::Greater,
+            _ => Ordering::Less,
+        };
+        let column = self.columns[i].column;
+        self.sort_by(column, order);
+
+        let cb = self.on_sort.clone();
+        EventResult::Consumed(
+            cb.map(|cb| Callback::from_fn(move |s| cb(s, column, order))),
+        )
+    }
+
+    fn select_cb(&self) -> Option<Callback> {
+        let cb = self.on_select.clone()?;
+        let index = self.item()?;
+        Some(Callback::from_fn(move |s| cb(s, index)))
+    }
+
+    fn submit(&self) -> EventResult {
+        let cb = self.on_submit.clone();
+        let index = self.item();
+        EventResult::Consumed(
+            cb.zip(index)
+                .map(|(cb, index)| Callback::from_fn(move |s| cb(s, index))),
+        )
+    }
+
+    // Returns the column at the given horizontal position.
+    fn column_at(&self, x: usize) -> Option<usize> {
+        let mut start = 0;
+        for (i, column) in self.columns.iter().enumerate() {
+            let end = start + column.last_width + SEPARATOR_WIDTH;
+            if x < end {
+                return Some(i);
+            }
+            start = end;
+        }
+        None
+    }
+
+    // Computes the width of each column, for the given total width.
+    fn layout_columns(&mut self, width: usize) {
+        let items = &self.items;
+        let separators =
+            SEPARATOR_WIDTH * self.columns.len().saturating_sub(1);
+
+        let mut remaining = width.saturating_sub(separators);
+        let mut weights = 0;
+        for column in &mut self.columns {
+            match column.width {
+                ColumnWidth::Weight(weight) => weights += weight,
+                _ => {
+                    column.last_width = column.required_width(items);
+                    remaining = remaining.saturating_sub(column.last_width);
+                }
+            }
+        }
+
+        // Share what's left between weighted columns.
+        let mut weight_left = weights;
+        for column in &mut self.columns {
+            if let ColumnWidth::Weight(weight) = column.width {
+                let share =
+                    (remaining * weight).checked_div(weight_left).unwrap_or(0);
+                column.last_width = share;
+                remaining -= share;
+                weight_left -= weight;
+            }
+        }
+    }
+
+    fn inner_required_size(&mut self, constraint: Vec2) -> Vec2 {
+        let items = &self.items;
+        let width = self
+            .columns
+            .iter()
+            .map(|column| column.required_width(items))
+            .sum::<usize>()
+            + SEPARATOR_WIDTH * self.columns.len().saturating_sub(1);
+
+        // Weighted columns take all the available space.
+        let weighted = self
+            .columns
+            .iter()
+            .any(|column| matches!(column.width, ColumnWidth::Weight(_)));
+        let width = if weighted {
+            cmp::max(width, constraint.x)
+        } else {
+            width
+        };
+
+        Vec2::new(width, self.rows.len())
+    }
+
+    fn inner_on_event(&mut self, event: Event) -> EventResult {
+        if let Some(column) = self.header_focus {
+            return self.on_header_event(column, event);
+        }
+
+        let last_row = self.rows.len().saturating_sub(1);
+        match event {
+            Event::Key(Key::Up) if self.focus > 0 => self.focus -= 1,
+            Event::Key(Key::Up) if !self.columns.is_empty() => {
+                self.header_focus = Some(self.sort.map_or(0, |(i, _)| i));
+                return EventResult::Consumed(None);
+            }
+            Event::Key(Key::Down) if self.focus < last_row => self.focus += 1,
+            Event::Key(Key::PageUp) => {
+                self.focus = self.focus.saturating_sub(10)
+            }
+            Event::Key(Key::PageDown) => {
+                self.focus = cmp::min(self.focus + 10, last_row)
+            }
+            Event::Key(Key::Home) => self.focus = 0,
+            Event::Key(Key::End) => self.focus = last_row,
+            Event::Key(Key::Enter) if self.on_submit.is_some() => {
+                return self.submit();
+            }
+            Event::Mouse {
+                event: MouseEvent::Press(_),
+                position,
+                offset,
+            } if position
+                .checked_sub(offset)
+                .map(|position| position.y < self.rows.len())
+                .unwrap_or(false) =>
+            {
+                self.focus = position.y - offset.y;
+            }
+            Event::Mouse {
+                event: MouseEvent::Release(MouseButton::Left),
+                position,
+                offset,
+            } if self.on_submit.is_some()
+                && position
+                    .checked_sub(offset)
+                    .map(|position| position.y == self.focus)
+                    .unwrap_or(false) =>
+            {
+                return self.submit();
+            }
+            _ => return EventResult::Ignored,
+        }
+
+        EventResult::Consumed(self.select_cb())
+    }
+
+    fn on_header_event(&mut self, column: usize, event: Event) -> EventResult {
+        match event {
+            Event::Key(Key::Left) if column > 0 => {
+                self.header_focus = Some(column - 1)
+            }
+            Event::Key(Key::Right) if column + 1 < self.columns.len() => {
+                self.header_focus = Some(column + 1)
+            }
+            Event::Key(Key::Down) => self.header_focus = None,
+            Event::Key(Key::Enter) => return self.sort_on_click(column),
+            Event::Mouse { .. } => {
+                // Clicking anywhere in the rows leaves the header.
+                self.header_focus = None;
+                return self.inner_on_event(event);
+            }
+            _ => return EventResult::Ignored,
+        }
+
+        EventResult::Consumed(None)
+    }
+
+    fn inner_important_area(&self, size: Vec2) -> Rect {
+        if self.header_focus.is_some() {
+            Rect::from_size((0, 0), (size.x, 1))
+        } else {
+            Rect::from_size((0, self.focus), (size.x, 1))
+        }
+    }
+
+    fn draw_header(&self, printer: &Printer) {
+        let mut x = 0;
+        for (i, column) in self.columns.iter().enumerate() {
+            if i > 0 {
+                printer.print((x, 0), SEPARATOR);
+                printer.print_hline((x, 1), SEPARATOR_WIDTH, "─");
+                printer.print((x + 1, 1), "┼");
+                x += SEPARATOR_WIDTH;
+            }
+
+            let indicator = match self.sort {
+                Some((sorted, Ordering::Greater)) if sorted == i => "▼",
+                Some((sorted, _)) if sorted == i => "▲",
+                _ => "",
+            };
+
+            let style = if self.header_focus == Some(i) && printer.focused {
+                ColorStyle::highlight()
+            } else {
+                ColorStyle::title_primary()
+            };
+
+            let cell = printer.offset((x, 0)).cropped((column.last_width, 1));
+            cell.with_color(style, |printer| {
+                printer.print_hline((0, 0), printer.size.x, " ");
+                printer.print((0, 0), &column.title);
+            });
+            cell.print((column.title.width() + 1, 0), indicator);
+
+            printer.print_hline((x, 1), column.last_width, "─");
+            x += column.last_width;
+        }
+    }
+
+    fn draw_row(&self, printer: &Printer, row: usize) {
+        let item = &self.items[self.rows[row]];
+        let selected = row == self.focus && self.header_focus.is_none();
+
+        printer.with_selection(selected, |printer| {
+            printer.print_hline((0, 0), printer.size.x, " ");
+
+            let mut x = 0;
+            for (i, column) in self.columns.iter().enumerate() {
+                if i > 0 {
+                    printer.print((x, 0), SEPARATOR);
+                    x += SEPARATOR_WIDTH;
+                }
+
+                let value = item.to_column(column.column);
+                let offset =
+                    column.align.get_offset(value.width(), column.last_width);
+                printer
+                    .offset((x, 0))
+                    .cropped((column.last_width, 1))
+                    .print((offset, 0), &value);
+                x += column.last_width;
+            }
+        });
+    }
+}
+
+impl<T, H> View for TableView<T, H>
+where
+    T: TableViewItem<H> + 'static,
+    H: Copy + Eq + 'static,
+{
+    fn draw(&self, printer: &Printer) {
+        let printer = &printer.enabled(self.enabled);
+        self.draw_header(printer);
+
+        let body = printer.offset((0, HEADER_HEIGHT));
+        scroll::draw_lines(self, &body, |s, printer, row| {
+            if row < s.rows.len() {
+                s.draw_row(printer, row);
+            }
+        });
+    }
+
+    fn required_size(&mut self, constraint: Vec2) -> Vec2 {
+        scroll::required_size(
+            self,
+            constraint.saturating_sub((0, HEADER_HEIGHT)),
+            true,
+            Self::inner_required_size,
+        ) + (0, HEADER_HEIGHT)
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        scroll::layout(
+            self,
+            size.saturating_sub((0, HEADER_HEIGHT)),
+            true,
+            |s, size| s.layout_columns(size.x),
+            Self::inner_required_size,
+        );
+    }
+
+    fn take_focus(
+        &mut self,
+        _: Direction,
+    ) -> Result<EventResult, CannotFocus> {
+        (self.enabled && !self.columns.is_empty())
+            .then(EventResult::consumed)
+            .ok_or(CannotFocus)
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        if !self.enabled {
+            return EventResult::Ignored;
+        }
+
+        // Clicks on the header sort the rows.
+        if let Event::Mouse {
+            event: MouseEvent::Press(MouseButton::Left),
+            position,
+            offset,
+        } = event
+        {
+            if let Some(position) = position.checked_sub(offset) {
+                if position.y < HEADER_HEIGHT {
+                    return match self.column_at(position.x) {
+                        Some(i) => {
+                            self.header_focus = None;
+                            self.sort_on_click(i)
+                        }
+                        None => EventResult::Ignored,
+                    };
+                }
+            }
+        }
+
+        scroll::on_event(
+            self,
+            event.relativized((0, HEADER_HEIGHT)),
+            Self::inner_on_event,
+            Self::inner_important_area,
+        )
+    }
+
+    fn important_area(&self, size: Vec2) -> Rect {
+        if self.header_focus.is_some() {
+            return Rect::from_size((0, 0), (size.x, 1));
+        }
+
+        scroll::important_area(
+            self,
+            size.saturating_sub((0, HEADER_HEIGHT)),
+            Self::inner_important_area,
+        )
+        .with(|area| area.offset((0, HEADER_HEIGHT)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    enum Col {
+        Name,
+        Size,
+    }
+
+    impl TableViewItem<Col> for (&'static str, u32) {
+        fn to_column(&self, column: Col) -> String {
+            match column {
+                Col::Name => self.0.to_string(),
+                Col::Size => self.1.to_string(),
+            }
+        }
+
+        fn cmp(&self, other: &Self, column: Col) -> Ordering {
+            match column {
+                Col::Name => self.0.cmp(other.0),
+                Col::Size => self.1.cmp(&other.1),
+            }
+        }
+    }
+
+    fn table() -> TableView<(&'static str, u32), Col> {
+        TableView::new()
+            .column(Col::Name, "Name", ColumnWidth::Weight(1))
+            .column(Col::Size, "Size", ColumnWidth::Auto)
+            .items(vec![("b", 300), ("c", 10), ("a", 2000)])
+    }
+
+    // Names of the items, in row order.
+    fn names(
+        table: &TableView<(&'static str, u32), Col>,
+    ) -> Vec<&'static str> {
+        table.rows.iter().map(|&i| table.items[i].0).collect()
+    }
+
+    #[test]
+    fn sorting_keeps_selection() {
+        let mut table = table();
+        table.set_selected_item(1);
+
+        table.sort_by(Col::Name, Ordering::Less);
+        assert_eq!(names(&table), vec!["a", "b", "c"]);
+        assert_eq!(table.item(), Some(1));
+        assert_eq!(table.row(), Some(2));
+
+        table.sort_by(Col::Size, Ordering::Greater);
+        assert_eq!(names(&table), vec!["a", "b", "c"]);
+        table.sort_by(Col::Size, Ordering::Less);
+        assert_eq!(names(&table), vec!["c", "b", "a"]);
+
+        // New items follow the current order.
+        table.insert_item(("d", 50));
+        assert_eq!(names(&table), vec!["c", "d", "b", "a"]);
+        assert_eq!(table.item(), Some(1));
+
+        assert_eq!(table.remove_item(0), Some(("b", 300)));
+        assert_eq!(names(&table), vec!["c", "d", "a"]);
+        assert_eq!(table.item(), Some(0));
+    }
+
+    #[test]
+    fn column_widths() {
+        let mut table = table();
+        assert_eq!(table.required_size(Vec2::new(30, 10)), Vec2::new(30, 5));
+
+        // The weighted column takes the remaining space.
+        table.layout(Vec2::new(30, 10));
+        let widths: Vec<_> =
+            table.columns.iter().map(|c| c.last_width).collect();
+        assert_eq!(widths, vec![21, 6]);
+    }
+
+    #[test]
+    fn click_header_to_sort() {
+        let mut table = table();
+        table.layout(Vec2::new(30, 10));
+
+        let click = |x| Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(x, 0),
+            event: MouseEvent::Press(MouseButton::Left),
+        };
+
+        table.on_event(click(25));
+        assert_eq!(table.order(), Some((Col::Size, Ordering::Less)));
+        table.on_event(click(25));
+        assert_eq!(table.order(), Some((Col::Size, Ordering::Greater)));
+        table.on_event(click(0));
+        assert_eq!(table.order(), Some((Col::Name, Ordering::Less)));
+
+        // The header can also be used from the keyboard.
+        table.on_event(Event::Key(Key::Home));
+        table.on_event(Event::Key(Key::Up));
+        table.on_event(Event::Key(Key::Right));
+        table.on_event(Event::Key(Key::Enter));
+        assert_eq!(table.order(), Some((Col::Size, Ordering::Less)));
+    }
+}
@@ -1,17 +1,43 @@
+use crate::utils::width::TextWidth;
 #[allow(deprecated)]
 use crate::{
     direction::Direction,
-    event::{Event, EventResult, Key, MouseButton, MouseEvent},
+    event::{Callback, Event, EventResult, Key, MouseButton, MouseEvent},
     rect::Rect,
     theme::{ColorStyle, Effect},
     utils::lines::simple::{prefix, simple_prefix, LinesIterator, Row},
     view::{CannotFocus, ScrollBase, SizeCache, View},
-    Vec2, {Printer, With, XY},
+    Cursive, Vec2, {Printer, With, XY},
 };
 use log::debug;
 use std::cmp::min;
+use std::rc::Rc;
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
+
+/// Closure type for callbacks when the content is modified.
+///
+/// Arguments are the `Cursive`, the current content, and the cursor position
+/// as `(line, column)`.
+pub type OnEdit = dyn Fn(&mut Cursive, &str, (usize, usize));
+
+/// Closure type for callbacks when the content is submitted.
+///
+/// Arguments are the `Cursive` and the current content.
+pub type OnSubmit = dyn Fn(&mut Cursive, &str);
+
+/// How a `TextArea` breaks lines too long for its width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WrapMode {
+    /// Lines are never wrapped.
+    ///
+    /// Wrap the view in a `ScrollView` to reach the end of long lines.
+    NoWrap,
+    /// Lines are wrapped at any character.
+    CharWrap,
+    /// Lines are wrapped between words, when possible.
+    #[default]
+    WordWrap,
+}
 
 /// Multi-lines text editor.
 ///
@@ -19,6 +45,10 @@ use unicode_width::UnicodeWidthStr;
 /// dependent on the content.  Wrap it in a `ResizedView` to
 /// constrain its size.
 ///
+/// <Enter> inserts a new line, while <Ctrl-Enter> submits the content (see
+/// [`TextArea::set_on_submit`]). Note that some terminals cannot tell
+/// <Ctrl-Enter> apart from <Enter>.
+///
 /// # Examples
 ///
 /// ```
@@ -53,12 +83,70 @@ pub struct TextArea {
 
     /// Byte offset of the currently selected grapheme.
     cursor: usize,
+
+    wrap: WrapMode,
+
+    /// Maximum number of lines the user can enter.
+    max_lines: Option<usize>,
+
+    on_edit: Option<Rc<OnEdit>>,
+    on_submit: Option<Rc<OnSubmit>>,
 }
 
-fn make_rows(text: &str, width: usize) -> Vec<Row> {
+fn make_rows(text: &str, width: usize, wrap: WrapMode) -> Vec<Row> {
     // We can't make rows with width=0, so force at least width=1.
     let width = usize::max(width, 1);
-    LinesIterator::new(text, width).show_spaces().collect()
+    match wrap {
+        WrapMode::WordWrap => {
+            LinesIterator::new(text, width).show_spaces().collect()
+        }
+        WrapMode::CharWrap => split_lines(text)
+            .flat_map(|row| char_wrap(text, row, width))
+            .collect(),
+        WrapMode::NoWrap => split_lines(text).collect(),
+    }
+}
+
+// Returns one un-wrapped row per line of `text`.
+fn split_lines(text: &str) -> impl Iterator<Item = Row> + '_ {
+    let mut start = 0;
+    text.split('\n').map(move |line| {
+        let row = Row {
+            start,
+            end: start + line.len(),
+            width: line.width(),
+            is_wrapped: false,
+        };
+        start = row.end + 1;
+        row
+    })
+}
+
+// Splits `line` into rows of `width` cells or less.
+//
+// Like `show_spaces`, we keep a blank cell at the end of rows for the cursor.
+fn char_wrap(text: &str, line: Row, width: usize) -> Vec<Row> {
+    let available = usize::max(width - 1, 1);
+
+    let mut rows = Vec::new();
+    let mut start = line.start;
+    while start < line.end || rows.is_empty() {
+        let rest = &text[start..line.end];
+        let mut length = prefix(rest.graphemes(true), available, "").length;
+        if length == 0 && !rest.is_empty() {
+            // Always make progress, even with graphemes wider than the row.
+            length = rest.graphemes(true).next().unwrap().len();
+        }
+        let end = start + length;
+        rows.push(Row {
+            start,
+            end,
+            width: text[start..end].width(),
+            is_wrapped: end < line.end,
+        });
+        start = end;
+    }
+    rows
 }
 
 new_default!(TextArea);
@@ -75,6 +163,10 @@ impl TextArea {
             size_cache: None,
             last_size: Vec2::zero(),
             cursor: 0,
+            wrap: WrapMode::WordWrap,
+            max_lines: None,
+            on_edit: None,
+            on_submit: None,
         }
         .with(|area| area.compute_rows(Vec2::new(1, 1)))
         // Make sure we have valid rows, even for empty text.
@@ -137,6 +229,150 @@ impl TextArea {
         self.with(|s| s.set_content(content))
     }
 
+    /// Returns the position of the cursor as `(line, column)`.
+    ///
+    /// Lines are separated by newlines (not by wrapping), and the column is
+    /// counted in characters. Both start at 0.
+    pub fn cursor_position(&self) -> (usize, usize) {
+        let before = &self.content[..self.cursor];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line = before.matches('\n').count();
+        let col = before[line_start..].chars().count();
+        (line, col)
+    }
+
+    /// Sets how long lines are wrapped.
+    ///
+    /// Defaults to `WrapMode::WordWrap`.
+    pub fn set_wrap(&mut self, wrap: WrapMode) {
+        self.wrap = wrap;
+        if let Some(size) = self.size_cache.map(|s| s.map(|s| s.value)) {
+            self.invalidate();
+            self.compute_rows(size);
+        }
+    }
+
+    /// Sets how long lines are wrapped.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::traits::Scrollable;
+    /// use cursive_core::views::{TextArea, WrapMode};
+    ///
+    /// // Long lines can be reached by scrolling horizontally.
+    /// let text_area = TextArea::new()
+    ///     .wrap(WrapMode::NoWrap)
+    ///     .scrollable()
+    ///     .scroll_x(true);
+    /// ```
+    #[must_use]
+    pub fn wrap(self, wrap: WrapMode) -> Self {
+        self.with(|s| s.set_wrap(wrap))
+    }
+
+    /// Returns the current wrap mode.
+    pub fn get_wrap(&self) -> WrapMode {
+        self.wrap
+    }
+
+    /// Sets the maximum number of lines the user can enter.
+    ///
+    /// New lines are refused once the content has `max_lines` lines. This
+    /// does not affect [`TextArea::set_content`].
+    pub fn set_max_lines(&mut self, max_lines: usize) {
+        self.max_lines = Some(max_lines);
+    }
+
+    /// Sets the maximum number of lines the user can enter.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn max_lines(self, max_lines: usize) -> Self {
+        self.with(|s| s.set_max_lines(max_lines))
+    }
+
+    /// Returns the maximum number of lines, if any.
+    pub fn get_max_lines(&self) -> Option<usize> {
+        self.max_lines
+    }
+
+    /// Sets a callback to be called whenever the content is modified.
+    ///
+    /// `callback` will be called with the view content and the cursor
+    /// position as `(line, column)`.
+    pub fn set_on_edit<F>(&mut self, callback: F)
+    where
+        F: Fn(&mut Cursive, &str, (usize, usize)) + 'static,
+    {
+        self.on_edit = Some(Rc::new(callback));
+    }
+
+    /// Sets a callback to be called whenever the content is modified.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::TextArea;
+    ///
+    /// let text_area = TextArea::new().on_edit(|_s, _text, (line, col)| {
+    ///     println!("Cursor at {}:{}", line + 1, col + 1);
+    /// });
+    /// ```
+    #[must_use]
+    pub fn on_edit<F>(self, callback: F) -> Self
+    where
+        F: Fn(&mut Cursive, &str, (usize, usize)) + 'static,
+    {
+        self.with(|s| s.set_on_edit(callback))
+    }
+
+    /// Sets a callback to be called when <Ctrl-Enter> is pressed.
+    ///
+    /// `callback` will be given the content of the view.
+    pub fn set_on_submit<F>(&mut self, callback: F)
+    where
+        F: Fn(&mut Cursive, &str) + 'static,
+    {
+        self.on_submit = Some(Rc::new(callback));
+    }
+
+    /// Sets a callback to be called when <Ctrl-Enter> is pressed.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn on_submit<F>(self, callback: F) -> Self
+    where
+        F: Fn(&mut Cursive, &str) + 'static,
+    {
+        self.with(|s| s.set_on_submit(callback))
+    }
+
+    fn make_edit_cb(&self) -> Option<Callback> {
+        self.on_edit.clone().map(|cb| {
+            let content = self.content.clone();
+            let position = self.cursor_position();
+
+            Callback::from_fn(move |s| {
+                cb(s, &content, position);
+            })
+        })
+    }
+
+    // Returns `false` if a new line would go over `max_lines`.
+    fn can_add_line(&self) -> bool {
+        match self.max_lines {
+            Some(max_lines) => {
+                self.content.matches('\n').count() + 1 < max_lines
+            }
+            None => true,
+        }
+    }
+
     /// Disables this view.
     ///
     /// A disabled view cannot be selected.
@@ -308,13 +544,13 @@ impl TextArea {
 
         let mut available = size.x;
 
-        self.rows = make_rows(&self.content, available);
+        self.rows = make_rows(&self.content, available, self.wrap);
         self.fix_ghost_row();
 
         if self.rows.len() > size.y {
             available = available.saturating_sub(1);
             // Apparently we'll need a scrollbar. Doh :(
-            self.rows = make_rows(&self.content, available);
+            self.rows = make_rows(&self.content, available, self.wrap);
             self.fix_ghost_row();
         }
 
@@ -442,8 +678,11 @@ impl TextArea {
 
         // First attempt, if scrollbase status didn't change.
         debug!("Rows: {:?}", self.rows);
-        let new_rows =
-            make_rows(&self.content[first_byte..last_byte], available);
+        let new_rows = make_rows(
+            &self.content[first_byte..last_byte],
+            available,
+            self.wrap,
+        );
         // How much did this add?
         debug!("New rows: {:?}", new_rows);
         debug!("{}-{}", first_row, last_row);
@@ -543,9 +782,22 @@ impl View for TextArea {
         }
 
         let mut fix_scroll = true;
+        let content_len = self.content.len();
         match event {
+            Event::Char('\n') | Event::Key(Key::Enter)
+                if !self.can_add_line() =>
+            {
+                return EventResult::Consumed(None);
+            }
             Event::Char(ch) => self.insert(ch),
             Event::Key(Key::Enter) => self.insert('\n'),
+            Event::Ctrl(Key::Enter) if self.on_submit.is_some() => {
+                let cb = self.on_submit.clone().unwrap();
+                let content = self.content.clone();
+                return EventResult::with_cb(move |s| {
+                    cb(s, &content);
+                });
+            }
             Event::Key(Key::Backspace) if self.cursor > 0 => self.backspace(),
             Event::Key(Key::Del) if self.cursor < self.content.len() => {
                 self.delete()
@@ -640,7 +892,12 @@ impl View for TextArea {
             self.scrollbase.scroll_to(focus);
         }
 
-        EventResult::Consumed(None)
+        // Every edit changes the length of the content.
+        if self.content.len() != content_len {
+            EventResult::Consumed(self.make_edit_cb())
+        } else {
+            EventResult::Consumed(None)
+        }
     }
 
     fn take_focus(
@@ -677,3 +934,39 @@ impl View for TextArea {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(text: &str, width: usize, wrap: WrapMode) -> Vec<&str> {
+        make_rows(text, width, wrap)
+            .into_iter()
+            .map(|row| &text[row.start..row.end])
+            .collect()
+    }
+
+    #[test]
+    fn wrap_modes() {
+        let text = "abc defgh\n\nij";
+
+        assert_eq!(
+            rows(text, 5, WrapMode::CharWrap),
+            vec!["abc ", "defg", "h", "", "ij"]
+        );
+        assert_eq!(
+            rows(text, 5, WrapMode::NoWrap),
+            vec!["abc defgh", "", "ij"]
+        );
+    }
+
+    #[test]
+    fn max_lines() {
+        let mut text_area = TextArea::new().content("a\nb").max_lines(2);
+        text_area.set_cursor(3);
+
+        text_area.on_event(Event::Key(Key::Enter));
+        assert_eq!(text_area.get_content(), "a\nb");
+        assert_eq!(text_area.cursor_position(), (1, 1));
+    }
+}
@@ -1,20 +1,32 @@
 use std::ops::Deref;
+use std::rc::Rc;
 use std::sync::Arc;
 use std::sync::{Mutex, MutexGuard};
 
+use crate::utils::width::TextWidth;
 use owning_ref::{ArcRef, OwningHandle};
-use unicode_width::UnicodeWidthStr;
 
 use crate::align::*;
+use crate::direction::Direction;
+use crate::event::{Event, EventResult, MouseButton, MouseEvent};
+use crate::rect::Rect;
 use crate::theme::{Effect, Style};
-use crate::utils::lines::spans::{LinesIterator, Row};
+use crate::utils::bidi;
+use crate::utils::lines::simple::TabStops;
+use crate::utils::lines::spans::{LinesIterator, Row, WrapPolicy};
+#[cfg(feature = "markdown")]
+use crate::utils::markup::markdown_lite;
 use crate::utils::markup::StyledString;
-use crate::view::{SizeCache, View};
-use crate::{Printer, Vec2, With, XY};
+use crate::utils::span::SpanAttrs;
+use crate::view::{CannotFocus, SearchMatch, Searchable, SizeCache, View};
+use crate::{Cursive, Printer, Vec2, With, XY};
 
 // Content type used internally for caching and storage
 type InnerContentType = Arc<StyledString>;
 
+// Callback for clicks on spans with attached data.
+type OnSpanClick = dyn Fn(&mut Cursive, &SpanAttrs);
+
 /// Provides access to the content of a [`TextView`].
 ///
 /// [`TextView`]: struct.TextView.html
@@ -52,6 +64,7 @@ impl TextContent {
                 content_value: content,
                 content_cache: Arc::new(StyledString::default()),
                 size_cache: None,
+                version: 0,
             })),
         }
     }
@@ -131,6 +144,11 @@ impl TextContent {
         let out = f(&mut content);
 
         content.size_cache = None;
+        content.version = content.version.wrapping_add(1);
+
+        // The content may be updated from another thread: make sure the
+        // change gets drawn.
+        crate::request_redraw();
 
         out
     }
@@ -148,6 +166,9 @@ struct TextContentInner {
 
     // We keep the cache here so it can be busted when we change the content.
     size_cache: Option<XY<SizeCache>>,
+
+    // Incremented whenever the content changes.
+    version: usize,
 }
 
 impl TextContentInner {
@@ -195,13 +216,32 @@ pub struct TextView {
 
     align: Align,
 
+    direction: TextDirection,
+
     style: Style,
 
-    // True if we can wrap long lines.
-    wrap: bool,
+    // How to wrap long lines.
+    wrap_policy: WrapPolicy,
+
+    // True if a hyphen is shown when a word is split.
+    hyphenation: bool,
+
+    // If set, tabs are expanded to the next tab stop.
+    tab_stops: Option<TabStops>,
 
     // ScrollBase make many scrolling-related things easier
     width: Option<usize>,
+
+    // Line of the last search match revealed, if any.
+    revealed_line: Option<usize>,
+
+    // Version of the content when `revealed_line` was set.
+    revealed_version: usize,
+
+    // Called when a span with attached data is clicked.
+    on_span_click: Option<Rc<OnSpanClick>>,
+
+    last_size: Vec2,
 }
 
 impl TextView {
@@ -234,12 +274,39 @@ impl TextView {
             content,
             style: Style::default(),
             rows: Vec::new(),
-            wrap: true,
+            wrap_policy: WrapPolicy::Word,
+            hyphenation: false,
+            tab_stops: None,
             align: Align::top_left(),
+            direction: TextDirection::Ltr,
             width: None,
+            revealed_line: None,
+            revealed_version: 0,
+            on_span_click: None,
+            last_size: Vec2::zero(),
         }
     }
 
+    /// Creates a new TextView rendering the given markdown text.
+    ///
+    /// Only a subset of markdown is supported: see
+    /// [`markdown_lite`](crate::utils::markup::markdown_lite).
+    ///
+    /// Needs the `markdown` feature to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::views::TextView;
+    /// let view = TextView::from_markdown("Press **q** to quit.");
+    /// assert_eq!(view.get_content().source(), "Press q to quit.");
+    /// ```
+    #[cfg(feature = "markdown")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "markdown")))]
+    pub fn from_markdown(content: &str) -> Self {
+        Self::new(markdown_lite::parse(content))
+    }
+
     /// Creates a new empty `TextView`.
     pub fn empty() -> Self {
         TextView::new("")
@@ -284,8 +351,203 @@ impl TextView {
     /// Controls content wrap for this view.
     ///
     /// If `true` (the default), text will wrap long lines when needed.
+    ///
+    /// This is a shortcut for [`set_wrap_policy`](Self::set_wrap_policy):
+    /// `false` selects `WrapPolicy::NoWrap`, and `true` selects
+    /// `WrapPolicy::Word` if lines were not wrapped before.
     pub fn set_content_wrap(&mut self, wrap: bool) {
-        self.wrap = wrap;
+        if !wrap {
+            self.set_wrap_policy(WrapPolicy::NoWrap);
+        } else if self.wrap_policy == WrapPolicy::NoWrap {
+            self.set_wrap_policy(WrapPolicy::Word);
+        }
+    }
+
+    /// Sets how long lines are wrapped.
+    ///
+    /// Defaults to `WrapPolicy::Word`. Use `WrapPolicy::WordOrPunctuation`
+    /// to break long words like paths or URLs after a `/`, `-`, `_` or `.`
+    /// when possible.
+    pub fn set_wrap_policy(&mut self, policy: WrapPolicy) {
+        self.wrap_policy = policy;
+
+        // Bust the cache, rows need to be computed again.
+        self.content.content.lock().unwrap().size_cache = None;
+    }
+
+    /// Sets how long lines are wrapped.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::utils::lines::spans::WrapPolicy;
+    /// use cursive_core::views::TextView;
+    ///
+    /// let view = TextView::new("some/long/path")
+    ///     .wrap_policy(WrapPolicy::WordOrPunctuation);
+    /// assert_eq!(view.get_wrap_policy(), WrapPolicy::WordOrPunctuation);
+    /// ```
+    #[must_use]
+    pub fn wrap_policy(self, policy: WrapPolicy) -> Self {
+        self.with(|s| s.set_wrap_policy(policy))
+    }
+
+    /// Returns the current wrap policy.
+    pub fn get_wrap_policy(&self) -> WrapPolicy {
+        self.wrap_policy
+    }
+
+    /// Shows a hyphen at the end of rows where a word was split.
+    ///
+    /// Words split after a `/`, `-`, `_` or `.` don't need a hyphen.
+    /// Defaults to `false`.
+    pub fn set_hyphenation(&mut self, hyphenation: bool) {
+        self.hyphenation = hyphenation;
+
+        // Bust the cache, rows need to be computed again.
+        self.content.content.lock().unwrap().size_cache = None;
+    }
+
+    /// Shows a hyphen at the end of rows where a word was split.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn hyphenation(self, hyphenation: bool) -> Self {
+        self.with(|s| s.set_hyphenation(hyphenation))
+    }
+
+    /// Sets the width of tab stops for this view.
+    ///
+    /// With `Some(width)`, each `\t` is expanded with spaces up to the next
+    /// multiple of `width` columns. With `None` (the default), tabs are
+    /// printed as-is.
+    pub fn set_tab_width(&mut self, tab_width: Option<usize>) {
+        self.set_tab_stops_inner(tab_width.map(TabStops::Every));
+    }
+
+    /// Sets the width of tab stops for this view.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn tab_width(self, tab_width: usize) -> Self {
+        self.with(|s| s.set_tab_width(Some(tab_width)))
+    }
+
+    /// Places tab stops at the given columns.
+    ///
+    /// Each `\t` is expanded with spaces up to the next stop, so that
+    /// tab-separated content lines up in columns. Past the last stop, a tab
+    /// is replaced by a single space.
+    ///
+    /// Tabs are expanded before lines are wrapped: use [`TextView::no_wrap`]
+    /// (maybe inside a `ScrollView`) to keep wide tables aligned.
+    pub fn set_tab_stops(&mut self, stops: &[usize]) {
+        let mut stops = stops.to_vec();
+        stops.sort_unstable();
+        self.set_tab_stops_inner(Some(TabStops::At(stops)));
+    }
+
+    /// Places tab stops at the given columns.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::views::TextView;
+    /// let view = TextView::new("PID\tCOMMAND\n1\tinit").tab_stops(&[8]);
+    /// ```
+    #[must_use]
+    pub fn tab_stops(self, stops: &[usize]) -> Self {
+        self.with(|s| s.set_tab_stops(stops))
+    }
+
+    /// Computes tab stops from the content, like `column -t`.
+    ///
+    /// Each tab-separated column is as wide as its widest cell, plus a
+    /// 2-spaces gap. Tab stops are updated when the content changes.
+    pub fn set_auto_tab_stops(&mut self) {
+        self.set_tab_stops_inner(Some(TabStops::Auto));
+    }
+
+    /// Computes tab stops from the content, like `column -t`.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::views::TextView;
+    /// let view = TextView::new("NAME\tSIZE\nREADME.md\t4096")
+    ///     .auto_tab_stops()
+    ///     .no_wrap();
+    /// ```
+    #[must_use]
+    pub fn auto_tab_stops(self) -> Self {
+        self.with(TextView::set_auto_tab_stops)
+    }
+
+    /// Returns the tab stops used by this view, if tabs are expanded.
+    pub fn get_tab_stops(&self) -> Option<&TabStops> {
+        self.tab_stops.as_ref()
+    }
+
+    fn set_tab_stops_inner(&mut self, tab_stops: Option<TabStops>) {
+        self.tab_stops = tab_stops;
+
+        // Bust the cache, we need to expand the content again.
+        self.content.content.lock().unwrap().size_cache = None;
+    }
+
+    /// Sets a callback to run when a span with attached data is clicked.
+    ///
+    /// The callback receives the data attached to the clicked span with
+    /// [`SpannedString::set_attrs`]. Clicks on other spans are ignored.
+    ///
+    /// When a callback is set, this view can take focus, so it receives
+    /// mouse events from its parent.
+    ///
+    /// [`SpannedString::set_attrs`]: crate::utils::span::SpannedString::set_attrs
+    pub fn set_on_span_click<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, &SpanAttrs) + 'static,
+    {
+        self.on_span_click = Some(Rc::new(cb));
+    }
+
+    /// Sets a callback to run when a span with attached data is clicked.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::utils::markup::StyledString;
+    /// use cursive_core::utils::span::SpanAttrs;
+    /// use cursive_core::views::{Dialog, TextView};
+    ///
+    /// struct Url(&'static str);
+    ///
+    /// let mut text = StyledString::plain("Read ");
+    /// text.append(
+    ///     StyledString::plain("the docs")
+    ///         .with_attrs(SpanAttrs::new().with_value(Url("https://docs.rs"))),
+    /// );
+    ///
+    /// let view = TextView::new(text).on_span_click(|s, attrs| {
+    ///     if let Some(Url(url)) = attrs.get::<Url>() {
+    ///         s.add_layer(Dialog::info(*url));
+    ///     }
+    /// });
+    /// ```
+    #[must_use]
+    pub fn on_span_click<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, &SpanAttrs) + 'static,
+    {
+        self.with(|s| s.set_on_span_click(cb))
     }
 
     /// Sets the horizontal alignment for this view.
@@ -312,6 +574,33 @@ impl TextView {
         self
     }
 
+    /// Sets the direction of the text.
+    ///
+    /// With `TextDirection::Rtl`, the horizontal alignment is mirrored (so
+    /// lines start on the right by default), and each line is displayed in
+    /// right-to-left order. Latin words and numbers inside such lines keep
+    /// their left-to-right order.
+    ///
+    /// With `TextDirection::Auto`, the direction comes from the first strong
+    /// character of the content. In any direction, right-to-left words
+    /// inside left-to-right lines are shown in visual order.
+    pub fn set_text_direction(&mut self, direction: TextDirection) {
+        self.direction = direction;
+    }
+
+    /// Sets the direction of the text.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn text_direction(self, direction: TextDirection) -> Self {
+        self.with(|s| s.set_text_direction(direction))
+    }
+
+    /// Returns the direction of the text.
+    pub fn get_text_direction(&self) -> TextDirection {
+        self.direction
+    }
+
     /// Center the text horizontally and vertically inside the view.
     #[must_use]
     pub fn center(mut self) -> Self {
@@ -363,9 +652,19 @@ impl TextView {
     // This must be non-destructive, as it may be called
     // multiple times during layout.
     fn compute_rows(&mut self, size: Vec2) {
-        let size = if self.wrap { size } else { Vec2::max_value() };
+        let size = if self.wrap_policy == WrapPolicy::NoWrap {
+            Vec2::max_value()
+        } else {
+            size
+        };
 
         let mut content = self.content.content.lock().unwrap();
+
+        // The revealed line may not exist anymore.
+        if content.version != self.revealed_version {
+            self.revealed_line = None;
+        }
+
         if content.is_cache_valid(size) {
             return;
         }
@@ -373,15 +672,25 @@ impl TextView {
         // Completely bust the cache
         // Just in case we fail, we don't want to leave a bad cache.
         content.size_cache = None;
-        content.content_cache = Arc::clone(&content.content_value);
+        content.content_cache = match self.tab_stops {
+            Some(ref stops) => {
+                Arc::new(content.content_value.expand_tab_stops(stops))
+            }
+            None => Arc::clone(&content.content_value),
+        };
 
         if size.x == 0 {
             // Nothing we can do at this point.
             return;
         }
 
-        self.rows =
-            LinesIterator::new(content.get_cache().as_ref(), size.x).collect();
+        let lines = LinesIterator::new(content.get_cache().as_ref(), size.x)
+            .wrap_policy(self.wrap_policy);
+        self.rows = if self.hyphenation {
+            lines.hyphenate().collect()
+        } else {
+            lines.collect()
+        };
 
         // Desired width
         self.width = if self.rows.iter().any(|row| row.is_wrapped) {
@@ -391,6 +700,58 @@ impl TextView {
             self.rows.iter().map(|row| row.width).max()
         }
     }
+
+    // Returns the data attached to the span drawn at `position`, if any.
+    fn attrs_at(&self, position: Vec2) -> Option<SpanAttrs> {
+        let offset =
+            self.align.v.get_offset(self.rows.len(), self.last_size.y);
+        let row = self.rows.get(position.y.checked_sub(offset)?)?;
+
+        let content = self.content.content.lock().unwrap();
+        let cache = content.get_cache();
+        let direction = self.direction.resolve(cache.source());
+        let h_align = direction.align(self.align.h);
+
+        let mut x = h_align.get_offset(row.width, self.last_size.x);
+        if row.hyphenated && direction == TextDirection::Rtl {
+            x += 1;
+        }
+
+        // Segments are looked up in logical order; this is only exact when
+        // the row doesn't need bidi reordering.
+        let mut column = position.x.checked_sub(x)?;
+        for segment in &row.segments {
+            if column < segment.width {
+                return cache.spans_raw()[segment.span_id].attrs.clone();
+            }
+            column -= segment.width;
+        }
+        None
+    }
+}
+
+impl Searchable for TextView {
+    /// Searches the content, line by line.
+    ///
+    /// The position of each match is the line number.
+    fn search(&self, query: &str) -> Vec<SearchMatch> {
+        SearchMatch::in_lines(self.get_content().source(), query)
+    }
+
+    /// Makes the matching line the important area of this view.
+    ///
+    /// A parent `ScrollView` can then scroll to it. This is forgotten when
+    /// the content changes.
+    fn reveal(&mut self, found: &SearchMatch) -> EventResult {
+        let content = self.content.content.lock().unwrap();
+        if found.position >= content.content_value.source().lines().count() {
+            return EventResult::Ignored;
+        }
+
+        self.revealed_line = Some(found.position);
+        self.revealed_version = content.version;
+        EventResult::Consumed(None)
+    }
 }
 
 impl View for TextView {
@@ -402,6 +763,10 @@ impl View for TextView {
 
         let content = self.content.content.lock().unwrap();
 
+        let cache = content.get_cache();
+        let direction = self.direction.resolve(cache.source());
+        let h_align = direction.align(self.align.h);
+
         printer.with_style(self.style, |printer| {
             for (y, row) in self
                 .rows
@@ -411,9 +776,34 @@ impl View for TextView {
                 .take(printer.output_size.y)
             {
                 let l = row.width;
-                let mut x = self.align.h.get_offset(l, printer.size.x);
+                let mut x = h_align.get_offset(l, printer.size.x);
+
+                if row.hyphenated {
+                    // The hyphen goes after the text, in reading order.
+                    if direction == TextDirection::Rtl {
+                        printer.print((x, y), "-");
+                        x += 1;
+                    } else {
+                        printer.print((x + l - 1, y), "-");
+                    }
+                }
+
+                let spans = row.resolve(cache.as_ref());
+                let needs_reorder = spans
+                    .iter()
+                    .any(|span| bidi::needs_reorder(span.content, direction));
+                if needs_reorder {
+                    // Print grapheme by grapheme, in visual order.
+                    bidi::print_styled(
+                        printer,
+                        Vec2::new(x, y),
+                        spans.iter().map(|span| (span.content, *span.attr)),
+                        direction,
+                    );
+                    continue;
+                }
 
-                for span in row.resolve(content.get_cache().as_ref()) {
+                for span in spans {
                     printer.with_style(*span.attr, |printer| {
                         printer.print((x, y), span.content);
                         x += span.content.width();
@@ -423,6 +813,67 @@ impl View for TextView {
         });
     }
 
+    fn important_area(&self, size: Vec2) -> Rect {
+        let line = match self.revealed_line {
+            Some(line) => line,
+            None => return Rect::from_size(Vec2::zero(), size),
+        };
+
+        // A new line starts after each row that wasn't wrapped.
+        let mut current = 0;
+        let row = self.rows.iter().position(|row| {
+            let found = current == line;
+            if !row.is_wrapped {
+                current += 1;
+            }
+            found
+        });
+
+        match row {
+            Some(row) => {
+                let offset = self.align.v.get_offset(self.rows.len(), size.y);
+                Rect::from_size((0, offset + row), (size.x, 1))
+            }
+            None => Rect::from_size(Vec2::zero(), size),
+        }
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        let cb = match self.on_span_click {
+            Some(ref cb) => Rc::clone(cb),
+            None => return EventResult::Ignored,
+        };
+
+        match event {
+            Event::Mouse {
+                event: MouseEvent::Release(MouseButton::Left),
+                position,
+                offset,
+            } => {
+                match position
+                    .checked_sub(offset)
+                    .and_then(|p| self.attrs_at(p))
+                {
+                    Some(attrs) => {
+                        EventResult::with_cb(move |s| cb(s, &attrs))
+                    }
+                    None => EventResult::Ignored,
+                }
+            }
+            _ => EventResult::Ignored,
+        }
+    }
+
+    fn take_focus(
+        &mut self,
+        _: Direction,
+    ) -> Result<EventResult, CannotFocus> {
+        self.on_span_click
+            .is_some()
+            .then(EventResult::consumed)
+            .ok_or(CannotFocus)
+    }
+
     fn needs_relayout(&self) -> bool {
         let content = self.content.content.lock().unwrap();
         content.size_cache.is_none()
@@ -438,6 +889,8 @@ impl View for TextView {
         // Compute the text rows.
         self.compute_rows(size);
 
+        self.last_size = size;
+
         // The entire "virtual" size (includes all rows)
         let my_size = Vec2::new(self.width.unwrap_or(0), self.rows.len());
 
@@ -446,3 +899,119 @@ impl View for TextView {
         content.size_cache = Some(SizeCache::build(my_size, size));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn click(view: &mut TextView, position: Vec2) -> EventResult {
+        view.on_event(Event::Mouse {
+            offset: Vec2::zero(),
+            position,
+            event: MouseEvent::Release(MouseButton::Left),
+        })
+    }
+
+    #[test]
+    fn span_click() {
+        let mut text = StyledString::plain("Hello ");
+        text.append(
+            StyledString::plain("big world")
+                .with_attrs(SpanAttrs::new().with_value(7usize)),
+        );
+
+        let clicked = Rc::new(Cell::new(None));
+        let mut view = TextView::new(text).on_span_click({
+            let clicked = Rc::clone(&clicked);
+            move |_, attrs| clicked.set(attrs.get::<usize>().copied())
+        });
+        assert!(view.take_focus(Direction::none()).is_ok());
+
+        // "Hello big" fits on the first row, "world" wraps on the second.
+        view.layout(Vec2::new(10, 2));
+        assert!(matches!(
+            click(&mut view, Vec2::new(2, 0)),
+            EventResult::Ignored
+        ));
+
+        let mut siv = Cursive::new();
+        for &position in &[Vec2::new(7, 0), Vec2::new(3, 1)] {
+            clicked.set(None);
+            click(&mut view, position).process(&mut siv);
+            assert_eq!(clicked.get(), Some(7));
+        }
+        assert!(matches!(
+            click(&mut view, Vec2::new(6, 1)),
+            EventResult::Ignored
+        ));
+    }
+
+    #[test]
+    fn auto_tab_stops() {
+        let mut view = TextView::new("a\tb\nccc\td").auto_tab_stops();
+        assert_eq!(view.required_size(Vec2::new(20, 5)), Vec2::new(6, 2));
+        assert_eq!(
+            view.content.content.lock().unwrap().get_cache().source(),
+            "a    b\nccc  d"
+        );
+
+        // Columns are computed again when the content changes.
+        view.set_content("a\tb");
+        assert_eq!(view.required_size(Vec2::new(20, 5)), Vec2::new(4, 1));
+
+        view.set_tab_stops(&[8]);
+        assert_eq!(view.required_size(Vec2::new(20, 5)), Vec2::new(9, 1));
+    }
+
+    #[test]
+    fn reveal_line() {
+        use crate::view::{Nameable, Resizable};
+        use crate::views::{NamedView, ScrollView};
+
+        let lines: Vec<String> =
+            (0..20).map(|i| format!("line {}", i)).collect();
+        let content = TextContent::new(lines.join("\n"));
+
+        let mut siv = Cursive::new();
+        siv.add_fullscreen_layer(
+            ScrollView::new(
+                TextView::new_with_content(content.clone()).with_name("text"),
+            )
+            .with_name("scroll")
+            .fixed_height(3),
+        );
+        siv.register_searchable::<TextView>("text");
+        siv.layout(Vec2::new(20, 3));
+
+        let hits = siv.search("line 15");
+        assert_eq!(hits.len(), 1);
+        (hits[0].callback)(&mut siv);
+
+        let offset = siv
+            .call_on_name(
+                "scroll",
+                |v: &mut ScrollView<NamedView<TextView>>| v.content_viewport(),
+            )
+            .unwrap();
+        assert!(offset.top() <= 15 && 15 <= offset.bottom());
+
+        let revealed = |siv: &mut Cursive| {
+            siv.call_on_name("text", |v: &mut TextView| v.revealed_line)
+                .unwrap()
+        };
+        assert_eq!(revealed(&mut siv), Some(15));
+
+        // A missing line is not revealed.
+        let missing = SearchMatch::new(42, "");
+        let result = siv
+            .call_on_name("text", |v: &mut TextView| v.reveal(&missing))
+            .unwrap();
+        assert!(!result.is_consumed());
+
+        // Changing the content forgets the revealed line.
+        content.set_content("short");
+        siv.layout(Vec2::new(20, 3));
+        assert_eq!(revealed(&mut siv), None);
+    }
+}
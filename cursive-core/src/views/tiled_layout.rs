@@ -0,0 +1,645 @@
+use crate::{
+    direction::{Absolute, Direction, Orientation, Relative},
+    event::{AnyCb, Event, EventResult, Key},
+    rect::Rect,
+    view::{CannotFocus, IntoBoxedView, Selector, ViewNotFound},
+    Printer, Vec2, View, With,
+};
+use std::collections::HashMap;
+
+/// Identifies a slot in a [`TiledLayout`].
+pub type SlotId = String;
+
+/// Describes how a [`TiledLayout`] is split into slots.
+///
+/// This is a tree of splits. Each pane of a split has a weight: panes share
+/// the available length in proportion to their weight.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::Layout;
+///
+/// // A main panel with a sidebar, above a status bar.
+/// let layout = Layout::vertical(vec![
+///     (
+///         9.0,
+///         Layout::horizontal(vec![
+///             (3.0, Layout::slot("main")),
+///             (1.0, Layout::slot("sidebar")),
+///         ]),
+///     ),
+///     (1.0, Layout::slot("status")),
+/// ]);
+///
+/// assert_eq!(layout.slots(), vec!["main", "sidebar", "status"]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Layout {
+    /// A single pane, showing the view for this slot.
+    Slot(SlotId),
+    /// Panes laid out next to each other.
+    Split {
+        /// Direction in which the panes are laid out.
+        orientation: Orientation,
+        /// Weight and layout of each pane.
+        panes: Vec<(f32, Layout)>,
+    },
+}
+
+impl Layout {
+    /// Returns a layout with a single slot.
+    pub fn slot<S: Into<SlotId>>(id: S) -> Self {
+        Layout::Slot(id.into())
+    }
+
+    /// Returns a split with panes side by side.
+    pub fn horizontal(panes: Vec<(f32, Layout)>) -> Self {
+        Layout::Split {
+            orientation: Orientation::Horizontal,
+            panes,
+        }
+    }
+
+    /// Returns a split with panes stacked on top of each other.
+    pub fn vertical(panes: Vec<(f32, Layout)>) -> Self {
+        Layout::Split {
+            orientation: Orientation::Vertical,
+            panes,
+        }
+    }
+
+    /// Returns the slots in this layout, in order.
+    pub fn slots(&self) -> Vec<&str> {
+        let mut slots = Vec::new();
+        self.for_each_slot(&mut |id| slots.push(id));
+        slots
+    }
+
+    fn for_each_slot<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        match *self {
+            Layout::Slot(ref id) => f(id),
+            Layout::Split { ref panes, .. } => {
+                for (_, pane) in panes {
+                    pane.for_each_slot(f);
+                }
+            }
+        }
+    }
+
+    /// Computes the area of each slot when laid out in `area`.
+    ///
+    /// Slots are returned in order. Negative weights count as zero. Slots
+    /// getting no space at all, for instance because of a zero weight, are
+    /// left out.
+    pub fn areas(&self, area: Rect) -> Vec<(&str, Rect)> {
+        let mut areas = Vec::new();
+        self.compute_areas(area, &mut areas);
+        areas
+    }
+
+    fn compute_areas<'a>(
+        &'a self,
+        area: Rect,
+        out: &mut Vec<(&'a str, Rect)>,
+    ) {
+        let (orientation, panes) = match *self {
+            Layout::Slot(ref id) => {
+                out.push((id, area));
+                return;
+            }
+            Layout::Split {
+                orientation,
+                ref panes,
+            } => (orientation, panes),
+        };
+
+        let length = *area.size().get(orientation);
+        let total: f32 = panes.iter().map(|(weight, _)| weight.max(0.0)).sum();
+
+        // Place each pane at its cumulative fraction of the length, so the
+        // rounding errors don't add up.
+        let position = |sum: f32| {
+            if total > 0.0 {
+                (sum / total * length as f32).round() as usize
+            } else {
+                0
+            }
+        };
+
+        let mut sum = 0.0;
+        for (weight, pane) in panes {
+            let start = position(sum);
+            sum += weight.max(0.0);
+            let end = position(sum);
+            if end == start {
+                continue;
+            }
+
+            let offset = area.top_left() + orientation.make_vec(start, 0);
+            let mut size = area.size();
+            *orientation.get_ref(&mut size) = end - start;
+            pane.compute_areas(Rect::from_size(offset, size), out);
+        }
+    }
+}
+
+/// Arranges views in tiles, like a tiling window manager.
+///
+/// The tiles are described by a [`Layout`], and each view is attached to a
+/// slot of this layout. The tiles are resized proportionally with the view.
+///
+/// Slots without a view are left blank.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{Layout, TextView, TiledLayout};
+///
+/// let layout = Layout::vertical(vec![
+///     (
+///         9.0,
+///         Layout::horizontal(vec![
+///             (3.0, Layout::slot("main")),
+///             (1.0, Layout::slot("sidebar")),
+///         ]),
+///     ),
+///     (1.0, Layout::slot("status")),
+/// ]);
+///
+/// let mut tiles = TiledLayout::new(layout)
+///     .slot("main", TextView::new("Content"))
+///     .slot("sidebar", TextView::new("Files"))
+///     .slot("status", TextView::new("Ready"));
+///
+/// // Later, swap a single panel.
+/// tiles.replace_slot("sidebar", TextView::new("Outline"));
+/// ```
+pub struct TiledLayout {
+    layout: Layout,
+    views: HashMap<SlotId, Box<dyn View>>,
+
+    // Slots in layout order, with their area from the last layout.
+    //
+    // Slots without any space are not included.
+    areas: Vec<(SlotId, Rect)>,
+
+    // Size given during the last layout.
+    size: Vec2,
+
+    // Index in `areas` of the focused slot.
+    focus: usize,
+}
+
+impl TiledLayout {
+    /// Creates a new layout with no views.
+    pub fn new(layout: Layout) -> Self {
+        TiledLayout {
+            areas: Vec::new(),
+            size: Vec2::zero(),
+            layout,
+            views: HashMap::new(),
+            focus: 0,
+        }
+    }
+
+    fn compute_areas(layout: &Layout, size: Vec2) -> Vec<(SlotId, Rect)> {
+        if size.x == 0 || size.y == 0 {
+            return Vec::new();
+        }
+
+        layout
+            .areas(Rect::from_size((0, 0), size))
+            .into_iter()
+            .map(|(id, area)| (id.to_string(), area))
+            .collect()
+    }
+
+    /// Returns the current layout description.
+    pub fn get_layout(&self) -> &Layout {
+        &self.layout
+    }
+
+    /// Sets a new layout description.
+    ///
+    /// Views are kept in their slot. Views for slots absent from the new
+    /// layout are kept too, but are not shown.
+    pub fn set_layout(&mut self, layout: Layout) {
+        self.layout = layout;
+        self.update_areas();
+    }
+
+    // Recomputes the areas for the current layout and size.
+    //
+    // The focus stays on the same slot, if it still has some space.
+    fn update_areas(&mut self) {
+        let focused = self.focused_slot().map(str::to_string);
+
+        self.areas = Self::compute_areas(&self.layout, self.size);
+        self.focus = focused.and_then(|id| self.position(&id)).unwrap_or(0);
+    }
+
+    /// Attaches a view to the given slot.
+    ///
+    /// Chainable variant of [`replace_slot`](Self::replace_slot).
+    #[must_use]
+    pub fn slot<S, V>(self, id: S, view: V) -> Self
+    where
+        S: Into<SlotId>,
+        V: IntoBoxedView,
+    {
+        self.with(|s| {
+            s.replace_slot(id, view);
+        })
+    }
+
+    /// Attaches a view to the given slot.
+    ///
+    /// Returns the view previously in this slot, if any. The rest of the
+    /// layout is left untouched.
+    pub fn replace_slot<S, V>(
+        &mut self,
+        id: S,
+        view: V,
+    ) -> Option<Box<dyn View>>
+    where
+        S: Into<SlotId>,
+        V: IntoBoxedView,
+    {
+        self.views.insert(id.into(), view.into_boxed_view())
+    }
+
+    /// Removes the view from the given slot, and returns it.
+    pub fn remove_slot(&mut self, id: &str) -> Option<Box<dyn View>> {
+        self.views.remove(id)
+    }
+
+    /// Returns a reference to the view in the given slot.
+    pub fn get_slot(&self, id: &str) -> Option<&dyn View> {
+        self.views.get(id).map(|view| &**view)
+    }
+
+    /// Returns a mutable reference to the view in the given slot.
+    pub fn get_slot_mut(&mut self, id: &str) -> Option<&mut dyn View> {
+        self.views.get_mut(id).map(|view| &mut **view)
+    }
+
+    /// Returns the area of the given slot, from the last layout.
+    ///
+    /// Returns `None` if the slot got no space.
+    pub fn slot_area(&self, id: &str) -> Option<Rect> {
+        self.position(id).map(|i| self.areas[i].1)
+    }
+
+    /// Returns the id of the focused slot.
+    pub fn focused_slot(&self) -> Option<&str> {
+        self.areas.get(self.focus).map(|(id, _)| id.as_str())
+    }
+
+    /// Attempts to give the focus to the view in the given slot.
+    pub fn focus_slot(
+        &mut self,
+        id: &str,
+    ) -> Result<EventResult, ViewNotFound> {
+        let i = self.position(id).ok_or(ViewNotFound)?;
+        let res = self
+            .views
+            .get_mut(id)
+            .and_then(|view| view.take_focus(Direction::none()).ok())
+            .ok_or(ViewNotFound)?;
+        Ok(self.set_focus_unchecked(i).and(res))
+    }
+
+    // Index of the given slot in the layout order.
+    fn position(&self, id: &str) -> Option<usize> {
+        self.areas.iter().position(|(slot, _)| slot == id)
+    }
+
+    fn set_focus_unchecked(&mut self, i: usize) -> EventResult {
+        if i == self.focus {
+            return EventResult::Consumed(None);
+        }
+
+        let result = match self.focused_view() {
+            Some((view, _)) => view.on_event(Event::FocusLost),
+            None => EventResult::Ignored,
+        };
+        self.focus = i;
+        result
+    }
+
+    fn focused_view(&mut self) -> Option<(&mut Box<dyn View>, Rect)> {
+        let (id, area) = self.areas.get(self.focus)?;
+        let area = *area;
+        self.views.get_mut(id).map(|view| (view, area))
+    }
+
+    // Gives the focus to the first slot in `candidates` accepting it.
+    fn focus_first<I>(
+        &mut self,
+        candidates: I,
+        source: Direction,
+    ) -> Option<EventResult>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let views = &mut self.views;
+        let areas = &self.areas;
+        let (i, res) = candidates.into_iter().find_map(|i| {
+            let view = views.get_mut(&areas[i].0)?;
+            view.take_focus(source).ok().map(|res| (i, res))
+        })?;
+        Some(self.set_focus_unchecked(i).and(res))
+    }
+
+    fn move_focus_rel(&mut self, target: Relative) -> EventResult {
+        let source = Direction::Rel(target.swap());
+        let candidates: Vec<usize> = match target {
+            Relative::Front => (0..self.focus).rev().collect(),
+            Relative::Back => (self.focus + 1..self.areas.len()).collect(),
+        };
+        self.focus_first(candidates, source)
+            .unwrap_or(EventResult::Ignored)
+    }
+
+    fn move_focus_abs(&mut self, target: Absolute) -> EventResult {
+        let source = Direction::Abs(target.opposite());
+        let (orientation, rel) = target.split();
+
+        fn intersects(a: (usize, usize), b: (usize, usize)) -> bool {
+            a.1 >= b.0 && a.0 <= b.1
+        }
+
+        let current = match self.areas.get(self.focus) {
+            Some(&(_, area)) => area,
+            None => return EventResult::Ignored,
+        };
+        let current_side = current.side(orientation.swap());
+        let current_edge = current.edge(target);
+
+        // Only consider slots past the current one in this direction, and
+        // aligned with it. Closest first.
+        let mut candidates: Vec<usize> = (0..self.areas.len())
+            .filter(|&i| {
+                let area = self.areas[i].1;
+                Relative::a_to_b(current_edge, area.edge(target.opposite()))
+                    == Some(rel.swap())
+                    && intersects(area.side(orientation.swap()), current_side)
+            })
+            .collect();
+        candidates.sort_by_key(|&i| self.areas[i].1.edge(target.opposite()));
+        if rel == Relative::Front {
+            candidates.reverse();
+        }
+
+        self.focus_first(candidates, source)
+            .unwrap_or(EventResult::Ignored)
+    }
+
+    fn check_focus_grab(&mut self, event: &Event) -> Option<EventResult> {
+        if let Event::Mouse {
+            offset,
+            position,
+            event,
+        } = *event
+        {
+            if !event.grabs_focus() {
+                return None;
+            }
+
+            let position = position.checked_sub(offset)?;
+            let i = self
+                .areas
+                .iter()
+                .position(|(_, area)| area.contains(position))?;
+            return self.focus_first(Some(i), Direction::none());
+        }
+
+        None
+    }
+}
+
+impl View for TiledLayout {
+    fn draw(&self, printer: &Printer) {
+        for (i, (id, area)) in self.areas.iter().enumerate() {
+            if let Some(view) = self.views.get(id) {
+                view.draw(&printer.windowed(*area).focused(i == self.focus));
+            }
+        }
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.size = size;
+        self.update_areas();
+        for (id, area) in &self.areas {
+            if let Some(view) = self.views.get_mut(id) {
+                view.layout(area.size());
+            }
+        }
+    }
+
+    /// Tiles always use all the available space.
+    fn required_size(&mut self, constraint: Vec2) -> Vec2 {
+        constraint
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        let res = self
+            .check_focus_grab(&event)
+            .unwrap_or(EventResult::Ignored);
+
+        let result = match self.focused_view() {
+            Some((view, area)) => {
+                view.on_event(event.relativized(area.top_left()))
+            }
+            None => EventResult::Ignored,
+        };
+
+        res.and(match result {
+            EventResult::Ignored => match event {
+                Event::Shift(Key::Tab) => self.move_focus_rel(Relative::Front),
+                Event::Key(Key::Tab) => self.move_focus_rel(Relative::Back),
+                Event::Key(Key::Left) => self.move_focus_abs(Absolute::Left),
+                Event::Key(Key::Right) => self.move_focus_abs(Absolute::Right),
+                Event::Key(Key::Up) => self.move_focus_abs(Absolute::Up),
+                Event::Key(Key::Down) => self.move_focus_abs(Absolute::Down),
+                _ => EventResult::Ignored,
+            },
+            res => res,
+        })
+    }
+
+    fn debug_children(&self, f: &mut dyn FnMut(&dyn View, Option<Rect>)) {
+        for (id, area) in &self.areas {
+            if let Some(view) = self.views.get(id) {
+                f(&**view, Some(*area));
+            }
+        }
+    }
+
+    fn important_area(&self, size: Vec2) -> Rect {
+        let focused = self
+            .areas
+            .get(self.focus)
+            .and_then(|(id, area)| Some((self.views.get(id)?, *area)));
+
+        match focused {
+            Some((view, area)) => {
+                view.important_area(area.size()) + area.top_left()
+            }
+            None => Rect::from_size((0, 0), size),
+        }
+    }
+
+    fn take_focus(
+        &mut self,
+        source: Direction,
+    ) -> Result<EventResult, CannotFocus> {
+        let len = self.areas.len();
+        let candidates: Vec<usize> = match source {
+            // Keep the current focus if possible.
+            Direction::Abs(Absolute::None) => {
+                (self.focus..len).chain(0..self.focus).collect()
+            }
+            Direction::Rel(Relative::Back) => (0..len).rev().collect(),
+            _ => (0..len).collect(),
+        };
+
+        self.focus_first(candidates, source).ok_or(CannotFocus)
+    }
+
+    fn call_on_any<'a>(
+        &mut self,
+        selector: &Selector<'_>,
+        callback: AnyCb<'a>,
+    ) {
+        for view in self.views.values_mut() {
+            view.call_on_any(selector, callback);
+        }
+    }
+
+    fn focus_view(
+        &mut self,
+        selector: &Selector<'_>,
+    ) -> Result<EventResult, ViewNotFound> {
+        let views = &mut self.views;
+        let (i, res) = self
+            .areas
+            .iter()
+            .enumerate()
+            .find_map(|(i, (id, _))| {
+                let view = views.get_mut(id)?;
+                view.focus_view(selector).ok().map(|res| (i, res))
+            })
+            .ok_or(ViewNotFound)?;
+        Ok(self.set_focus_unchecked(i).and(res))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::{Button, DummyView};
+
+    fn layout() -> Layout {
+        Layout::vertical(vec![
+            (
+                3.0,
+                Layout::horizontal(vec![
+                    (2.0, Layout::slot("main")),
+                    (1.0, Layout::slot("side")),
+                ]),
+            ),
+            (1.0, Layout::slot("status")),
+        ])
+    }
+
+    #[test]
+    fn proportional_areas() {
+        let layout = layout();
+        let areas = layout.areas(Rect::from_size((0, 0), (30, 8)));
+        assert_eq!(
+            areas,
+            vec![
+                ("main", Rect::from_size((0, 0), (20, 6))),
+                ("side", Rect::from_size((20, 0), (10, 6))),
+                ("status", Rect::from_size((0, 6), (30, 2))),
+            ]
+        );
+
+        // Rounding never loses or overlaps cells.
+        let areas = layout.areas(Rect::from_size((0, 0), (31, 7)));
+        assert_eq!(areas[0].1, Rect::from_size((0, 0), (21, 5)));
+        assert_eq!(areas[1].1, Rect::from_size((21, 0), (10, 5)));
+        assert_eq!(areas[2].1, Rect::from_size((0, 5), (31, 2)));
+    }
+
+    #[test]
+    fn zero_weight() {
+        let layout = Layout::horizontal(vec![
+            (1.0, Layout::slot("left")),
+            (0.0, Layout::slot("hidden")),
+            (1.0, Layout::slot("right")),
+        ]);
+        let areas = layout.areas(Rect::from_size((0, 0), (10, 2)));
+        assert_eq!(
+            areas,
+            vec![
+                ("left", Rect::from_size((0, 0), (5, 2))),
+                ("right", Rect::from_size((5, 0), (5, 2))),
+            ]
+        );
+
+        let mut tiles = TiledLayout::new(layout)
+            .slot("left", DummyView)
+            .slot("hidden", Button::new("Hidden", |_| ()))
+            .slot("right", Button::new("Right", |_| ()));
+        assert_eq!(tiles.slot_area("left"), None);
+
+        tiles.layout(Vec2::new(10, 2));
+        assert_eq!(tiles.slot_area("hidden"), None);
+        assert!(tiles.focus_slot("hidden").is_err());
+        assert!(tiles.take_focus(Direction::none()).is_ok());
+        assert_eq!(tiles.focused_slot(), Some("right"));
+
+        // A new layout uses the size from the last layout.
+        tiles.set_layout(Layout::vertical(vec![
+            (1.0, Layout::slot("left")),
+            (1.0, Layout::slot("right")),
+        ]));
+        assert_eq!(tiles.focused_slot(), Some("right"));
+        assert_eq!(
+            tiles.slot_area("right"),
+            Some(Rect::from_size((0, 1), (10, 1)))
+        );
+    }
+
+    #[test]
+    fn replace_and_focus() {
+        let mut tiles = TiledLayout::new(layout())
+            .slot("main", Button::new("Main", |_| ()))
+            .slot("side", Button::new("Side", |_| ()))
+            .slot("status", DummyView);
+        tiles.layout(Vec2::new(30, 8));
+        assert!(tiles.take_focus(Direction::none()).is_ok());
+        assert_eq!(tiles.focused_slot(), Some("main"));
+
+        tiles.on_event(Event::Key(Key::Right));
+        assert_eq!(tiles.focused_slot(), Some("side"));
+
+        // The status bar can't take focus.
+        tiles.on_event(Event::Key(Key::Down));
+        assert_eq!(tiles.focused_slot(), Some("side"));
+
+        // Replacing a view keeps the focus and the other slots.
+        assert!(tiles.replace_slot("side", DummyView).is_some());
+        assert_eq!(tiles.focused_slot(), Some("side"));
+        assert!(tiles.get_slot("main").is_some());
+
+        tiles.layout(Vec2::new(60, 16));
+        assert_eq!(
+            tiles.slot_area("side"),
+            Some(Rect::from_size((40, 0), (20, 12)))
+        );
+    }
+}
@@ -0,0 +1,993 @@
+use crate::direction::{Absolute, Direction};
+use crate::event::{
+    Callback, Event, EventResult, Key, MouseButton, MouseEvent,
+};
+use crate::theme::ColorStyle;
+use crate::utils::markup::StyledString;
+use crate::view::{CannotFocus, View};
+use crate::Cursive;
+use crate::Printer;
+use crate::Rect;
+use crate::Vec2;
+use crate::With;
+
+use std::cell::RefCell;
+use std::cmp::min;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Identifies a row in a [`TreeView`].
+///
+/// Ids are never re-used: they stay valid when other rows are inserted,
+/// removed, expanded or collapsed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RowId(usize);
+
+/// A child loaded lazily by [`TreeView::set_on_expand`].
+pub struct TreeItem<T> {
+    label: StyledString,
+    value: T,
+    container: bool,
+}
+
+impl<T> TreeItem<T> {
+    /// Creates a new item without children.
+    pub fn leaf<S: Into<StyledString>>(label: S, value: T) -> Self {
+        TreeItem {
+            label: label.into(),
+            value,
+            container: false,
+        }
+    }
+
+    /// Creates a new item whose children will be loaded when expanded.
+    ///
+    /// See [`TreeView::insert_container_item`].
+    pub fn container<S: Into<StyledString>>(label: S, value: T) -> Self {
+        TreeItem {
+            label: label.into(),
+            value,
+            container: true,
+        }
+    }
+}
+
+type RowCallback = dyn Fn(&mut Cursive, RowId);
+type ExpandCallback<T> = dyn Fn(&mut Cursive, RowId) -> Vec<TreeItem<T>>;
+
+// Children returned by the expand callback, waiting to be inserted.
+type Loaded<T> = Rc<RefCell<Vec<(RowId, Vec<TreeItem<T>>)>>>;
+
+/// View showing a collapsible tree of items.
+///
+/// Each item has a label and a value of type `T`, and is identified by a
+/// [`RowId`]. Items with children can be expanded with `<Right>` and
+/// collapsed with `<Left>`, or by clicking on the `▸`/`▾` glyph.
+///
+/// Children can be loaded lazily: container items (see
+/// [`insert_container_item`](Self::insert_container_item)) call the
+/// [`on_expand`](Self::on_expand) callback the first time they are expanded.
+///
+/// This view does not scroll on its own: wrap it in a
+/// [`ScrollView`](crate::views::ScrollView) (for example with
+/// [`Scrollable::scrollable`](crate::view::Scrollable::scrollable)) to keep
+/// the selection visible.
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive_core::traits::Scrollable;
+/// # use cursive_core::views::{Dialog, TreeItem, TreeView};
+/// let mut tree = TreeView::new().on_submit(|s, _row| {
+///     s.add_layer(Dialog::info("Opening file..."));
+/// });
+///
+/// let src = tree.insert_item(None, "src", "src/");
+/// tree.insert_item(Some(src), "lib.rs", "src/lib.rs");
+///
+/// // The content of `target` is only listed when expanded.
+/// tree.insert_container_item(None, "target", "target/");
+/// tree.set_on_expand(|_, _row| vec![TreeItem::leaf("debug", "target/debug")]);
+///
+/// let view = tree.scrollable();
+/// ```
+pub struct TreeView<T> {
+    nodes: HashMap<RowId, Node<T>>,
+    roots: Vec<RowId>,
+    next_id: usize,
+
+    // Visible rows from top to bottom, with their depth.
+    rows: Vec<(RowId, usize)>,
+
+    // Selected row, as an index in `rows`.
+    focus: usize,
+
+    enabled: bool,
+
+    on_select: Option<Rc<RowCallback>>,
+    on_submit: Option<Rc<RowCallback>>,
+    on_expand: Option<Rc<ExpandCallback<T>>>,
+
+    loaded: Loaded<T>,
+
+    guide_style: ColorStyle,
+}
+
+struct Node<T> {
+    label: StyledString,
+    value: T,
+    parent: Option<RowId>,
+    children: Vec<RowId>,
+    expanded: bool,
+
+    // `true` for container items whose children were not loaded yet.
+    lazy: bool,
+}
+
+impl<T> Node<T> {
+    fn is_expandable(&self) -> bool {
+        self.lazy || !self.children.is_empty()
+    }
+}
+
+// Each level of depth takes this many columns.
+const INDENT: usize = 2;
+
+impl<T: 'static> Default for TreeView<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: 'static> TreeView<T> {
+    impl_enabled!(self.enabled);
+
+    /// Creates a new empty `TreeView`.
+    pub fn new() -> Self {
+        TreeView {
+            nodes: HashMap::new(),
+            roots: Vec::new(),
+            next_id: 0,
+            rows: Vec::new(),
+            focus: 0,
+            enabled: true,
+            on_select: None,
+            on_submit: None,
+            on_expand: None,
+            loaded: Rc::new(RefCell::new(Vec::new())),
+            guide_style: ColorStyle::tertiary(),
+        }
+    }
+
+    /// Sets a callback to be used when a row is selected.
+    pub fn set_on_select<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, RowId) + 'static,
+    {
+        self.on_select = Some(Rc::new(cb));
+    }
+
+    /// Sets a callback to be used when a row is selected.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn on_select<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, RowId) + 'static,
+    {
+        self.with(|s| s.set_on_select(cb))
+    }
+
+    /// Sets a callback to be used when `<Enter>` is pressed.
+    ///
+    /// Also happens if the user clicks the label of the selected row.
+    pub fn set_on_submit<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, RowId) + 'static,
+    {
+        self.on_submit = Some(Rc::new(cb));
+    }
+
+    /// Sets a callback to be used when `<Enter>` is pressed.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn on_submit<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, RowId) + 'static,
+    {
+        self.with(|s| s.set_on_submit(cb))
+    }
+
+    /// Sets a callback loading the children of container items.
+    ///
+    /// It is called the first time a container item is expanded, and the
+    /// returned items are added as its children.
+    pub fn set_on_expand<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, RowId) -> Vec<TreeItem<T>> + 'static,
+    {
+        self.on_expand = Some(Rc::new(cb));
+    }
+
+    /// Sets a callback loading the children of container items.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn on_expand<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, RowId) -> Vec<TreeItem<T>> + 'static,
+    {
+        self.with(|s| s.set_on_expand(cb))
+    }
+
+    /// Sets the style used for the indentation guides.
+    ///
+    /// Defaults to `ColorStyle::tertiary()`.
+    pub fn set_guide_style(&mut self, style: ColorStyle) {
+        self.guide_style = style;
+    }
+
+    /// Sets the style used for the indentation guides.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn guide_style(self, style: ColorStyle) -> Self {
+        self.with(|s| s.set_guide_style(style))
+    }
+
+    /// Adds an item as the last child of `parent`.
+    ///
+    /// If `parent` is `None`, the item is added at the top level.
+    ///
+    /// # Panics
+    ///
+    /// If `parent` is not in this tree.
+    pub fn insert_item<S>(
+        &mut self,
+        parent: Option<RowId>,
+        label: S,
+        value: T,
+    ) -> RowId
+    where
+        S: Into<StyledString>,
+    {
+        self.insert_node(parent, label.into(), value, false)
+    }
+
+    /// Adds an item whose children are loaded when it is first expanded.
+    ///
+    /// The item starts collapsed. See [`set_on_expand`](Self::set_on_expand).
+    ///
+    /// # Panics
+    ///
+    /// If `parent` is not in this tree.
+    pub fn insert_container_item<S>(
+        &mut self,
+        parent: Option<RowId>,
+        label: S,
+        value: T,
+    ) -> RowId
+    where
+        S: Into<StyledString>,
+    {
+        self.insert_node(parent, label.into(), value, true)
+    }
+
+    fn insert_node(
+        &mut self,
+        parent: Option<RowId>,
+        label: StyledString,
+        value: T,
+        lazy: bool,
+    ) -> RowId {
+        let id = self.add_node(parent, label, value, lazy);
+        self.insert_row(id);
+        id
+    }
+
+    // Adds an item as the last child of `parent`, without updating the
+    // visible rows.
+    fn add_node(
+        &mut self,
+        parent: Option<RowId>,
+        label: StyledString,
+        value: T,
+        lazy: bool,
+    ) -> RowId {
+        let id = RowId(self.next_id);
+        self.next_id += 1;
+
+        match parent {
+            Some(parent) => self
+                .nodes
+                .get_mut(&parent)
+                .expect("parent should be in the tree")
+                .children
+                .push(id),
+            None => self.roots.push(id),
+        }
+
+        self.nodes.insert(
+            id,
+            Node {
+                label,
+                value,
+                parent,
+                children: Vec::new(),
+                expanded: !lazy,
+                lazy,
+            },
+        );
+        id
+    }
+
+    // Adds the row for a new item, if it is visible.
+    //
+    // The item must be the last child of its parent. This avoids going
+    // through the whole tree for each new item.
+    fn insert_row(&mut self, id: RowId) {
+        let parent = match self.nodes[&id].parent {
+            Some(parent) => parent,
+            None => {
+                // The new root goes at the very end.
+                self.rows.push((id, 0));
+                return;
+            }
+        };
+
+        let node = &self.nodes[&parent];
+        if !node.expanded {
+            return;
+        }
+
+        // The row goes after the last visible descendant of the parent.
+        let mut last = match node.children.len() {
+            0 | 1 => parent,
+            n => node.children[n - 2],
+        };
+        while last != parent {
+            let node = &self.nodes[&last];
+            match node.children.last() {
+                Some(&child) if node.expanded => last = child,
+                _ => break,
+            }
+        }
+
+        // Items are usually added near the end: search from there.
+        let i = match self.rows.iter().rposition(|&(row, _)| row == last) {
+            Some(i) => i + 1,
+            // The parent is not visible.
+            None => return,
+        };
+
+        let mut depth = 0;
+        let mut ancestor = Some(parent);
+        while let Some(a) = ancestor {
+            depth += 1;
+            ancestor = self.item_parent(a);
+        }
+
+        self.rows.insert(i, (id, depth));
+        if self.focus >= i {
+            self.focus += 1;
+        }
+    }
+
+    /// Removes an item and all its descendants.
+    ///
+    /// Returns the value of the removed item, or `None` if it was not found.
+    pub fn remove_item(&mut self, id: RowId) -> Option<T> {
+        let node = self.nodes.remove(&id)?;
+
+        let siblings = match node.parent {
+            Some(parent) => &mut self.nodes.get_mut(&parent).unwrap().children,
+            None => &mut self.roots,
+        };
+        siblings.retain(|&child| child != id);
+
+        let mut stack = node.children;
+        while let Some(child) = stack.pop() {
+            if let Some(child) = self.nodes.remove(&child) {
+                stack.extend(child.children);
+            }
+        }
+
+        self.refresh();
+        Some(node.value)
+    }
+
+    /// Removes all items.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.roots.clear();
+        self.rows.clear();
+        self.focus = 0;
+    }
+
+    /// Returns `true` if `id` is an item of this tree.
+    pub fn contains(&self, id: RowId) -> bool {
+        self.nodes.contains_key(&id)
+    }
+
+    /// Returns the value of the given item.
+    pub fn get_item(&self, id: RowId) -> Option<&T> {
+        self.nodes.get(&id).map(|node| &node.value)
+    }
+
+    /// Returns a mutable reference to the value of the given item.
+    pub fn get_item_mut(&mut self, id: RowId) -> Option<&mut T> {
+        self.nodes.get_mut(&id).map(|node| &mut node.value)
+    }
+
+    /// Returns the label of the given item.
+    pub fn get_label(&self, id: RowId) -> Option<&str> {
+        self.nodes.get(&id).map(|node| node.label.source())
+    }
+
+    /// Returns the parent of the given item.
+    ///
+    /// Returns `None` for top-level items.
+    pub fn item_parent(&self, id: RowId) -> Option<RowId> {
+        self.nodes.get(&id).and_then(|node| node.parent)
+    }
+
+    /// Returns the children of `parent`, or the top-level items for `None`.
+    pub fn children(&self, parent: Option<RowId>) -> &[RowId] {
+        match parent {
+            Some(parent) => self
+                .nodes
+                .get(&parent)
+                .map(|node| &node.children[..])
+                .unwrap_or(&[]),
+            None => &self.roots,
+        }
+    }
+
+    /// Returns the number of items in this tree, visible or not.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if this tree has no item.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the number of visible rows.
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns `true` if the given item is expanded.
+    pub fn is_expanded(&self, id: RowId) -> bool {
+        matches!(self.nodes.get(&id), Some(node) if node.expanded)
+    }
+
+    /// Expands the given item.
+    ///
+    /// The returned callback loads the children of container items, and
+    /// should be run on the `Cursive` root.
+    pub fn expand_item(&mut self, id: RowId) -> Callback {
+        let node = match self.nodes.get_mut(&id) {
+            Some(node) => node,
+            None => return Callback::dummy(),
+        };
+        node.expanded = true;
+
+        let cb = match self.on_expand.clone() {
+            Some(cb) if node.lazy => {
+                node.lazy = false;
+                let loaded = Rc::clone(&self.loaded);
+                Callback::from_fn(move |s| {
+                    let items = cb(s, id);
+                    loaded.borrow_mut().push((id, items));
+                })
+            }
+            _ => Callback::dummy(),
+        };
+
+        self.refresh();
+        cb
+    }
+
+    /// Collapses the given item.
+    pub fn collapse_item(&mut self, id: RowId) {
+        if let Some(node) = self.nodes.get_mut(&id) {
+            node.expanded = false;
+            self.refresh();
+        }
+    }
+
+    /// Returns the selected row, if any.
+    pub fn selected_row(&self) -> Option<RowId> {
+        self.rows.get(self.focus).map(|&(id, _)| id)
+    }
+
+    /// Selects the given item, expanding its ancestors if needed.
+    ///
+    /// Returns a callback in response to the selection change.
+    pub fn set_selected_row(&mut self, id: RowId) -> Callback {
+        if !self.contains(id) {
+            return Callback::dummy();
+        }
+
+        let mut parent = self.item_parent(id);
+        while let Some(p) = parent {
+            let node = self.nodes.get_mut(&p).unwrap();
+            node.expanded = true;
+            parent = node.parent;
+        }
+        self.refresh();
+
+        if let Some(i) = self.rows.iter().position(|&(row, _)| row == id) {
+            self.focus = i;
+        }
+
+        self.make_select_cb().unwrap_or_else(Callback::dummy)
+    }
+
+    /// Selects the given item.
+    ///
+    /// Chainable variant.
+    #[must_use]
+    pub fn selected_row_id(self, id: RowId) -> Self {
+        self.with(|s| {
+            s.set_selected_row(id);
+        })
+    }
+
+    // Recomputes the visible rows, keeping the selection on the same item,
+    // or on its closest visible ancestor.
+    fn refresh(&mut self) {
+        let mut selected = self.selected_row();
+
+        self.rows.clear();
+        let mut stack: Vec<(RowId, usize)> =
+            self.roots.iter().rev().map(|&id| (id, 0)).collect();
+        while let Some((id, depth)) = stack.pop() {
+            self.rows.push((id, depth));
+            let node = &self.nodes[&id];
+            if node.expanded {
+                stack.extend(
+                    node.children
+                        .iter()
+                        .rev()
+                        .map(|&child| (child, depth + 1)),
+                );
+            }
+        }
+
+        while let Some(id) = selected {
+            if let Some(i) = self.rows.iter().position(|&(row, _)| row == id) {
+                self.focus = i;
+                return;
+            }
+            selected = self.item_parent(id);
+        }
+
+        self.focus = min(self.focus, self.rows.len().saturating_sub(1));
+    }
+
+    // Inserts the children returned by the expand callback.
+    fn insert_loaded(&mut self) {
+        let loaded: Vec<_> = self.loaded.borrow_mut().drain(..).collect();
+        if loaded.is_empty() {
+            return;
+        }
+
+        for (parent, items) in loaded {
+            // The parent may have been removed in the meantime.
+            if !self.contains(parent) {
+                continue;
+            }
+            for item in items {
+                self.add_node(
+                    Some(parent),
+                    item.label,
+                    item.value,
+                    item.container,
+                );
+            }
+        }
+
+        // Update the rows once for the whole batch.
+        self.refresh();
+    }
+
+    fn make_select_cb(&self) -> Option<Callback> {
+        let cb = self.on_select.clone()?;
+        let id = self.selected_row()?;
+        Some(Callback::from_fn(move |s| cb(s, id)))
+    }
+
+    fn submit(&self) -> EventResult {
+        let cb = self.on_submit.clone().unwrap();
+        EventResult::Consumed(
+            self.selected_row()
+                .map(|id| Callback::from_fn(move |s| cb(s, id))),
+        )
+    }
+
+    fn focus_up(&mut self, n: usize) {
+        self.focus = self.focus.saturating_sub(n);
+    }
+
+    fn focus_down(&mut self, n: usize) {
+        self.focus = min(self.focus + n, self.rows.len().saturating_sub(1));
+    }
+
+    fn toggle(&mut self, id: RowId) -> EventResult {
+        if self.is_expanded(id) {
+            self.collapse_item(id);
+            EventResult::Consumed(self.make_select_cb())
+        } else {
+            EventResult::Consumed(Some(self.expand_item(id)))
+        }
+    }
+
+    fn on_right(&mut self) -> EventResult {
+        let id = match self.selected_row() {
+            Some(id) => id,
+            None => return EventResult::Ignored,
+        };
+        let node = &self.nodes[&id];
+
+        if !node.is_expandable() {
+            EventResult::Ignored
+        } else if !node.expanded {
+            EventResult::Consumed(Some(self.expand_item(id)))
+        } else if !node.children.is_empty() {
+            // The first child is right below.
+            self.focus_down(1);
+            EventResult::Consumed(self.make_select_cb())
+        } else {
+            EventResult::Ignored
+        }
+    }
+
+    fn on_left(&mut self) -> EventResult {
+        let id = match self.selected_row() {
+            Some(id) => id,
+            None => return EventResult::Ignored,
+        };
+        let node = &self.nodes[&id];
+
+        if node.is_expandable() && node.expanded {
+            self.collapse_item(id);
+            EventResult::Consumed(None)
+        } else if let Some(parent) = node.parent {
+            self.set_selected_row(parent);
+            EventResult::Consumed(self.make_select_cb())
+        } else {
+            EventResult::Ignored
+        }
+    }
+
+    // Returns the row at the given position, and whether the position is
+    // on the expand glyph.
+    fn row_at(&self, position: Vec2) -> Option<(usize, bool)> {
+        let &(id, depth) = self.rows.get(position.y)?;
+        let glyph = depth * INDENT;
+        let on_glyph = self.nodes[&id].is_expandable()
+            && position.x >= glyph
+            && position.x < glyph + INDENT;
+        Some((position.y, on_glyph))
+    }
+
+    // Returns `true` if `id` is the last of its siblings.
+    fn is_last_child(&self, id: RowId) -> bool {
+        self.children(self.item_parent(id)).last() == Some(&id)
+    }
+
+    fn draw_row(&self, printer: &Printer, i: usize) {
+        let (id, depth) = self.rows[i];
+        let node = &self.nodes[&id];
+
+        printer.with_color(self.guide_style, |printer| {
+            // Ancestors with more siblings below have a vertical guide.
+            let mut ancestor = node.parent;
+            for level in (0..depth.saturating_sub(1)).rev() {
+                let a = ancestor.unwrap();
+                if !self.is_last_child(a) {
+                    printer.print((level * INDENT, 0), "│");
+                }
+                ancestor = self.item_parent(a);
+            }
+            if depth > 0 {
+                let guide = if self.is_last_child(id) { "└" } else { "├" };
+                printer.print(((depth - 1) * INDENT, 0), guide);
+            }
+        });
+
+        let x = depth * INDENT;
+        if node.is_expandable() {
+            let glyph = if node.expanded { "▾" } else { "▸" };
+            printer.print((x, 0), glyph);
+        } else if depth > 0 {
+            printer.with_color(self.guide_style, |printer| {
+                printer.print((x - 1, 0), "─");
+            });
+        }
+
+        printer.offset((x + INDENT, 0)).with_selection(
+            i == self.focus,
+            |printer| {
+                printer.print_styled((0, 0), (&node.label).into());
+            },
+        );
+    }
+}
+
+impl<T: 'static> View for TreeView<T> {
+    fn draw(&self, printer: &Printer) {
+        let start = min(printer.content_offset.y, self.rows.len());
+        let end = min(start + printer.output_size.y, self.rows.len());
+
+        for i in start..end {
+            let printer = &printer.offset((0, i)).cropped((printer.size.x, 1));
+            if i != self.focus && !(self.enabled && printer.enabled) {
+                printer.with_color(ColorStyle::secondary(), |printer| {
+                    self.draw_row(printer, i)
+                });
+            } else {
+                self.draw_row(printer, i);
+            }
+        }
+    }
+
+    fn required_size(&mut self, _: Vec2) -> Vec2 {
+        self.insert_loaded();
+
+        let width = self
+            .rows
+            .iter()
+            .map(|&(id, depth)| {
+                (depth + 1) * INDENT + self.nodes[&id].label.width()
+            })
+            .max()
+            .unwrap_or(0);
+
+        Vec2::new(width, self.rows.len())
+    }
+
+    fn layout(&mut self, _: Vec2) {
+        self.insert_loaded();
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        if !self.enabled {
+            return EventResult::Ignored;
+        }
+
+        match event {
+            Event::Key(Key::Up) if self.focus > 0 => self.focus_up(1),
+            Event::Key(Key::Down) if self.focus + 1 < self.rows.len() => {
+                self.focus_down(1)
+            }
+            Event::Key(Key::PageUp) => self.focus_up(10),
+            Event::Key(Key::PageDown) => self.focus_down(10),
+            Event::Key(Key::Home) => self.focus = 0,
+            Event::Key(Key::End) => {
+                self.focus = self.rows.len().saturating_sub(1)
+            }
+            Event::Key(Key::Right) => return self.on_right(),
+            Event::Key(Key::Left) => return self.on_left(),
+            Event::Key(Key::Enter) if self.on_submit.is_some() => {
+                return self.submit();
+            }
+            Event::Mouse {
+                event: MouseEvent::Press(MouseButton::Left),
+                position,
+                offset,
+            } => {
+                let (i, on_glyph) = match position
+                    .checked_sub(offset)
+                    .and_then(|position| self.row_at(position))
+                {
+                    Some(row) => row,
+                    None => return EventResult::Ignored,
+                };
+                self.focus = i;
+                if on_glyph {
+                    let (id, _) = self.rows[i];
+                    return self.toggle(id);
+                }
+            }
+            Event::Mouse {
+                event: MouseEvent::Release(MouseButton::Left),
+                position,
+                offset,
+            } if self.on_submit.is_some()
+                && position
+                    .checked_sub(offset)
+                    .and_then(|position| self.row_at(position))
+                    == Some((self.focus, false)) =>
+            {
+                return self.submit();
+            }
+            _ => return EventResult::Ignored,
+        }
+
+        EventResult::Consumed(self.make_select_cb())
+    }
+
+    fn take_focus(
+        &mut self,
+        source: Direction,
+    ) -> Result<EventResult, CannotFocus> {
+        (self.enabled && !self.rows.is_empty())
+            .then(|| {
+                match source {
+                    Direction::Abs(Absolute::Up) => self.focus = 0,
+                    Direction::Abs(Absolute::Down) => {
+                        self.focus = self.rows.len().saturating_sub(1)
+                    }
+                    _ => (),
+                }
+                EventResult::Consumed(None)
+            })
+            .ok_or(CannotFocus)
+    }
+
+    fn important_area(&self, size: Vec2) -> Rect {
+        if self.rows.is_empty() {
+            Rect::from_size(Vec2::zero(), size)
+        } else {
+            Rect::from_size((0, self.focus), (size.x, 1))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::Scrollable;
+
+    // Builds a tree with two roots, the first one with three children.
+    fn tree() -> (TreeView<u32>, Vec<RowId>) {
+        let mut tree = TreeView::new();
+        let a = tree.insert_item(None, "a", 0);
+        let b = tree.insert_item(Some(a), "b", 1);
+        let c = tree.insert_item(Some(a), "c", 2);
+        let d = tree.insert_item(Some(c), "d", 3);
+        let e = tree.insert_item(Some(a), "e", 4);
+        let f = tree.insert_item(None, "f", 5);
+        (tree, vec![a, b, c, d, e, f])
+    }
+
+    fn labels(tree: &TreeView<u32>) -> Vec<&str> {
+        tree.rows
+            .iter()
+            .map(|&(id, _)| tree.get_label(id).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn incremental_rows() {
+        let (mut tree, ids) = tree();
+        tree.set_selected_row(ids[4]);
+        tree.collapse_item(ids[2]);
+
+        // Children of visible, hidden and collapsed items.
+        let g = tree.insert_item(Some(ids[2]), "g", 6);
+        tree.insert_item(Some(ids[0]), "h", 7);
+        tree.insert_item(Some(g), "i", 8);
+        tree.insert_item(Some(ids[5]), "j", 9);
+        tree.insert_item(Some(ids[1]), "k", 10);
+        tree.insert_item(None, "l", 11);
+        assert_eq!(tree.selected_row(), Some(ids[4]));
+
+        let rows = tree.rows.clone();
+        let focus = tree.focus;
+        tree.refresh();
+        assert_eq!(tree.rows, rows);
+        assert_eq!(tree.focus, focus);
+        assert_eq!(
+            labels(&tree),
+            ["a", "b", "k", "c", "e", "h", "f", "j", "l"]
+        );
+    }
+
+    #[test]
+    fn stable_row_ids() {
+        let (mut tree, ids) = tree();
+        assert_eq!(labels(&tree), ["a", "b", "c", "d", "e", "f"]);
+
+        let g = tree.insert_item(Some(ids[1]), "g", 6);
+        assert_eq!(labels(&tree), ["a", "b", "g", "c", "d", "e", "f"]);
+        assert_eq!(tree.remove_item(ids[1]), Some(1));
+
+        // Other ids still point to the same items.
+        assert!(!tree.contains(g));
+        assert_eq!(tree.get_item(ids[3]), Some(&3));
+        assert_eq!(tree.get_item(ids[5]), Some(&5));
+        assert_eq!(tree.item_parent(ids[3]), Some(ids[2]));
+
+        // New ids are never re-used.
+        let h = tree.insert_item(None, "h", 7);
+        assert!(h != ids[1] && h != g);
+    }
+
+    #[test]
+    fn remove_subtree() {
+        let (mut tree, ids) = tree();
+
+        // Select `d`, inside the subtree.
+        tree.set_selected_row(ids[3]);
+        assert_eq!(tree.remove_item(ids[2]), Some(2));
+
+        assert_eq!(tree.len(), 4);
+        assert!(!tree.contains(ids[3]));
+        assert_eq!(tree.children(Some(ids[0])), [ids[1], ids[4]]);
+        assert_eq!(labels(&tree), ["a", "b", "e", "f"]);
+        assert!(tree.selected_row().is_some());
+
+        assert_eq!(tree.remove_item(ids[0]), Some(0));
+        assert_eq!(labels(&tree), ["f"]);
+        assert_eq!(tree.remove_item(ids[0]), None);
+    }
+
+    #[test]
+    fn expand_collapse() {
+        let (mut tree, ids) = tree();
+
+        // From the leaf `b`, `<Left>` moves to `a`, then collapses it.
+        tree.set_selected_row(ids[1]);
+        tree.on_event(Event::Key(Key::Left));
+        assert_eq!(tree.selected_row(), Some(ids[0]));
+        tree.on_event(Event::Key(Key::Left));
+        assert_eq!(labels(&tree), ["a", "f"]);
+
+        tree.on_event(Event::Key(Key::Right));
+        assert_eq!(labels(&tree), ["a", "b", "c", "d", "e", "f"]);
+        tree.on_event(Event::Key(Key::Right));
+        assert_eq!(tree.selected_row(), Some(ids[1]));
+
+        // Clicking the glyph of `c` collapses it.
+        tree.on_event(Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(2, 2),
+            event: MouseEvent::Press(MouseButton::Left),
+        });
+        assert!(!tree.is_expanded(ids[2]));
+        assert_eq!(tree.selected_row(), Some(ids[2]));
+    }
+
+    #[test]
+    fn lazy_children() {
+        let mut tree = TreeView::new().on_expand(|_, _| {
+            vec![TreeItem::leaf("x", 10), TreeItem::container("y", 11)]
+        });
+        let a = tree.insert_container_item(None, "a", 0);
+        assert_eq!(labels(&tree), ["a"]);
+
+        let mut siv = Cursive::new();
+        tree.on_event(Event::Key(Key::Right)).process(&mut siv);
+        tree.layout(Vec2::new(10, 10));
+        assert_eq!(labels(&tree), ["a", "x", "y"]);
+
+        // Children are only loaded once.
+        tree.collapse_item(a);
+        (tree.expand_item(a))(&mut siv);
+        tree.layout(Vec2::new(10, 10));
+        assert_eq!(tree.len(), 3);
+    }
+
+    #[test]
+    fn scroll_to_selection() {
+        let mut tree = TreeView::new();
+        for i in 0..20 {
+            tree.insert_item(None, format!("{}", i), i);
+        }
+
+        let mut view = tree.scrollable();
+        view.layout(Vec2::new(10, 5));
+        for _ in 0..12 {
+            view.on_event(Event::Key(Key::Down));
+        }
+
+        assert_eq!(view.get_inner().selected_row(), Some(RowId(12)));
+        assert!(view.content_viewport().contains(Vec2::new(0, 12)));
+    }
+}
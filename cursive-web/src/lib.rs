@@ -0,0 +1,713 @@
+//! # Cursive-web
+//!
+//! Backend rendering [`cursive_core`] to a DOM element, for WebAssembly
+//! targets.
+//!
+//! Cells are rendered as `<span>` elements inside the given container,
+//! using CSS classes for the base colors and effects. Keyboard and mouse
+//! events are read from the container, and its size is tracked with a
+//! `ResizeObserver`.
+//!
+//! The browser cannot block, so [`CursiveRunner::run`] and
+//! [`CursiveRunner::step`] cannot be used (they sleep between frames).
+//! Instead, call [`CursiveRunner::process_events`] and
+//! [`CursiveRunner::refresh`] from a `requestAnimationFrame` callback:
+//!
+//! ```rust,ignore
+//! let document = web_sys::window().unwrap().document().unwrap();
+//! let container = document.get_element_by_id("terminal").unwrap();
+//!
+//! let backend = cursive_web::Backend::init(container)?;
+//! let mut runner = siv.into_runner(backend);
+//!
+//! // On every animation frame:
+//! if runner.process_events() {
+//!     runner.refresh();
+//! }
+//! ```
+//!
+//! [`CursiveRunner::run`]: cursive_core::CursiveRunner::run
+//! [`CursiveRunner::step`]: cursive_core::CursiveRunner::step
+//! [`CursiveRunner::process_events`]: cursive_core::CursiveRunner::process_events
+//! [`CursiveRunner::refresh`]: cursive_core::CursiveRunner::refresh
+#![deny(missing_docs)]
+
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::fmt::Write;
+use std::rc::Rc;
+
+pub use wasm_bindgen;
+pub use web_sys;
+
+use cursive_core::utils::width::TextWidth;
+use unicode_segmentation::UnicodeSegmentation;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Element, HtmlElement, KeyboardEvent, ResizeObserver};
+
+use cursive_core::backend;
+use cursive_core::event::{Event, Key, Modifier, MouseButton, MouseEvent};
+use cursive_core::reexports::enumset::EnumSet;
+use cursive_core::theme::{BaseColor, Color, ColorPair, Effect};
+use cursive_core::Vec2;
+
+/// Class added to the container element.
+const SCREEN_CLASS: &str = "cursive-screen";
+
+/// Id of the `<style>` element added to the document.
+const STYLE_ID: &str = "cursive-style";
+
+type Listener = Closure<dyn FnMut(web_sys::Event)>;
+
+enum ColorRole {
+    Foreground,
+    Background,
+}
+
+#[derive(Clone)]
+struct ScreenCell {
+    // Empty for the cells covered by a wide character.
+    text: String,
+    colors: ColorPair,
+    effects: EnumSet<Effect>,
+}
+
+/// Backend rendering to a DOM element.
+pub struct Backend {
+    container: Element,
+
+    // Cells of the current frame, row by row.
+    grid: RefCell<Vec<ScreenCell>>,
+    grid_size: Cell<Vec2>,
+
+    size: Rc<Cell<Vec2>>,
+    colors: Cell<ColorPair>,
+    effects: Cell<EnumSet<Effect>>,
+
+    events: Rc<RefCell<VecDeque<Event>>>,
+
+    // Kept alive for as long as the backend.
+    listeners: Vec<(&'static str, Listener)>,
+    resize_observer: ResizeObserver,
+    _on_resize: Closure<dyn FnMut()>,
+}
+
+impl Backend {
+    /// Creates a new backend rendering to the given element.
+    ///
+    /// The element is made focusable, and focused, to receive key events.
+    pub fn init(
+        container: Element,
+    ) -> Result<Box<dyn backend::Backend>, JsValue> {
+        let document = container
+            .owner_document()
+            .ok_or_else(|| JsValue::from_str("Element has no document"))?;
+
+        if document.get_element_by_id(STYLE_ID).is_none() {
+            let style = document.create_element("style")?;
+            style.set_id(STYLE_ID);
+            style.set_text_content(Some(&stylesheet()));
+            if let Some(head) = document.head() {
+                head.append_child(&style)?;
+            }
+        }
+
+        container.class_list().add_1(SCREEN_CLASS)?;
+        container.set_attribute("tabindex", "0")?;
+        if let Some(element) = container.dyn_ref::<HtmlElement>() {
+            element.focus()?;
+        }
+
+        let cell_size = Rc::new(measure_cell(&container)?);
+        let size = Rc::new(Cell::new(screen_size(&container, &cell_size)));
+        let events = Rc::new(RefCell::new(VecDeque::new()));
+
+        let on_resize = {
+            let container = container.clone();
+            let cell_size = Rc::clone(&cell_size);
+            let size = Rc::clone(&size);
+            let events = Rc::clone(&events);
+            Closure::wrap(Box::new(move || {
+                let new_size = screen_size(&container, &cell_size);
+                if new_size != size.get() {
+                    size.set(new_size);
+                    events.borrow_mut().push_back(Event::WindowResize);
+                }
+            }) as Box<dyn FnMut()>)
+        };
+        let resize_observer =
+            ResizeObserver::new(on_resize.as_ref().unchecked_ref())?;
+        resize_observer.observe(&container);
+
+        let mut listeners = Vec::new();
+
+        listeners.push(listen(&container, "keydown", {
+            let events = Rc::clone(&events);
+            move |event: web_sys::Event| {
+                let event = match event.dyn_into::<KeyboardEvent>() {
+                    Ok(event) => event,
+                    Err(_) => return,
+                };
+                // Leave browser shortcuts alone.
+                if event.meta_key() {
+                    return;
+                }
+                if let Some(e) = key_event(
+                    &event.key(),
+                    event.ctrl_key(),
+                    event.alt_key(),
+                    event.shift_key(),
+                ) {
+                    event.prevent_default();
+                    events.borrow_mut().push_back(e);
+                }
+            }
+        })?);
+
+        // Button currently held, to report mouse moves as `Hold`.
+        let pressed = Rc::new(Cell::new(None));
+
+        for &name in &["mousedown", "mouseup", "mousemove", "wheel"] {
+            listeners.push(listen(&container, name, {
+                let container = container.clone();
+                let cell_size = Rc::clone(&cell_size);
+                let events = Rc::clone(&events);
+                let pressed = Rc::clone(&pressed);
+                move |event: web_sys::Event| {
+                    let event = match event.dyn_into::<web_sys::MouseEvent>() {
+                        Ok(event) => event,
+                        Err(_) => return,
+                    };
+                    let button = mouse_button(event.button());
+                    let mouse_event = match name {
+                        "mousedown" => {
+                            pressed.set(Some(button));
+                            MouseEvent::Press(button)
+                        }
+                        "mouseup" => {
+                            pressed.set(None);
+                            MouseEvent::Release(button)
+                        }
+                        "mousemove" => match pressed.get() {
+                            Some(button) => MouseEvent::Hold(button),
+                            None => return,
+                        },
+                        _ => match event.dyn_ref::<web_sys::WheelEvent>() {
+                            Some(wheel) if wheel.delta_y() < 0.0 => {
+                                MouseEvent::WheelUp
+                            }
+                            Some(_) => MouseEvent::WheelDown,
+                            None => return,
+                        },
+                    };
+                    event.prevent_default();
+
+                    let rect = container.get_bounding_client_rect();
+                    let x = (f64::from(event.client_x()) - rect.left())
+                        / cell_size.0;
+                    let y = (f64::from(event.client_y()) - rect.top())
+                        / cell_size.1;
+                    events.borrow_mut().push_back(Event::Mouse {
+                        event: mouse_event,
+                        position: Vec2::new(
+                            x.max(0.0) as usize,
+                            y.max(0.0) as usize,
+                        ),
+                        offset: Vec2::zero(),
+                    });
+                }
+            })?);
+        }
+
+        let c = Backend {
+            container,
+            grid: RefCell::new(Vec::new()),
+            grid_size: Cell::new(Vec2::zero()),
+            size,
+            colors: Cell::new(ColorPair {
+                front: Color::TerminalDefault,
+                back: Color::TerminalDefault,
+            }),
+            effects: Cell::new(EnumSet::new()),
+            events,
+            listeners,
+            resize_observer,
+            _on_resize: on_resize,
+        };
+
+        Ok(Box::new(c))
+    }
+
+    fn blank(&self, back: Color) -> ScreenCell {
+        ScreenCell {
+            text: " ".to_string(),
+            colors: ColorPair {
+                front: Color::TerminalDefault,
+                back,
+            },
+            effects: EnumSet::new(),
+        }
+    }
+
+    // Renders the current frame as HTML.
+    fn render(&self) -> String {
+        let grid = self.grid.borrow();
+        let width = self.grid_size.get().x;
+
+        let mut html = String::new();
+        if width == 0 {
+            return html;
+        }
+
+        for row in grid.chunks(width) {
+            let mut run: Option<(ColorPair, EnumSet<Effect>)> = None;
+            let mut text = String::new();
+
+            for cell in row {
+                if cell.text.is_empty() {
+                    continue;
+                }
+                let style = (cell.colors, cell.effects);
+                if run != Some(style) {
+                    if let Some((colors, effects)) = run {
+                        push_span(&mut html, colors, effects, &text);
+                    }
+                    run = Some(style);
+                    text.clear();
+                }
+                text.push_str(&cell.text);
+            }
+            if let Some((colors, effects)) = run {
+                push_span(&mut html, colors, effects, &text);
+            }
+            html.push('\n');
+        }
+        html
+    }
+}
+
+impl Drop for Backend {
+    fn drop(&mut self) {
+        self.resize_observer.disconnect();
+        for (name, listener) in &self.listeners {
+            let _ = self.container.remove_event_listener_with_callback(
+                name,
+                listener.as_ref().unchecked_ref(),
+            );
+        }
+    }
+}
+
+impl backend::Backend for Backend {
+    fn name(&self) -> &str {
+        "wasm"
+    }
+
+    fn poll_event(&mut self) -> Option<Event> {
+        self.events.borrow_mut().pop_front()
+    }
+
+    fn set_title(&mut self, title: String) {
+        if let Some(document) = self.container.owner_document() {
+            document.set_title(&title);
+        }
+    }
+
+    fn refresh(&mut self) {
+        self.container.set_inner_html(&self.render());
+    }
+
+    fn has_colors(&self) -> bool {
+        true
+    }
+
+    fn screen_size(&self) -> Vec2 {
+        self.size.get()
+    }
+
+    fn print_at(&self, pos: Vec2, text: &str) {
+        let size = self.grid_size.get();
+        if pos.y >= size.y {
+            return;
+        }
+
+        let mut grid = self.grid.borrow_mut();
+        let colors = self.colors.get();
+        let effects = self.effects.get();
+
+        let mut x = pos.x;
+        for grapheme in text.graphemes(true) {
+            let width = grapheme.width();
+            if x + width > size.x {
+                break;
+            }
+            let start = pos.y * size.x + x;
+            grid[start] = ScreenCell {
+                text: grapheme.to_string(),
+                colors,
+                effects,
+            };
+            for cell in &mut grid[start + 1..start + width] {
+                cell.text.clear();
+            }
+            x += width;
+        }
+    }
+
+    fn clear(&self, color: Color) {
+        let size = self.size.get();
+        self.grid_size.set(size);
+        *self.grid.borrow_mut() = vec![self.blank(color); size.x * size.y];
+    }
+
+    fn set_color(&self, colors: ColorPair) -> ColorPair {
+        self.colors.replace(colors)
+    }
+
+    fn set_effect(&self, effect: Effect) {
+        let mut effects = self.effects.get();
+        effects.insert(effect);
+        self.effects.set(effects);
+    }
+
+    fn unset_effect(&self, effect: Effect) {
+        let mut effects = self.effects.get();
+        effects.remove(effect);
+        self.effects.set(effects);
+    }
+}
+
+fn listen<F>(
+    target: &Element,
+    name: &'static str,
+    f: F,
+) -> Result<(&'static str, Listener), JsValue>
+where
+    F: 'static + FnMut(web_sys::Event),
+{
+    let listener = Closure::wrap(Box::new(f) as Box<dyn FnMut(_)>);
+    target.add_event_listener_with_callback(
+        name,
+        listener.as_ref().unchecked_ref(),
+    )?;
+    Ok((name, listener))
+}
+
+// Returns the size in pixels of a single cell in the container.
+fn measure_cell(container: &Element) -> Result<(f64, f64), JsValue> {
+    let document = container
+        .owner_document()
+        .ok_or_else(|| JsValue::from_str("Element has no document"))?;
+
+    let probe = document.create_element("span")?;
+    probe.set_text_content(Some("W"));
+    container.append_child(&probe)?;
+    let rect = probe.get_bounding_client_rect();
+    container.remove_child(&probe)?;
+
+    // Avoid dividing by zero if the container is hidden.
+    Ok((rect.width().max(1.0), rect.height().max(1.0)))
+}
+
+fn screen_size(container: &Element, cell_size: &(f64, f64)) -> Vec2 {
+    let x = f64::from(container.client_width()) / cell_size.0;
+    let y = f64::from(container.client_height()) / cell_size.1;
+    Vec2::new(x as usize, y as usize).or_max((1, 1))
+}
+
+fn mouse_button(button: i16) -> MouseButton {
+    match button {
+        0 => MouseButton::Left,
+        1 => MouseButton::Middle,
+        2 => MouseButton::Right,
+        _ => MouseButton::Other,
+    }
+}
+
+/// Translates a browser key, as given by `KeyboardEvent.key`, to an event.
+///
+/// Returns `None` for keys with no matching event, like modifier keys.
+fn key_event(key: &str, ctrl: bool, alt: bool, shift: bool) -> Option<Event> {
+    let mut chars = key.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        // The key already includes shift.
+        return Some(if ctrl {
+            Event::CtrlChar(c.to_ascii_lowercase())
+        } else if alt {
+            Event::AltChar(c)
+        } else {
+            Event::Char(c)
+        });
+    }
+
+    let key = match key {
+        "Enter" => Key::Enter,
+        "Tab" => Key::Tab,
+        "Backspace" => Key::Backspace,
+        "Escape" => Key::Esc,
+        "ArrowLeft" => Key::Left,
+        "ArrowRight" => Key::Right,
+        "ArrowUp" => Key::Up,
+        "ArrowDown" => Key::Down,
+        "Insert" => Key::Ins,
+        "Delete" => Key::Del,
+        "Home" => Key::Home,
+        "End" => Key::End,
+        "PageUp" => Key::PageUp,
+        "PageDown" => Key::PageDown,
+        "Pause" => Key::PauseBreak,
+        "Clear" => Key::NumpadCenter,
+        f if f.starts_with('F') => Key::from_f(f[1..].parse().ok()?),
+        _ => return None,
+    };
+
+    let mut modifiers = EnumSet::new();
+    for (held, modifier) in [
+        (ctrl, Modifier::Ctrl),
+        (alt, Modifier::Alt),
+        (shift, Modifier::Shift),
+    ] {
+        if held {
+            modifiers.insert(modifier);
+        }
+    }
+    Some(Event::from_key(key, modifiers))
+}
+
+fn push_span(
+    html: &mut String,
+    colors: ColorPair,
+    effects: EnumSet<Effect>,
+    text: &str,
+) {
+    let (front, back) = if effects.contains(Effect::Reverse) {
+        (colors.back, colors.front)
+    } else {
+        (colors.front, colors.back)
+    };
+
+    let mut class = String::new();
+    let mut style = String::new();
+    push_color(&mut class, &mut style, front, ColorRole::Foreground);
+    push_color(&mut class, &mut style, back, ColorRole::Background);
+    for effect in effects {
+        let name = match effect {
+            Effect::Simple | Effect::Reverse => continue,
+            Effect::Dim => "dim",
+            Effect::Bold => "bold",
+            Effect::Italic => "italic",
+            Effect::Strikethrough => "strikethrough",
+            Effect::Underline => "underline",
+            Effect::Blink => "blink",
+        };
+        let _ = write!(class, " cursive-{}", name);
+    }
+
+    html.push_str("<span");
+    if !class.is_empty() {
+        let _ = write!(html, " class=\"{}\"", class.trim_start());
+    }
+    if !style.is_empty() {
+        let _ = write!(html, " style=\"{}\"", style);
+    }
+    html.push('>');
+    for c in text.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            c => html.push(c),
+        }
+    }
+    html.push_str("</span>");
+}
+
+fn push_color(
+    class: &mut String,
+    style: &mut String,
+    color: Color,
+    role: ColorRole,
+) {
+    let (prefix, property) = match role {
+        ColorRole::Foreground => ("fg", "color"),
+        ColorRole::Background => ("bg", "background-color"),
+    };
+    let (r, g, b) = match color {
+        Color::TerminalDefault => return,
+        Color::Dark(base) => {
+            let _ = write!(class, " cursive-{}-{}", prefix, base_name(base));
+            return;
+        }
+        Color::Light(base) => {
+            let _ =
+                write!(class, " cursive-{}-light-{}", prefix, base_name(base));
+            return;
+        }
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::RgbLowRes(r, g, b) => (r * 51, g * 51, b * 51),
+    };
+    let _ = write!(style, "{}:#{:02x}{:02x}{:02x};", property, r, g, b);
+}
+
+fn base_name(color: BaseColor) -> &'static str {
+    match color {
+        BaseColor::Black => "black",
+        BaseColor::Red => "red",
+        BaseColor::Green => "green",
+        BaseColor::Yellow => "yellow",
+        BaseColor::Blue => "blue",
+        BaseColor::Magenta => "magenta",
+        BaseColor::Cyan => "cyan",
+        BaseColor::White => "white",
+    }
+}
+
+// Default stylesheet, with the xterm palette.
+fn stylesheet() -> String {
+    const DARK: [&str; 8] = [
+        "#000000", "#cd0000", "#00cd00", "#cdcd00", "#0000ee", "#cd00cd",
+        "#00cdcd", "#e5e5e5",
+    ];
+    const LIGHT: [&str; 8] = [
+        "#7f7f7f", "#ff0000", "#00ff00", "#ffff00", "#5c5cff", "#ff00ff",
+        "#00ffff", "#ffffff",
+    ];
+
+    let mut css = format!(
+        ".{} {{ white-space: pre; font-family: monospace; \
+         overflow: hidden; outline: none; \
+         color: {}; background-color: {}; }}\n",
+        SCREEN_CLASS, DARK[7], DARK[0]
+    );
+    for i in 0..8 {
+        let name = base_name(BaseColor::from(i));
+        for &(variant, palette) in &[("", &DARK), ("light-", &LIGHT)] {
+            let _ = writeln!(
+                css,
+                ".cursive-fg-{0}{1} {{ color: {2}; }}\n\
+                 .cursive-bg-{0}{1} {{ background-color: {2}; }}",
+                variant, name, palette[i as usize]
+            );
+        }
+    }
+    css.push_str(
+        ".cursive-dim { opacity: 0.6; }\n\
+         .cursive-bold { font-weight: bold; }\n\
+         .cursive-italic { font-style: italic; }\n\
+         .cursive-underline { text-decoration: underline; }\n\
+         .cursive-strikethrough { text-decoration: line-through; }\n\
+         .cursive-underline.cursive-strikethrough \
+         { text-decoration: underline line-through; }\n\
+         .cursive-blink { animation: cursive-blink 1s step-end infinite; }\n\
+         @keyframes cursive-blink { 50% { opacity: 0; } }\n",
+    );
+    css
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(
+        colors: ColorPair,
+        effects: EnumSet<Effect>,
+        text: &str,
+    ) -> String {
+        let mut html = String::new();
+        push_span(&mut html, colors, effects, text);
+        html
+    }
+
+    #[test]
+    fn key_events() {
+        assert_eq!(
+            key_event("a", false, false, false),
+            Some(Event::Char('a'))
+        );
+        assert_eq!(key_event("A", false, false, true), Some(Event::Char('A')));
+        assert_eq!(
+            key_event("A", true, false, true),
+            Some(Event::CtrlChar('a'))
+        );
+        assert_eq!(
+            key_event("x", false, true, false),
+            Some(Event::AltChar('x'))
+        );
+        assert_eq!(
+            key_event("Enter", false, false, false),
+            Some(Event::Key(Key::Enter))
+        );
+        assert_eq!(
+            key_event("ArrowUp", true, false, true),
+            Some(Event::CtrlShift(Key::Up))
+        );
+        assert_eq!(
+            key_event("F5", false, false, false),
+            Some(Event::Key(Key::F5))
+        );
+
+        // Modifier keys alone, or unknown keys.
+        assert_eq!(key_event("Shift", false, false, true), None);
+        assert_eq!(key_event("Fn", false, false, false), None);
+        assert_eq!(key_event("Unidentified", false, false, false), None);
+    }
+
+    #[test]
+    fn mouse_buttons() {
+        assert_eq!(mouse_button(0), MouseButton::Left);
+        assert_eq!(mouse_button(1), MouseButton::Middle);
+        assert_eq!(mouse_button(2), MouseButton::Right);
+        assert_eq!(mouse_button(3), MouseButton::Other);
+    }
+
+    #[test]
+    fn spans() {
+        let default = ColorPair {
+            front: Color::TerminalDefault,
+            back: Color::TerminalDefault,
+        };
+        assert_eq!(
+            span(default, EnumSet::new(), "a<b>&c"),
+            "<span>a&lt;b&gt;&amp;c</span>"
+        );
+
+        let colors = ColorPair {
+            front: Color::Dark(BaseColor::Red),
+            back: Color::Light(BaseColor::Blue),
+        };
+        assert_eq!(
+            span(colors, Effect::Bold.into(), "x"),
+            "<span class=\"cursive-fg-red cursive-bg-light-blue \
+             cursive-bold\">x</span>"
+        );
+
+        // Reverse swaps the colors.
+        assert_eq!(
+            span(colors, Effect::Reverse.into(), "x"),
+            "<span class=\"cursive-fg-light-blue cursive-bg-red\">x</span>"
+        );
+
+        let colors = ColorPair {
+            front: Color::Rgb(255, 128, 0),
+            back: Color::RgbLowRes(0, 1, 5),
+        };
+        assert_eq!(
+            span(colors, EnumSet::new(), "x"),
+            "<span style=\"color:#ff8000;background-color:#0033ff;\">\
+             x</span>"
+        );
+    }
+
+    #[test]
+    fn stylesheet_classes() {
+        let css = stylesheet();
+        assert!(css.contains(".cursive-screen {"));
+        for i in 0..8 {
+            let name = base_name(BaseColor::from(i));
+            assert!(css.contains(&format!(".cursive-fg-{} ", name)));
+            assert!(css.contains(&format!(".cursive-bg-light-{} ", name)));
+        }
+        assert!(css.contains(".cursive-bold "));
+    }
+}
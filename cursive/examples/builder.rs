@@ -0,0 +1,51 @@
+use cursive::builder::{Config, Context};
+use cursive::views::{Checkbox, Dialog, EditView, SelectView};
+
+// This example builds a settings dialog from a YAML description.
+//
+// Requires the `builder` feature.
+
+fn main() {
+    let config: Config =
+        serde_yaml::from_str(include_str!("assets/settings.yaml")).unwrap();
+
+    let mut context = Context::new();
+    context.set_variable("username", "ferris");
+    context.add_callback("quit", |s| s.quit());
+    context.add_callback("save", save);
+
+    let view = match context.build(&config) {
+        Ok(view) => view,
+        Err(e) => {
+            eprintln!("Invalid settings layout: {}", e);
+            return;
+        }
+    };
+
+    let mut siv = cursive::default();
+    siv.add_layer(view);
+    siv.run();
+}
+
+fn save(s: &mut cursive::Cursive) {
+    // Named views can be found with their concrete type.
+    let username = s
+        .call_on_name("username", |v: &mut EditView| v.get_content())
+        .unwrap();
+    let theme = s
+        .call_on_name("theme", |v: &mut SelectView| v.selection())
+        .unwrap();
+    let notifications = s
+        .call_on_name("notifications", |v: &mut Checkbox| v.is_checked())
+        .unwrap();
+
+    let theme = theme.map(|t| (*t).clone()).unwrap_or_default();
+    s.add_layer(
+        Dialog::text(format!(
+            "Username: {}\nTheme: {}\nNotifications: {}",
+            username, theme, notifications
+        ))
+        .title("Saved")
+        .button("Quit", |s| s.quit()),
+    );
+}
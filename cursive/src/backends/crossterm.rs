@@ -6,6 +6,7 @@
 
 use std::{
     cell::{Cell, RefCell, RefMut},
+    fmt,
     io::{self, BufWriter, Write},
     time::Duration,
 };
@@ -15,10 +16,9 @@ pub use crossterm;
 use crossterm::{
     cursor::{Hide, MoveTo, Show},
     event::{
-        poll, read, DisableMouseCapture, EnableMouseCapture, Event as CEvent,
-        KeyCode, KeyEvent as CKeyEvent, KeyModifiers,
-        MouseButton as CMouseButton, MouseEvent as CMouseEvent,
-        MouseEventKind,
+        poll, read, DisableMouseCapture, Event as CEvent, KeyCode,
+        KeyEvent as CKeyEvent, KeyModifiers, MouseButton as CMouseButton,
+        MouseEvent as CMouseEvent, MouseEventKind,
     },
     execute, queue,
     style::{
@@ -29,12 +29,14 @@ use crossterm::{
         self, disable_raw_mode, enable_raw_mode, Clear, ClearType,
         EnterAlternateScreen, LeaveAlternateScreen,
     },
+    Command,
 };
 
 use crate::{
     backend,
-    event::{Event, Key, MouseButton, MouseEvent},
-    theme, Vec2,
+    event::{Event, Key, Modifier, MouseButton, MouseEvent},
+    reexports::enumset::EnumSet,
+    theme, Vec2, With,
 };
 
 #[cfg(windows)]
@@ -48,6 +50,124 @@ pub struct Backend {
     current_style: Cell<theme::ColorPair>,
 
     stdout: RefCell<BufWriter<Stdout>>,
+
+    options: BackendOptions,
+
+    // Size given by the last resize event.
+    //
+    // On Windows, querying the size right after a resize can still return
+    // the previous one.
+    size: Cell<Option<Vec2>>,
+}
+
+/// Options for the crossterm backend.
+///
+/// Used with [`Backend::new_with_options`]. New options may be added later:
+/// start from [`BackendOptions::new`] and use the chainable setters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct BackendOptions {
+    /// Whether to capture mouse events.
+    ///
+    /// When disabled, the terminal handles the mouse itself, for example to
+    /// select text. Defaults to `true`.
+    pub mouse_capture: bool,
+
+    /// Whether to use extended mouse tracking.
+    ///
+    /// When enabled, the terminal also reports mouse motion when no button
+    /// is pressed (any-event tracking). Clicks, drags and the wheel are
+    /// reported either way. Only used with `mouse_capture`.
+    /// Defaults to `true`.
+    pub extended_mouse: bool,
+
+    /// Whether to enable bracketed paste mode.
+    ///
+    /// When enabled, the terminal wraps pasted text in markers instead of
+    /// sending it as if it was typed. The markers are dropped by the backend,
+    /// so the pasted text still reaches views as key events.
+    /// Defaults to `false`.
+    pub bracketed_paste: bool,
+}
+
+impl Default for BackendOptions {
+    fn default() -> Self {
+        BackendOptions {
+            mouse_capture: true,
+            extended_mouse: true,
+            bracketed_paste: false,
+        }
+    }
+}
+
+impl BackendOptions {
+    /// Returns the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether to capture mouse events.
+    #[must_use]
+    pub fn mouse_capture(self, mouse_capture: bool) -> Self {
+        self.with(|o| o.mouse_capture = mouse_capture)
+    }
+
+    /// Sets whether to use extended mouse tracking.
+    #[must_use]
+    pub fn extended_mouse(self, extended_mouse: bool) -> Self {
+        self.with(|o| o.extended_mouse = extended_mouse)
+    }
+
+    /// Sets whether to enable bracketed paste mode.
+    #[must_use]
+    pub fn bracketed_paste(self, bracketed_paste: bool) -> Self {
+        self.with(|o| o.bracketed_paste = bracketed_paste)
+    }
+}
+
+// Enables mouse capture, with or without any-event tracking.
+//
+// crossterm's `EnableMouseCapture` always asks for any-event tracking.
+struct EnableMouse {
+    extended: bool,
+}
+
+impl Command for EnableMouse {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        // Normal and button-event tracking: presses, releases and drags.
+        f.write_str("\x1B[?1000h\x1B[?1002h")?;
+        if self.extended {
+            // Any-event tracking: all motion events.
+            f.write_str("\x1B[?1003h")?;
+        }
+        // RXVT and SGR modes, for coordinates above 223.
+        f.write_str("\x1B[?1015h\x1B[?1006h")
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> crossterm::Result<()> {
+        crossterm::event::EnableMouseCapture.execute_winapi()
+    }
+
+    #[cfg(windows)]
+    fn is_ansi_code_supported(&self) -> bool {
+        crossterm::event::EnableMouseCapture.is_ansi_code_supported()
+    }
+}
+
+// Enables or disables bracketed paste mode.
+struct BracketedPaste(bool);
+
+impl Command for BracketedPaste {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        f.write_str(if self.0 { "\x1B[?2004h" } else { "\x1B[?2004l" })
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> crossterm::Result<()> {
+        // Only supported through VT sequences.
+        Ok(())
+    }
 }
 
 fn translate_button(button: CMouseButton) -> MouseButton {
@@ -81,73 +201,40 @@ fn translate_key(code: KeyCode) -> Key {
     }
 }
 
+fn translate_modifiers(modifiers: KeyModifiers) -> EnumSet<Modifier> {
+    let mut result = EnumSet::new();
+    if modifiers.contains(KeyModifiers::CONTROL) {
+        result.insert(Modifier::Ctrl);
+    }
+    if modifiers.contains(KeyModifiers::ALT) {
+        result.insert(Modifier::Alt);
+    }
+    if modifiers.contains(KeyModifiers::SHIFT) {
+        result.insert(Modifier::Shift);
+    }
+    result
+}
+
 fn translate_event(event: CKeyEvent) -> Event {
-    const CTRL_ALT: KeyModifiers = KeyModifiers::from_bits_truncate(
-        KeyModifiers::CONTROL.bits() | KeyModifiers::ALT.bits(),
-    );
-    const CTRL_SHIFT: KeyModifiers = KeyModifiers::from_bits_truncate(
-        KeyModifiers::CONTROL.bits() | KeyModifiers::SHIFT.bits(),
-    );
-    const ALT_SHIFT: KeyModifiers = KeyModifiers::from_bits_truncate(
-        KeyModifiers::ALT.bits() | KeyModifiers::SHIFT.bits(),
-    );
-
-    match event {
-        // Handle Char + modifier.
-        CKeyEvent {
-            modifiers: KeyModifiers::CONTROL,
-            code: KeyCode::Char(c),
-        } => Event::CtrlChar(c),
-        CKeyEvent {
-            modifiers: KeyModifiers::ALT,
-            code: KeyCode::Char(c),
-        } => Event::AltChar(c),
-        CKeyEvent {
-            modifiers: KeyModifiers::SHIFT,
-            code: KeyCode::Char(c),
-        } => Event::Char(c),
-        CKeyEvent {
-            code: KeyCode::Char(c),
-            ..
-        } => Event::Char(c),
-        // From now on, assume the key is never a `Char`.
+    let modifiers = translate_modifiers(event.modifiers);
+
+    match event.code {
+        // Windows reports AltGr as Ctrl+Alt, and some layouts need Shift on
+        // top of it: these combinations are regular characters.
+        KeyCode::Char(c)
+            if modifiers.contains(Modifier::Ctrl)
+                && modifiers.contains(Modifier::Alt) =>
+        {
+            Event::Char(c)
+        }
+        KeyCode::Char(c) => Event::from_char(c, modifiers),
 
         // Explicitly handle 'backtab' since crossterm does not sent SHIFT alongside the back tab key.
-        CKeyEvent {
-            code: KeyCode::BackTab,
-            ..
-        } => Event::Shift(Key::Tab),
-
-        // Handle key + multiple modifiers
-        CKeyEvent {
-            modifiers: CTRL_ALT,
-            code,
-        } => Event::CtrlAlt(translate_key(code)),
-        CKeyEvent {
-            modifiers: CTRL_SHIFT,
-            code,
-        } => Event::CtrlShift(translate_key(code)),
-        CKeyEvent {
-            modifiers: ALT_SHIFT,
-            code,
-        } => Event::AltShift(translate_key(code)),
-
-        // Handle key + single modifier
-        CKeyEvent {
-            modifiers: KeyModifiers::CONTROL,
-            code,
-        } => Event::Ctrl(translate_key(code)),
-        CKeyEvent {
-            modifiers: KeyModifiers::ALT,
-            code,
-        } => Event::Alt(translate_key(code)),
-        CKeyEvent {
-            modifiers: KeyModifiers::SHIFT,
-            code,
-        } => Event::Shift(translate_key(code)),
-
-        // All other keys.
-        CKeyEvent { code, .. } => Event::Key(translate_key(code)),
+        KeyCode::BackTab => {
+            Event::from_key(Key::Tab, modifiers | Modifier::Shift)
+        }
+
+        code => Event::from_key(translate_key(code), modifiers),
     }
 }
 
@@ -190,18 +277,44 @@ fn translate_color(base_color: theme::Color) -> Color {
 impl Backend {
     /// Creates a new crossterm backend.
     pub fn init() -> Result<Box<dyn backend::Backend>, crossterm::ErrorKind>
+    where
+        Self: Sized,
+    {
+        Self::new_with_options(BackendOptions::default())
+    }
+
+    /// Creates a new crossterm backend with the given options.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use cursive::backends::crossterm::{Backend, BackendOptions};
+    ///
+    /// let backend =
+    ///     Backend::new_with_options(BackendOptions::new().bracketed_paste(true))
+    ///         .unwrap();
+    /// ```
+    pub fn new_with_options(
+        options: BackendOptions,
+    ) -> Result<Box<dyn backend::Backend>, crossterm::ErrorKind>
     where
         Self: Sized,
     {
         enable_raw_mode()?;
 
         // TODO: Use the stdout we define down there
-        execute!(
-            io::stdout(),
-            EnterAlternateScreen,
-            EnableMouseCapture,
-            Hide
-        )?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        if options.mouse_capture {
+            execute!(
+                io::stdout(),
+                EnableMouse {
+                    extended: options.extended_mouse
+                }
+            )?;
+        }
+        if options.bracketed_paste {
+            execute!(io::stdout(), BracketedPaste(true))?;
+        }
 
         #[cfg(unix)]
         let stdout =
@@ -213,9 +326,30 @@ impl Backend {
         Ok(Box::new(Backend {
             current_style: Cell::new(theme::ColorPair::from_256colors(0, 0)),
             stdout,
+            options,
+            size: Cell::new(None),
         }))
     }
 
+    // Restores the terminal to its normal state.
+    fn leave(&self) {
+        // We have to execute the show cursor command at the `stdout`.
+        self.with_stdout(|stdout| {
+            if self.options.mouse_capture {
+                execute!(stdout, DisableMouseCapture)
+                    .expect("Can not disable mouse capture.");
+            }
+            if self.options.bracketed_paste {
+                execute!(stdout, BracketedPaste(false))
+                    .expect("Can not disable bracketed paste.");
+            }
+            execute!(stdout, LeaveAlternateScreen, Show)
+                .expect("Can not show cursor.")
+        });
+
+        disable_raw_mode().unwrap();
+    }
+
     fn apply_colors(&self, colors: theme::ColorPair) {
         self.with_stdout(|stdout| {
             queue!(
@@ -272,20 +406,17 @@ impl Backend {
                     offset: Vec2::zero(),
                 }
             }
-            CEvent::Resize(_, _) => Event::WindowResize,
+            CEvent::Resize(width, height) => {
+                self.size.set(Some((width, height).into()));
+                Event::WindowResize
+            }
         })
     }
 }
 
 impl Drop for Backend {
     fn drop(&mut self) {
-        // We have to execute the show cursor command at the `stdout`.
-        self.with_stdout(|stdout| {
-            execute!(stdout, LeaveAlternateScreen, DisableMouseCapture, Show)
-                .expect("Can not disable mouse capture or show cursor.")
-        });
-
-        disable_raw_mode().unwrap();
+        self.leave();
     }
 }
 
@@ -309,6 +440,34 @@ impl backend::Backend for Backend {
         });
     }
 
+    fn suspend(&mut self) {
+        self.leave();
+    }
+
+    fn resume(&mut self) {
+        enable_raw_mode().unwrap();
+
+        self.with_stdout(|stdout| {
+            execute!(stdout, EnterAlternateScreen, Hide)
+                .expect("Can not hide cursor.");
+            if self.options.mouse_capture {
+                execute!(
+                    stdout,
+                    EnableMouse {
+                        extended: self.options.extended_mouse
+                    }
+                )
+                .expect("Can not enable mouse capture.");
+            }
+            if self.options.bracketed_paste {
+                execute!(stdout, BracketedPaste(true))
+                    .expect("Can not enable bracketed paste.");
+            }
+        });
+        // The terminal may have been resized in the meantime.
+        self.size.set(None);
+    }
+
     fn refresh(&mut self) {
         self.with_stdout(|stdout| stdout.flush().unwrap());
     }
@@ -319,8 +478,10 @@ impl backend::Backend for Backend {
     }
 
     fn screen_size(&self) -> Vec2 {
-        let size = terminal::size().unwrap_or((1, 1));
-        Vec2::from(size)
+        self.size.get().unwrap_or_else(|| {
+            let size = terminal::size().unwrap_or((1, 1));
+            Vec2::from(size)
+        })
     }
 
     fn print_at(&self, pos: Vec2, text: &str) {
@@ -403,3 +564,107 @@ impl backend::Backend for Backend {
         "crossterm"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> Event {
+        translate_event(CKeyEvent { code, modifiers })
+    }
+
+    #[test]
+    fn translate_chars() {
+        assert_eq!(
+            key(KeyCode::Char('a'), KeyModifiers::NONE),
+            Event::Char('a')
+        );
+        assert_eq!(
+            key(KeyCode::Char('A'), KeyModifiers::SHIFT),
+            Event::Char('A')
+        );
+        assert_eq!(
+            key(KeyCode::Char('a'), KeyModifiers::CONTROL),
+            Event::CtrlChar('a')
+        );
+        assert_eq!(
+            key(KeyCode::Char('a'), KeyModifiers::ALT),
+            Event::AltChar('a')
+        );
+
+        // AltGr characters on Windows.
+        for &c in &['@', '{', '['] {
+            assert_eq!(
+                key(
+                    KeyCode::Char(c),
+                    KeyModifiers::CONTROL | KeyModifiers::ALT
+                ),
+                Event::Char(c)
+            );
+            assert_eq!(
+                key(KeyCode::Char(c), KeyModifiers::all()),
+                Event::Char(c)
+            );
+        }
+    }
+
+    #[test]
+    fn translate_ctrl_shift_chars() {
+        let event = key(
+            KeyCode::Char('A'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        );
+        assert_eq!(
+            event,
+            Event::from_char('A', Modifier::Ctrl | Modifier::Shift)
+        );
+        assert_eq!(event.modifiers(), Modifier::Ctrl | Modifier::Shift);
+    }
+
+    #[test]
+    fn translate_keys() {
+        assert_eq!(
+            key(KeyCode::Up, KeyModifiers::CONTROL | KeyModifiers::SHIFT),
+            Event::CtrlShift(Key::Up)
+        );
+        assert_eq!(
+            key(KeyCode::BackTab, KeyModifiers::NONE),
+            Event::Shift(Key::Tab)
+        );
+    }
+
+    fn ansi(command: impl Command) -> String {
+        let mut result = String::new();
+        command.write_ansi(&mut result).unwrap();
+        result
+    }
+
+    #[test]
+    fn mouse_tracking() {
+        let basic = ansi(EnableMouse { extended: false });
+        assert!(basic.contains("\x1B[?1002h"));
+        assert!(basic.contains("\x1B[?1006h"));
+        assert!(!basic.contains("\x1B[?1003h"));
+
+        let extended = ansi(EnableMouse { extended: true });
+        assert!(extended.contains("\x1B[?1003h"));
+
+        // Extended tracking matches what crossterm enables by default.
+        assert_eq!(extended, ansi(crossterm::event::EnableMouseCapture));
+    }
+
+    #[test]
+    fn bracketed_paste() {
+        assert_eq!(ansi(BracketedPaste(true)), "\x1B[?2004h");
+        assert_eq!(ansi(BracketedPaste(false)), "\x1B[?2004l");
+
+        let options = BackendOptions::default();
+        assert!(options.mouse_capture);
+        assert!(options.extended_mouse);
+        assert!(!options.bracketed_paste);
+
+        let options = BackendOptions::new().extended_mouse(false);
+        assert!(options.mouse_capture);
+        assert!(!options.extended_mouse);
+    }
+}
@@ -4,6 +4,7 @@
 #![cfg(any(feature = "ncurses-backend", feature = "pancurses-backend"))]
 
 use crate::event::{Event, Key};
+use crate::reexports::enumset::EnumSet;
 use crate::theme::{BaseColor, Color, ColorPair};
 use maplit::hashmap;
 
@@ -56,6 +57,7 @@ where
             "5" => Event::Ctrl(key),
             "6" => Event::CtrlShift(key),
             "7" => Event::CtrlAlt(key),
+            "8" => Event::from_key(key, EnumSet::all()),
             _ => continue,
         };
         target.insert(code, event);
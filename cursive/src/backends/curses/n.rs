@@ -338,6 +338,17 @@ impl backend::Backend for Backend {
         write_to_tty(format!("\x1B]0;{}\x07", title).as_bytes()).unwrap();
     }
 
+    fn suspend(&mut self) {
+        write_to_tty(b"\x1B[?1002l").unwrap();
+        ncurses::endwin();
+    }
+
+    fn resume(&mut self) {
+        // Refreshing the screen after `endwin` restores curses mode.
+        ncurses::refresh();
+        write_to_tty(b"\x1B[?1002h").unwrap();
+    }
+
     fn screen_size(&self) -> Vec2 {
         let mut x: i32 = 0;
         let mut y: i32 = 0;
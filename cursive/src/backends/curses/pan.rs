@@ -388,6 +388,20 @@ impl backend::Backend for Backend {
         stdout().flush().expect("could not flush stdout");
     }
 
+    fn suspend(&mut self) {
+        print!("\x1B[?1002l");
+        stdout().flush().expect("could not flush stdout");
+        pancurses::endwin();
+    }
+
+    fn resume(&mut self) {
+        // Refreshing the screen after `endwin` restores curses mode.
+        self.window.refresh();
+        #[cfg(not(windows))]
+        print!("\x1B[?1002h");
+        stdout().flush().expect("could not flush stdout");
+    }
+
     fn screen_size(&self) -> Vec2 {
         // Coordinates are reversed here
         let (y, x) = self.window.get_max_yx();
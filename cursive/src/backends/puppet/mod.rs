@@ -1,4 +1,11 @@
 //! Puppet backend
+//!
+//! This backend doesn't use a terminal: it records every frame in memory as
+//! an [`ObservedScreen`], and reads its input from a channel.
+//!
+//! It can be used to test an application, or to run it without a terminal.
+//! [`PuppetRunner`] drives a `Cursive` root on this backend: it sends
+//! events, runs the event loop step by step and keeps the last frame.
 use crossbeam_channel::{self, Receiver, Sender, TryRecvError};
 
 use self::observed::ObservedCell;
@@ -7,16 +14,19 @@ use self::observed::ObservedStyle;
 use crate::backend;
 use crate::event::Event;
 use crate::theme;
+use crate::utils::width::TextWidth;
 use crate::Vec2;
 use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
 
 pub mod observed;
 pub mod observed_screen_view;
+mod runner;
 mod static_values;
 
+pub use self::runner::PuppetRunner;
+
 use static_values::*;
 
 /// Puppet backend for testing.
@@ -115,7 +125,7 @@ impl backend::Backend for Backend {
                 Some(grapheme.to_string()),
             ));
 
-            for _ in 0..grapheme.width() - 1 {
+            for _ in 1..grapheme.width() {
                 offset += 1;
                 let spos = pos + Vec2::new(idx + offset, 0);
                 screen[spos] =
@@ -2,6 +2,7 @@
 use crate::reexports::enumset::EnumSet;
 use crate::theme::ColorPair;
 use crate::theme::Effect;
+use crate::utils::width::TextWidth;
 use crate::Vec2;
 use std::ops::Index;
 use std::ops::IndexMut;
@@ -9,7 +10,6 @@ use std::rc::Rc;
 use std::string::ToString;
 use std::{fmt, fmt::Display, fmt::Formatter};
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
 
 /// Style of observed cell
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -154,16 +154,20 @@ impl ObservedScreen {
     fn unflatten_index(&self, index: usize) -> Vec2 {
         assert!(index < self.contents.len());
 
-        Vec2::new(index / self.size.x, index % self.size.x)
+        Vec2::new(index % self.size.x, index / self.size.x)
     }
 
-    /// Sets all cells to empty cells with given style
+    /// Sets all cells to blank cells with given style
+    ///
+    /// Each cell holds a space, like on a real terminal, so that cleared
+    /// cells show up in [`ObservedScreen::line`] and in pieces printed as
+    /// strings. Cells holding `None` are the continuation of a wide grapheme.
     pub fn clear(&mut self, style: &Rc<ObservedStyle>) {
         for idx in 0..self.contents.len() {
             self.contents[idx] = Some(ObservedCell::new(
                 self.unflatten_index(idx),
                 style.clone(),
-                None,
+                Some(" ".to_string()),
             ))
         }
     }
@@ -173,6 +177,25 @@ impl ObservedScreen {
         self.size
     }
 
+    /// Returns the text shown on line `y`.
+    ///
+    /// Empty cells are shown as spaces.
+    pub fn line(&self, y: usize) -> String {
+        self.piece(Vec2::new(0, y), Vec2::new(self.size.x, y + 1))
+            .as_strings()
+            .remove(0)
+    }
+
+    /// Returns the cell at the given position, if it was drawn.
+    pub fn cell(&self, pos: Vec2) -> Option<&ObservedCell> {
+        self[pos].as_ref()
+    }
+
+    /// Returns `true` if the given text is visible somewhere on screen.
+    pub fn contains(&self, pattern: &str) -> bool {
+        !self.find_occurences(pattern).is_empty()
+    }
+
     /// Returns a rectangular subset of observed screen.
     pub fn piece(&self, min: Vec2, max: Vec2) -> ObservedPiece {
         ObservedPiece::new(self, min, max)
@@ -604,4 +627,19 @@ mod tests {
         assert_eq!(expanded_right.size(), Vec2::new(10, 1));
         assert_eq!(expanded_right.to_string(), "▸ <root> e");
     }
+
+    #[test]
+    fn test_clear_and_lines() {
+        let style = Rc::new(DEFAULT_OBSERVED_STYLE.clone());
+        let mut os = ObservedScreen::new(Vec2::new(4, 2));
+        os.clear(&style);
+        os[Vec2::new(1, 1)] =
+            Some(ObservedCell::new(Vec2::new(1, 1), style, Some("x".into())));
+
+        assert_eq!(os.line(0), "    ");
+        assert_eq!(os.line(1), " x  ");
+        assert_eq!(os.cell(Vec2::new(3, 0)).unwrap().pos, Vec2::new(3, 0));
+        assert!(os.contains("x "));
+        assert!(!os.contains("y"));
+    }
 }
@@ -0,0 +1,145 @@
+use crossbeam_channel::{Receiver, Sender};
+
+use super::observed::ObservedScreen;
+use super::Backend;
+use crate::event::{Event, Key};
+use crate::{Cursive, CursiveRunner, Vec2};
+
+/// Drives a `Cursive` root on the puppet backend.
+///
+/// This runs the application without a terminal: events are injected with
+/// [`send`](PuppetRunner::send), and the last rendered frame can be
+/// inspected with [`screen`](PuppetRunner::screen).
+///
+/// It implements `DerefMut<Target=Cursive>`, so views and callbacks can be
+/// added just like on a regular `Cursive` object.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive::event::Key;
+/// use cursive::views::{Dialog, TextView};
+///
+/// let mut siv = cursive::puppet((40, 10));
+///
+/// siv.add_layer(
+///     Dialog::around(TextView::new("Hello!")).button("Quit", |s| s.quit()),
+/// );
+/// siv.refresh();
+/// assert!(siv.screen().contains("Hello!"));
+///
+/// siv.send_key(Key::Enter);
+/// assert!(!siv.is_running());
+/// ```
+pub struct PuppetRunner {
+    runner: CursiveRunner<Cursive>,
+    input: Sender<Option<Event>>,
+    frames: Receiver<ObservedScreen>,
+    screen: ObservedScreen,
+}
+
+impl std::ops::Deref for PuppetRunner {
+    type Target = Cursive;
+
+    fn deref(&self) -> &Cursive {
+        &self.runner
+    }
+}
+
+impl std::ops::DerefMut for PuppetRunner {
+    fn deref_mut(&mut self) -> &mut Cursive {
+        &mut self.runner
+    }
+}
+
+impl PuppetRunner {
+    /// Runs the given `Cursive` root on a puppet screen of the given size.
+    pub fn new<S: Into<Vec2>>(siv: Cursive, size: S) -> Self {
+        let size = size.into();
+        let backend = Backend::init(Some(size));
+        let input = backend.input();
+        let frames = backend.stream();
+
+        let mut runner = PuppetRunner {
+            runner: CursiveRunner::new(siv, backend),
+            input,
+            frames,
+            screen: ObservedScreen::new(size),
+        };
+        runner.refresh();
+
+        runner
+    }
+
+    /// Sends an event to the application, and processes it.
+    ///
+    /// The screen is redrawn afterwards.
+    pub fn send<E: Into<Event>>(&mut self, event: E) {
+        self.input.send(Some(event.into())).unwrap();
+        self.step();
+    }
+
+    /// Sends a key press to the application.
+    pub fn send_key(&mut self, key: Key) {
+        self.send(Event::Key(key));
+    }
+
+    /// Types the given text, one character at a time.
+    pub fn send_str(&mut self, text: &str) {
+        for c in text.chars() {
+            self.send(Event::Char(c));
+        }
+    }
+
+    /// Sends all the given events, one after the other.
+    pub fn send_all<I>(&mut self, events: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<Event>,
+    {
+        for event in events {
+            self.send(event);
+        }
+    }
+
+    /// Runs a single step of the event loop.
+    ///
+    /// This processes pending events and callbacks, like the ones sent
+    /// through [`Cursive::cb_sink`].
+    ///
+    /// Returns `true` if an event or callback was processed.
+    pub fn step(&mut self) -> bool {
+        let received_something = self.runner.process_events();
+        if received_something {
+            self.refresh();
+        }
+        received_something
+    }
+
+    /// Lays out and draws the views, updating the screen.
+    pub fn refresh(&mut self) {
+        self.runner.refresh();
+        self.update_screen();
+    }
+
+    /// Returns the last frame drawn.
+    pub fn screen(&self) -> &ObservedScreen {
+        &self.screen
+    }
+
+    /// Returns the underlying `CursiveRunner`.
+    pub fn runner_mut(&mut self) -> &mut CursiveRunner<Cursive> {
+        &mut self.runner
+    }
+
+    /// Stops driving the application, and returns the `Cursive` root.
+    pub fn into_inner(self) -> Cursive {
+        self.runner.into_inner()
+    }
+
+    fn update_screen(&mut self) {
+        while let Ok(screen) = self.frames.try_recv() {
+            self.screen = screen;
+        }
+    }
+}
@@ -29,6 +29,10 @@ use std::io::{BufWriter, Write};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
+// Same escape sequences as `MouseTerminal`, used when suspending.
+const MOUSE_ENABLE: &str = "\x1B[?1000h\x1B[?1002h\x1B[?1015h\x1B[?1006h";
+const MOUSE_DISABLE: &str = "\x1B[?1006l\x1B[?1015l\x1B[?1002l\x1B[?1000l";
+
 /// Backend using termion
 pub struct Backend {
     // Do we want to make this generic on the writer?
@@ -272,6 +276,34 @@ impl backend::Backend for Backend {
         write!(self.terminal.get_mut(), "\x1B]0;{}\x07", title).unwrap();
     }
 
+    fn suspend(&mut self) {
+        let terminal = self.terminal.get_mut();
+        write!(
+            terminal,
+            "{}{}{}",
+            MOUSE_DISABLE,
+            termion::cursor::Show,
+            termion::screen::ToMainScreen
+        )
+        .unwrap();
+        terminal.flush().unwrap();
+        terminal.suspend_raw_mode().unwrap();
+    }
+
+    fn resume(&mut self) {
+        let terminal = self.terminal.get_mut();
+        terminal.activate_raw_mode().unwrap();
+        write!(
+            terminal,
+            "{}{}{}",
+            termion::screen::ToAlternateScreen,
+            MOUSE_ENABLE,
+            termion::cursor::Hide
+        )
+        .unwrap();
+        terminal.flush().unwrap();
+    }
+
     fn set_color(&self, color: theme::ColorPair) -> theme::ColorPair {
         let current_style = self.current_style.get();
 
@@ -136,3 +136,13 @@ pub fn blt() -> CursiveRunnable {
 pub fn dummy() -> CursiveRunnable {
     CursiveRunnable::dummy()
 }
+
+/// Creates a new Cursive root running on a headless puppet backend.
+///
+/// No terminal is used: events are sent programmatically, and each frame is
+/// recorded in memory. This is mostly useful for tests.
+///
+/// See [`backends::puppet::PuppetRunner`].
+pub fn puppet<S: Into<Vec2>>(size: S) -> backends::puppet::PuppetRunner {
+    backends::puppet::PuppetRunner::new(Cursive::new(), size)
+}